@@ -1,6 +1,6 @@
 #![feature(test)]
 
-#[cfg(feature = "x86_ssse3")]
+#[cfg(any(feature = "x86_ssse3", feature = "x86_avx2", feature = "x86_avx512"))]
 use std::arch::x86_64::__m128i;
 
 extern crate test;
@@ -11,15 +11,25 @@ use rand::{
     distributions::{IndependentSample, Range},
     Rng,
 };
-#[cfg(feature = "x86_ssse3")]
-use stream_vbyte::x86::{self, Ssse3};
+#[cfg(any(
+    feature = "x86_ssse3",
+    feature = "x86_sse41",
+    feature = "x86_avx2",
+    feature = "x86_avx512"
+))]
+use stream_vbyte::x86;
 use stream_vbyte::{
+    blocked::{encode_blocked, BlockedReader, DEFAULT_BLOCK_LEN},
     decode::{
-        cursor::DecodeCursor, decode, DecodeQuadSink, DecodeSingleSink, Decoder, WriteQuadToSlice,
+        cursor::DecodeCursor, decode, slice::StreamVbyteSlice, DecodeQuadSink, DecodeSingleSink,
+        Decoder, WriteQuadToSlice,
     },
     decode_quad_scalar,
-    encode::{encode, Encoder},
+    encode::{encode, encode_iter, encode_to_vec, stream::StreamEncoder, Encoder},
     scalar::Scalar,
+    svb0124::{self, Decoder0124, Encoder0124},
+    svb16::{self, Decoder16, Encoder16},
+    svb64::{self, Decoder64, Encoder64},
 };
 use test::Bencher;
 
@@ -51,6 +61,26 @@ fn encode_sse41_rand_1k(b: &mut Bencher) {
     );
 }
 
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn encode_ssse3_rand_1k(b: &mut Bencher) {
+    do_encode_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1000),
+        x86::Ssse3,
+    );
+}
+
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn encode_avx512_rand_1k(b: &mut Bencher) {
+    do_encode_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1000),
+        x86::Avx512Encoder,
+    );
+}
+
 #[cfg(feature = "x86_sse41")]
 #[bench]
 fn encode_sse41_rand_1m(b: &mut Bencher) {
@@ -61,6 +91,26 @@ fn encode_sse41_rand_1m(b: &mut Bencher) {
     );
 }
 
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn encode_ssse3_rand_1m(b: &mut Bencher) {
+    do_encode_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1000 * 1000),
+        x86::Ssse3,
+    );
+}
+
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn encode_avx512_rand_1m(b: &mut Bencher) {
+    do_encode_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1000 * 1000),
+        x86::Avx512Encoder,
+    );
+}
+
 #[bench]
 fn encode_scalar_zeros_1k(b: &mut Bencher) {
     do_encode_bench(b, iter::repeat(0).take(1000), Scalar);
@@ -77,12 +127,36 @@ fn encode_sse41_zeros_1k(b: &mut Bencher) {
     do_encode_bench(b, iter::repeat(0).take(1000), x86::Sse41);
 }
 
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn encode_ssse3_zeros_1k(b: &mut Bencher) {
+    do_encode_bench(b, iter::repeat(0).take(1000), x86::Ssse3);
+}
+
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn encode_avx512_zeros_1k(b: &mut Bencher) {
+    do_encode_bench(b, iter::repeat(0).take(1000), x86::Avx512Encoder);
+}
+
 #[cfg(feature = "x86_sse41")]
 #[bench]
 fn encode_sse41_zeros_1m(b: &mut Bencher) {
     do_encode_bench(b, iter::repeat(0).take(1_000_000), x86::Sse41);
 }
 
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn encode_ssse3_zeros_1m(b: &mut Bencher) {
+    do_encode_bench(b, iter::repeat(0).take(1_000_000), x86::Ssse3);
+}
+
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn encode_avx512_zeros_1m(b: &mut Bencher) {
+    do_encode_bench(b, iter::repeat(0).take(1_000_000), x86::Avx512Encoder);
+}
+
 #[bench]
 fn decode_scalar_rand_1k(b: &mut Bencher) {
     do_decode_bench(
@@ -102,6 +176,26 @@ fn decode_ssse3_rand_1k(b: &mut Bencher) {
     );
 }
 
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn decode_avx512_rand_1k(b: &mut Bencher) {
+    do_decode_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1000),
+        x86::Avx512Decoder,
+    );
+}
+
+#[cfg(feature = "x86_avx2")]
+#[bench]
+fn decode_avx2_rand_1k(b: &mut Bencher) {
+    do_decode_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1000),
+        x86::Avx2,
+    );
+}
+
 #[bench]
 fn decode_scalar_rand_1m(b: &mut Bencher) {
     do_decode_bench(
@@ -121,6 +215,26 @@ fn decode_ssse3_rand_1m(b: &mut Bencher) {
     );
 }
 
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn decode_avx512_rand_1m(b: &mut Bencher) {
+    do_decode_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1_000_000),
+        x86::Avx512Decoder,
+    );
+}
+
+#[cfg(feature = "x86_avx2")]
+#[bench]
+fn decode_avx2_rand_1m(b: &mut Bencher) {
+    do_decode_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1_000_000),
+        x86::Avx2,
+    );
+}
+
 #[bench]
 fn decode_cursor_slice_scalar_rand_1k(b: &mut Bencher) {
     do_decode_cursor_slice_bench(
@@ -140,6 +254,26 @@ fn decode_cursor_slice_ssse3_rand_1k(b: &mut Bencher) {
     );
 }
 
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn decode_cursor_slice_avx512_rand_1k(b: &mut Bencher) {
+    do_decode_cursor_slice_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1000),
+        x86::Avx512Decoder,
+    );
+}
+
+#[cfg(feature = "x86_avx2")]
+#[bench]
+fn decode_cursor_slice_avx2_rand_1k(b: &mut Bencher) {
+    do_decode_cursor_slice_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1000),
+        x86::Avx2,
+    );
+}
+
 #[bench]
 fn decode_cursor_slice_scalar_rand_1m(b: &mut Bencher) {
     do_decode_cursor_slice_bench(
@@ -159,6 +293,26 @@ fn decode_cursor_slice_ssse3_rand_1m(b: &mut Bencher) {
     );
 }
 
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn decode_cursor_slice_avx512_rand_1m(b: &mut Bencher) {
+    do_decode_cursor_slice_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1_000_000),
+        x86::Avx512Decoder,
+    );
+}
+
+#[cfg(feature = "x86_avx2")]
+#[bench]
+fn decode_cursor_slice_avx2_rand_1m(b: &mut Bencher) {
+    do_decode_cursor_slice_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1_000_000),
+        x86::Avx2,
+    );
+}
+
 #[bench]
 fn decode_cursor_sink_no_op_scalar_rand_1m(b: &mut Bencher) {
     do_decode_cursor_sink_no_op_bench(
@@ -178,6 +332,26 @@ fn decode_cursor_sink_no_op_ssse3_rand_1m(b: &mut Bencher) {
     );
 }
 
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn decode_cursor_sink_no_op_avx512_rand_1m(b: &mut Bencher) {
+    do_decode_cursor_sink_no_op_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1_000_000),
+        x86::Avx512Decoder,
+    );
+}
+
+#[cfg(feature = "x86_avx2")]
+#[bench]
+fn decode_cursor_sink_no_op_avx2_rand_1m(b: &mut Bencher) {
+    do_decode_cursor_sink_no_op_bench(
+        b,
+        RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(1_000_000),
+        x86::Avx2,
+    );
+}
+
 #[bench]
 fn decode_scalar_zeros_1k(b: &mut Bencher) {
     do_decode_bench(b, iter::repeat(0).take(1000), Scalar);
@@ -189,6 +363,18 @@ fn decode_ssse3_zeros_1k(b: &mut Bencher) {
     do_decode_bench(b, iter::repeat(0).take(1000), x86::Ssse3);
 }
 
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn decode_avx512_zeros_1k(b: &mut Bencher) {
+    do_decode_bench(b, iter::repeat(0).take(1000), x86::Avx512Decoder);
+}
+
+#[cfg(feature = "x86_avx2")]
+#[bench]
+fn decode_avx2_zeros_1k(b: &mut Bencher) {
+    do_decode_bench(b, iter::repeat(0).take(1000), x86::Avx2);
+}
+
 #[bench]
 fn decode_scalar_zeros_1m(b: &mut Bencher) {
     do_decode_bench(b, iter::repeat(0).take(1_000_000), Scalar);
@@ -200,6 +386,64 @@ fn decode_ssse3_zeros_1m(b: &mut Bencher) {
     do_decode_bench(b, iter::repeat(0).take(1_000_000), x86::Ssse3);
 }
 
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn decode_avx512_zeros_1m(b: &mut Bencher) {
+    do_decode_bench(b, iter::repeat(0).take(1_000_000), x86::Avx512Decoder);
+}
+
+#[cfg(feature = "x86_avx2")]
+#[bench]
+fn decode_avx2_zeros_1m(b: &mut Bencher) {
+    do_decode_bench(b, iter::repeat(0).take(1_000_000), x86::Avx2);
+}
+
+#[bench]
+fn encode_scalar_short_lists(b: &mut Bencher) {
+    do_encode_short_lists_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_sse41")]
+#[bench]
+fn encode_sse41_short_lists(b: &mut Bencher) {
+    do_encode_short_lists_bench(b, x86::Sse41);
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn encode_ssse3_short_lists(b: &mut Bencher) {
+    do_encode_short_lists_bench(b, x86::Ssse3);
+}
+
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn encode_avx512_short_lists(b: &mut Bencher) {
+    do_encode_short_lists_bench(b, x86::Avx512Encoder);
+}
+
+#[bench]
+fn decode_scalar_short_lists(b: &mut Bencher) {
+    do_decode_short_lists_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn decode_ssse3_short_lists(b: &mut Bencher) {
+    do_decode_short_lists_bench(b, x86::Ssse3);
+}
+
+#[cfg(feature = "x86_avx2")]
+#[bench]
+fn decode_avx2_short_lists(b: &mut Bencher) {
+    do_decode_short_lists_bench(b, x86::Avx2);
+}
+
+#[cfg(feature = "x86_avx512")]
+#[bench]
+fn decode_avx512_short_lists(b: &mut Bencher) {
+    do_decode_short_lists_bench(b, x86::Avx512Decoder);
+}
+
 #[bench]
 fn skip_all_1m(b: &mut Bencher) {
     let mut nums: Vec<u32> = Vec::new();
@@ -220,6 +464,117 @@ fn skip_all_1m(b: &mut Bencher) {
     });
 }
 
+#[bench]
+fn blocked_get_1m(b: &mut Bencher) {
+    let nums: Vec<u32> = RandomVarintEncodedLengthIter::new(rand::weak_rng())
+        .take(1_000_000)
+        .collect();
+    let mut encoded = Vec::new();
+    encode_blocked::<Scalar>(&nums, DEFAULT_BLOCK_LEN, &mut encoded);
+
+    let reader = BlockedReader::new(&encoded).unwrap();
+    let mut rng = rand::weak_rng();
+    b.iter(|| reader.get(rng.gen_range(0, nums.len())).unwrap());
+}
+
+#[bench]
+fn slice_get_1m(b: &mut Bencher) {
+    do_slice_get_bench(b, None);
+}
+
+#[bench]
+fn slice_get_sampled_index_1m(b: &mut Bencher) {
+    do_slice_get_bench(b, Some(1024));
+}
+
+#[bench]
+fn encode_iter_scalar_rand_1m(b: &mut Bencher) {
+    do_encode_iter_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_sse41")]
+#[bench]
+fn encode_iter_sse41_rand_1m(b: &mut Bencher) {
+    do_encode_iter_bench(b, x86::Sse41);
+}
+
+#[bench]
+fn stream_encoder_push_scalar_rand_1m(b: &mut Bencher) {
+    do_stream_encoder_push_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_sse41")]
+#[bench]
+fn stream_encoder_push_sse41_rand_1m(b: &mut Bencher) {
+    do_stream_encoder_push_bench(b, x86::Sse41);
+}
+
+#[bench]
+fn svb16_encode_scalar_rand_1m(b: &mut Bencher) {
+    do_svb16_encode_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn svb16_encode_ssse3_rand_1m(b: &mut Bencher) {
+    do_svb16_encode_bench(b, x86::Ssse3);
+}
+
+#[bench]
+fn svb16_decode_scalar_rand_1m(b: &mut Bencher) {
+    do_svb16_decode_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn svb16_decode_ssse3_rand_1m(b: &mut Bencher) {
+    do_svb16_decode_bench(b, x86::Ssse3);
+}
+
+#[bench]
+fn svb64_encode_scalar_rand_1m(b: &mut Bencher) {
+    do_svb64_encode_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn svb64_encode_ssse3_rand_1m(b: &mut Bencher) {
+    do_svb64_encode_bench(b, x86::Ssse3);
+}
+
+#[bench]
+fn svb64_decode_scalar_rand_1m(b: &mut Bencher) {
+    do_svb64_decode_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn svb64_decode_ssse3_rand_1m(b: &mut Bencher) {
+    do_svb64_decode_bench(b, x86::Ssse3);
+}
+
+#[bench]
+fn svb0124_encode_scalar_rand_1m(b: &mut Bencher) {
+    do_svb0124_encode_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn svb0124_encode_ssse3_rand_1m(b: &mut Bencher) {
+    do_svb0124_encode_bench(b, x86::Ssse3);
+}
+
+#[bench]
+fn svb0124_decode_scalar_rand_1m(b: &mut Bencher) {
+    do_svb0124_decode_bench(b, Scalar);
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[bench]
+fn svb0124_decode_ssse3_rand_1m(b: &mut Bencher) {
+    do_svb0124_decode_bench(b, x86::Ssse3);
+}
+
 fn do_encode_bench<I: Iterator<Item = u32>, E: Encoder>(b: &mut Bencher, iter: I, _encoder: E) {
     let mut nums: Vec<u32> = Vec::new();
     let mut encoded = Vec::new();
@@ -235,6 +590,120 @@ fn do_encode_bench<I: Iterator<Item = u32>, E: Encoder>(b: &mut Bencher, iter: I
     });
 }
 
+// Encode numbers from an iterator that doesn't know how many there are, so
+// control bytes have to be moved into place at the end
+fn do_encode_iter_bench<E: Encoder>(b: &mut Bencher, _encoder: E) {
+    let nums: Vec<u32> = RandomVarintEncodedLengthIter::new(rand::weak_rng())
+        .take(1_000_000)
+        .collect();
+    let mut encoded = Vec::with_capacity(nums.len() * 5);
+
+    b.iter(|| {
+        encoded.clear();
+        encode_iter::<E, _>(nums.iter().cloned().filter(|_| true), &mut encoded)
+    });
+}
+
+fn do_stream_encoder_push_bench<E: Encoder>(b: &mut Bencher, _encoder: E) {
+    let nums: Vec<u32> = RandomVarintEncodedLengthIter::new(rand::weak_rng())
+        .take(1_000_000)
+        .collect();
+
+    b.iter(|| {
+        let mut encoder = StreamEncoder::<E>::new();
+        for &num in nums.iter() {
+            encoder.push(num);
+        }
+        encoder.finish()
+    });
+}
+
+// half 1-byte and half 2-byte numbers
+fn random_u16s(count: usize) -> Vec<u16> {
+    let mut rng = rand::weak_rng();
+    (0..count)
+        .map(|_| {
+            if rng.gen() {
+                rng.gen_range(0x100, 0x1_0000) as u16
+            } else {
+                rng.gen_range(0, 0x100) as u16
+            }
+        })
+        .collect()
+}
+
+fn do_svb16_encode_bench<E: Encoder16>(b: &mut Bencher, _encoder: E) {
+    let nums = random_u16s(1_000_000);
+    let mut encoded = vec![0; svb16::max_encoded_len(nums.len())];
+
+    b.iter(|| svb16::encode::<E>(&nums, &mut encoded));
+}
+
+fn do_svb16_decode_bench<D: Decoder16>(b: &mut Bencher, _decoder: D) {
+    let nums = random_u16s(1_000_000);
+    let encoded = svb16::encode_to_vec::<Scalar>(&nums);
+    let mut decoded = vec![0; nums.len()];
+
+    b.iter(|| svb16::decode::<D>(&encoded, nums.len(), &mut decoded));
+}
+
+// an even mix of the 4 encoded lengths
+fn random_u64s(count: usize) -> Vec<u64> {
+    let mut rng = rand::weak_rng();
+    (0..count)
+        .map(|_| match rng.gen_range(0, 4) {
+            0 => rng.gen_range(0, 0x100),
+            1 => rng.gen_range(0x100, 0x1_0000),
+            2 => rng.gen_range(0x1_0000, 0x1_0000_0000),
+            _ => rng.gen_range(0x1_0000_0000, u64::MAX),
+        })
+        .collect()
+}
+
+fn do_svb64_encode_bench<E: Encoder64>(b: &mut Bencher, _encoder: E) {
+    let nums = random_u64s(1_000_000);
+    let mut encoded = vec![0; svb64::max_encoded_len(nums.len())];
+
+    b.iter(|| svb64::encode::<E>(&nums, &mut encoded));
+}
+
+fn do_svb64_decode_bench<D: Decoder64>(b: &mut Bencher, _decoder: D) {
+    let nums = random_u64s(1_000_000);
+    let encoded = svb64::encode_to_vec::<Scalar>(&nums);
+    let mut decoded = vec![0; nums.len()];
+
+    b.iter(|| svb64::decode::<D>(&encoded, nums.len(), &mut decoded));
+}
+
+// mostly zeros, like a sparse vector of counts
+fn random_sparse_u32s(count: usize) -> Vec<u32> {
+    let mut rng = rand::weak_rng();
+    (0..count)
+        .map(|_| {
+            if rng.gen_weighted_bool(4) {
+                rng.gen_range(1, 0x1_0000)
+            } else {
+                0
+            }
+        })
+        .collect()
+}
+
+fn do_svb0124_encode_bench<E: Encoder0124>(b: &mut Bencher, _encoder: E) {
+    let nums = random_sparse_u32s(1_000_000);
+    let mut encoded = vec![0; svb0124::max_encoded_len(nums.len())];
+
+    b.iter(|| svb0124::encode_0124::<E>(&nums, &mut encoded));
+}
+
+fn do_svb0124_decode_bench<D: Decoder0124>(b: &mut Bencher, _decoder: D) {
+    let nums = random_sparse_u32s(1_000_000);
+    let encoded = svb0124::encode_0124_to_vec::<Scalar>(&nums);
+    let mut decoded = vec![0; nums.len()];
+
+    b.iter(|| svb0124::decode_0124::<D>(&encoded, nums.len(), &mut decoded));
+}
+
 // take a decoder param to save us some typing -- type inference won't work if
 // you only specify some of the generic types
 fn do_decode_bench<I, D>(b: &mut Bencher, iter: I, _decoder: D)
@@ -306,6 +775,60 @@ fn do_decode_cursor_sink_no_op_bench<I: Iterator<Item = u32>, D: Decoder>(
     })
 }
 
+// Encode lists of every length from 1 to 100, where the last few quads of
+// each list are a big part of the work
+fn do_encode_short_lists_bench<E: Encoder>(b: &mut Bencher, _encoder: E) {
+    let nums: Vec<u32> = RandomVarintEncodedLengthIter::new(rand::weak_rng())
+        .take(100)
+        .collect();
+    let mut encoded = vec![0; nums.len() * 5];
+
+    b.iter(|| {
+        for len in 1..=nums.len() {
+            let _ = encode::<E>(&nums[0..len], &mut encoded);
+        }
+    });
+}
+
+// Decode lists of every length from 1 to 100, where the last few quads of
+// each list are a big part of the work
+fn do_decode_short_lists_bench<D: Decoder + WriteQuadToSlice>(b: &mut Bencher, _decoder: D) {
+    let nums: Vec<u32> = RandomVarintEncodedLengthIter::new(rand::weak_rng())
+        .take(100)
+        .collect();
+    let mut decoded = vec![0; nums.len()];
+
+    let lists: Vec<Vec<u8>> = (1..=nums.len())
+        .map(|len| {
+            let mut encoded = vec![0; len * 5];
+            let bytes_written = encode::<Scalar>(&nums[0..len], &mut encoded);
+            encoded.truncate(bytes_written);
+            encoded
+        })
+        .collect();
+
+    b.iter(|| {
+        for (i, encoded) in lists.iter().enumerate() {
+            decode::<D>(encoded, i + 1, &mut decoded);
+        }
+    });
+}
+
+fn do_slice_get_bench(b: &mut Bencher, sample_interval: Option<usize>) {
+    let nums: Vec<u32> = RandomVarintEncodedLengthIter::new(rand::weak_rng())
+        .take(1_000_000)
+        .collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+
+    let mut slice = StreamVbyteSlice::new(&encoded, nums.len());
+    if let Some(interval) = sample_interval {
+        slice = slice.with_sampled_index(interval);
+    }
+
+    let mut rng = rand::weak_rng();
+    b.iter(|| slice.get(rng.gen_range(0, nums.len())).unwrap());
+}
+
 // copied from tests because it's handy here too
 struct RandomVarintEncodedLengthIter<R: Rng> {
     ranges: [Range<u32>; 4],
@@ -348,6 +871,16 @@ impl DecodeSingleSink for NoOpSink {
 decode_quad_scalar!(NoOpSink);
 
 #[cfg(feature = "x86_ssse3")]
-impl DecodeQuadSink<Ssse3> for NoOpSink {
+impl DecodeQuadSink<x86::Ssse3> for NoOpSink {
+    fn on_quad(&mut self, _quad: __m128i, _nums_decoded: usize) {}
+}
+
+#[cfg(feature = "x86_avx2")]
+impl DecodeQuadSink<x86::Avx2> for NoOpSink {
+    fn on_quad(&mut self, _quad: __m128i, _nums_decoded: usize) {}
+}
+
+#[cfg(feature = "x86_avx512")]
+impl DecodeQuadSink<x86::Avx512Decoder> for NoOpSink {
     fn on_quad(&mut self, _quad: __m128i, _nums_decoded: usize) {}
 }
@@ -0,0 +1,48 @@
+//! Rejects features that need a newer Rust than the compiler in use, with a
+//! clearer message than the errors compiling them would give.
+
+use std::{env, process::Command};
+
+/// The oldest Rust whose `std::arch` has the AVX-512 intrinsics and target
+/// features that `x86_avx512` uses
+const AVX512_MIN_MINOR_VERSION: u32 = 89;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_X86_AVX512").is_none() {
+        return;
+    }
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+
+    // if the version can't be found, leave it to the compiler to complain
+    let minor = match version.as_deref().and_then(rustc_minor_version) {
+        Some(minor) => minor,
+        None => return,
+    };
+
+    if minor < AVX512_MIN_MINOR_VERSION {
+        panic!(
+            "The x86_avx512 feature needs Rust 1.{} or newer, but this is {}",
+            AVX512_MIN_MINOR_VERSION,
+            version.unwrap_or_default().trim()
+        );
+    }
+}
+
+/// The minor version from `rustc --version` output like
+/// `rustc 1.89.0 (29483883e 2025-08-04)`.
+fn rustc_minor_version(version: &str) -> Option<u32> {
+    let mut parts = version.split_whitespace().nth(1)?.split('.');
+    if parts.next()? != "1" {
+        return None;
+    }
+
+    parts.next()?.parse().ok()
+}
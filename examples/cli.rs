@@ -1,33 +1,47 @@
 use std::io::{BufRead, Read, Write};
 
 use clap::{App, Arg, SubCommand};
-use stream_vbyte::{decode::decode, encode::encode, scalar::Scalar};
+use stream_vbyte::{
+    decode::decode_to_vec,
+    encode::encode_to_vec,
+    frame::{decode_framed, encode_framed, FrameOptions},
+    scalar::Scalar,
+};
 
 fn main() {
     let matches = App::new("stream-vbyte cli")
-        .subcommand(SubCommand::with_name("enc").about("Encode numbers"))
+        .subcommand(
+            SubCommand::with_name("enc").about("Encode numbers").arg(
+                Arg::with_name("framed")
+                    .help("write a frame that records the count, so dec doesn't need --count")
+                    .short("f")
+                    .long("framed"),
+            ),
+        )
         .subcommand(
             SubCommand::with_name("dec").about("Decode numbers").arg(
                 Arg::with_name("count")
-                    .help("count of numbers in encoded input")
+                    .help("count of numbers in encoded input; if omitted, input must be a frame")
                     .short("c")
                     .long("count")
-                    .takes_value(true)
-                    .required(true),
+                    .takes_value(true),
             ),
         )
         .get_matches();
 
     match matches.subcommand_name() {
-        Some("enc") => run_encode(),
+        Some("enc") => run_encode(
+            matches
+                .subcommand_matches("enc")
+                .unwrap()
+                .is_present("framed"),
+        ),
         Some("dec") => {
-            let count: usize = matches
+            let count: Option<usize> = matches
                 .subcommand_matches("dec")
                 .unwrap()
                 .value_of("count")
-                .unwrap()
-                .parse()
-                .expect("count must be an int");
+                .map(|c| c.parse().expect("count must be an int"));
 
             run_decode(count);
         }
@@ -35,7 +49,7 @@ fn main() {
     }
 }
 
-fn run_encode() {
+fn run_encode(framed: bool) {
     let stdin = std::io::stdin();
     let stdin_handle = stdin.lock();
 
@@ -45,20 +59,24 @@ fn run_encode() {
         .map(|s| s.parse().expect("Each line must be a u32"))
         .collect();
 
-    let mut encoded = Vec::new();
-    encoded.resize(nums.len() * 5, 0);
-    let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+    let encoded = if framed {
+        let mut encoded = Vec::new();
+        encode_framed::<Scalar>(&nums, FrameOptions::default(), &mut encoded);
+        encoded
+    } else {
+        encode_to_vec::<Scalar>(&nums)
+    };
 
     let stdout = std::io::stdout();
     let mut stdout_handle = stdout.lock();
     stdout_handle
-        .write_all(&encoded[0..encoded_len])
+        .write_all(&encoded)
         .expect("Should be able to write to stdout");
 
     eprintln!("Encoded {} numbers", nums.len());
 }
 
-fn run_decode(count: usize) {
+fn run_decode(count: Option<usize>) {
     let stdin = std::io::stdin();
     let mut stdin_handle = stdin.lock();
 
@@ -67,9 +85,10 @@ fn run_decode(count: usize) {
         .read_to_end(&mut encoded)
         .expect("Should be able to read stdin");
 
-    let mut decoded = Vec::new();
-    decoded.resize(count, 0);
-    decode::<Scalar>(&encoded, count, &mut decoded);
+    let decoded = match count {
+        Some(count) => decode_to_vec::<Scalar>(&encoded, count),
+        None => decode_framed::<Scalar>(&encoded).expect("Input must be a valid frame"),
+    };
 
     for d in &decoded {
         println!("{}", d);
@@ -47,7 +47,7 @@ fn main() {
     println!();
 
     println!("#[cfg_attr(rustfmt, rustfmt_skip)]");
-    println!("#[cfg(feature = \"x86_ssse3\")]");
+    println!("#[cfg(any(feature = \"x86_ssse3\", feature = \"x86_avx2\"))]");
     println!("pub const X86_SSSE3_DECODE_SHUFFLE_TABLE: &'static [[u8; 16]; 256] = &[");
 
     for b in 0..256 {
@@ -116,9 +116,289 @@ fn main() {
         );
     }
 
+    println!("];");
+    println!();
+
+    // 16-bit variant tables, where each control byte has a bit per number for 8
+    // numbers
+
+    println!("#[rustfmt::skip]");
+    println!("#[cfg(any(feature = \"x86_ssse3\", feature = \"aarch64_neon\"))]");
+    println!("pub const SVB16_DECODE_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[");
+
+    for b in 0..256 {
+        let byte = b as u8;
+
+        let lengths = lengths_for_svb16_control_byte(byte);
+
+        // map encoded numbers to 8 adjacent u16s
+        let mut shuffle_bytes = Vec::new();
+        let mut start_of_encoded_num = 0;
+        for &len in lengths.iter() {
+            shuffle_bytes.push(start_of_encoded_num as u8);
+            // zero the high byte of 1-byte numbers
+            shuffle_bytes.push(if len == 2 {
+                (start_of_encoded_num + 1) as u8
+            } else {
+                0x80
+            });
+            start_of_encoded_num += len;
+        }
+
+        assert_eq!(16, shuffle_bytes.len());
+
+        print_svb16_shuffle(byte, &lengths, &shuffle_bytes);
+    }
+
+    println!("];");
+    println!();
+
+    println!("#[rustfmt::skip]");
+    println!(
+        "#[cfg(any(feature = \"x86_ssse3\", feature = \"x86_sse41\", feature = \"aarch64_neon\"))]"
+    );
+    println!("pub const SVB16_ENCODE_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[");
+
+    for b in 0..256 {
+        let byte = b as u8;
+
+        let lengths = lengths_for_svb16_control_byte(byte);
+
+        // map 8 adjacent u16s to encoded numbers
+        let mut shuffle_bytes = Vec::new();
+        for (i, &len) in lengths.iter().enumerate() {
+            push_encode_u32_shuffle_bytes(2 * i, len, &mut shuffle_bytes);
+        }
+
+        // fill the rest with bytes with the high bit set so output will be zero'd
+        shuffle_bytes.resize(16, 128);
+
+        print_svb16_shuffle(byte, &lengths, &shuffle_bytes);
+    }
+
+    println!("];");
+    println!();
+
+    // 64-bit variant tables, where each number has a 2-bit code for 1, 2, 4 or 8
+    // bytes
+
+    println!("#[rustfmt::skip]");
+    println!("pub const SVB64_DECODE_LENGTH_PER_QUAD_TABLE: &[u8; 256] = &[");
+
+    for b in 0..256 {
+        let byte = b as u8;
+
+        let lengths = lengths_for_svb64_control_byte(byte);
+
+        println!(
+            "    {}, // {} = 0x{:X} = 0b{:08b}, lengths {} {} {} {}",
+            lengths.iter().sum::<usize>(),
+            byte,
+            byte,
+            byte,
+            lengths[0],
+            lengths[1],
+            lengths[2],
+            lengths[3]
+        );
+    }
+
+    println!("];");
+    println!();
+
+    // SIMD code handles a pair of numbers at a time, described by 4 bits of a
+    // control byte
+
+    println!("#[rustfmt::skip]");
+    println!("#[cfg(any(feature = \"x86_ssse3\", feature = \"aarch64_neon\"))]");
+    println!("pub const SVB64_DECODE_PAIR_SHUFFLE_TABLE: &[[u8; 16]; 16] = &[");
+
+    for code in 0..16 {
+        let lengths = lengths_for_svb64_control_byte(code);
+
+        // map encoded numbers to 2 adjacent u64s
+        let mut shuffle_bytes = Vec::new();
+        push_decode_u64_shuffle_bytes(0, lengths[0], &mut shuffle_bytes);
+        push_decode_u64_shuffle_bytes(lengths[0], lengths[1], &mut shuffle_bytes);
+
+        assert_eq!(16, shuffle_bytes.len());
+
+        print_svb64_pair_shuffle(code, &lengths, &shuffle_bytes);
+    }
+
+    println!("];");
+    println!();
+
+    println!("#[rustfmt::skip]");
+    println!(
+        "#[cfg(any(feature = \"x86_ssse3\", feature = \"x86_sse41\", feature = \"aarch64_neon\"))]"
+    );
+    println!("pub const SVB64_ENCODE_PAIR_SHUFFLE_TABLE: &[[u8; 16]; 16] = &[");
+
+    for code in 0..16 {
+        let lengths = lengths_for_svb64_control_byte(code);
+
+        // map 2 adjacent u64s to encoded numbers
+        let mut shuffle_bytes = Vec::new();
+        push_encode_u32_shuffle_bytes(0, lengths[0], &mut shuffle_bytes);
+        push_encode_u32_shuffle_bytes(8, lengths[1], &mut shuffle_bytes);
+
+        // fill the rest with bytes with the high bit set so output will be zero'd
+        shuffle_bytes.resize(16, 128);
+
+        print_svb64_pair_shuffle(code, &lengths, &shuffle_bytes);
+    }
+
+    println!("];");
+    println!();
+
+    // 0124 variant tables, where each number has a 2-bit code for 0, 1, 2 or 4
+    // bytes
+
+    println!("#[rustfmt::skip]");
+    println!("pub const DECODE_0124_LENGTH_PER_QUAD_TABLE: &[u8; 256] = &[");
+
+    for b in 0..256 {
+        let byte = b as u8;
+
+        let lengths = lengths_for_0124_control_byte(byte);
+
+        println!(
+            "    {}, // {} = 0x{:X} = 0b{:08b}, lengths {} {} {} {}",
+            lengths.iter().sum::<usize>(),
+            byte,
+            byte,
+            byte,
+            lengths[0],
+            lengths[1],
+            lengths[2],
+            lengths[3]
+        );
+    }
+
+    println!("];");
+    println!();
+
+    println!("#[rustfmt::skip]");
+    println!("#[cfg(any(feature = \"x86_ssse3\", feature = \"aarch64_neon\"))]");
+    println!("pub const DECODE_0124_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[");
+
+    for b in 0..256 {
+        let byte = b as u8;
+
+        let lengths = lengths_for_0124_control_byte(byte);
+
+        // map encoded numbers to 4 adjacent u32s, where a 0-length number is all
+        // zero bytes
+        let mut shuffle_bytes = Vec::new();
+        let mut start_of_encoded_num = 0;
+        for &len in lengths.iter() {
+            push_decode_u32_shuffle_bytes(start_of_encoded_num, len, &mut shuffle_bytes);
+            start_of_encoded_num += len;
+        }
+
+        assert_eq!(16, shuffle_bytes.len());
+
+        print_0124_shuffle(byte, &lengths, &shuffle_bytes);
+    }
+
+    println!("];");
+    println!();
+
+    println!("#[rustfmt::skip]");
+    println!(
+        "#[cfg(any(feature = \"x86_ssse3\", feature = \"x86_sse41\", feature = \"aarch64_neon\"))]"
+    );
+    println!("pub const ENCODE_0124_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[");
+
+    for b in 0..256 {
+        let byte = b as u8;
+
+        let lengths = lengths_for_0124_control_byte(byte);
+
+        // map 4 adjacent u32s to encoded numbers
+        let mut shuffle_bytes = Vec::new();
+        for (i, &len) in lengths.iter().enumerate() {
+            push_encode_u32_shuffle_bytes(4 * i, len, &mut shuffle_bytes);
+        }
+
+        // fill the rest with bytes with the high bit set so output will be zero'd
+        shuffle_bytes.resize(16, 128);
+
+        print_0124_shuffle(byte, &lengths, &shuffle_bytes);
+    }
+
     println!("];");
 }
 
+fn print_0124_shuffle(byte: u8, lengths: &[usize; 4], shuffle_bytes: &[u8]) {
+    println!(
+        "    // {} = 0x{:X} = 0b{:08b}, lengths {} {} {} {}",
+        byte, byte, byte, lengths[0], lengths[1], lengths[2], lengths[3]
+    );
+    println!(
+        "    [{}],",
+        shuffle_bytes
+            .iter()
+            .map(|b| format!("{:4 }", b))
+            .collect::<Vec<String>>()
+            .join(","),
+    );
+}
+
+fn print_svb64_pair_shuffle(code: u8, lengths: &[usize; 4], shuffle_bytes: &[u8]) {
+    println!(
+        "    // {} = 0x{:X} = 0b{:04b}, lengths {} {}",
+        code, code, code, lengths[0], lengths[1]
+    );
+    println!(
+        "    [{}],",
+        shuffle_bytes
+            .iter()
+            .map(|b| format!("{:4 }", b))
+            .collect::<Vec<String>>()
+            .join(","),
+    );
+}
+
+/// Push 8 shuffle bytes into a SSSE3 PSHUFB mask
+fn push_decode_u64_shuffle_bytes(
+    start_of_encoded_num: usize,
+    encoded_length: usize,
+    shuffle_bytes: &mut Vec<u8>,
+) {
+    for l in 0..encoded_length {
+        shuffle_bytes.push((start_of_encoded_num + l) as u8);
+    }
+
+    // zero out any unused most significant bytes in the u64
+    for _ in 0..(8 - encoded_length) {
+        shuffle_bytes.push(0x80);
+    }
+}
+
+fn print_svb16_shuffle(byte: u8, lengths: &[usize; 8], shuffle_bytes: &[u8]) {
+    println!(
+        "    // {} = 0x{:X} = 0b{:08b}, lengths {}",
+        byte,
+        byte,
+        byte,
+        lengths
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<String>>()
+            .join(" "),
+    );
+    println!(
+        "    [{}],",
+        shuffle_bytes
+            .iter()
+            .map(|b| format!("{:4 }", b))
+            .collect::<Vec<String>>()
+            .join(","),
+    );
+}
+
 /// Push 4 shuffle bytes into a SSSE3 PSHUFB mask
 fn push_decode_u32_shuffle_bytes(
     start_of_encoded_num: usize,
@@ -157,3 +437,35 @@ fn lengths_for_control_byte(byte: u8) -> (usize, usize, usize, usize) {
 
     (len0, len1, len2, len3)
 }
+
+/// Each bit is the length of one number, low bit first: 0 for 1 byte, 1 for 2
+fn lengths_for_svb16_control_byte(byte: u8) -> [usize; 8] {
+    let mut lengths = [0; 8];
+    for (i, len) in lengths.iter_mut().enumerate() {
+        *len = ((byte >> i) & 1) as usize + 1;
+    }
+
+    lengths
+}
+
+/// Each 2-bit code is the length of one number, low bits first: 1, 2, 4 or 8
+/// bytes
+fn lengths_for_svb64_control_byte(byte: u8) -> [usize; 4] {
+    let mut lengths = [0; 4];
+    for (i, len) in lengths.iter_mut().enumerate() {
+        *len = 1 << ((byte >> (2 * i)) & 0x03);
+    }
+
+    lengths
+}
+
+/// Each 2-bit code is the length of one number, low bits first: 0, 1, 2 or 4
+/// bytes
+fn lengths_for_0124_control_byte(byte: u8) -> [usize; 4] {
+    let mut lengths = [0; 4];
+    for (i, len) in lengths.iter_mut().enumerate() {
+        *len = [0, 1, 2, 4][((byte >> (2 * i)) & 0x03) as usize];
+    }
+
+    lengths
+}
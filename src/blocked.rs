@@ -0,0 +1,485 @@
+//! A container that splits numbers into fixed size blocks, each encoded
+//! separately, with an index of where each block ends so that any number can
+//! be found without walking all the control bytes before it.
+//!
+//! `DecodeCursor::skip()` has to add up the lengths in every control byte it
+//! skips, so reaching a position far into a long stream takes time
+//! proportional to that position. With blocks, it only takes a lookup in the
+//! index and a skip within one block.
+//!
+//! A blocked container is:
+//!
+//! - the 4 magic bytes `SVBB`
+//! - a format version byte, currently 1
+//! - the count of numbers, as an unsigned LEB128 varint
+//! - the count of numbers per block, as an unsigned LEB128 varint. This is a
+//!   nonzero multiple of 4, and every block but the last has exactly that many
+//!   numbers.
+//! - the index: for each block, the offset of the end of that block from the
+//!   start of the first block, as 8 little-endian bytes
+//! - the blocks, each one the control bytes and encoded numbers just like
+//!   `encode::encode()` writes them
+//!
+//! # Examples
+//!
+//! ```
+//! use stream_vbyte::{
+//!     blocked::{encode_blocked, BlockedReader},
+//!     scalar::Scalar
+//! };
+//!
+//! let nums: Vec<u32> = (0..100_000).map(|i| i * 3).collect();
+//!
+//! let mut encoded = Vec::new();
+//! encode_blocked::<Scalar>(&nums, 1024, &mut encoded);
+//!
+//! let mut reader = BlockedReader::new(&encoded).unwrap();
+//! assert_eq!(nums.len(), reader.len());
+//! assert_eq!(Ok(90_000 * 3), reader.get(90_000));
+//!
+//! let mut decoded = vec![0; 1000];
+//! reader.seek(50_000).unwrap();
+//! assert_eq!(Ok(1000), reader.decode_slice::<Scalar>(&mut decoded));
+//! assert_eq!(&nums[50_000..51_000], &decoded[..]);
+//! ```
+
+use std::{cmp, error, fmt};
+
+use crate::{
+    cumulative_encoded_len,
+    decode::{cursor::DecodeCursor, decode_num_scalar, DecodeError, Decoder, WriteQuadToSlice},
+    encode::{encode_append, Encoder},
+    encoded_shape,
+    varint::{read_varint, write_varint, VarintError},
+};
+
+const MAGIC: [u8; 4] = *b"SVBB";
+const VERSION: u8 = 1;
+
+const INDEX_ENTRY_LEN: usize = 8;
+
+/// A reasonable number of numbers per block: small enough that skipping
+/// within a block is quick, and large enough that the index is a tiny
+/// fraction of the size of the encoded numbers.
+pub const DEFAULT_BLOCK_LEN: usize = 1 << 16;
+
+/// Encode `input` as a blocked container with `block_len` numbers per block,
+/// appending it to `output`.
+///
+/// Returns the number of bytes appended to `output`.
+///
+/// # Panics
+///
+/// Panics if `block_len` is 0 or not a multiple of 4.
+pub fn encode_blocked<E: Encoder>(input: &[u32], block_len: usize, output: &mut Vec<u8>) -> usize {
+    assert!(
+        block_len > 0 && block_len % 4 == 0,
+        "Block length must be a nonzero multiple of 4"
+    );
+
+    let start = output.len();
+
+    output.extend_from_slice(&MAGIC);
+    output.push(VERSION);
+    write_varint(input.len() as u64, output);
+    write_varint(block_len as u64, output);
+
+    // leave room for the index, which is filled in as the blocks are written
+    let index_start = output.len();
+    let block_count = block_count(input.len(), block_len);
+    output.resize(index_start + block_count * INDEX_ENTRY_LEN, 0);
+
+    let blocks_start = output.len();
+    for (i, block) in input.chunks(block_len).enumerate() {
+        encode_append::<E>(block, output);
+
+        let block_end = (output.len() - blocks_start) as u64;
+        let entry_start = index_start + i * INDEX_ENTRY_LEN;
+        output[entry_start..(entry_start + INDEX_ENTRY_LEN)]
+            .copy_from_slice(&block_end.to_le_bytes());
+    }
+
+    output.len() - start
+}
+
+/// Reads numbers from a blocked container written by `encode_blocked()`,
+/// either in order like `DecodeCursor` or by position.
+#[derive(Debug)]
+pub struct BlockedReader<'a> {
+    count: usize,
+    block_len: usize,
+    header_len: usize,
+    index: &'a [u8],
+    blocks: &'a [u8],
+    /// The block `cursor` is reading
+    block: usize,
+    cursor: DecodeCursor<'a>,
+    /// The position of the next number to decode
+    position: usize,
+}
+
+impl<'a> BlockedReader<'a> {
+    /// Create a new reader for the blocked container at the start of `input`,
+    /// positioned at the first number.
+    ///
+    /// This checks the header and index, but the encoded numbers in each block
+    /// are only checked when the block is first read from, so that opening a
+    /// large container is quick.
+    pub fn new(input: &'a [u8]) -> Result<BlockedReader<'a>, BlockedError> {
+        if input.len() < MAGIC.len() + 1 {
+            return Err(BlockedError::TruncatedHeader);
+        }
+
+        if input[0..MAGIC.len()] != MAGIC {
+            return Err(BlockedError::BadMagic);
+        }
+
+        let version = input[MAGIC.len()];
+        if version != VERSION {
+            return Err(BlockedError::UnsupportedVersion(version));
+        }
+
+        let mut offset = MAGIC.len() + 1;
+        let (count, count_len) = read_header_varint(&input[offset..])?;
+        offset += count_len;
+        let (block_len, block_len_len) = read_header_varint(&input[offset..])?;
+        offset += block_len_len;
+
+        if block_len == 0 || block_len % 4 != 0 {
+            return Err(BlockedError::InvalidBlockLen(block_len));
+        }
+
+        let index_len = block_count(count, block_len)
+            .checked_mul(INDEX_ENTRY_LEN)
+            .filter(|&len| len <= input.len() - offset)
+            .ok_or(BlockedError::TruncatedIndex)?;
+        let index = &input[offset..(offset + index_len)];
+        let after_index = &input[(offset + index_len)..];
+
+        // Blocks can't end before they start, or after the end of the input, so
+        // that looking up any block is just slicing.
+        let mut prev_end = 0;
+        for (block, entry) in index.chunks_exact(INDEX_ENTRY_LEN).enumerate() {
+            let end = read_index_entry(entry);
+            if end < prev_end || end > after_index.len() as u64 {
+                return Err(BlockedError::InvalidIndex { block });
+            }
+            prev_end = end;
+        }
+
+        let mut reader = BlockedReader {
+            count,
+            block_len,
+            header_len: offset,
+            index,
+            blocks: &after_index[0..(prev_end as usize)],
+            block: 0,
+            cursor: DecodeCursor::new(&[], 0),
+            position: 0,
+        };
+
+        if count > 0 {
+            reader.cursor = reader.open_block(0)?;
+        }
+
+        Ok(reader)
+    }
+
+    /// The count of numbers in the container.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// True iff there are no numbers in the container.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The count of numbers in each block, except maybe the last one.
+    pub fn block_len(&self) -> usize {
+        self.block_len
+    }
+
+    /// The position of the next number to be decoded.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The length of the container: the header, the index, and all the
+    /// blocks.
+    pub fn container_len(&self) -> usize {
+        // the index and blocks are contiguous, and immediately follow the header
+        self.header_len + self.index.len() + self.blocks.len()
+    }
+
+    /// Move to `position`, so that the next number decoded is the one at that
+    /// position.
+    ///
+    /// This only has to skip within the block containing `position`, rather
+    /// than all of the numbers before it.
+    pub fn seek(&mut self, position: usize) -> Result<(), BlockedError> {
+        if position > self.count {
+            return Err(BlockedError::OutOfBounds {
+                index: position,
+                len: self.count,
+            });
+        }
+
+        if position == self.count {
+            // nothing left to decode, so there's no block to open
+            self.cursor = DecodeCursor::new(&[], 0);
+            self.block = self.block_count();
+            self.position = position;
+            return Ok(());
+        }
+
+        let block = position / self.block_len;
+        let mut cursor = self.open_block(block)?;
+        cursor.skip(position % self.block_len);
+
+        self.block = block;
+        self.cursor = cursor;
+        self.position = position;
+
+        Ok(())
+    }
+
+    /// Decode the number at position `index`, without changing the position
+    /// of the reader.
+    ///
+    /// This only checks that the quad holding `index` is within its block, so
+    /// a malformed block can still be read from up to where it goes wrong.
+    pub fn get(&self, index: usize) -> Result<u32, BlockedError> {
+        if index >= self.count {
+            return Err(BlockedError::OutOfBounds {
+                index,
+                len: self.count,
+            });
+        }
+
+        // Rather than checking the whole block like open_block() does, only read
+        // the control bytes up to this number's, and only check that its quad is
+        // in bounds.
+        let block = index / self.block_len;
+        let (input, count) = self.block_input(block);
+        let to_error = |error| BlockedError::Decode { block, error };
+
+        let control_bytes_len = encoded_shape(count).control_bytes_len;
+        if input.len() < control_bytes_len {
+            return Err(to_error(DecodeError::TruncatedControlBytes {
+                required: control_bytes_len,
+                actual: input.len(),
+            }));
+        }
+        let (control_bytes, encoded_nums) = input.split_at(control_bytes_len);
+
+        let index_in_block = index % self.block_len;
+        let quad = index_in_block / 4;
+        let control_byte = control_bytes[quad];
+        let lane_len = |lane: usize| ((control_byte >> (lane * 2)) & 0x03) as usize + 1;
+        let lanes_len = |lanes: usize| -> usize { (0..lanes).map(lane_len).sum() };
+
+        let quad_start = cumulative_encoded_len(&control_bytes[0..quad]);
+        let required = quad_start + lanes_len(cmp::min(4, count - quad * 4));
+        if encoded_nums.len() < required {
+            return Err(to_error(DecodeError::TruncatedData {
+                required,
+                actual: encoded_nums.len(),
+            }));
+        }
+
+        let lane = index_in_block % 4;
+        let num_start = quad_start + lanes_len(lane);
+
+        Ok(decode_num_scalar(
+            lane_len(lane),
+            &encoded_nums[num_start..],
+        ))
+    }
+
+    /// Decode into the `output` buffer from the current position, continuing
+    /// on into later blocks as needed.
+    ///
+    /// As with `DecodeCursor::decode_slice()`, `output` must have room for at
+    /// least 4 numbers, or for all of the remaining numbers if there are
+    /// fewer than that.
+    ///
+    /// Returns the number of numbers decoded, which may be less than the size
+    /// of the buffer, and is 0 once everything has been decoded. If the next
+    /// block is malformed, the numbers before it are returned first, and the
+    /// error on the following call.
+    pub fn decode_slice<D: Decoder + WriteQuadToSlice>(
+        &mut self,
+        output: &mut [u32],
+    ) -> Result<usize, BlockedError> {
+        let mut nums_decoded = 0;
+
+        while nums_decoded < output.len() {
+            if !self.cursor.has_more() {
+                if self.block + 1 >= self.block_count() {
+                    break;
+                }
+
+                match self.open_block(self.block + 1) {
+                    Ok(cursor) => {
+                        self.cursor = cursor;
+                        self.block += 1;
+                    }
+                    Err(e) if nums_decoded == 0 => return Err(e),
+                    // hand back what's already decoded, and report the error next
+                    // time
+                    Err(_) => break,
+                }
+            }
+
+            let decoded = self.cursor.decode_slice::<D>(&mut output[nums_decoded..]);
+            if decoded == 0 {
+                // not enough room left in output for a quad
+                break;
+            }
+
+            nums_decoded += decoded;
+            self.position += decoded;
+        }
+
+        Ok(nums_decoded)
+    }
+
+    /// Returns true iff there are more numbers to be decoded.
+    pub fn has_more(&self) -> bool {
+        self.position < self.count
+    }
+
+    fn block_count(&self) -> usize {
+        block_count(self.count, self.block_len)
+    }
+
+    /// Create a cursor for `block`, after checking that the block is long
+    /// enough for its numbers.
+    fn open_block(&self, block: usize) -> Result<DecodeCursor<'a>, BlockedError> {
+        let (input, count) = self.block_input(block);
+
+        DecodeCursor::try_new(input, count).map_err(|error| BlockedError::Decode { block, error })
+    }
+
+    /// The encoded bytes of `block`, and the count of numbers in it.
+    fn block_input(&self, block: usize) -> (&'a [u8], usize) {
+        let start = if block == 0 {
+            0
+        } else {
+            self.block_end(block - 1)
+        };
+        let end = self.block_end(block);
+        let count = cmp::min(self.block_len, self.count - block * self.block_len);
+
+        (&self.blocks[start..end], count)
+    }
+
+    fn block_end(&self, block: usize) -> usize {
+        let entry_start = block * INDEX_ENTRY_LEN;
+        // checked to fit in `blocks` in new()
+        read_index_entry(&self.index[entry_start..(entry_start + INDEX_ENTRY_LEN)]) as usize
+    }
+}
+
+/// Errors that can occur when reading a blocked container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BlockedError {
+    /// The input doesn't start with the blocked container magic bytes.
+    BadMagic,
+    /// The container's format version isn't one this version of the crate can
+    /// read.
+    UnsupportedVersion(u8),
+    /// The input ends partway through the header.
+    TruncatedHeader,
+    /// The count of numbers or block length is too large, or its varint is
+    /// too long.
+    CountOverflow,
+    /// The block length is 0 or not a multiple of 4.
+    InvalidBlockLen(usize),
+    /// The input ends partway through the index.
+    TruncatedIndex,
+    /// The index says a block ends before the previous one, or after the end
+    /// of the input.
+    InvalidIndex {
+        /// The block with the bad index entry
+        block: usize,
+    },
+    /// The encoded numbers in a block are malformed.
+    Decode {
+        /// The malformed block
+        block: usize,
+        /// What's wrong with it
+        error: DecodeError,
+    },
+    /// A position past the end of the container was requested.
+    OutOfBounds {
+        /// The requested position
+        index: usize,
+        /// The count of numbers in the container
+        len: usize,
+    },
+}
+
+impl fmt::Display for BlockedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BlockedError::BadMagic => {
+                write!(f, "Input does not start with a blocked container header")
+            }
+            BlockedError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported blocked container version {}", version)
+            }
+            BlockedError::TruncatedHeader => {
+                write!(f, "Input ends partway through blocked container header")
+            }
+            BlockedError::CountOverflow => write!(f, "Blocked container count is too large"),
+            BlockedError::InvalidBlockLen(block_len) => write!(
+                f,
+                "Block length {} is not a nonzero multiple of 4",
+                block_len
+            ),
+            BlockedError::TruncatedIndex => {
+                write!(f, "Input ends partway through blocked container index")
+            }
+            BlockedError::InvalidIndex { block } => {
+                write!(f, "Index entry for block {} is out of range", block)
+            }
+            BlockedError::Decode { block, error } => {
+                write!(f, "Invalid contents in block {}: {}", block, error)
+            }
+            BlockedError::OutOfBounds { index, len } => {
+                write!(f, "Position {} is out of bounds for {} numbers", index, len)
+            }
+        }
+    }
+}
+
+impl error::Error for BlockedError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            BlockedError::Decode { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+fn block_count(count: usize, block_len: usize) -> usize {
+    // round up without overflowing for huge counts
+    count / block_len + if count % block_len == 0 { 0 } else { 1 }
+}
+
+fn read_index_entry(entry: &[u8]) -> u64 {
+    let mut bytes = [0; INDEX_ENTRY_LEN];
+    bytes.copy_from_slice(entry);
+
+    u64::from_le_bytes(bytes)
+}
+
+fn read_header_varint(input: &[u8]) -> Result<(usize, usize), BlockedError> {
+    read_varint(input).map_err(|e| match e {
+        VarintError::Truncated => BlockedError::TruncatedHeader,
+        VarintError::Overflow => BlockedError::CountOverflow,
+    })
+}
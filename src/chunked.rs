@@ -0,0 +1,380 @@
+//! Encode numbers straight to an `io::Write`, such as a file or a socket, as a
+//! series of self-delimiting chunks, and read them back from an `io::BufRead`.
+//!
+//! `encode()` needs room for the whole encoding in memory. `StreamVbyteWriter`
+//! instead encodes a chunk of numbers at a time and writes each one out before
+//! starting the next, so it only ever holds one chunk's worth of numbers and
+//! their encoding.
+//!
+//! A chunked stream is any number of chunks, each one:
+//!
+//! - the count of numbers in the chunk, as an unsigned LEB128 varint. This is
+//!   never 0.
+//! - the control bytes and encoded numbers, just like `encode::encode()` writes
+//!   them
+//!
+//! There is no header or trailer, so streams can be concatenated, and the end
+//! of the stream is simply the end of the input.
+//!
+//! # Examples
+//!
+//! ```
+//! use stream_vbyte::{
+//!     chunked::{StreamVbyteReader, StreamVbyteWriter},
+//!     scalar::Scalar
+//! };
+//!
+//! let mut writer = StreamVbyteWriter::<_, Scalar>::with_chunk_len(Vec::new(), 1000);
+//! for i in 0..2500 {
+//!     writer.write_num(i * 3).unwrap();
+//! }
+//! let (file, totals) = writer.finish().unwrap();
+//! assert_eq!(2500, totals.nums);
+//! assert_eq!(3, totals.chunks);
+//! assert_eq!(file.len() as u64, totals.bytes);
+//!
+//! let mut reader = StreamVbyteReader::new(&file[..]);
+//! let mut decoded = Vec::new();
+//! while reader.read_chunk::<Scalar>(&mut decoded).unwrap() > 0 {}
+//! assert_eq!((0..2500).map(|i| i * 3).collect::<Vec<u32>>(), decoded);
+//! ```
+
+use std::{
+    cmp,
+    io::{self, BufRead, Write},
+    marker::PhantomData,
+};
+
+use crate::{
+    decode::{decode, encoded_len_from_control_bytes, Decoder, WriteQuadToSlice},
+    encode::{encode_append, Encoder},
+    encoded_shape,
+    varint::{read_varint, write_varint},
+};
+
+/// The longest a chunk's count varint can be
+const MAX_COUNT_LEN: usize = 10;
+
+/// A reasonable number of numbers per chunk: large enough that the count at
+/// the start of each chunk costs next to nothing, and small enough that a
+/// chunk's buffers are a few hundred KiB.
+pub const DEFAULT_CHUNK_LEN: usize = 1 << 16;
+
+/// Encodes numbers to an `io::Write` in chunks of up to `chunk_len` numbers.
+///
+/// Numbers are held back until there's a whole chunk of them, then encoded
+/// with `E` and written with a single `write_all()`, so wrapping a file in a
+/// `BufWriter` isn't necessary. Call `finish()` when done to write the last,
+/// partial chunk: numbers that haven't been written yet are lost if the writer
+/// is dropped instead.
+///
+/// If writing to the underlying writer fails, the chunk that was being written
+/// may be incomplete, and the stream should be discarded.
+#[derive(Debug)]
+pub struct StreamVbyteWriter<W: Write, E: Encoder> {
+    writer: W,
+    chunk_len: usize,
+    /// Numbers that haven't been written yet, always fewer than `chunk_len`
+    /// between calls
+    pending: Vec<u32>,
+    /// The encoding of the chunk being written
+    chunk: Vec<u8>,
+    totals: WriteTotals,
+    encoder: PhantomData<E>,
+}
+
+/// How much a `StreamVbyteWriter` wrote.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WriteTotals {
+    /// The count of numbers written
+    pub nums: usize,
+    /// The count of chunks written
+    pub chunks: usize,
+    /// The count of bytes written to the underlying writer
+    pub bytes: u64,
+}
+
+impl<W: Write, E: Encoder> StreamVbyteWriter<W, E> {
+    /// Create a writer that writes chunks of `DEFAULT_CHUNK_LEN` numbers to
+    /// `writer`.
+    pub fn new(writer: W) -> StreamVbyteWriter<W, E> {
+        StreamVbyteWriter::with_chunk_len(writer, DEFAULT_CHUNK_LEN)
+    }
+
+    /// Create a writer that writes chunks of `chunk_len` numbers to `writer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_len` is 0.
+    pub fn with_chunk_len(writer: W, chunk_len: usize) -> StreamVbyteWriter<W, E> {
+        assert!(chunk_len > 0, "Chunk length must be nonzero");
+
+        StreamVbyteWriter {
+            writer,
+            chunk_len,
+            pending: Vec::with_capacity(chunk_len),
+            chunk: Vec::new(),
+            totals: WriteTotals::default(),
+            encoder: PhantomData,
+        }
+    }
+
+    /// Add one number, writing a chunk if that fills one.
+    pub fn write_num(&mut self, num: u32) -> io::Result<()> {
+        self.pending.push(num);
+
+        if self.pending.len() == self.chunk_len {
+            self.write_pending()?;
+        }
+
+        Ok(())
+    }
+
+    /// Add all the numbers in `nums`, writing as many chunks as they fill.
+    ///
+    /// Whole chunks are encoded straight from `nums` once any numbers held back
+    /// from earlier calls have been written.
+    pub fn write_nums(&mut self, mut nums: &[u32]) -> io::Result<()> {
+        if !self.pending.is_empty() {
+            let len = cmp::min(self.chunk_len - self.pending.len(), nums.len());
+            self.pending.extend_from_slice(&nums[0..len]);
+            nums = &nums[len..];
+
+            if self.pending.len() < self.chunk_len {
+                // ran out of numbers before filling the chunk
+                return Ok(());
+            }
+            self.write_pending()?;
+        }
+
+        let mut chunks = nums.chunks_exact(self.chunk_len);
+        for chunk_nums in &mut chunks {
+            write_chunk::<W, E>(
+                &mut self.writer,
+                chunk_nums,
+                &mut self.chunk,
+                &mut self.totals,
+            )?;
+        }
+        self.pending.extend_from_slice(chunks.remainder());
+
+        Ok(())
+    }
+
+    /// Write any numbers that have been held back as a chunk of their own, and
+    /// flush the underlying writer.
+    ///
+    /// Flushing often makes for short chunks, which waste a little space on
+    /// their counts and are slower to decode.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.write_pending()?;
+
+        self.writer.flush()
+    }
+
+    /// The count of numbers added so far, including any not yet written.
+    pub fn len(&self) -> usize {
+        self.totals.nums + self.pending.len()
+    }
+
+    /// True iff no numbers have been added.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The count of numbers added that haven't been written yet.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// How much has been written to the underlying writer so far.
+    pub fn totals(&self) -> WriteTotals {
+        self.totals
+    }
+
+    /// A reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Write any numbers that have been held back, flush the underlying
+    /// writer, and return it along with how much was written in total.
+    pub fn finish(mut self) -> io::Result<(W, WriteTotals)> {
+        self.flush()?;
+
+        Ok((self.writer, self.totals))
+    }
+
+    fn write_pending(&mut self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        write_chunk::<W, E>(
+            &mut self.writer,
+            &self.pending,
+            &mut self.chunk,
+            &mut self.totals,
+        )?;
+        self.pending.clear();
+
+        Ok(())
+    }
+}
+
+/// Encode `nums` as a chunk in `chunk`, and write it to `writer`.
+fn write_chunk<W: Write, E: Encoder>(
+    writer: &mut W,
+    nums: &[u32],
+    chunk: &mut Vec<u8>,
+    totals: &mut WriteTotals,
+) -> io::Result<()> {
+    chunk.clear();
+    write_varint(nums.len() as u64, chunk);
+    encode_append::<E>(nums, chunk);
+
+    writer.write_all(chunk)?;
+
+    totals.nums += nums.len();
+    totals.chunks += 1;
+    totals.bytes += chunk.len() as u64;
+
+    Ok(())
+}
+
+/// Reads the chunks written by `StreamVbyteWriter` from an `io::BufRead`, one
+/// at a time.
+///
+/// Each chunk's count is a varint whose length isn't known until its last byte
+/// is read, so it's read from the reader's buffer rather than with a read call
+/// per byte. Wrap an unbuffered reader like a `File` in an `io::BufReader`.
+///
+/// Malformed input is reported as an `io::Error` of kind `InvalidData`, and
+/// input that ends partway through a chunk as one of kind `UnexpectedEof`.
+#[derive(Debug)]
+pub struct StreamVbyteReader<R: BufRead> {
+    reader: R,
+    max_chunk_len: usize,
+    /// The encoding of the chunk being read
+    chunk: Vec<u8>,
+}
+
+impl<R: BufRead> StreamVbyteReader<R> {
+    /// Create a reader that accepts chunks of up to `DEFAULT_CHUNK_LEN`
+    /// numbers.
+    pub fn new(reader: R) -> StreamVbyteReader<R> {
+        StreamVbyteReader::with_max_chunk_len(reader, DEFAULT_CHUNK_LEN)
+    }
+
+    /// Create a reader that accepts chunks of up to `max_chunk_len` numbers.
+    ///
+    /// A chunk's count is read before anything else, so this limits how much
+    /// memory corrupt or malicious input can make `read_chunk()` allocate.
+    pub fn with_max_chunk_len(reader: R, max_chunk_len: usize) -> StreamVbyteReader<R> {
+        StreamVbyteReader {
+            reader,
+            max_chunk_len,
+            chunk: Vec::new(),
+        }
+    }
+
+    /// Read the next chunk, and decode its numbers with `D` onto the end of
+    /// `output`.
+    ///
+    /// Returns the count of numbers decoded, which is 0 only at the end of the
+    /// input.
+    pub fn read_chunk<D: Decoder + WriteQuadToSlice>(
+        &mut self,
+        output: &mut Vec<u32>,
+    ) -> io::Result<usize> {
+        let count = match self.read_count()? {
+            Some(count) => count,
+            None => return Ok(0),
+        };
+
+        if count == 0 {
+            return Err(invalid_data("Chunk has a count of 0"));
+        }
+        if count > self.max_chunk_len {
+            return Err(invalid_data(format!(
+                "Chunk count {} is larger than the maximum of {}",
+                count, self.max_chunk_len
+            )));
+        }
+
+        let control_bytes_len = encoded_shape(count).control_bytes_len;
+        self.chunk.resize(control_bytes_len, 0);
+        self.reader.read_exact(&mut self.chunk)?;
+
+        let encoded_len =
+            encoded_len_from_control_bytes(&self.chunk, count).map_err(invalid_data)?;
+        self.chunk.resize(encoded_len, 0);
+        self.reader
+            .read_exact(&mut self.chunk[control_bytes_len..encoded_len])?;
+
+        let start = output.len();
+        output.resize(start + count, 0);
+        decode::<D>(&self.chunk, count, &mut output[start..]);
+
+        Ok(count)
+    }
+
+    /// A reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Return the underlying reader, positioned after the last chunk read.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the count at the start of a chunk, or `None` if the input ends
+    /// before it starts.
+    fn read_count(&mut self) -> io::Result<Option<usize>> {
+        let mut varint = [0_u8; MAX_COUNT_LEN];
+        let mut len = 0;
+
+        while len < MAX_COUNT_LEN {
+            let available = match self.reader.fill_buf() {
+                Ok(available) => available,
+                Err(ref error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            if available.is_empty() {
+                return if len == 0 {
+                    Ok(None)
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "Input ends partway through a chunk count",
+                    ))
+                };
+            }
+
+            // take bytes up to the varint's last one, which has no continuation bit
+            let wanted = cmp::min(available.len(), MAX_COUNT_LEN - len);
+            let taken = available[0..wanted]
+                .iter()
+                .position(|&b| b & 0x80 == 0)
+                .map_or(wanted, |i| i + 1);
+            varint[len..(len + taken)].copy_from_slice(&available[0..taken]);
+            self.reader.consume(taken);
+            len += taken;
+
+            if varint[len - 1] & 0x80 == 0 {
+                break;
+            }
+        }
+
+        // the varint ended, or all 10 bytes are there, so it can only be too long
+        // or too large
+        read_varint(&varint[0..len])
+            .map(|(count, _)| Some(count))
+            .map_err(|_| invalid_data("Chunk count is too large"))
+    }
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
@@ -0,0 +1,69 @@
+//! CRC32C (the Castagnoli polynomial, as used by iSCSI, ext4, etc), for frame
+//! checksums.
+
+/// The Castagnoli polynomial, bit reversed because bits are processed low bit
+/// first
+const POLYNOMIAL: u32 = 0x82F6_3B78;
+
+/// The CRC of each possible byte, so that a byte at a time can be processed
+/// instead of a bit at a time
+const TABLE: [u32; 256] = make_table();
+
+const fn make_table() -> [u32; 256] {
+    let mut table = [0_u32; 256];
+
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[byte] = crc;
+        byte += 1;
+    }
+
+    table
+}
+
+/// The CRC32C of `input`.
+pub(crate) fn crc32c(input: &[u8]) -> u32 {
+    let crc = input.iter().fold(!0_u32, |crc, &byte| {
+        TABLE[((crc as u8) ^ byte) as usize] ^ (crc >> 8)
+    });
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_value() {
+        // the standard check input, see e.g. the CRC catalogue's CRC-32/ISCSI
+        assert_eq!(0xE306_9283, crc32c(b"123456789"));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(0, crc32c(&[]));
+    }
+
+    #[test]
+    fn rfc_3720_vectors() {
+        // from RFC 3720 appendix B.4
+        assert_eq!(0x8A91_36AA, crc32c(&[0x00; 32]));
+        assert_eq!(0x62A8_AB43, crc32c(&[0xFF; 32]));
+
+        let ascending: Vec<u8> = (0..32).collect();
+        assert_eq!(0x46DD_794E, crc32c(&ascending));
+    }
+}
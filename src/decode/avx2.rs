@@ -0,0 +1,193 @@
+use std::{
+    arch::x86_64::{
+        __m128i, _mm256_castsi256_si128, _mm256_extracti128_si256, _mm256_loadu2_m128i,
+        _mm256_shuffle_epi8, _mm_loadu_si128, _mm_shuffle_epi8, _mm_storeu_si128,
+    },
+    cmp,
+};
+
+use super::{DecodeQuadSink, Decoder, DeltaDecodeQuad, WriteQuadToSlice, ZigzagDecodeQuad};
+use crate::{
+    tables,
+    x86::{delta_decode_quad_sse2, zigzag_decode_quad_sse2},
+};
+
+/// Decoder using AVX2 instructions.
+///
+/// Decodes two quads at a time with one 256-bit shuffle, but hands them to
+/// sinks one quad at a time just like `Ssse3`.
+pub struct Avx2;
+
+impl Decoder for Avx2 {
+    type DecodedQuad = __m128i;
+
+    fn decode_quads<S: DecodeQuadSink<Self>>(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        control_bytes_to_decode: usize,
+        nums_already_decoded: usize,
+        sink: &mut S,
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("avx2") {
+            return (0, 0);
+        }
+
+        unsafe {
+            decode_quads_avx2(
+                control_bytes,
+                encoded_nums,
+                control_bytes_to_decode,
+                nums_already_decoded,
+                sink,
+            )
+        }
+    }
+}
+
+/// The body of `Avx2::decode_quads()`, compiled with AVX2 enabled so that the
+/// intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports AVX2.
+#[target_feature(enable = "avx2")]
+unsafe fn decode_quads_avx2<S: DecodeQuadSink<Avx2>>(
+    control_bytes: &[u8],
+    encoded_nums: &[u8],
+    control_bytes_to_decode: usize,
+    nums_already_decoded: usize,
+    sink: &mut S,
+) -> (usize, usize) {
+    let mut bytes_read: usize = 0;
+    let mut nums_decoded: usize = nums_already_decoded;
+    let mut control_bytes_decoded: usize = 0;
+
+    // Each quad reads 16 bytes from input, just like Ssse3, so pairs of quads stop
+    // 3 control bytes short of the end, where there may not be enough encoded
+    // bytes after them. The second quad in a pair is always before that limit
+    // too, so its 16 bytes are available.
+    let control_byte_limit = cmp::min(control_bytes_to_decode, control_bytes.len());
+    let pair_limit = cmp::min(control_byte_limit, control_bytes.len().saturating_sub(3));
+
+    while control_bytes_decoded + 2 <= pair_limit {
+        let control_byte_0 = control_bytes[control_bytes_decoded] as usize;
+        let control_byte_1 = control_bytes[control_bytes_decoded + 1] as usize;
+        let length_0 = tables::DECODE_LENGTH_PER_QUAD_TABLE[control_byte_0] as usize;
+        let length_1 = tables::DECODE_LENGTH_PER_QUAD_TABLE[control_byte_1] as usize;
+        let mask_bytes_0 = tables::DECODE_SHUFFLE_TABLE[control_byte_0];
+        let mask_bytes_1 = tables::DECODE_SHUFFLE_TABLE[control_byte_1];
+        // we'll read 16 bytes from each of these always, so using explicit slice size
+        // to make sure it's ok to read unsafe
+        let next_4_0 = &encoded_nums[bytes_read..(bytes_read + 16)];
+        let next_4_1 = &encoded_nums[(bytes_read + length_0)..(bytes_read + length_0 + 16)];
+
+        // first quad in the low 128-bit lane, second in the high lane, since the
+        // 256-bit shuffle can't move bytes between lanes anyway
+        let mask = _mm256_loadu2_m128i(
+            mask_bytes_1.as_ptr() as *const __m128i,
+            mask_bytes_0.as_ptr() as *const __m128i,
+        );
+        let data = _mm256_loadu2_m128i(
+            next_4_1.as_ptr() as *const __m128i,
+            next_4_0.as_ptr() as *const __m128i,
+        );
+
+        let decompressed = _mm256_shuffle_epi8(data, mask);
+
+        sink.on_quad(_mm256_castsi256_si128(decompressed), nums_decoded);
+        sink.on_quad(_mm256_extracti128_si256(decompressed, 1), nums_decoded + 4);
+
+        bytes_read += length_0 + length_1;
+        nums_decoded += 8;
+        control_bytes_decoded += 2;
+    }
+
+    // Then the rest one at a time. The last few quads may be encoded in fewer
+    // than 16 bytes with nothing after them, so those are copied here first so
+    // that there are always 16 bytes to read.
+    let mut tail = [0_u8; 16];
+
+    for &control_byte in control_bytes[control_bytes_decoded..control_byte_limit].iter() {
+        let length = tables::DECODE_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+        let mask_bytes = tables::DECODE_SHUFFLE_TABLE[control_byte as usize];
+        let next_4 = if bytes_read + 16 <= encoded_nums.len() {
+            &encoded_nums[bytes_read..(bytes_read + 16)]
+        } else {
+            // the shuffle ignores anything after the first `length` bytes
+            tail[0..length].copy_from_slice(&encoded_nums[bytes_read..(bytes_read + length)]);
+            &tail[..]
+        };
+
+        let mask = _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i);
+        let data = _mm_loadu_si128(next_4.as_ptr() as *const __m128i);
+
+        sink.on_quad(_mm_shuffle_epi8(data, mask), nums_decoded);
+
+        bytes_read += length;
+        nums_decoded += 4;
+    }
+
+    (nums_decoded - nums_already_decoded, bytes_read)
+}
+
+impl WriteQuadToSlice for Avx2 {
+    fn write_quad_to_slice(quad: Self::DecodedQuad, slice: &mut [u32]) {
+        unsafe { _mm_storeu_si128(slice.as_ptr() as *mut __m128i, quad) }
+    }
+}
+
+impl DeltaDecodeQuad for Avx2 {
+    #[inline]
+    fn delta_decode_quad(quad: Self::DecodedQuad, prev: u32) -> (Self::DecodedQuad, u32) {
+        unsafe { delta_decode_quad_sse2(quad, prev) }
+    }
+}
+
+impl ZigzagDecodeQuad for Avx2 {
+    #[inline]
+    fn zigzag_decode_quad(quad: Self::DecodedQuad) -> Self::DecodedQuad {
+        unsafe { zigzag_decode_quad_sse2(quad) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cumulative_encoded_len, decode::SliceDecodeSink, encode::encode, scalar::Scalar};
+
+    #[test]
+    fn reads_all_requested_control_bytes_without_extra_input_bytes() {
+        let nums: Vec<u32> = (0..64).map(|i| i * 100).collect();
+        let mut encoded = Vec::new();
+        let mut decoded: Vec<u32> = Vec::new();
+        encoded.resize(nums.len() * 5, 0xFF);
+
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+
+        // 16 control bytes
+        let control_bytes = &encoded[0..16];
+        // no trailing bytes to read past the end into, so the last few quads have to
+        // go through the tail buffer
+        let encoded_nums = &encoded[16..encoded_len];
+
+        // odd and even counts exercise both the paired and single quad paths
+        for control_bytes_to_decode in 0..17 {
+            decoded.clear();
+            decoded.resize(nums.len(), 54321);
+
+            let (nums_decoded, bytes_read) = Avx2::decode_quads(
+                control_bytes,
+                encoded_nums,
+                control_bytes_to_decode,
+                0,
+                &mut SliceDecodeSink::new(&mut decoded),
+            );
+            assert_eq!(control_bytes_to_decode * 4, nums_decoded);
+            assert_eq!(
+                cumulative_encoded_len(&control_bytes[0..control_bytes_to_decode]),
+                bytes_read
+            );
+            assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
+            assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u32));
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    arch::x86_64::{
+        __m128i, __m512i, _mm512_add_epi8, _mm512_maskz_loadu_epi8, _mm512_maskz_permutexvar_epi8,
+        _mm512_movepi8_mask, _mm_loadu_si128, _mm_set1_epi8, _mm_storeu_si128,
+    },
+    cmp, mem,
+};
+
+use super::{DecodeQuadSink, Decoder, DeltaDecodeQuad, WriteQuadToSlice, ZigzagDecodeQuad};
+use crate::{
+    tables,
+    x86::{delta_decode_quad_sse2, low_bits_mask, zigzag_decode_quad_sse2},
+};
+
+/// Decoder using AVX-512 instructions, including VBMI's `vpermb`.
+///
+/// Decodes four quads at a time from one 64-byte register, but hands them to
+/// sinks one quad at a time just like `Ssse3`. Input is read with masked loads
+/// that don't read past the encoded numbers, so unlike `Ssse3` it can decode
+/// every quad.
+pub struct Avx512Decoder;
+
+impl Decoder for Avx512Decoder {
+    type DecodedQuad = __m128i;
+
+    fn decode_quads<S: DecodeQuadSink<Self>>(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        control_bytes_to_decode: usize,
+        nums_already_decoded: usize,
+        sink: &mut S,
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !crate::x86::avx512_vbmi_detected() {
+            return (0, 0);
+        }
+
+        unsafe {
+            decode_quads_avx512(
+                control_bytes,
+                encoded_nums,
+                control_bytes_to_decode,
+                nums_already_decoded,
+                sink,
+            )
+        }
+    }
+}
+
+/// The body of `Avx512Decoder::decode_quads()`, compiled with AVX-512 enabled
+/// so that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports AVX-512 F, BW, and VBMI.
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+unsafe fn decode_quads_avx512<S: DecodeQuadSink<Avx512Decoder>>(
+    control_bytes: &[u8],
+    encoded_nums: &[u8],
+    control_bytes_to_decode: usize,
+    nums_already_decoded: usize,
+    sink: &mut S,
+) -> (usize, usize) {
+    let mut bytes_read: usize = 0;
+    let mut nums_decoded: usize = nums_already_decoded;
+
+    let control_byte_limit = cmp::min(control_bytes_to_decode, control_bytes.len());
+
+    for group in control_bytes[0..control_byte_limit].chunks(4) {
+        // Quads missing from the last group use the shuffle for control byte 0,
+        // but they're never handed to the sink, so it doesn't matter.
+        let mut masks = [_mm_set1_epi8(0); 4];
+        let mut offsets = [_mm_set1_epi8(0); 4];
+        let mut group_length = 0;
+        for (i, &control_byte) in group.iter().enumerate() {
+            masks[i] = _mm_loadu_si128(
+                tables::DECODE_SHUFFLE_TABLE[control_byte as usize].as_ptr() as *const __m128i,
+            );
+            offsets[i] = _mm_set1_epi8(group_length as i8);
+            group_length += tables::DECODE_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+        }
+        let masks = mem::transmute::<[__m128i; 4], __m512i>(masks);
+        let offsets = mem::transmute::<[__m128i; 4], __m512i>(offsets);
+
+        // Shuffle masks select bytes within a quad's encoded numbers, or have the high
+        // bit set for bytes that should be zero. Since the quads are all in the same
+        // 64 bytes, offset each quad's selections by where its encoded numbers
+        // start.
+        let zeros = _mm512_movepi8_mask(masks);
+        let indices = _mm512_add_epi8(masks, offsets);
+
+        // Masked out bytes aren't read, so this won't read past the encoded numbers
+        // even if they are shorter than 64 bytes.
+        let encoded = &encoded_nums[bytes_read..(bytes_read + group_length)];
+        let data =
+            _mm512_maskz_loadu_epi8(low_bits_mask(group_length), encoded.as_ptr() as *const i8);
+
+        let decompressed = _mm512_maskz_permutexvar_epi8(!zeros, indices, data);
+        let quads = mem::transmute::<__m512i, [__m128i; 4]>(decompressed);
+
+        for &quad in &quads[0..group.len()] {
+            sink.on_quad(quad, nums_decoded);
+            nums_decoded += 4;
+        }
+
+        bytes_read += group_length;
+    }
+
+    (nums_decoded - nums_already_decoded, bytes_read)
+}
+
+impl WriteQuadToSlice for Avx512Decoder {
+    fn write_quad_to_slice(quad: Self::DecodedQuad, slice: &mut [u32]) {
+        unsafe { _mm_storeu_si128(slice.as_ptr() as *mut __m128i, quad) }
+    }
+}
+
+impl DeltaDecodeQuad for Avx512Decoder {
+    #[inline]
+    fn delta_decode_quad(quad: Self::DecodedQuad, prev: u32) -> (Self::DecodedQuad, u32) {
+        unsafe { delta_decode_quad_sse2(quad, prev) }
+    }
+}
+
+impl ZigzagDecodeQuad for Avx512Decoder {
+    #[inline]
+    fn zigzag_decode_quad(quad: Self::DecodedQuad) -> Self::DecodedQuad {
+        unsafe { zigzag_decode_quad_sse2(quad) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cumulative_encoded_len, decode::SliceDecodeSink, encode::encode, scalar::Scalar};
+
+    #[test]
+    fn reads_all_requested_control_bytes_without_extra_input_bytes() {
+        if !crate::x86::avx512_vbmi_detected() {
+            return;
+        }
+
+        let nums: Vec<u32> = (0..64).map(|i| i * 100).collect();
+        let mut encoded = Vec::new();
+        let mut decoded: Vec<u32> = Vec::new();
+        encoded.resize(nums.len() * 5, 0xFF);
+
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+
+        // 16 control bytes
+        let control_bytes = &encoded[0..16];
+        // no trailing bytes to read past the end into
+        let encoded_nums = &encoded[16..encoded_len];
+
+        for control_bytes_to_decode in 0..17 {
+            decoded.clear();
+            decoded.resize(nums.len(), 54321);
+
+            let (nums_decoded, bytes_read) = Avx512Decoder::decode_quads(
+                control_bytes,
+                encoded_nums,
+                control_bytes_to_decode,
+                0,
+                &mut SliceDecodeSink::new(&mut decoded),
+            );
+            assert_eq!(control_bytes_to_decode * 4, nums_decoded);
+            assert_eq!(
+                cumulative_encoded_len(&control_bytes[0..control_bytes_to_decode]),
+                bytes_read
+            );
+            assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
+            assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u32));
+        }
+    }
+}
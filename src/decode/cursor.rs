@@ -1,6 +1,11 @@
+use std::{cmp, iter::FusedIterator, marker::PhantomData};
+
 use crate::{
     cumulative_encoded_len,
-    decode::{decode_num_scalar, DecodeQuadSink, Decoder, SliceDecodeSink, WriteQuadToSlice},
+    decode::{
+        decode_num_scalar, required_encoded_nums_len, DecodeError, DecodeQuadSink,
+        DecodeSingleSink, Decoder, DeltaDecodeQuad, DeltaSink, SliceDecodeSink, WriteQuadToSlice,
+    },
     encoded_shape,
     scalar::Scalar,
     EncodedShape,
@@ -88,7 +93,7 @@ use crate::{
 ///     assert_eq!(34, sink.max);
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DecodeCursor<'a> {
     control_bytes: &'a [u8],
     encoded_nums: &'a [u8],
@@ -97,10 +102,17 @@ pub struct DecodeCursor<'a> {
     nums_decoded: usize,
     control_bytes_read: usize,
     encoded_bytes_read: usize,
+    /// How many numbers of the quad at `control_bytes_read` have already been
+    /// skipped or decoded, when positioned partway through it
+    quad_offset: usize,
 }
 
 impl<'a> DecodeCursor<'a> {
     /// Create a new cursor.
+    ///
+    /// This does not inspect the encoded numbers, so if `input` is truncated,
+    /// decoding will panic when it runs off the end. Use `try_new()` for input
+    /// you don't trust.
     pub fn new(input: &'a [u8], count: usize) -> DecodeCursor<'a> {
         let shape = encoded_shape(count);
 
@@ -112,32 +124,110 @@ impl<'a> DecodeCursor<'a> {
             nums_decoded: 0,
             control_bytes_read: 0,
             encoded_bytes_read: 0,
+            quad_offset: 0,
         }
     }
 
-    /// Skip `to_skip` numbers. `to_skip` must be a multiple of 4, and must not
-    /// be greater than the count of remaining numbers that are in complete
-    /// blocks of 4. In other words, if you have 7 numbers remaining (a
-    /// block of 4 and a partial block of 3), the only count you can skip is
-    /// 4.
+    /// Create a new cursor, first checking that `input` is long enough to hold
+    /// `count` encoded numbers.
+    ///
+    /// This walks all the control bytes to find the length of the encoded
+    /// numbers, which is about as fast as `skip()`ing all of them. Once it
+    /// succeeds, decoding with any `Decoder` will not run off the end of
+    /// `input`.
+    pub fn try_new(input: &'a [u8], count: usize) -> Result<DecodeCursor<'a>, DecodeError> {
+        let shape = encoded_shape(count);
+        let required_nums_len = required_encoded_nums_len(input, &shape)?;
+        let actual_nums_len = input.len() - shape.control_bytes_len;
+
+        if actual_nums_len < required_nums_len {
+            return Err(DecodeError::TruncatedData {
+                required: required_nums_len,
+                actual: actual_nums_len,
+            });
+        }
+
+        Ok(DecodeCursor::new(input, count))
+    }
+
+    /// Skip `to_skip` numbers, which must not be more than the count of
+    /// remaining numbers.
+    ///
+    /// Skipping can end partway through a quad, in which case the next decode
+    /// starts with the rest of that quad.
     ///
     /// Skipping numbers is several times faster than decoding them.
     pub fn skip(&mut self, to_skip: usize) {
-        assert_eq!(to_skip % 4, 0, "Must be a multiple of 4");
-        let control_bytes_to_skip = to_skip / 4;
         assert!(
-            self.control_bytes_read + control_bytes_to_skip
-                <= self.encoded_shape.complete_control_bytes_len,
-            "Can't skip past the end of complete control bytes"
+            to_skip <= self.total_nums - self.nums_decoded,
+            "Can't skip past the end of the input"
         );
 
+        // finish off the current quad if we're partway through it
+        let skipped_in_quad = if self.quad_offset > 0 {
+            self.decode_within_quad(&mut DiscardSink, to_skip, 0)
+        } else {
+            0
+        };
+
+        // Whole quads can be skipped by their control bytes alone. This can't go
+        // past the complete quads because to_skip is no more than the remaining
+        // numbers.
+        let control_bytes_to_skip = (to_skip - skipped_in_quad) / 4;
         let slice_to_skip = &self.control_bytes
             [self.control_bytes_read..(self.control_bytes_read + control_bytes_to_skip)];
         let skipped_encoded_len = cumulative_encoded_len(slice_to_skip);
 
         self.control_bytes_read += control_bytes_to_skip;
         self.encoded_bytes_read += skipped_encoded_len;
-        self.nums_decoded += to_skip;
+        self.nums_decoded += control_bytes_to_skip * 4;
+
+        // then into the next quad, if the skip doesn't end on a quad boundary
+        let rest = to_skip - skipped_in_quad - control_bytes_to_skip * 4;
+        self.decode_within_quad(&mut DiscardSink, rest, 0);
+    }
+
+    /// Move to `index`, so that the next number decoded is the one at that
+    /// position, either forwards or back from the current position.
+    ///
+    /// Moving forwards only skips from the current position, but moving back
+    /// skips from the start of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the count of numbers.
+    pub fn seek_to(&mut self, index: usize) {
+        assert!(
+            index <= self.total_nums,
+            "Can't seek past the end of the input"
+        );
+
+        if index < self.nums_decoded {
+            self.nums_decoded = 0;
+            self.control_bytes_read = 0;
+            self.encoded_bytes_read = 0;
+            self.quad_offset = 0;
+        }
+
+        self.skip(index - self.nums_decoded);
+    }
+
+    /// Move straight to the start of the quad `quad`, whose encoded numbers
+    /// start `encoded_offset` bytes into the encoded numbers, from the start
+    /// of the input.
+    pub(crate) fn jump_to_quad(&mut self, quad: usize, encoded_offset: usize) {
+        debug_assert_eq!(0, self.nums_decoded);
+        debug_assert!(quad <= self.encoded_shape.control_bytes_len);
+
+        self.control_bytes_read = quad;
+        self.encoded_bytes_read = encoded_offset;
+        self.nums_decoded = quad * 4;
+    }
+
+    /// Returns the position of the next number to be decoded, which is the
+    /// count of numbers decoded or skipped so far.
+    pub fn position(&self) -> usize {
+        self.nums_decoded
     }
 
     /// Decode into the `output` buffer.
@@ -148,6 +238,9 @@ impl<'a> DecodeCursor<'a> {
     /// If there is only a final partial quad of input, the buffer must be at
     /// least as big as the remaining input.
     ///
+    /// If a `skip()` ended partway through a quad, the rest of that quad is
+    /// decoded first, as much of it as there is room for.
+    ///
     /// Returns the number of numbers decoded by this invocation, which may be
     /// less than the size of the buffer.
     pub fn decode_slice<D: Decoder + WriteQuadToSlice>(&mut self, output: &mut [u32]) -> usize {
@@ -158,6 +251,39 @@ impl<'a> DecodeCursor<'a> {
         self.decode_sink::<D, SliceDecodeSink>(&mut sink, output_len)
     }
 
+    /// Decode into the `output` buffer, returning an error rather than decoding
+    /// nothing if `output` is too small to make progress.
+    ///
+    /// `output` must have room for at least one complete quad if there is one
+    /// remaining, or for all of the final partial quad otherwise.
+    ///
+    /// If this cursor was created with `try_new()`, this will not panic.
+    ///
+    /// Returns the number of numbers decoded by this invocation, which may be
+    /// less than the size of the buffer.
+    pub fn try_decode_slice<D: Decoder + WriteQuadToSlice>(
+        &mut self,
+        output: &mut [u32],
+    ) -> Result<usize, DecodeError> {
+        let required = if self.quad_offset > 0 && self.has_more() {
+            // any room at all is enough to decode the rest of the current quad
+            1
+        } else if self.control_bytes_read < self.encoded_shape.complete_control_bytes_len {
+            4
+        } else {
+            self.total_nums - self.nums_decoded
+        };
+
+        if output.len() < required {
+            return Err(DecodeError::OutputTooSmall {
+                required,
+                actual: output.len(),
+            });
+        }
+
+        Ok(self.decode_slice::<D>(output))
+    }
+
     /// Decode at most `max_numbers_to_decode` numbers from the input and hand
     /// them to `sink`.
     ///
@@ -166,7 +292,9 @@ impl<'a> DecodeCursor<'a> {
     /// the number of numbers decoded will be a multiple of 4, unless
     /// `max_numbers_to_decode` includes the end of the encoded input, in
     /// which case the number of numbers will be all remaining numbers in the
-    /// input regardless of whether that's a multiple of 4 or not.
+    /// input regardless of whether that's a multiple of 4 or not. If a
+    /// `skip()` ended partway through a quad, the rest of that quad is decoded
+    /// first, one number at a time, which also changes the count.
     ///
     /// With each invocation of `decode()`, the `nums_decoded` parameter used in
     /// `DecodeQuadSink.on_quad()` will start counting up from 0 again.
@@ -179,6 +307,21 @@ impl<'a> DecodeCursor<'a> {
         S: DecodeQuadSink<D> + DecodeQuadSink<Scalar>,
     {
         let start_nums_decoded = self.nums_decoded;
+
+        // finish off the current quad if a skip left us partway through it
+        let partial_quad_nums_decoded = if self.quad_offset > 0 {
+            let decoded = self.decode_within_quad(sink, max_numbers_to_decode, 0);
+            if self.quad_offset > 0 {
+                // ran out of room before the end of the quad
+                return decoded;
+            }
+
+            decoded
+        } else {
+            0
+        };
+        let max_numbers_to_decode = max_numbers_to_decode - partial_quad_nums_decoded;
+
         let mut complete_quad_nums_decoded_this_invocation;
 
         let complete_control_bytes_to_decode = max_numbers_to_decode / 4;
@@ -190,7 +333,7 @@ impl<'a> DecodeCursor<'a> {
                     [self.control_bytes_read..self.encoded_shape.complete_control_bytes_len],
                 &self.encoded_nums[self.encoded_bytes_read..],
                 complete_control_bytes_to_decode,
-                0,
+                partial_quad_nums_decoded,
                 sink,
             );
 
@@ -208,7 +351,7 @@ impl<'a> DecodeCursor<'a> {
                     [self.control_bytes_read..self.encoded_shape.complete_control_bytes_len],
                 &self.encoded_nums[self.encoded_bytes_read..],
                 complete_control_bytes_to_decode - complete_quad_nums_decoded_this_invocation / 4,
-                complete_quad_nums_decoded_this_invocation,
+                partial_quad_nums_decoded + complete_quad_nums_decoded_this_invocation,
                 sink,
             );
 
@@ -226,24 +369,80 @@ impl<'a> DecodeCursor<'a> {
             && self.encoded_shape.leftover_numbers > 0
             && self.nums_decoded < self.total_nums
         {
-            let control_byte = self.control_bytes[self.encoded_shape.complete_control_bytes_len];
-
-            for i in 0..self.encoded_shape.leftover_numbers {
-                // first num's length in low 2 bits, last in high 2 bits
-                let bitmask = 0x03 << (i * 2);
-                let len = ((control_byte & bitmask) >> (i * 2)) as usize + 1;
-                sink.on_number(
-                    decode_num_scalar(len, &self.encoded_nums[self.encoded_bytes_read..]),
-                    complete_quad_nums_decoded_this_invocation + i,
-                );
-                self.nums_decoded += 1;
-                self.encoded_bytes_read += len;
-            }
+            self.decode_within_quad(
+                sink,
+                self.encoded_shape.leftover_numbers,
+                partial_quad_nums_decoded + complete_quad_nums_decoded_this_invocation,
+            );
         }
 
         self.nums_decoded - start_nums_decoded
     }
 
+    /// Decode exactly `count` numbers, which must not be more than the count
+    /// of remaining numbers, ending partway through a quad if need be.
+    ///
+    /// Returns the number of numbers decoded.
+    pub(crate) fn decode_sink_exact<D, S>(&mut self, sink: &mut S, count: usize) -> usize
+    where
+        D: Decoder,
+        S: DecodeQuadSink<D> + DecodeQuadSink<Scalar>,
+    {
+        let nums_decoded = self.decode_sink::<D, S>(sink, count);
+
+        // decode_sink() stops at the last whole quad unless it's at the end of the
+        // input
+        nums_decoded + self.decode_within_quad(sink, count - nums_decoded, nums_decoded)
+    }
+
+    /// Decode up to `max_numbers_to_decode` numbers one at a time, starting at
+    /// `quad_offset` in the current quad and stopping at the end of it.
+    ///
+    /// Returns the number of numbers decoded.
+    fn decode_within_quad<S: DecodeSingleSink>(
+        &mut self,
+        sink: &mut S,
+        max_numbers_to_decode: usize,
+        nums_already_decoded: usize,
+    ) -> usize {
+        if !self.has_more() {
+            return 0;
+        }
+
+        let quad_len = if self.control_bytes_read < self.encoded_shape.complete_control_bytes_len {
+            4
+        } else {
+            self.encoded_shape.leftover_numbers
+        };
+        let quad_end = cmp::min(quad_len, self.quad_offset + max_numbers_to_decode);
+        let control_byte = self.control_bytes[self.control_bytes_read];
+
+        for i in self.quad_offset..quad_end {
+            // first num's length in low 2 bits, last in high 2 bits
+            let bitmask = 0x03 << (i * 2);
+            let len = ((control_byte & bitmask) >> (i * 2)) as usize + 1;
+            sink.on_number(
+                decode_num_scalar(len, &self.encoded_nums[self.encoded_bytes_read..]),
+                nums_already_decoded + i - self.quad_offset,
+            );
+            self.encoded_bytes_read += len;
+        }
+
+        let nums_decoded = quad_end - self.quad_offset;
+        self.nums_decoded += nums_decoded;
+
+        if quad_end == 4 {
+            self.control_bytes_read += 1;
+            self.quad_offset = 0;
+        } else {
+            // for the trailing partial quad, the offset stays at the end so that
+            // nothing is decoded twice
+            self.quad_offset = quad_end;
+        }
+
+        nums_decoded
+    }
+
     /// Returns the total length of input scanned so far: the complete block of
     /// control bytes, plus any encoded numbers decoded.
     pub fn input_consumed(&self) -> usize {
@@ -254,6 +453,247 @@ impl<'a> DecodeCursor<'a> {
     pub fn has_more(&self) -> bool {
         self.nums_decoded < self.total_nums
     }
+
+    /// Returns an iterator over the remaining numbers, which decodes them a
+    /// batch at a time with `D`.
+    ///
+    /// The iterator has its own copy of the cursor, so this cursor stays where
+    /// it is. Use `DecodeIter::into_cursor()` to carry on from wherever the
+    /// iterator got to.
+    pub fn iter<D: Decoder + WriteQuadToSlice>(&self) -> DecodeIter<'a, D> {
+        DecodeIter {
+            cursor: self.clone(),
+            batch_start: self.clone(),
+            buffer: [0; ITER_BATCH_LEN],
+            buffer_pos: 0,
+            buffer_len: 0,
+            decoder: PhantomData,
+        }
+    }
+}
+
+/// How many numbers `DecodeIter` decodes at once
+const ITER_BATCH_LEN: usize = 64;
+
+/// An iterator over numbers decoded from a `DecodeCursor`. See
+/// `DecodeCursor::iter()`.
+///
+/// # Examples
+///
+/// ```
+/// use stream_vbyte::{decode::cursor::DecodeCursor, encode::encode_to_vec, scalar::Scalar};
+///
+/// let nums: Vec<u32> = (0..1000).collect();
+/// let encoded = encode_to_vec::<Scalar>(&nums);
+///
+/// let cursor = DecodeCursor::new(&encoded, nums.len());
+/// let sum: u32 = cursor.iter::<Scalar>().sum();
+/// assert_eq!(499_500, sum);
+///
+/// // nth() skips without decoding
+/// assert_eq!(Some(900), cursor.iter::<Scalar>().nth(900));
+/// ```
+#[derive(Debug)]
+pub struct DecodeIter<'a, D> {
+    cursor: DecodeCursor<'a>,
+    /// `cursor` as it was before decoding what's in `buffer`
+    batch_start: DecodeCursor<'a>,
+    buffer: [u32; ITER_BATCH_LEN],
+    /// The next number to hand out from `buffer`
+    buffer_pos: usize,
+    buffer_len: usize,
+    decoder: PhantomData<D>,
+}
+
+impl<'a, D: Decoder + WriteQuadToSlice> DecodeIter<'a, D> {
+    /// Returns a cursor positioned at the next number this iterator would
+    /// return.
+    pub fn into_cursor(self) -> DecodeCursor<'a> {
+        // numbers in the buffer have been decoded, but not all handed out yet
+        let mut cursor = self.batch_start;
+        cursor.skip(self.buffer_pos);
+
+        cursor
+    }
+}
+
+impl<'a, D: Decoder + WriteQuadToSlice> Iterator for DecodeIter<'a, D> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.buffer_pos == self.buffer_len {
+            self.batch_start = self.cursor.clone();
+            self.buffer_pos = 0;
+            self.buffer_len = self.cursor.decode_slice::<D>(&mut self.buffer);
+
+            if self.buffer_len == 0 {
+                return None;
+            }
+        }
+
+        let num = self.buffer[self.buffer_pos];
+        self.buffer_pos += 1;
+
+        Some(num)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len =
+            self.buffer_len - self.buffer_pos + self.cursor.total_nums - self.cursor.nums_decoded;
+
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<u32> {
+        let buffered = self.buffer_len - self.buffer_pos;
+        if n < buffered {
+            self.buffer_pos += n;
+            return self.next();
+        }
+
+        // skip the rest of the buffer, then skip in the cursor without decoding
+        self.buffer_pos = 0;
+        self.buffer_len = 0;
+        let remaining = self.cursor.total_nums - self.cursor.nums_decoded;
+        self.cursor.skip(cmp::min(n - buffered, remaining));
+
+        self.next()
+    }
+}
+
+impl<'a, D: Decoder + WriteQuadToSlice> ExactSizeIterator for DecodeIter<'a, D> {}
+
+impl<'a, D: Decoder + WriteQuadToSlice> FusedIterator for DecodeIter<'a, D> {}
+
+/// A `DecodeCursor` for input encoded with `encode::encode_delta()`.
+///
+/// This keeps track of the running sum of the decoded deltas, so numbers are
+/// decoded correctly no matter how decoding is split across `decode_slice()`,
+/// `decode_sink()`, and `skip()` calls.
+#[derive(Debug)]
+pub struct DeltaDecodeCursor<'a> {
+    cursor: DecodeCursor<'a>,
+    prev: u32,
+}
+
+impl<'a> DeltaDecodeCursor<'a> {
+    /// Create a new cursor. `prev` must be the same as the `prev` used when
+    /// encoding.
+    pub fn new(input: &'a [u8], count: usize, prev: u32) -> DeltaDecodeCursor<'a> {
+        DeltaDecodeCursor {
+            cursor: DecodeCursor::new(input, count),
+            prev,
+        }
+    }
+
+    /// Create a new cursor, first checking that `input` is long enough to hold
+    /// `count` encoded numbers. See `DecodeCursor::try_new()`.
+    pub fn try_new(
+        input: &'a [u8],
+        count: usize,
+        prev: u32,
+    ) -> Result<DeltaDecodeCursor<'a>, DecodeError> {
+        Ok(DeltaDecodeCursor {
+            cursor: DecodeCursor::try_new(input, count)?,
+            prev,
+        })
+    }
+
+    /// Skip `to_skip` numbers, with the same restrictions as
+    /// `DecodeCursor::skip()`.
+    ///
+    /// Unlike `DecodeCursor::skip()`, the skipped numbers must be decoded to
+    /// keep the running sum up to date, so this is not much faster than
+    /// decoding.
+    pub fn skip(&mut self, to_skip: usize) {
+        assert!(
+            to_skip <= self.cursor.total_nums - self.cursor.nums_decoded,
+            "Can't skip past the end of the input"
+        );
+
+        let mut sink = SumSink { sum: 0 };
+        let skipped = self
+            .cursor
+            .decode_sink_exact::<Scalar, _>(&mut sink, to_skip);
+        debug_assert_eq!(to_skip, skipped);
+
+        self.prev = self.prev.wrapping_add(sink.sum);
+    }
+
+    /// Decode into the `output` buffer. See `DecodeCursor::decode_slice()`.
+    pub fn decode_slice<D: DeltaDecodeQuad + WriteQuadToSlice>(
+        &mut self,
+        output: &mut [u32],
+    ) -> usize {
+        let output_len = output.len();
+
+        let mut sink = SliceDecodeSink::new(output);
+
+        self.decode_sink::<D, SliceDecodeSink>(&mut sink, output_len)
+    }
+
+    /// Decode at most `max_numbers_to_decode` numbers from the input and hand
+    /// them to `sink`. See `DecodeCursor::decode_sink()`.
+    pub fn decode_sink<D, S>(&mut self, sink: &mut S, max_numbers_to_decode: usize) -> usize
+    where
+        D: DeltaDecodeQuad,
+        S: DecodeQuadSink<D> + DecodeQuadSink<Scalar>,
+    {
+        let mut delta_sink = DeltaSink::new(sink, self.prev);
+        let nums_decoded = self
+            .cursor
+            .decode_sink::<D, _>(&mut delta_sink, max_numbers_to_decode);
+        self.prev = delta_sink.prev();
+
+        nums_decoded
+    }
+
+    /// Returns the most recently decoded (or skipped) number, or the initial
+    /// `prev` if nothing has been decoded yet.
+    pub fn prev(&self) -> u32 {
+        self.prev
+    }
+
+    /// Returns the total length of input scanned so far. See
+    /// `DecodeCursor::input_consumed()`.
+    pub fn input_consumed(&self) -> usize {
+        self.cursor.input_consumed()
+    }
+
+    /// Returns true iff there are more numbers to be decoded.
+    pub fn has_more(&self) -> bool {
+        self.cursor.has_more()
+    }
+}
+
+/// Throws away skipped numbers.
+pub(crate) struct DiscardSink;
+
+impl DecodeSingleSink for DiscardSink {
+    fn on_number(&mut self, _num: u32, _nums_decoded: usize) {}
+}
+
+impl DecodeQuadSink<Scalar> for DiscardSink {
+    fn on_quad(&mut self, _quad: <Scalar as Decoder>::DecodedQuad, _nums_decoded: usize) {
+        unreachable!()
+    }
+}
+
+/// Sums skipped deltas.
+struct SumSink {
+    sum: u32,
+}
+
+impl DecodeSingleSink for SumSink {
+    fn on_number(&mut self, num: u32, _nums_decoded: usize) {
+        self.sum = self.sum.wrapping_add(num);
+    }
+}
+
+impl DecodeQuadSink<Scalar> for SumSink {
+    fn on_quad(&mut self, _quad: <Scalar as Decoder>::DecodedQuad, _nums_decoded: usize) {
+        unreachable!()
+    }
 }
 
 #[cfg(test)]
@@ -262,28 +702,51 @@ mod tests {
     use crate::encode::encode;
 
     #[test]
-    #[should_panic(expected = "Must be a multiple of 4")]
-    fn skip_panics_on_not_multiple_of_4() {
-        DecodeCursor::new(&vec![], 0).skip(3)
+    #[should_panic(expected = "Can't skip past the end of the input")]
+    fn skip_panics_on_exceeding_count() {
+        DecodeCursor::new(&[], 0).skip(3)
     }
 
     #[test]
-    #[should_panic(expected = "Can't skip past the end of complete control bytes")]
+    #[should_panic(expected = "Can't skip past the end of the input")]
     fn skip_panics_on_exceeding_full_quads() {
         let nums: Vec<u32> = (0..100).collect();
-        let mut encoded = Vec::new();
-        encoded.resize(nums.len() * 5, 0);
+        let mut encoded = vec![0; nums.len() * 5];
 
         let encoded_len = encode::<Scalar>(&nums, &mut encoded);
 
         DecodeCursor::new(&encoded[0..encoded_len], nums.len()).skip(104);
     }
 
+    #[test]
+    fn try_new_truncated_control_bytes() {
+        assert_eq!(
+            DecodeError::TruncatedControlBytes {
+                required: 3,
+                actual: 2
+            },
+            DecodeCursor::try_new(&[0, 0], 9).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn try_new_truncated_data_in_partial_quad() {
+        // 1 complete quad of 1-byte nums, then 1 4-byte num
+        let encoded = [0x00, 0x03, 1, 2, 3, 4, 5, 6, 7];
+
+        assert_eq!(
+            DecodeError::TruncatedData {
+                required: 8,
+                actual: 7
+            },
+            DecodeCursor::try_new(&encoded, 5).unwrap_err()
+        );
+    }
+
     #[test]
     fn skip_entire_enput_is_done() {
         let nums: Vec<u32> = (0..100).collect();
-        let mut encoded = Vec::new();
-        encoded.resize(nums.len() * 5, 0);
+        let mut encoded = vec![0; nums.len() * 5];
 
         let encoded_len = encode::<Scalar>(&nums, &mut encoded);
         let mut cursor = DecodeCursor::new(&encoded[0..encoded_len], nums.len());
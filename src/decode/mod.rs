@@ -1,22 +1,39 @@
-#[cfg(not(any(feature = "x86_ssse3", feature = "aarch64_neon")))]
-use crate::scalar;
+use std::{
+    error, fmt, mem,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use crate::{encoded_nums_len, encoded_shape, scalar, EncodedShape};
 
 pub mod cursor;
+pub mod slice;
 
 #[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
 pub mod ssse3;
 
+#[cfg(all(feature = "x86_avx2", target_arch = "x86_64"))]
+pub mod avx2;
+
+// AVX-512 intrinsics need a newer Rust than the rest of the crate, which
+// build.rs checks for when the `x86_avx512` feature is enabled
+#[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+#[clippy::msrv = "1.89"]
+pub mod avx512;
+
 #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
 pub mod neon;
 
 #[cfg(test)]
 mod tests;
 
-#[cfg(not(any(feature = "x86_ssse3", feature = "aarch64_neon")))]
+#[cfg(not(any(
+    all(feature = "x86_ssse3", target_arch = "x86_64"),
+    all(feature = "aarch64_neon", target_arch = "aarch64")
+)))]
 pub type StreamVbyteDecoder = scalar::Scalar;
 
 #[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
-pub type StreamVbyteDecoder = ssse3::Ssse3;
+pub type StreamVbyteDecoder = crate::x86::Ssse3;
 
 #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
 pub type StreamVbyteDecoder = neon::NeonDecoder;
@@ -61,6 +78,24 @@ pub trait WriteQuadToSlice: Decoder {
     fn write_quad_to_slice(quad: Self::DecodedQuad, slice: &mut [u32]);
 }
 
+/// For decoders that can compute the running sum of a whole quad at once,
+/// which enables `decode_delta()`.
+pub trait DeltaDecodeQuad: Decoder {
+    /// Replace each number in `quad` with the sum of it and every number before
+    /// it, where the sum of the numbers before the first one is `prev`.
+    ///
+    /// Returns the summed quad and its last number.
+    fn delta_decode_quad(quad: Self::DecodedQuad, prev: u32) -> (Self::DecodedQuad, u32);
+}
+
+/// For decoders that can undo zigzag mapping of a whole quad at once, which
+/// enables `decode_zigzag()`.
+pub trait ZigzagDecodeQuad: Decoder {
+    /// Replace each number in `quad` with the `i32` it is the zigzag mapping
+    /// of (see `zigzag_decode_num()`), represented as a `u32`.
+    fn zigzag_decode_quad(quad: Self::DecodedQuad) -> Self::DecodedQuad;
+}
+
 /// Receives numbers decoded via a Decoder in `DecodeCursor.decode_sink()` that
 /// weren't handed to `DecodeQuadSink.on_quad()`, whether because the `Decoder`
 /// implementation doesn't have a natural quad representation, or because the
@@ -94,7 +129,7 @@ impl<'a> SliceDecodeSink<'a> {
     /// Create a new sink that wraps a slice.
     ///
     /// `output` must be at least as big as the
-    fn new(output: &'a mut [u32]) -> SliceDecodeSink<'a> {
+    pub(crate) fn new(output: &'a mut [u32]) -> SliceDecodeSink<'a> {
         SliceDecodeSink { output }
     }
 }
@@ -112,6 +147,85 @@ impl<'a, D: Decoder + WriteQuadToSlice> DecodeQuadSink<D> for SliceDecodeSink<'a
     }
 }
 
+/// A sink that undoes delta encoding (see `encode::encode_delta()`) by keeping
+/// a running sum of the numbers it receives, and hands the sums to another
+/// sink.
+pub struct DeltaSink<'a, S: ?Sized> {
+    inner: &'a mut S,
+    prev: u32,
+}
+
+impl<'a, S: ?Sized> DeltaSink<'a, S> {
+    /// Create a new sink wrapping `inner`, where the number before the first
+    /// decoded number is `prev`.
+    pub fn new(inner: &'a mut S, prev: u32) -> DeltaSink<'a, S> {
+        DeltaSink { inner, prev }
+    }
+
+    /// Returns the most recent running sum, or the initial `prev` if no
+    /// numbers have been received.
+    pub fn prev(&self) -> u32 {
+        self.prev
+    }
+}
+
+impl<'a, S: DecodeSingleSink + ?Sized> DecodeSingleSink for DeltaSink<'a, S> {
+    #[inline]
+    fn on_number(&mut self, num: u32, nums_decoded: usize) {
+        self.prev = self.prev.wrapping_add(num);
+        self.inner.on_number(self.prev, nums_decoded);
+    }
+}
+
+impl<'a, D, S> DecodeQuadSink<D> for DeltaSink<'a, S>
+where
+    D: DeltaDecodeQuad,
+    S: DecodeQuadSink<D> + ?Sized,
+{
+    #[inline]
+    fn on_quad(&mut self, quad: D::DecodedQuad, nums_decoded: usize) {
+        let (sums, last) = D::delta_decode_quad(quad, self.prev);
+        self.prev = last;
+        self.inner.on_quad(sums, nums_decoded);
+    }
+}
+
+/// A sink that undoes zigzag mapping (see `encode::encode_zigzag()`) and hands
+/// the resulting signed numbers, represented as `u32`s, to another sink.
+///
+/// To undo zigzag mapping of deltas, wrap a `DeltaSink`, since the deltas must
+/// be un-mapped before they are summed.
+pub struct ZigzagSink<'a, S: ?Sized> {
+    inner: &'a mut S,
+}
+
+impl<'a, S: ?Sized> ZigzagSink<'a, S> {
+    /// Create a new sink wrapping `inner`.
+    pub fn new(inner: &'a mut S) -> ZigzagSink<'a, S> {
+        ZigzagSink { inner }
+    }
+}
+
+impl<'a, S: DecodeSingleSink + ?Sized> DecodeSingleSink for ZigzagSink<'a, S> {
+    #[inline]
+    fn on_number(&mut self, num: u32, nums_decoded: usize) {
+        self.inner
+            .on_number(zigzag_decode_num(num) as u32, nums_decoded);
+    }
+}
+
+impl<'a, D, S> DecodeQuadSink<D> for ZigzagSink<'a, S>
+where
+    D: ZigzagDecodeQuad,
+    S: DecodeQuadSink<D> + ?Sized,
+{
+    #[inline]
+    fn on_quad(&mut self, quad: D::DecodedQuad, nums_decoded: usize) {
+        self.inner
+            .on_quad(D::zigzag_decode_quad(quad), nums_decoded);
+    }
+}
+
 /// Decode `count` numbers from `input`, writing them to `output`.
 ///
 /// The `count` must be the same as the number of items originally encoded.
@@ -136,6 +250,362 @@ pub fn decode<D: Decoder + WriteQuadToSlice>(
     cursor.input_consumed()
 }
 
+/// Decode `count` numbers from `input` into a new `Vec` that is exactly
+/// `count` long.
+///
+/// The `count` must be the same as the number of items originally encoded.
+pub fn decode_to_vec<D: Decoder + WriteQuadToSlice>(input: &[u8], count: usize) -> Vec<u32> {
+    let mut output = vec![0; count];
+    decode::<D>(input, count, &mut output);
+
+    output
+}
+
+/// Decode `count` numbers from `input`, writing them to `output`, using the
+/// fastest `Decoder` that the running CPU supports.
+///
+/// Only decoders enabled by this crate's features (e.g. `x86_ssse3`) are
+/// candidates. Unlike `StreamVbyteDecoder`, which is chosen at compile time,
+/// the choice is made by checking which instructions the CPU has, so one binary
+/// can run everywhere and still be fast on newer hardware. The check is done
+/// the first time this is called, and the choice is reused after that.
+///
+/// `output` must be large enough for all `count` numbers.
+///
+/// Returns the number of bytes read from `input`.
+pub fn decode_auto(input: &[u8], count: usize, output: &mut [u32]) -> usize {
+    // only ever holds `DecodeFn`s
+    let decode_fn =
+        unsafe { mem::transmute::<*mut (), DecodeFn>(AUTO_DECODE_FN.load(Ordering::Relaxed)) };
+
+    decode_fn(input, count, output)
+}
+
+type DecodeFn = fn(&[u8], usize, &mut [u32]) -> usize;
+
+/// Starts out as `detect_decode()`, which replaces itself with the decoder it
+/// selects.
+static AUTO_DECODE_FN: AtomicPtr<()> = AtomicPtr::new(detect_decode as DecodeFn as *mut ());
+
+fn detect_decode(input: &[u8], count: usize, output: &mut [u32]) -> usize {
+    let decode_fn = select_auto_decoder();
+    // racing threads will select the same thing, so it doesn't matter who wins
+    AUTO_DECODE_FN.store(decode_fn as *mut (), Ordering::Relaxed);
+
+    decode_fn(input, count, output)
+}
+
+fn select_auto_decoder() -> DecodeFn {
+    #[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+    {
+        if crate::x86::avx512_vbmi_detected() {
+            return decode::<avx512::Avx512Decoder>;
+        }
+    }
+
+    #[cfg(all(feature = "x86_avx2", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return decode::<avx2::Avx2>;
+        }
+    }
+
+    #[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("ssse3") {
+            return decode::<crate::x86::Ssse3>;
+        }
+    }
+
+    #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return decode::<neon::NeonDecoder>;
+        }
+    }
+
+    decode::<scalar::Scalar>
+}
+
+/// Decode `count` numbers that were encoded with `encode::encode_delta()` from
+/// `input`, writing them to `output`.
+///
+/// `prev` must be the same as the `prev` used when encoding.
+///
+/// `output` must be large enough for all `count` numbers.
+///
+/// Returns the number of bytes read from `input`.
+pub fn decode_delta<D: DeltaDecodeQuad + WriteQuadToSlice>(
+    input: &[u8],
+    count: usize,
+    prev: u32,
+    output: &mut [u32],
+) -> usize {
+    let mut cursor = cursor::DeltaDecodeCursor::new(input, count, prev);
+
+    assert_eq!(
+        count,
+        cursor.decode_slice::<D>(output),
+        "output buffer was not large enough"
+    );
+
+    cursor.input_consumed()
+}
+
+/// Decode `count` signed numbers that were encoded with
+/// `encode::encode_zigzag()` from `input`, writing them to `output`.
+///
+/// `output` must be large enough for all `count` numbers.
+///
+/// Returns the number of bytes read from `input`.
+pub fn decode_zigzag<D: ZigzagDecodeQuad + WriteQuadToSlice>(
+    input: &[u8],
+    count: usize,
+    output: &mut [i32],
+) -> usize {
+    let mut cursor = cursor::DecodeCursor::new(input, count);
+
+    let output = as_u32_slice_mut(output);
+    let output_len = output.len();
+    let mut slice_sink = SliceDecodeSink::new(output);
+    let mut sink = ZigzagSink::new(&mut slice_sink);
+
+    assert_eq!(
+        count,
+        cursor.decode_sink::<D, _>(&mut sink, output_len),
+        "output buffer was not large enough"
+    );
+
+    cursor.input_consumed()
+}
+
+/// Decode `count` signed numbers that were encoded with
+/// `encode::encode_zigzag_delta()` from `input`, writing them to `output`.
+///
+/// `prev` must be the same as the `prev` used when encoding.
+///
+/// `output` must be large enough for all `count` numbers.
+///
+/// Returns the number of bytes read from `input`.
+pub fn decode_zigzag_delta<D: ZigzagDecodeQuad + DeltaDecodeQuad + WriteQuadToSlice>(
+    input: &[u8],
+    count: usize,
+    prev: i32,
+    output: &mut [i32],
+) -> usize {
+    let mut cursor = cursor::DecodeCursor::new(input, count);
+
+    let output = as_u32_slice_mut(output);
+    let output_len = output.len();
+    let mut slice_sink = SliceDecodeSink::new(output);
+    let mut delta_sink = DeltaSink::new(&mut slice_sink, prev as u32);
+    let mut sink = ZigzagSink::new(&mut delta_sink);
+
+    assert_eq!(
+        count,
+        cursor.decode_sink::<D, _>(&mut sink, output_len),
+        "output buffer was not large enough"
+    );
+
+    cursor.input_consumed()
+}
+
+/// Decode `count` numbers from `input`, writing them to `output`, without
+/// panicking if `input` or `output` are too short.
+///
+/// This is like `decode()`, except that `input` is checked to make sure it has
+/// all the control bytes and encoded numbers that `count` numbers need before
+/// any decoding is done, so it is suitable for input that may have been
+/// truncated or corrupted.
+///
+/// `output` must be large enough for all `count` numbers.
+///
+/// Returns the number of bytes read from `input`.
+pub fn try_decode<D: Decoder + WriteQuadToSlice>(
+    input: &[u8],
+    count: usize,
+    output: &mut [u32],
+) -> Result<usize, DecodeError> {
+    let mut cursor = cursor::DecodeCursor::try_new(input, count)?;
+
+    if output.len() < count {
+        return Err(DecodeError::OutputTooSmall {
+            required: count,
+            actual: output.len(),
+        });
+    }
+
+    let nums_decoded = cursor.decode_slice::<D>(output);
+    debug_assert_eq!(count, nums_decoded);
+
+    Ok(cursor.input_consumed())
+}
+
+/// Check that `input` is exactly the encoding of `count` numbers, without
+/// decoding any of them.
+///
+/// Only the control bytes are inspected: they must all be present, the
+/// unused high bits of a trailing partial quad's control byte must be zero,
+/// and the encoded numbers that follow must be exactly as long as the control
+/// bytes describe. An input that passes can be decoded with
+/// `DecodeCursor::new()` or `decode()` without panicking.
+///
+/// Returns the length of the encoded input, which is `input.len()`.
+pub fn validate(input: &[u8], count: usize) -> Result<usize, DecodeError> {
+    let shape = encoded_shape(count);
+    let required_nums_len = required_encoded_nums_len(input, &shape)?;
+
+    if shape.leftover_numbers > 0 {
+        let control_byte = input[shape.complete_control_bytes_len];
+        // each leftover number uses 2 bits starting from the low bits
+        let unused_bits = 0xFF_u8 << (shape.leftover_numbers * 2);
+
+        if control_byte & unused_bits != 0 {
+            return Err(DecodeError::UnusedControlBitsSet { control_byte });
+        }
+    }
+
+    let actual_nums_len = input.len() - shape.control_bytes_len;
+
+    if actual_nums_len < required_nums_len {
+        return Err(DecodeError::TruncatedData {
+            required: required_nums_len,
+            actual: actual_nums_len,
+        });
+    }
+
+    if actual_nums_len > required_nums_len {
+        return Err(DecodeError::TrailingData {
+            required: required_nums_len,
+            actual: actual_nums_len,
+        });
+    }
+
+    Ok(input.len())
+}
+
+/// The length of the encoding of `count` numbers at the start of `input`,
+/// computed from its control bytes alone.
+///
+/// Only the control bytes need to be present: the encoded numbers after them
+/// are not read, so `input` can be just the start of a buffer that is still
+/// being read, for example.
+///
+/// Returns the length, including the control bytes, or an error if `input`
+/// doesn't have all of the control bytes.
+pub fn encoded_len_from_control_bytes(input: &[u8], count: usize) -> Result<usize, DecodeError> {
+    let shape = encoded_shape(count);
+
+    Ok(shape.control_bytes_len + required_encoded_nums_len(input, &shape)?)
+}
+
+/// Returns the length of the encoded numbers described by the control bytes
+/// at the start of `input`, or an error if `input` doesn't have all of the
+/// control bytes.
+pub(crate) fn required_encoded_nums_len(
+    input: &[u8],
+    shape: &EncodedShape,
+) -> Result<usize, DecodeError> {
+    if input.len() < shape.control_bytes_len {
+        return Err(DecodeError::TruncatedControlBytes {
+            required: shape.control_bytes_len,
+            actual: input.len(),
+        });
+    }
+
+    Ok(encoded_nums_len(&input[0..shape.control_bytes_len], shape))
+}
+
+/// Errors that can occur when decoding with the `try_` family of functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The input is too short to hold the control bytes for the requested
+    /// count of numbers.
+    TruncatedControlBytes {
+        /// The number of control bytes needed
+        required: usize,
+        /// The length of the input
+        actual: usize,
+    },
+    /// The input has all of its control bytes, but fewer encoded bytes after
+    /// them than the control bytes describe.
+    TruncatedData {
+        /// The number of encoded bytes described by the control bytes
+        required: usize,
+        /// The number of bytes available after the control bytes
+        actual: usize,
+    },
+    /// There are more bytes after the control bytes than the control bytes
+    /// describe.
+    TrailingData {
+        /// The number of encoded bytes described by the control bytes
+        required: usize,
+        /// The number of bytes available after the control bytes
+        actual: usize,
+    },
+    /// The control byte for a trailing partial quad has bits set for numbers
+    /// that aren't present.
+    UnusedControlBitsSet {
+        /// The offending control byte
+        control_byte: u8,
+    },
+    /// The output slice is too small to hold the numbers to decode.
+    OutputTooSmall {
+        /// The minimum size of output needed
+        required: usize,
+        /// The size of the provided output
+        actual: usize,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::TruncatedControlBytes { required, actual } => write!(
+                f,
+                "Input of {} bytes is too short for {} control bytes",
+                actual, required
+            ),
+            DecodeError::TruncatedData { required, actual } => write!(
+                f,
+                "Control bytes describe {} bytes of encoded numbers, but only {} are present",
+                required, actual
+            ),
+            DecodeError::TrailingData { required, actual } => write!(
+                f,
+                "Control bytes describe {} bytes of encoded numbers, but {} are present",
+                required, actual
+            ),
+            DecodeError::UnusedControlBitsSet { control_byte } => write!(
+                f,
+                "Unused bits are set in final control byte 0x{:02X}",
+                control_byte
+            ),
+            DecodeError::OutputTooSmall { required, actual } => write!(
+                f,
+                "Output of size {} is too small, must be at least {}",
+                actual, required
+            ),
+        }
+    }
+}
+
+impl error::Error for DecodeError {}
+
+/// View signed output as the unsigned numbers with the same bits.
+fn as_u32_slice_mut(output: &mut [i32]) -> &mut [u32] {
+    // i32 and u32 have the same size and alignment, and any bits are valid for both
+    unsafe { std::slice::from_raw_parts_mut(output.as_mut_ptr() as *mut u32, output.len()) }
+}
+
+/// Undo the mapping done by `encode::zigzag_encode_num()`: 0 -> 0, 1 -> -1,
+/// 2 -> 1, 3 -> -2, etc.
+#[inline]
+pub fn zigzag_decode_num(num: u32) -> i32 {
+    ((num >> 1) as i32) ^ -((num & 1) as i32)
+}
+
 #[inline]
 pub fn decode_num_scalar(len: usize, input: &[u8]) -> u32 {
     let mut buf = [0_u8; 4];
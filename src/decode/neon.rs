@@ -1,6 +1,10 @@
-use std::arch::aarch64::{uint8x16_t, vld1q_u8, vqtbl1q_u8, vst1q_u8};
+use std::arch::aarch64::{
+    uint8x16_t, vaddq_u32, vdupq_n_u32, veorq_u32, vextq_u32, vgetq_lane_u32, vld1q_u8, vqtbl1q_u8,
+    vreinterpretq_s32_u32, vreinterpretq_u32_s32, vreinterpretq_u32_u8, vreinterpretq_u8_u32,
+    vshlq_n_u32, vshrq_n_s32, vshrq_n_u32, vst1q_u8,
+};
 
-use super::{DecodeQuadSink, Decoder, WriteQuadToSlice};
+use super::{DecodeQuadSink, Decoder, DeltaDecodeQuad, WriteQuadToSlice, ZigzagDecodeQuad};
 use crate::tables;
 
 /// Decoder using NEON instructions.
@@ -16,47 +20,89 @@ impl Decoder for NeonDecoder {
         nums_already_decoded: usize,
         sink: &mut S,
     ) -> (usize, usize) {
-        let mut bytes_read: usize = 0;
-        let mut nums_decoded: usize = nums_already_decoded;
-
-        // Decoding reads 16 bytes at a time from input, so we won't be able to read the
-        // last few control byte's worth because they may be encoded at 1 byte
-        // per number, so we need 3 additional control bytes' worth of numbers
-        // to provide the extra 12 bytes. However, if control_bytes_to_decode is
-        // short enough, we can decode all the requested numbers because we'll
-        // have un-processed input to ensure we can read 16 bytes.
-        let control_byte_limit = std::cmp::min(
-            control_bytes_to_decode,
-            control_bytes.len().saturating_sub(3),
-        );
-
-        // need to ensure that we can copy 16 encoded bytes, so last few quads will be
-        // handled by a slower loop
-        for &control_byte in control_bytes[0..control_byte_limit].iter() {
-            let length = tables::DECODE_LENGTH_PER_QUAD_TABLE[control_byte as usize];
-            let mask_bytes = tables::DECODE_SHUFFLE_TABLE[control_byte as usize];
-            // we'll read 16 bytes from this always, so using explicit slice size to make
-            // sure it's ok to read unsafe
-            let next_4 = &encoded_nums[bytes_read..(bytes_read + 16)];
-
-            let mask;
-            let data;
-
-            unsafe {
-                mask = vld1q_u8(mask_bytes.as_ptr() as *const u8);
-                data = vld1q_u8(next_4.as_ptr() as *const u8);
-            }
-
-            let decompressed = unsafe { vqtbl1q_u8(data, mask) };
-
-            sink.on_quad(decompressed, nums_decoded);
-
-            bytes_read += length as usize;
-            nums_decoded += 4;
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !std::arch::is_aarch64_feature_detected!("neon") {
+            return (0, 0);
         }
 
-        (nums_decoded - nums_already_decoded, bytes_read)
+        unsafe {
+            decode_quads_neon(
+                control_bytes,
+                encoded_nums,
+                control_bytes_to_decode,
+                nums_already_decoded,
+                sink,
+            )
+        }
+    }
+}
+
+/// The body of `NeonDecoder::decode_quads()`, compiled with NEON enabled so
+/// that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports NEON.
+#[target_feature(enable = "neon")]
+unsafe fn decode_quads_neon<S: DecodeQuadSink<NeonDecoder>>(
+    control_bytes: &[u8],
+    encoded_nums: &[u8],
+    control_bytes_to_decode: usize,
+    nums_already_decoded: usize,
+    sink: &mut S,
+) -> (usize, usize) {
+    let mut bytes_read: usize = 0;
+    let mut nums_decoded: usize = nums_already_decoded;
+
+    let control_byte_limit = std::cmp::min(control_bytes_to_decode, control_bytes.len());
+
+    // Decoding reads 16 bytes at a time from input, so stop 3 control bytes short
+    // of the end, where there may not be enough encoded bytes after them. Every
+    // quad before that has at least 3 quads of at least 4 bytes each after it,
+    // so its 16 bytes are always available.
+    let direct_limit = std::cmp::min(control_byte_limit, control_bytes.len().saturating_sub(3));
+
+    for &control_byte in control_bytes[0..direct_limit].iter() {
+        let length = tables::DECODE_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+        let mask_bytes = tables::DECODE_SHUFFLE_TABLE[control_byte as usize];
+        // we'll read 16 bytes from this always, so using explicit slice size to make
+        // sure it's ok to read unsafe
+        let next_4 = &encoded_nums[bytes_read..(bytes_read + 16)];
+
+        let mask;
+        let data;
+
+        unsafe {
+            mask = vld1q_u8(mask_bytes.as_ptr() as *const u8);
+            data = vld1q_u8(next_4.as_ptr() as *const u8);
+        }
+
+        let decompressed = unsafe { vqtbl1q_u8(data, mask) };
+
+        sink.on_quad(decompressed, nums_decoded);
+
+        bytes_read += length;
+        nums_decoded += 4;
     }
+
+    // The last few quads may be encoded in fewer than 16 bytes with nothing after
+    // them, so those are copied here first so that there are always 16 bytes to
+    // read. The shuffle ignores anything after the first `length` bytes.
+    let mut tail = [0_u8; 16];
+
+    for &control_byte in control_bytes[direct_limit..control_byte_limit].iter() {
+        let length = tables::DECODE_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+        let mask_bytes = tables::DECODE_SHUFFLE_TABLE[control_byte as usize];
+        tail[0..length].copy_from_slice(&encoded_nums[bytes_read..(bytes_read + length)]);
+
+        let decompressed =
+            unsafe { vqtbl1q_u8(vld1q_u8(tail.as_ptr()), vld1q_u8(mask_bytes.as_ptr())) };
+
+        sink.on_quad(decompressed, nums_decoded);
+
+        bytes_read += length;
+        nums_decoded += 4;
+    }
+
+    (nums_decoded - nums_already_decoded, bytes_read)
 }
 
 impl WriteQuadToSlice for NeonDecoder {
@@ -65,29 +111,63 @@ impl WriteQuadToSlice for NeonDecoder {
     }
 }
 
+impl DeltaDecodeQuad for NeonDecoder {
+    #[inline]
+    fn delta_decode_quad(quad: Self::DecodedQuad, prev: u32) -> (Self::DecodedQuad, u32) {
+        unsafe {
+            let nums = vreinterpretq_u32_u8(quad);
+            let zeros = vdupq_n_u32(0);
+            // prefix sum in two steps: add each number to its neighbor, then to the
+            // pair 2 lanes over
+            let sums = vaddq_u32(nums, vextq_u32(zeros, nums, 3));
+            let sums = vaddq_u32(sums, vextq_u32(zeros, sums, 2));
+            let sums = vaddq_u32(sums, vdupq_n_u32(prev));
+
+            (vreinterpretq_u8_u32(sums), vgetq_lane_u32(sums, 3))
+        }
+    }
+}
+
+impl ZigzagDecodeQuad for NeonDecoder {
+    #[inline]
+    fn zigzag_decode_quad(quad: Self::DecodedQuad) -> Self::DecodedQuad {
+        unsafe {
+            let nums = vreinterpretq_u32_u8(quad);
+            // (num >> 1) ^ -(num & 1): smear the low bit across the lane to negate
+            let sign = vreinterpretq_u32_s32(vshrq_n_s32(
+                vreinterpretq_s32_u32(vshlq_n_u32(nums, 31)),
+                31,
+            ));
+
+            vreinterpretq_u8_u32(veorq_u32(vshrq_n_u32(nums, 1), sign))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{cumulative_encoded_len, decode::SliceDecodeSink, encode::encode, scalar::Scalar};
 
     #[test]
-    fn reads_all_requested_control_bytes_when_12_extra_input_bytes() {
+    fn reads_all_requested_control_bytes_without_extra_input_bytes() {
         let nums: Vec<u32> = (0..64).map(|i| i * 100).collect();
         let mut encoded = Vec::new();
         let mut decoded: Vec<u32> = Vec::new();
         encoded.resize(nums.len() * 5, 0xFF);
 
-        encode::<Scalar>(&nums, &mut encoded);
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
 
         // 16 control bytes
         let control_bytes = &encoded[0..16];
-        let encoded_nums = &encoded[16..];
+        // no trailing bytes to read past the end into, so the last few quads have to
+        // go through the tail buffer
+        let encoded_nums = &encoded[16..encoded_len];
 
-        for control_bytes_to_decode in 0..14 {
+        for control_bytes_to_decode in 0..17 {
             decoded.clear();
             decoded.resize(nums.len(), 54321);
 
-            // requesting 13 or fewer control bytes decodes all requested bytes
             let (nums_decoded, bytes_read) = NeonDecoder::decode_quads(
                 control_bytes,
                 encoded_nums,
@@ -103,27 +183,5 @@ mod tests {
             assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
             assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u32));
         }
-
-        for control_bytes_to_decode in 14..17 {
-            decoded.clear();
-            decoded.resize(nums.len(), 54321);
-
-            // requesting more than 13 gets capped to 13 because there may not be enough
-            // encoded nums to read 16 bytes at a time
-            let (nums_decoded, bytes_read) = NeonDecoder::decode_quads(
-                control_bytes,
-                encoded_nums,
-                control_bytes_to_decode,
-                0,
-                &mut SliceDecodeSink::new(&mut decoded),
-            );
-            assert_eq!(13 * 4, nums_decoded);
-            assert_eq!(
-                cumulative_encoded_len(&control_bytes[0..(nums_decoded / 4)]),
-                bytes_read
-            );
-            assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
-            assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u32));
-        }
     }
 }
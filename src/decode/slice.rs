@@ -0,0 +1,222 @@
+use std::{cmp, ops::Range};
+
+use crate::{
+    cumulative_encoded_len,
+    decode::{
+        cursor::{DecodeCursor, DecodeIter},
+        required_encoded_nums_len, DecodeError, Decoder, SliceDecodeSink, StreamVbyteDecoder,
+        WriteQuadToSlice,
+    },
+    encoded_shape, EncodedShape,
+};
+
+/// A view of encoded numbers that can be read by position, like a `&[u32]`.
+///
+/// Finding the `i`th number means adding up the lengths of the quads before
+/// it, which takes time proportional to `i`. If you'll be reading many
+/// numbers, `with_sampled_index()` records where every so many quads start, so
+/// that only the quads since the nearest sample need to be added up.
+///
+/// # Examples
+///
+/// ```
+/// use stream_vbyte::{decode::slice::StreamVbyteSlice, encode::encode_to_vec, scalar::Scalar};
+///
+/// let nums: Vec<u32> = (0..10_000).map(|i| i * 7).collect();
+/// let encoded = encode_to_vec::<Scalar>(&nums);
+///
+/// let slice = StreamVbyteSlice::try_new(&encoded, nums.len())
+///     .unwrap()
+///     .with_sampled_index(256);
+///
+/// assert_eq!(Some(9_999 * 7), slice.get(9_999));
+/// assert_eq!(None, slice.get(10_000));
+///
+/// let mut range = [0; 3];
+/// slice.get_range(5_000..5_003, &mut range);
+/// assert_eq!([35_000, 35_007, 35_014], range);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamVbyteSlice<'a> {
+    input: &'a [u8],
+    shape: EncodedShape,
+    count: usize,
+    /// Numbers between samples, if there's an index
+    sample_interval: usize,
+    /// Where in the encoded numbers each `sample_interval`th number starts
+    samples: Vec<usize>,
+}
+
+impl<'a> StreamVbyteSlice<'a> {
+    /// Create a view of `count` numbers encoded in `input`.
+    ///
+    /// This does not inspect the encoded numbers, so if `input` is truncated,
+    /// reading will panic when it runs off the end. Use `try_new()` for input
+    /// you don't trust.
+    pub fn new(input: &'a [u8], count: usize) -> StreamVbyteSlice<'a> {
+        StreamVbyteSlice {
+            input,
+            shape: encoded_shape(count),
+            count,
+            sample_interval: 0,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Create a view of `count` numbers encoded in `input`, first checking
+    /// that `input` is long enough to hold them. See
+    /// `DecodeCursor::try_new()`.
+    pub fn try_new(input: &'a [u8], count: usize) -> Result<StreamVbyteSlice<'a>, DecodeError> {
+        let shape = encoded_shape(count);
+        let required_nums_len = required_encoded_nums_len(input, &shape)?;
+        let actual_nums_len = input.len() - shape.control_bytes_len;
+
+        if actual_nums_len < required_nums_len {
+            return Err(DecodeError::TruncatedData {
+                required: required_nums_len,
+                actual: actual_nums_len,
+            });
+        }
+
+        Ok(StreamVbyteSlice::new(input, count))
+    }
+
+    /// Build an index of where every `interval`th number starts, so that
+    /// finding a number only means adding up the lengths of at most
+    /// `interval / 4` quads.
+    ///
+    /// The index takes one `usize` per `interval` numbers, and building it
+    /// takes about as long as `DecodeCursor::skip()`ing all of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0 or not a multiple of 4.
+    pub fn with_sampled_index(mut self, interval: usize) -> StreamVbyteSlice<'a> {
+        assert!(
+            interval > 0 && interval % 4 == 0,
+            "Interval must be a nonzero multiple of 4"
+        );
+
+        let control_bytes = &self.input[0..self.shape.complete_control_bytes_len];
+        let mut offset = 0;
+        self.samples = control_bytes
+            .chunks(interval / 4)
+            .map(|chunk| {
+                let sample = offset;
+                offset += cumulative_encoded_len(chunk);
+                sample
+            })
+            .collect();
+        self.sample_interval = interval;
+
+        self
+    }
+
+    /// The count of numbers.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// True iff there are no numbers.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the number at position `index`, or `None` if it's out of
+    /// bounds.
+    ///
+    /// Decodes with `StreamVbyteDecoder`; see `get_with()` to pick a decoder.
+    pub fn get(&self, index: usize) -> Option<u32> {
+        self.get_with::<StreamVbyteDecoder>(index)
+    }
+
+    /// Like `get()`, but decoding with `D`.
+    pub fn get_with<D: Decoder + WriteQuadToSlice>(&self, index: usize) -> Option<u32> {
+        if index >= self.count {
+            return None;
+        }
+
+        let mut cursor = self.cursor_at(index);
+        // at the start of a quad, there has to be room for all of it
+        let mut nums = [0; 4];
+        cursor.decode_slice::<D>(&mut nums);
+
+        Some(nums[0])
+    }
+
+    /// Decode the numbers in `range` into the start of `output`.
+    ///
+    /// Decodes with `StreamVbyteDecoder`; see `get_range_with()` to pick a
+    /// decoder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds, or `output` is shorter than `range`.
+    pub fn get_range(&self, range: Range<usize>, output: &mut [u32]) {
+        self.get_range_with::<StreamVbyteDecoder>(range, output)
+    }
+
+    /// Like `get_range()`, but decoding with `D`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds, or `output` is shorter than `range`.
+    pub fn get_range_with<D: Decoder + WriteQuadToSlice>(
+        &self,
+        range: Range<usize>,
+        output: &mut [u32],
+    ) {
+        assert!(
+            range.start <= range.end && range.end <= self.count,
+            "Range {:?} is out of bounds for {} numbers",
+            range,
+            self.count
+        );
+        let len = range.end - range.start;
+
+        let mut cursor = self.cursor_at(range.start);
+        let mut sink = SliceDecodeSink::new(&mut output[0..len]);
+        cursor.decode_sink_exact::<D, _>(&mut sink, len);
+    }
+
+    /// Returns an iterator over all the numbers. See `DecodeCursor::iter()`.
+    ///
+    /// Decodes with `StreamVbyteDecoder`; see `iter_with()` to pick a decoder.
+    pub fn iter(&self) -> DecodeIter<'a, StreamVbyteDecoder> {
+        self.iter_with::<StreamVbyteDecoder>()
+    }
+
+    /// Like `iter()`, but decoding with `D`.
+    pub fn iter_with<D: Decoder + WriteQuadToSlice>(&self) -> DecodeIter<'a, D> {
+        self.cursor_at(0).iter()
+    }
+
+    /// Returns a cursor positioned at `index`, which may be the count of
+    /// numbers to position it at the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the count of numbers.
+    pub fn cursor_at(&self, index: usize) -> DecodeCursor<'a> {
+        assert!(index <= self.count, "Can't seek past the end of the input");
+
+        let mut cursor = DecodeCursor::new(self.input, self.count);
+
+        let quad = index / 4;
+        // start from the nearest sample if there's an index, and add up the lengths
+        // of the quads since then
+        let (start_quad, start_offset) = if self.samples.is_empty() {
+            (0, 0)
+        } else {
+            // the quad at the very end has no sample of its own if the complete quads
+            // are a multiple of the interval
+            let sample = cmp::min(quad / (self.sample_interval / 4), self.samples.len() - 1);
+            (sample * self.sample_interval / 4, self.samples[sample])
+        };
+        let control_bytes = &self.input[start_quad..quad];
+        cursor.jump_to_quad(quad, start_offset + cumulative_encoded_len(control_bytes));
+        cursor.skip(index % 4);
+
+        cursor
+    }
+}
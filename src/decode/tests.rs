@@ -2,11 +2,17 @@ use rand::Rng;
 
 #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
 use crate::aarch64;
-#[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
+#[cfg(all(
+    any(feature = "x86_ssse3", feature = "x86_avx2", feature = "x86_avx512"),
+    target_arch = "x86_64"
+))]
 use crate::x86;
 use crate::{
     cumulative_encoded_len,
-    decode::{decode_num_scalar, Decoder, SliceDecodeSink, StreamVbyteDecoder, WriteQuadToSlice},
+    decode::{
+        decode_num_scalar, zigzag_decode_num, Decoder, SliceDecodeSink, StreamVbyteDecoder,
+        WriteQuadToSlice,
+    },
     encode::encode,
     random_varint::RandomVarintEncodedLengthIter,
     scalar::Scalar,
@@ -14,15 +20,22 @@ use crate::{
 
 #[test]
 fn decode_num_zero() {
-    assert_eq!(0, decode_num_scalar(1, &vec![0, 0, 0, 0]));
+    assert_eq!(0, decode_num_scalar(1, &[0, 0, 0, 0]));
+}
+
+#[test]
+fn zigzag_decode_num_interleaves_signs() {
+    assert_eq!(0, zigzag_decode_num(0));
+    assert_eq!(-1, zigzag_decode_num(1));
+    assert_eq!(1, zigzag_decode_num(2));
+    assert_eq!(-2, zigzag_decode_num(3));
+    assert_eq!(i32::MAX, zigzag_decode_num(u32::MAX - 1));
+    assert_eq!(i32::MIN, zigzag_decode_num(u32::MAX));
 }
 
 #[test]
 fn decode_num_u32_max() {
-    assert_eq!(
-        u32::MAX,
-        decode_num_scalar(4, &vec![0xFF, 0xFF, 0xFF, 0xFF])
-    );
+    assert_eq!(u32::MAX, decode_num_scalar(4, &[0xFF, 0xFF, 0xFF, 0xFF]));
 }
 
 #[test]
@@ -30,64 +43,69 @@ fn decode_num_4_byte() {
     // 0x04030201
     assert_eq!(
         (4 << 24) + (3 << 16) + (2 << 8) + 1,
-        decode_num_scalar(4, &vec![1, 2, 3, 4])
+        decode_num_scalar(4, &[1, 2, 3, 4])
     );
 }
 
 #[test]
 fn decode_num_3_byte() {
     // 0x04030201
-    assert_eq!(
-        (3 << 16) + (2 << 8) + 1,
-        decode_num_scalar(3, &vec![1, 2, 3])
-    );
+    assert_eq!((3 << 16) + (2 << 8) + 1, decode_num_scalar(3, &[1, 2, 3]));
 }
 
 #[test]
 fn decode_num_2_byte() {
     // 0x04030201
-    assert_eq!((2 << 8) + 1, decode_num_scalar(2, &vec![1, 2]));
+    assert_eq!((2 << 8) + 1, decode_num_scalar(2, &[1, 2]));
 }
 
 #[test]
 fn decode_num_1_byte() {
     // 0x04030201
-    assert_eq!(1, decode_num_scalar(1, &vec![1]));
+    assert_eq!(1, decode_num_scalar(1, &[1]));
 }
 
 #[test]
 fn decoder_honors_nums_to_decode_scalar() {
-    // scalar should be able to decode all control bytes regardless of remaining
-    // input
-    decoder_honors_nums_to_decode::<Scalar>(0);
+    decoder_honors_nums_to_decode::<Scalar>();
 }
 
 #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
 #[test]
 fn decoder_honors_nums_to_decode_neon() {
-    // Sse3 reads 16 bytes at a time, so it cannot handle the last 3 control bytes
-    // in case their encoded nums are <16 bytes
-    decoder_honors_nums_to_decode::<aarch64::NeonDecoder>(3);
+    decoder_honors_nums_to_decode::<aarch64::NeonDecoder>();
 }
 
 #[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
 #[test]
 fn decoder_honors_nums_to_decode_ssse3() {
-    // Sse3 reads 16 bytes at a time, so it cannot handle the last 3 control bytes
-    // in case their encoded nums are <16 bytes
-    decoder_honors_nums_to_decode::<x86::Ssse3>(3);
+    decoder_honors_nums_to_decode::<x86::Ssse3>();
+}
+
+#[cfg(all(feature = "x86_avx2", target_arch = "x86_64"))]
+#[test]
+fn decoder_honors_nums_to_decode_avx2() {
+    decoder_honors_nums_to_decode::<x86::Avx2>();
+}
+
+#[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+#[test]
+fn decoder_honors_nums_to_decode_avx512() {
+    if !crate::x86::avx512_vbmi_detected() {
+        return;
+    }
+
+    decoder_honors_nums_to_decode::<x86::Avx512Decoder>();
 }
 
 #[test]
 fn decoder_honors_nums_to_decode_general() {
-    // Sse3 reads 16 bytes at a time, so it cannot handle the last 3 control bytes
-    // in case their encoded nums are <16 bytes
-    decoder_honors_nums_to_decode::<StreamVbyteDecoder>(3);
+    decoder_honors_nums_to_decode::<StreamVbyteDecoder>();
 }
 
-fn decoder_honors_nums_to_decode<D: Decoder + WriteQuadToSlice>(
-    control_byte_limit_fudge_factor: usize,
-) {
+// every decoder should be able to decode all control bytes regardless of
+// remaining input
+fn decoder_honors_nums_to_decode<D: Decoder + WriteQuadToSlice>() {
     let mut nums: Vec<u32> = Vec::new();
     let mut encoded = Vec::new();
     let mut decoded = Vec::new();
@@ -95,7 +113,7 @@ fn decoder_honors_nums_to_decode<D: Decoder + WriteQuadToSlice>(
 
     let count = 1000;
 
-    for control_bytes_to_decode in 0..(count / 4 - control_byte_limit_fudge_factor) {
+    for control_bytes_to_decode in 0..=(count / 4) {
         nums.clear();
         encoded.clear();
         decoded.clear();
@@ -116,8 +134,8 @@ fn decoder_honors_nums_to_decode<D: Decoder + WriteQuadToSlice>(
         let control_bytes = &encoded[0..count / 4];
         let encoded_nums = &encoded[count / 4..encoded_len];
         let (nums_decoded, bytes_read) = D::decode_quads(
-            &control_bytes,
-            &encoded_nums,
+            control_bytes,
+            encoded_nums,
             control_bytes_to_decode,
             0,
             &mut SliceDecodeSink::new(&mut decoded),
@@ -0,0 +1,271 @@
+use std::{
+    arch::x86_64::{
+        __m128i, __m512i, _mm512_cmpge_epu8_mask, _mm512_cmpgt_epu32_mask, _mm512_loadu_si512,
+        _mm512_mask_add_epi8, _mm512_mask_cmpgt_epu32_mask, _mm512_mask_storeu_epi8,
+        _mm512_maskz_loadu_epi32, _mm512_permutexvar_epi8, _mm512_set1_epi32, _mm512_set1_epi8,
+        _mm512_shuffle_epi8, _mm_loadu_si128, _mm_setzero_si128,
+    },
+    mem,
+};
+
+use super::{DeltaEncodeQuad, EncodeQuadTransform, EncodedLenQuads, Encoder, ZigzagEncodeQuad};
+use crate::{
+    tables,
+    x86::{delta_encode_quad_ssse3, low_bits_mask, zigzag_encode_quad_sse2},
+};
+
+/// Encoder using AVX-512 instructions, including VBMI's `vpermb`.
+///
+/// Encodes four quads at a time into one 64-byte register, and uses masked
+/// stores so that it never writes past the encoded numbers, so unlike `Sse41`
+/// it can encode every quad.
+pub struct Avx512Encoder;
+
+// byte indices 0 to 63
+#[rustfmt::skip]
+const IOTA: [u8; 64] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+    16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+    32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+    48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+
+impl Encoder for Avx512Encoder {
+    type InputQuad = __m128i;
+
+    fn encode_quads<T: EncodeQuadTransform<Self>>(
+        input: &[u32],
+        control_bytes: &mut [u8],
+        output: &mut [u8],
+        transform: &mut T,
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !crate::x86::avx512_vbmi_detected() {
+            return (0, 0);
+        }
+
+        unsafe { encode_quads_avx512(input, control_bytes, output, transform) }
+    }
+}
+
+/// The body of `Avx512Encoder::encode_quads()`, compiled with AVX-512 enabled
+/// so that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports AVX-512 F, BW, and VBMI.
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+unsafe fn encode_quads_avx512<T: EncodeQuadTransform<Avx512Encoder>>(
+    input: &[u32],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+    transform: &mut T,
+) -> (usize, usize) {
+    let mut nums_encoded: usize = 0;
+    let mut bytes_encoded: usize = 0;
+
+    let iota = _mm512_loadu_si512(IOTA.as_ptr() as *const _);
+    let max_1_byte = _mm512_set1_epi32(0xFF);
+    let max_2_bytes = _mm512_set1_epi32(0xFFFF);
+    let max_3_bytes = _mm512_set1_epi32(0xFF_FFFF);
+
+    for group in control_bytes.chunks_mut(4) {
+        // any quads missing from the last group are left as zeros, and are never
+        // stored
+        let mut quads = [_mm_setzero_si128(); 4];
+        for (i, quad) in quads.iter_mut().enumerate().take(group.len()) {
+            let offset = nums_encoded + i * 4;
+            *quad = transform.transform_quad(_mm_loadu_si128(
+                input[offset..(offset + 4)].as_ptr() as *const __m128i
+            ));
+        }
+        let nums = mem::transmute::<[__m128i; 4], __m512i>(quads);
+
+        // A number's lane code is how many of these it exceeds. Spread each mask's
+        // bits out to every other bit so that each number gets a 2-bit field,
+        // then add them up to get the lane codes in control byte order.
+        let codes = spread_bits(_mm512_cmpgt_epu32_mask(nums, max_1_byte))
+            + spread_bits(_mm512_cmpgt_epu32_mask(nums, max_2_bytes))
+            + spread_bits(_mm512_cmpgt_epu32_mask(nums, max_3_bytes));
+        let codes = codes.to_le_bytes();
+
+        let lengths = [
+            tables::DECODE_LENGTH_PER_QUAD_TABLE[codes[0] as usize] as usize,
+            tables::DECODE_LENGTH_PER_QUAD_TABLE[codes[1] as usize] as usize,
+            tables::DECODE_LENGTH_PER_QUAD_TABLE[codes[2] as usize] as usize,
+            tables::DECODE_LENGTH_PER_QUAD_TABLE[codes[3] as usize] as usize,
+        ];
+
+        // pack each quad at the start of its own 16 byte lane
+        let masks = [
+            _mm_loadu_si128(
+                tables::X86_ENCODE_SHUFFLE_TABLE[codes[0] as usize].as_ptr() as *const __m128i
+            ),
+            _mm_loadu_si128(
+                tables::X86_ENCODE_SHUFFLE_TABLE[codes[1] as usize].as_ptr() as *const __m128i
+            ),
+            _mm_loadu_si128(
+                tables::X86_ENCODE_SHUFFLE_TABLE[codes[2] as usize].as_ptr() as *const __m128i
+            ),
+            _mm_loadu_si128(
+                tables::X86_ENCODE_SHUFFLE_TABLE[codes[3] as usize].as_ptr() as *const __m128i
+            ),
+        ];
+        let packed_lanes =
+            _mm512_shuffle_epi8(nums, mem::transmute::<[__m128i; 4], __m512i>(masks));
+
+        // Then close the gaps between lanes with one cross-lane byte permute. Output
+        // byte i comes from lane j, where j is the number of quads that end at or
+        // before i, so the index to read from is i plus the unused bytes at the
+        // end of each of those j lanes.
+        let mut end = 0;
+        let mut indices = iota;
+        for &length in &lengths[0..3] {
+            end += length;
+            let after_quad = _mm512_cmpge_epu8_mask(iota, _mm512_set1_epi8(end as i8));
+            indices = _mm512_mask_add_epi8(
+                indices,
+                after_quad,
+                indices,
+                _mm512_set1_epi8((16 - length) as i8),
+            );
+        }
+        let packed = _mm512_permutexvar_epi8(indices, packed_lanes);
+
+        let group_length: usize = lengths[0..group.len()].iter().sum();
+        // Masked out bytes aren't written, so this won't touch anything past the
+        // encoded numbers even if the encoded numbers for all the quads are short.
+        let encoded = &mut output[bytes_encoded..(bytes_encoded + group_length)];
+        _mm512_mask_storeu_epi8(
+            encoded.as_mut_ptr() as *mut i8,
+            low_bits_mask(group_length),
+            packed,
+        );
+
+        group.copy_from_slice(&codes[0..group.len()]);
+
+        bytes_encoded += group_length;
+        nums_encoded += group.len() * 4;
+    }
+
+    (nums_encoded, bytes_encoded)
+}
+
+/// Move bit `i` of `mask` to bit `2 * i`.
+#[inline]
+fn spread_bits(mask: u16) -> u32 {
+    let mut bits = mask as u32;
+    bits = (bits | bits << 8) & 0x00FF_00FF;
+    bits = (bits | bits << 4) & 0x0F0F_0F0F;
+    bits = (bits | bits << 2) & 0x3333_3333;
+    (bits | bits << 1) & 0x5555_5555
+}
+
+impl EncodedLenQuads for Avx512Encoder {
+    fn encoded_len_quads(input: &[u32]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !crate::x86::avx512_vbmi_detected() {
+            return (0, 0);
+        }
+
+        unsafe { encoded_len_quads_avx512(input) }
+    }
+}
+
+/// The body of `Avx512Encoder::encoded_len_quads()`, compiled with AVX-512
+/// enabled so that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports AVX-512 F, BW, and VBMI.
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+unsafe fn encoded_len_quads_avx512(input: &[u32]) -> (usize, usize) {
+    let mut len: usize = 0;
+
+    let max_1_byte = _mm512_set1_epi32(0xFF);
+    let max_2_bytes = _mm512_set1_epi32(0xFFFF);
+    let max_3_bytes = _mm512_set1_epi32(0xFF_FFFF);
+
+    // 4 quads at a time, with masked loads for any missing from the last group
+    for group in input.chunks(16) {
+        let lanes = low_bits_mask(group.len()) as u16;
+        let nums = _mm512_maskz_loadu_epi32(lanes, group.as_ptr() as *const i32);
+
+        // every number takes at least 1 byte, plus 1 for each of these it exceeds
+        len += group.len()
+            + _mm512_mask_cmpgt_epu32_mask(lanes, nums, max_1_byte).count_ones() as usize
+            + _mm512_mask_cmpgt_epu32_mask(lanes, nums, max_2_bytes).count_ones() as usize
+            + _mm512_mask_cmpgt_epu32_mask(lanes, nums, max_3_bytes).count_ones() as usize;
+    }
+
+    (input.len(), len)
+}
+
+impl DeltaEncodeQuad for Avx512Encoder {
+    #[inline]
+    fn delta_encode_quad(quad: Self::InputQuad, prev: u32) -> (Self::InputQuad, u32) {
+        unsafe { delta_encode_quad_ssse3(quad, prev) }
+    }
+}
+
+impl ZigzagEncodeQuad for Avx512Encoder {
+    #[inline]
+    fn zigzag_encode_quad(quad: Self::InputQuad) -> Self::InputQuad {
+        unsafe { zigzag_encode_quad_sse2(quad) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{encode::NoTransform, *};
+
+    #[test]
+    fn spread_bits_interleaves_zeros() {
+        assert_eq!(0, spread_bits(0));
+        assert_eq!(0b01, spread_bits(0b1));
+        assert_eq!(0b01_00_01, spread_bits(0b101));
+        assert_eq!(0x5555_5555, spread_bits(u16::MAX));
+    }
+
+    #[test]
+    fn low_bits_mask_lengths() {
+        assert_eq!(0, low_bits_mask(0));
+        assert_eq!(0b1, low_bits_mask(1));
+        assert_eq!(u64::MAX >> 1, low_bits_mask(63));
+        assert_eq!(u64::MAX, low_bits_mask(64));
+    }
+
+    #[test]
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
+        if !crate::x86::avx512_vbmi_detected() {
+            return;
+        }
+
+        // cover the whole byte length range
+        let nums: Vec<u32> = (0..32).map(|i| 1 << i).collect();
+        let mut encoded = Vec::new();
+
+        for control_bytes_len in 0..(nums.len() / 4 + 1) {
+            encoded.clear();
+            encoded.resize(nums.len() * 5, 0xFF);
+
+            let (nums_encoded, bytes_written) = {
+                let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
+
+                Avx512Encoder::encode_quads(
+                    &nums[0..4 * control_bytes_len],
+                    control_bytes,
+                    num_bytes,
+                    &mut NoTransform,
+                )
+            };
+
+            assert_eq!(control_bytes_len * 4, nums_encoded);
+            assert_eq!(
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
+                bytes_written
+            );
+
+            let mut expected = vec![0xFF; nums.len() * 5];
+            encode::encode::<scalar::Scalar>(&nums[0..4 * control_bytes_len], &mut expected);
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
+        }
+    }
+}
@@ -1,22 +1,57 @@
+use std::{
+    mem, slice,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
 use crate::{encoded_shape, scalar};
 
 #[cfg(all(feature = "x86_sse41", target_arch = "x86_64"))]
 pub mod sse41;
 
+#[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
+mod ssse3;
+
+// AVX-512 intrinsics need a newer Rust than the rest of the crate, which
+// build.rs checks for when the `x86_avx512` feature is enabled
+#[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+#[clippy::msrv = "1.89"]
+pub mod avx512;
+
 #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
 pub mod neon;
 
-#[cfg(not(any(feature = "x86_sse41", feature = "aarch64_neon")))]
+pub mod stream;
+
+#[cfg(not(any(
+    all(
+        any(feature = "x86_sse41", feature = "x86_ssse3"),
+        target_arch = "x86_64"
+    ),
+    all(feature = "aarch64_neon", target_arch = "aarch64")
+)))]
 pub type StreamVbyteEncoder = scalar::Scalar;
 
 #[cfg(all(feature = "x86_sse41", target_arch = "x86_64"))]
 pub type StreamVbyteEncoder = sse41::Sse41;
 
+// for x86 CPUs that have SSSE3 but not SSE4.1
+#[cfg(all(
+    feature = "x86_ssse3",
+    not(feature = "x86_sse41"),
+    target_arch = "x86_64"
+))]
+pub type StreamVbyteEncoder = crate::x86::Ssse3;
+
 #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
 pub type StreamVbyteEncoder = neon::NeonEncoder;
 
 /// Encode numbers to bytes.
 pub trait Encoder {
+    /// The encoder-specific representation of a quad of input numbers, e.g. a
+    /// SIMD register, that is handed to an `EncodeQuadTransform` before
+    /// encoding.
+    type InputQuad;
+
     /// Encode complete quads of input numbers.
     ///
     /// `control_bytes` will be exactly as long as the number of complete
@@ -25,6 +60,9 @@ pub trait Encoder {
     /// Control bytes are written to `control_bytes` and encoded numbers to
     /// `output`.
     ///
+    /// Each number (or quad of numbers) must be passed through `transform`
+    /// exactly once, in input order, before being encoded.
+    ///
     /// Implementations may choose to encode fewer than the full provided input,
     /// but any writes done must be for full quads.
     ///
@@ -34,18 +72,509 @@ pub trait Encoder {
     ///
     /// Returns the number of numbers encoded and the number of bytes written to
     /// `output`.
-    fn encode_quads(input: &[u32], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize);
+    fn encode_quads<T: EncodeQuadTransform<Self>>(
+        input: &[u32],
+        control_bytes: &mut [u8],
+        output: &mut [u8],
+        transform: &mut T,
+    ) -> (usize, usize);
+}
+
+/// For encoders that can compute the encoded length of quads without encoding
+/// them, which speeds up `encoded_len()`.
+pub trait EncodedLenQuads: Encoder {
+    /// Compute the encoded length of complete quads of input numbers, not
+    /// counting their control bytes.
+    ///
+    /// `input` will be a multiple of 4 long.
+    ///
+    /// Implementations may choose to measure fewer than the full provided
+    /// input, but must measure full quads.
+    ///
+    /// Returns the number of numbers measured and their encoded length.
+    fn encoded_len_quads(input: &[u32]) -> (usize, usize);
+}
+
+/// For encoders that can compute the deltas for a whole quad at once, which
+/// enables `encode_delta()`.
+pub trait DeltaEncodeQuad: Encoder {
+    /// Replace each number in `quad` with the difference between it and the
+    /// number before it, where the number before the first one is `prev`.
+    ///
+    /// Returns the quad of deltas and the last number of the original quad.
+    fn delta_encode_quad(quad: Self::InputQuad, prev: u32) -> (Self::InputQuad, u32);
+}
+
+/// For encoders that can zigzag-map a whole quad at once, which enables
+/// `encode_zigzag()`.
+pub trait ZigzagEncodeQuad: Encoder {
+    /// Replace each number in `quad`, interpreted as an `i32`, with its zigzag
+    /// mapping (see `zigzag_encode_num()`).
+    fn zigzag_encode_quad(quad: Self::InputQuad) -> Self::InputQuad;
+}
+
+/// Applied to each number by `Encoder.encode_quads()` before it is encoded,
+/// for numbers that aren't handed to `EncodeQuadTransform.transform_quad()`.
+pub trait EncodeSingleTransform {
+    /// Returns the number to encode in place of `num`.
+    fn transform_number(&mut self, num: u32) -> u32;
+}
+
+/// Applied to numbers by `Encoder.encode_quads()` before they are encoded.
+///
+/// Like decode sinks, encoders with a natural quad representation will hand
+/// whole quads to `transform_quad()`, while others (and any trailing partial
+/// quad) will use `EncodeSingleTransform.transform_number()`.
+pub trait EncodeQuadTransform<E: Encoder + ?Sized>: EncodeSingleTransform {
+    /// Returns the quad to encode in place of `quad`.
+    fn transform_quad(&mut self, quad: E::InputQuad) -> E::InputQuad;
+}
+
+/// Encode numbers as they are.
+pub struct NoTransform;
+
+impl EncodeSingleTransform for NoTransform {
+    #[inline]
+    fn transform_number(&mut self, num: u32) -> u32 {
+        num
+    }
+}
+
+impl<E: Encoder + ?Sized> EncodeQuadTransform<E> for NoTransform {
+    #[inline]
+    fn transform_quad(&mut self, quad: E::InputQuad) -> E::InputQuad {
+        quad
+    }
+}
+
+/// Encode the difference between each number and the one before it.
+pub struct DeltaTransform {
+    prev: u32,
+}
+
+impl DeltaTransform {
+    /// Create a transform where the number before the first number is `prev`.
+    pub fn new(prev: u32) -> DeltaTransform {
+        DeltaTransform { prev }
+    }
+}
+
+impl EncodeSingleTransform for DeltaTransform {
+    #[inline]
+    fn transform_number(&mut self, num: u32) -> u32 {
+        let delta = num.wrapping_sub(self.prev);
+        self.prev = num;
+
+        delta
+    }
+}
+
+impl<E: DeltaEncodeQuad + ?Sized> EncodeQuadTransform<E> for DeltaTransform {
+    #[inline]
+    fn transform_quad(&mut self, quad: E::InputQuad) -> E::InputQuad {
+        let (deltas, last) = E::delta_encode_quad(quad, self.prev);
+        self.prev = last;
+
+        deltas
+    }
+}
+
+/// Encode the zigzag mapping of each number after applying another transform
+/// to it.
+///
+/// Use `ZigzagTransform::new(NoTransform)` to zigzag-map numbers as they are,
+/// or `ZigzagTransform::new(DeltaTransform::new(prev))` to zigzag-map their
+/// deltas.
+pub struct ZigzagTransform<T> {
+    inner: T,
+}
+
+impl<T> ZigzagTransform<T> {
+    /// Create a transform that zigzag-maps the output of `inner`.
+    pub fn new(inner: T) -> ZigzagTransform<T> {
+        ZigzagTransform { inner }
+    }
+}
+
+impl<T: EncodeSingleTransform> EncodeSingleTransform for ZigzagTransform<T> {
+    #[inline]
+    fn transform_number(&mut self, num: u32) -> u32 {
+        zigzag_encode_num(self.inner.transform_number(num) as i32)
+    }
+}
+
+impl<E, T> EncodeQuadTransform<E> for ZigzagTransform<T>
+where
+    E: ZigzagEncodeQuad + ?Sized,
+    T: EncodeQuadTransform<E>,
+{
+    #[inline]
+    fn transform_quad(&mut self, quad: E::InputQuad) -> E::InputQuad {
+        E::zigzag_encode_quad(self.inner.transform_quad(quad))
+    }
 }
 
 /// Encode the `input` slice into the `output` slice.
 ///
 /// If you don't have specific knowledge of the input that would let you
-/// determine the encoded length ahead of time, make `output` 5x as long as
-/// `input`. The worst-case encoded length is 4 bytes per `u32` plus another
-/// byte for every 4 `u32`s, including any trailing partial 4-some.
+/// determine the encoded length ahead of time, make `output`
+/// `max_encoded_len(input.len())` long. The worst-case encoded length is 4
+/// bytes per `u32` plus another byte for every 4 `u32`s, including any
+/// trailing partial 4-some.
 ///
 /// Returns the number of bytes written to the `output` slice.
 pub fn encode<E: Encoder>(input: &[u32], output: &mut [u8]) -> usize {
+    encode_transformed::<E, _>(input, output, &mut NoTransform)
+}
+
+/// Encode the `input` slice into a new `Vec` that is exactly as long as the
+/// encoded bytes.
+pub fn encode_to_vec<E: Encoder>(input: &[u32]) -> Vec<u8> {
+    let mut output = Vec::new();
+    encode_append::<E>(input, &mut output);
+
+    output
+}
+
+/// Encode the `input` slice onto the end of `output`.
+///
+/// `output` grows by exactly the encoded length. Only the space the encoding
+/// could need is zeroed before encoding into it, and it's zeroed only once.
+///
+/// Returns the number of bytes appended to `output`.
+pub fn encode_append<E: Encoder>(input: &[u32], output: &mut Vec<u8>) -> usize {
+    append_with(output, max_encoded_len(input.len()), |out| {
+        encode::<E>(input, out)
+    })
+}
+
+/// Grow `output` by `max_len` zeroed bytes for `write` to write into, then
+/// shrink it to the number of bytes `write` says it wrote.
+///
+/// Encoders write to a `&mut [u8]`, which has to be initialized, so the space
+/// is zeroed rather than handed over from `output`'s spare capacity as is.
+///
+/// Returns the number of bytes `write` wrote.
+pub(crate) fn append_with<F: FnOnce(&mut [u8]) -> usize>(
+    output: &mut Vec<u8>,
+    max_len: usize,
+    write: F,
+) -> usize {
+    let start = output.len();
+    output.resize(start + max_len, 0);
+
+    let len = write(&mut output[start..]);
+    debug_assert!(len <= max_len);
+    output.truncate(start + len);
+
+    len
+}
+
+/// How many numbers `encode_iter()` and `StreamEncoder` collect before
+/// encoding them
+const ITER_BATCH_LEN: usize = 256;
+
+/// Encode the numbers produced by `iter` onto the end of `output`, without
+/// collecting them into a slice first.
+///
+/// Numbers are encoded a batch at a time from a buffer on the stack. Since
+/// the control bytes come before all the encoded numbers, but how many there
+/// are isn't known until `iter` runs out, the encoded numbers are appended to
+/// `output` as they go and the control bytes are put in front of them at the
+/// end. If `iter`'s `size_hint()` is accurate, space for the control bytes is
+/// set aside up front, so the encoded numbers don't have to be moved.
+///
+/// Returns the count of numbers encoded and the number of bytes appended to
+/// `output`.
+///
+/// # Examples
+///
+/// ```
+/// use stream_vbyte::{decode::decode_to_vec, encode::encode_iter, scalar::Scalar};
+///
+/// let mut encoded = Vec::new();
+/// let (count, len) = encode_iter::<Scalar, _>((0..1000).map(|i| i * 3), &mut encoded);
+/// assert_eq!(1000, count);
+/// assert_eq!(encoded.len(), len);
+///
+/// let decoded = decode_to_vec::<Scalar>(&encoded, count);
+/// assert_eq!(2997, decoded[999]);
+/// ```
+pub fn encode_iter<E, I>(iter: I, output: &mut Vec<u8>) -> (usize, usize)
+where
+    E: Encoder,
+    I: IntoIterator<Item = u32>,
+{
+    let mut iter = iter.into_iter();
+    let start = output.len();
+
+    // set aside as many control bytes as the iterator says there will be
+    let reserved_control_bytes_len = encoded_shape(iter.size_hint().0).control_bytes_len;
+    output.resize(start + reserved_control_bytes_len, 0);
+
+    let mut control_bytes = Vec::with_capacity(reserved_control_bytes_len);
+    let mut batch = [0_u32; ITER_BATCH_LEN];
+    let mut count = 0;
+
+    loop {
+        let mut batch_len = 0;
+        // zip() stops at the end of the batch without taking another number
+        for (slot, num) in batch.iter_mut().zip(&mut iter) {
+            *slot = num;
+            batch_len += 1;
+        }
+        if batch_len == 0 {
+            break;
+        }
+
+        // only the last batch can have a partial quad
+        let shape = encoded_shape(batch_len);
+        let control_bytes_start = control_bytes.len();
+        control_bytes.resize(control_bytes_start + shape.control_bytes_len, 0);
+
+        append_with(output, 4 * batch_len, |out| {
+            encode_split::<E, _>(
+                &batch[0..batch_len],
+                &mut control_bytes[control_bytes_start..],
+                out,
+                &mut NoTransform,
+            )
+        });
+
+        count += batch_len;
+        if batch_len < ITER_BATCH_LEN {
+            break;
+        }
+    }
+
+    // make the space set aside for control bytes the right size, if the size
+    // hint was wrong
+    let control_bytes_end = start + control_bytes.len();
+    let reserved_end = start + reserved_control_bytes_len;
+    if control_bytes_end > reserved_end {
+        let extra = control_bytes_end - reserved_end;
+        output.splice(reserved_end..reserved_end, std::iter::repeat(0).take(extra));
+    } else {
+        output.drain(control_bytes_end..reserved_end);
+    }
+    output[start..control_bytes_end].copy_from_slice(&control_bytes);
+
+    (count, output.len() - start)
+}
+
+/// The most bytes that encoding `count` numbers could take: 4 bytes per number,
+/// plus a control byte for every 4 numbers, including any trailing partial
+/// quad.
+pub fn max_encoded_len(count: usize) -> usize {
+    encoded_shape(count).control_bytes_len + 4 * count
+}
+
+/// The exact number of bytes that encoding `input` takes, without encoding it.
+///
+/// Like `encode_auto()`, lengths are computed with the fastest enabled
+/// implementation that the running CPU supports.
+pub fn encoded_len(input: &[u32]) -> usize {
+    // only ever holds `EncodedLenFn`s
+    let len_fn = unsafe {
+        mem::transmute::<*mut (), EncodedLenFn>(AUTO_ENCODED_LEN_FN.load(Ordering::Relaxed))
+    };
+
+    len_fn(input)
+}
+
+type EncodedLenFn = fn(&[u32]) -> usize;
+
+/// Starts out as `detect_encoded_len()`, which replaces itself with the
+/// implementation it selects.
+static AUTO_ENCODED_LEN_FN: AtomicPtr<()> =
+    AtomicPtr::new(detect_encoded_len as EncodedLenFn as *mut ());
+
+fn detect_encoded_len(input: &[u32]) -> usize {
+    let len_fn = select_auto_encoded_len();
+    // racing threads will select the same thing, so it doesn't matter who wins
+    AUTO_ENCODED_LEN_FN.store(len_fn as *mut (), Ordering::Relaxed);
+
+    len_fn(input)
+}
+
+fn select_auto_encoded_len() -> EncodedLenFn {
+    #[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+    {
+        if crate::x86::avx512_vbmi_detected() {
+            return encoded_len_with::<avx512::Avx512Encoder>;
+        }
+    }
+
+    #[cfg(all(feature = "x86_sse41", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("sse4.1") {
+            return encoded_len_with::<sse41::Sse41>;
+        }
+    }
+
+    #[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("ssse3") {
+            return encoded_len_with::<crate::x86::Ssse3>;
+        }
+    }
+
+    #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return encoded_len_with::<neon::NeonEncoder>;
+        }
+    }
+
+    encoded_len_with::<scalar::Scalar>
+}
+
+fn encoded_len_with<E: EncodedLenQuads>(input: &[u32]) -> usize {
+    let shape = encoded_shape(input.len());
+    let complete_quads = &input[0..shape.complete_control_bytes_len * 4];
+
+    let (nums_measured, nums_len) = E::encoded_len_quads(complete_quads);
+
+    // may be some complete quads left, use Scalar to finish them
+    let (_, more_nums_len) = scalar::Scalar::encoded_len_quads(&complete_quads[nums_measured..]);
+
+    let leftover_len: usize = input[complete_quads.len()..]
+        .iter()
+        .map(|&num| encoded_num_len(num))
+        .sum();
+
+    shape.control_bytes_len + nums_len + more_nums_len + leftover_len
+}
+
+/// Encode the `input` slice into the `output` slice, using the fastest
+/// `Encoder` that the running CPU supports.
+///
+/// Only encoders enabled by this crate's features (e.g. `x86_sse41`) are
+/// candidates. Unlike `StreamVbyteEncoder`, which is chosen at compile time,
+/// the choice is made by checking which instructions the CPU has, so one binary
+/// can run everywhere and still be fast on newer hardware. The check is done
+/// the first time this is called, and the choice is reused after that.
+///
+/// `output` must be sized just like it would be for `encode()`.
+///
+/// Returns the number of bytes written to the `output` slice.
+pub fn encode_auto(input: &[u32], output: &mut [u8]) -> usize {
+    // only ever holds `EncodeFn`s
+    let encode_fn =
+        unsafe { mem::transmute::<*mut (), EncodeFn>(AUTO_ENCODE_FN.load(Ordering::Relaxed)) };
+
+    encode_fn(input, output)
+}
+
+type EncodeFn = fn(&[u32], &mut [u8]) -> usize;
+
+/// Starts out as `detect_encode()`, which replaces itself with the encoder it
+/// selects.
+static AUTO_ENCODE_FN: AtomicPtr<()> = AtomicPtr::new(detect_encode as EncodeFn as *mut ());
+
+fn detect_encode(input: &[u32], output: &mut [u8]) -> usize {
+    let encode_fn = select_auto_encoder();
+    // racing threads will select the same thing, so it doesn't matter who wins
+    AUTO_ENCODE_FN.store(encode_fn as *mut (), Ordering::Relaxed);
+
+    encode_fn(input, output)
+}
+
+fn select_auto_encoder() -> EncodeFn {
+    #[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+    {
+        if crate::x86::avx512_vbmi_detected() {
+            return encode::<avx512::Avx512Encoder>;
+        }
+    }
+
+    #[cfg(all(feature = "x86_sse41", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("sse4.1") {
+            return encode::<sse41::Sse41>;
+        }
+    }
+
+    #[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("ssse3") {
+            return encode::<crate::x86::Ssse3>;
+        }
+    }
+
+    #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return encode::<neon::NeonEncoder>;
+        }
+    }
+
+    encode::<scalar::Scalar>
+}
+
+/// Encode the differences between consecutive numbers in `input`, starting
+/// with the difference between `prev` and the first number, into `output`.
+///
+/// This is the same as the reference implementation's
+/// `streamvbyte_delta_encode()`. Differences are calculated with wrapping
+/// arithmetic, so this is most compact for input that is sorted in increasing
+/// order, like posting lists or timestamps.
+///
+/// `output` must be sized just like it would be for `encode()`.
+///
+/// Returns the number of bytes written to the `output` slice.
+pub fn encode_delta<E: DeltaEncodeQuad>(input: &[u32], prev: u32, output: &mut [u8]) -> usize {
+    encode_transformed::<E, _>(input, output, &mut DeltaTransform::new(prev))
+}
+
+/// Encode the zigzag mapping of signed numbers in `input` into `output`.
+///
+/// Zigzag mapping interleaves negative and positive numbers (0, -1, 1, -2, 2,
+/// ...) so that numbers of small magnitude encode compactly regardless of
+/// their sign. The mapping is done as part of encoding each quad, so no
+/// intermediate buffer is needed.
+///
+/// `output` must be sized just like it would be for `encode()`.
+///
+/// Returns the number of bytes written to the `output` slice.
+pub fn encode_zigzag<E: ZigzagEncodeQuad>(input: &[i32], output: &mut [u8]) -> usize {
+    encode_transformed::<E, _>(
+        as_u32_slice(input),
+        output,
+        &mut ZigzagTransform::new(NoTransform),
+    )
+}
+
+/// Encode the zigzag mapping of the differences between consecutive signed
+/// numbers in `input`, starting with the difference between `prev` and the
+/// first number, into `output`.
+///
+/// This is the same as the reference implementation's `zigzag_delta_encode()`
+/// followed by `streamvbyte_encode()`, and suits input that goes both up and
+/// down by small amounts, like sensor readings. Differences are calculated
+/// with wrapping arithmetic.
+///
+/// `output` must be sized just like it would be for `encode()`.
+///
+/// Returns the number of bytes written to the `output` slice.
+pub fn encode_zigzag_delta<E: ZigzagEncodeQuad + DeltaEncodeQuad>(
+    input: &[i32],
+    prev: i32,
+    output: &mut [u8],
+) -> usize {
+    encode_transformed::<E, _>(
+        as_u32_slice(input),
+        output,
+        &mut ZigzagTransform::new(DeltaTransform::new(prev as u32)),
+    )
+}
+
+fn encode_transformed<E, T>(input: &[u32], output: &mut [u8], transform: &mut T) -> usize
+where
+    E: Encoder,
+    // must support scalar encodes for leftover numbers
+    T: EncodeQuadTransform<E> + EncodeQuadTransform<scalar::Scalar>,
+{
     if input.is_empty() {
         return 0;
     }
@@ -54,10 +583,32 @@ pub fn encode<E: Encoder>(input: &[u32], output: &mut [u8]) -> usize {
 
     let (control_bytes, encoded_bytes) = output.split_at_mut(shape.control_bytes_len);
 
+    control_bytes.len() + encode_split::<E, _>(input, control_bytes, encoded_bytes, transform)
+}
+
+/// Encode `input` with its control bytes written to `control_bytes`, which
+/// must be exactly as long as `input` needs, and its encoded numbers written
+/// to `encoded_bytes`.
+///
+/// Returns the number of bytes written to `encoded_bytes`.
+fn encode_split<E, T>(
+    input: &[u32],
+    control_bytes: &mut [u8],
+    encoded_bytes: &mut [u8],
+    transform: &mut T,
+) -> usize
+where
+    E: Encoder,
+    T: EncodeQuadTransform<E> + EncodeQuadTransform<scalar::Scalar>,
+{
+    let shape = encoded_shape(input.len());
+    debug_assert_eq!(shape.control_bytes_len, control_bytes.len());
+
     let (nums_encoded, mut num_bytes_written) = E::encode_quads(
         input,
         &mut control_bytes[0..shape.complete_control_bytes_len],
         &mut encoded_bytes[..],
+        transform,
     );
 
     // may be some input left, use Scalar to finish it
@@ -67,6 +618,7 @@ pub fn encode<E: Encoder>(input: &[u32], output: &mut [u8]) -> usize {
         &input[nums_encoded..],
         &mut control_bytes[control_bytes_written..shape.complete_control_bytes_len],
         &mut encoded_bytes[num_bytes_written..],
+        transform,
     );
 
     num_bytes_written += more_bytes_written;
@@ -79,37 +631,70 @@ pub fn encode<E: Encoder>(input: &[u32], output: &mut [u8]) -> usize {
     // last control byte, if there were leftovers
     if shape.leftover_numbers > 0 {
         let mut control_byte = 0;
-        let mut nums_encoded = shape.complete_control_bytes_len * 4;
+        let nums_encoded = shape.complete_control_bytes_len * 4;
 
         for i in 0..shape.leftover_numbers {
-            let num = input[nums_encoded];
+            let num = transform.transform_number(input[nums_encoded + i]);
             let len = encode_num_scalar(num, &mut encoded_bytes[num_bytes_written..]);
 
             control_byte |= ((len - 1) as u8) << (i * 2);
 
             num_bytes_written += len;
-            nums_encoded += 1;
         }
         control_bytes[shape.complete_control_bytes_len] = control_byte;
     }
 
-    control_bytes.len() + num_bytes_written
+    num_bytes_written
+}
+
+/// View signed input as the unsigned numbers with the same bits.
+fn as_u32_slice(input: &[i32]) -> &[u32] {
+    // i32 and u32 have the same size and alignment, and any bits are valid for both
+    unsafe { slice::from_raw_parts(input.as_ptr() as *const u32, input.len()) }
+}
+
+/// Map a signed number to an unsigned one such that numbers with small
+/// magnitude map to small numbers: 0 -> 0, -1 -> 1, 1 -> 2, -2 -> 3, etc.
+#[inline]
+pub fn zigzag_encode_num(num: i32) -> u32 {
+    ((num << 1) ^ (num >> 31)) as u32
 }
 
 #[inline]
 pub fn encode_num_scalar(num: u32, output: &mut [u8]) -> usize {
-    // this will calculate 0_u32 as taking 0 bytes, so ensure at least 1 byte
-    let len = std::cmp::max(1_usize, 4 - num.leading_zeros() as usize / 8);
+    let len = encoded_num_len(num);
     let buf = num.to_le_bytes();
     output[..len].copy_from_slice(&buf[..len]);
 
     len
 }
 
+/// The number of bytes `num` is encoded in.
+#[inline]
+pub(crate) fn encoded_num_len(num: u32) -> usize {
+    // this will calculate 0_u32 as taking 0 bytes, so ensure at least 1 byte
+    std::cmp::max(1_usize, 4 - num.leading_zeros() as usize / 8)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(all(
+        feature = "x86_ssse3",
+        not(feature = "x86_sse41"),
+        target_arch = "x86_64"
+    ))]
+    #[test]
+    fn stream_vbyte_encoder_is_ssse3_without_sse41() {
+        use std::any::TypeId;
+
+        assert_eq!(
+            TypeId::of::<crate::x86::Ssse3>(),
+            TypeId::of::<StreamVbyteEncoder>()
+        );
+    }
+
     #[test]
     fn encode_num_zero() {
         let mut buf = [0; 4];
@@ -141,4 +726,14 @@ mod tests {
         assert_eq!(4, encode_num_scalar(u32::MAX, &mut buf));
         assert_eq!(&[0xFF_u8, 0xFF_u8, 0xFF_u8, 0xFF_u8], &buf);
     }
+
+    #[test]
+    fn zigzag_encode_num_interleaves_signs() {
+        assert_eq!(0, zigzag_encode_num(0));
+        assert_eq!(1, zigzag_encode_num(-1));
+        assert_eq!(2, zigzag_encode_num(1));
+        assert_eq!(3, zigzag_encode_num(-2));
+        assert_eq!(u32::MAX - 1, zigzag_encode_num(i32::MAX));
+        assert_eq!(u32::MAX, zigzag_encode_num(i32::MIN));
+    }
 }
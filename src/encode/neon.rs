@@ -1,12 +1,14 @@
 use std::arch::aarch64::{
-    vclzq_u32, vld1_u32, vld1_u8, vld1q_u32, vld1q_u8, vmul_u32, vqsubq_u32, vqtbl1_u8, vqtbl1q_u8,
-    vreinterpret_u32_u8, vreinterpretq_u8_u32, vshrq_n_u32, vst1_u32, vst1q_u8,
+    uint32x4_t, uint8x16_t, vaddvq_u32, vcgtq_u32, vclzq_u32, vdupq_n_u32, veorq_u32, vextq_u32,
+    vgetq_lane_u32, vld1_u32, vld1_u8, vld1q_u32, vld1q_u8, vmul_u32, vqsubq_u32, vqtbl1_u8,
+    vqtbl1q_u8, vreinterpret_u32_u8, vreinterpretq_s32_u32, vreinterpretq_u32_s32,
+    vreinterpretq_u8_u32, vshlq_n_u32, vshrq_n_s32, vshrq_n_u32, vst1_u32, vst1q_u8, vsubq_u32,
 };
 
-use super::Encoder;
+use super::{DeltaEncodeQuad, EncodeQuadTransform, EncodedLenQuads, Encoder, ZigzagEncodeQuad};
 use crate::tables::NEON_ENCODE_SHUFFLE_TABLE;
 
-/// Encoder using SSE4.1 instructions.
+/// Encoder using NEON instructions.
 pub struct NeonEncoder;
 
 // multiplicand to achieve shifts by multiplication
@@ -22,109 +24,228 @@ const SUM: u32 = 1 | 1 << 8 | 1 << 16 | 1 << 24;
 const AGGREGATORS: [u32; 2] = [CONCAT, SUM];
 
 impl Encoder for NeonEncoder {
-    fn encode_quads(input: &[u32], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
-        let nums_encoded: usize = 0;
+    type InputQuad = uint32x4_t;
+
+    fn encode_quads<T: EncodeQuadTransform<Self>>(
+        input: &[u32],
+        control_bytes: &mut [u8],
+        output: &mut [u8],
+        transform: &mut T,
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !std::arch::is_aarch64_feature_detected!("neon") {
+            return (0, 0);
+        }
+
+        unsafe { encode_quads_neon(input, control_bytes, output, transform) }
+    }
+}
 
-        let mut code_and_length: [u32; 2] = [0, 0];
+/// The body of `NeonEncoder::encode_quads()`, compiled with NEON enabled so
+/// that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports NEON.
+#[target_feature(enable = "neon")]
+unsafe fn encode_quads_neon<T: EncodeQuadTransform<NeonEncoder>>(
+    input: &[u32],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+    transform: &mut T,
+) -> (usize, usize) {
+    let mut nums_encoded: usize = 0;
+    let mut bytes_encoded: usize = 0;
+
+    // Encoding writes 16 bytes at a time, but if numbers are encoded with 1 byte
+    // each, that means the last 3 quads could write past what is actually
+    // necessary. So, those are left for a second loop that writes them here
+    // first, and only copies their encoded bytes to `output`.
+    let direct_len = control_bytes.len().saturating_sub(3);
+    let (direct_control_bytes, tail_control_bytes) = control_bytes.split_at_mut(direct_len);
+
+    for control_byte in direct_control_bytes.iter_mut() {
+        let to_encode = transform
+            .transform_quad(unsafe { vld1q_u32(input[nums_encoded..(nums_encoded + 4)].as_ptr()) });
+        let (encoded, code, length) = encode_quad_neon(to_encode);
 
         unsafe {
-            let inq = vld1q_u32(input.as_ptr() as *const u32);
+            vst1q_u8(
+                output[bytes_encoded..(bytes_encoded + 16)].as_mut_ptr(),
+                encoded,
+            )
+        };
 
-            let shifts = vld1q_u32(SHIFTS.as_ptr() as *const u32);
+        *control_byte = code;
 
-            let clzbytes = vshrq_n_u32(vclzq_u32(inq), 3);
-            let lanecodes = vqsubq_u32(shifts, clzbytes);
+        bytes_encoded += length;
+        nums_encoded += 4;
+    }
 
-            let lanebytes = vreinterpretq_u8_u32(lanecodes);
-            let gather_lo = vld1_u8(GATHER_LO.as_ptr() as *const u8);
-            let aggregators = vld1_u32(AGGREGATORS.as_ptr() as *const u32);
-            let lobytes = vqtbl1_u8(lanebytes, gather_lo);
-            let mulshift = vreinterpret_u32_u8(lobytes);
+    let mut tail = [0_u8; 16];
 
-            vst1_u32(
-                code_and_length.as_mut_ptr() as *mut u32,
-                vmul_u32(mulshift, aggregators),
-            );
-        };
+    for control_byte in tail_control_bytes.iter_mut() {
+        let to_encode = transform
+            .transform_quad(unsafe { vld1q_u32(input[nums_encoded..(nums_encoded + 4)].as_ptr()) });
+        let (encoded, code, length) = encode_quad_neon(to_encode);
+
+        unsafe { vst1q_u8(tail.as_mut_ptr(), encoded) };
+        output[bytes_encoded..(bytes_encoded + length)].copy_from_slice(&tail[0..length]);
+
+        *control_byte = code;
+
+        bytes_encoded += length;
+        nums_encoded += 4;
+    }
+
+    (nums_encoded, bytes_encoded)
+}
+
+/// Shuffle a quad into its encoded bytes, returning them along with its control
+/// byte and encoded length. Only the first `length` bytes are part of the
+/// encoding.
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn encode_quad_neon(to_encode: uint32x4_t) -> (uint8x16_t, u8, usize) {
+    let mut code_and_length: [u32; 2] = [0, 0];
+
+    unsafe {
+        let shifts = vld1q_u32(SHIFTS.as_ptr() as *const u32);
+        let gather_lo = vld1_u8(GATHER_LO.as_ptr());
+        let aggregators = vld1_u32(AGGREGATORS.as_ptr());
+
+        // count of leading zero bytes, clamped to 3 so that 0 is encoded in 1 byte
+        let clzbytes = vshrq_n_u32(vclzq_u32(to_encode), 3);
+        let lanecodes = vqsubq_u32(shifts, clzbytes);
+
+        let lanebytes = vreinterpretq_u8_u32(lanecodes);
+        let lobytes = vqtbl1_u8(lanebytes, gather_lo);
+        let mulshift = vreinterpret_u32_u8(lobytes);
+
+        vst1_u32(
+            code_and_length.as_mut_ptr(),
+            vmul_u32(mulshift, aggregators),
+        );
+    }
+
+    let code = code_and_length[0] >> 24;
+    let length = 4 + (code_and_length[1] >> 24);
+
+    let encoded = unsafe {
+        let encoding_shuffle = vld1q_u8(NEON_ENCODE_SHUFFLE_TABLE[code as usize].as_ptr());
+
+        vqtbl1q_u8(vreinterpretq_u8_u32(to_encode), encoding_shuffle)
+    };
+
+    (encoded, code as u8, length as usize)
+}
 
-        let (code, length) = (code_and_length[0] >> 24, 4 + (code_and_length[1] >> 24));
+impl EncodedLenQuads for NeonEncoder {
+    fn encoded_len_quads(input: &[u32]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !std::arch::is_aarch64_feature_detected!("neon") {
+            return (0, 0);
+        }
+
+        unsafe { encoded_len_quads_neon(input) }
+    }
+}
+
+/// The body of `NeonEncoder::encoded_len_quads()`, compiled with NEON enabled
+/// so that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports NEON.
+#[target_feature(enable = "neon")]
+unsafe fn encoded_len_quads_neon(input: &[u32]) -> (usize, usize) {
+    let mut len: usize = 0;
+
+    let max_1_byte = unsafe { vdupq_n_u32(0xFF) };
+    let max_2_bytes = unsafe { vdupq_n_u32(0xFFFF) };
+    let max_3_bytes = unsafe { vdupq_n_u32(0xFF_FFFF) };
+
+    // Each lane counts how many byte length thresholds its numbers exceed, which
+    // grows by at most 3 per quad, so add the lanes up every 2^28 quads before
+    // they could overflow.
+    for batch in input.chunks(4 << 28) {
+        let mut extra_bytes = unsafe { vdupq_n_u32(0) };
+
+        for quad in batch.chunks_exact(4) {
+            unsafe {
+                let nums = vld1q_u32(quad.as_ptr());
+
+                // lanes that compare greater are all 1s, i.e. u32::MAX, so subtracting
+                // adds 1
+                extra_bytes = vsubq_u32(extra_bytes, vcgtq_u32(nums, max_1_byte));
+                extra_bytes = vsubq_u32(extra_bytes, vcgtq_u32(nums, max_2_bytes));
+                extra_bytes = vsubq_u32(extra_bytes, vcgtq_u32(nums, max_3_bytes));
+            }
+        }
 
+        // every number takes at least 1 byte
+        len += batch.len() + unsafe { vaddvq_u32(extra_bytes) } as usize;
+    }
+
+    (input.len() / 4 * 4, len)
+}
+
+impl DeltaEncodeQuad for NeonEncoder {
+    #[inline]
+    fn delta_encode_quad(quad: Self::InputQuad, prev: u32) -> (Self::InputQuad, u32) {
         unsafe {
-            let encoding_shuffle =
-                vld1q_u8(NEON_ENCODE_SHUFFLE_TABLE[code as usize].as_ptr() as *const u8);
-            let inu = vreinterpretq_u8_u32(vld1q_u32(input.as_ptr() as *const u32));
+            // shift in prev from the low end: [prev, num0, num1, num2]
+            let preceding = vextq_u32(vdupq_n_u32(prev), quad, 3);
 
-            vst1q_u8(
-                output.as_mut_ptr() as *mut u8,
-                vqtbl1q_u8(inu, encoding_shuffle),
-            );
+            (vsubq_u32(quad, preceding), vgetq_lane_u32(quad, 3))
         }
+    }
+}
 
-        // Encoding writes 16 bytes at a time, but if numbers are encoded with 1 byte
-        // each, that means the last 3 quads could write past what is actually
-        // necessary. So, don't process the last few control bytes.
-        let _control_byte_limit = control_bytes.len().saturating_sub(3);
+impl ZigzagEncodeQuad for NeonEncoder {
+    #[inline]
+    fn zigzag_encode_quad(quad: Self::InputQuad) -> Self::InputQuad {
+        unsafe {
+            // (num << 1) ^ (num >> 31), with an arithmetic shift to smear the sign bit
+            let sign = vreinterpretq_u32_s32(vshrq_n_s32(vreinterpretq_s32_u32(quad), 31));
 
-        (nums_encoded, length as usize)
+            veorq_u32(vshlq_n_u32(quad, 1), sign)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::*;
+    use crate::{encode::NoTransform, *};
 
     #[test]
-    fn encodes_all_but_last_3_control_bytes() {
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
         // cover the whole byte length range
         let nums: Vec<u32> = (0..32).map(|i| 1 << i).collect();
         let mut encoded = Vec::new();
-        let mut decoded: Vec<u32> = Vec::new();
 
         for control_bytes_len in 0..(nums.len() / 4 + 1) {
             encoded.clear();
             encoded.resize(nums.len() * 5, 0xFF);
-            decoded.clear();
-            decoded.resize(nums.len(), 54321);
 
             let (nums_encoded, bytes_written) = {
                 let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
 
-                NeonEncoder::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes)
+                NeonEncoder::encode_quads(
+                    &nums[0..4 * control_bytes_len],
+                    control_bytes,
+                    num_bytes,
+                    &mut NoTransform,
+                )
             };
 
-            let control_bytes_written = nums_encoded / 4;
-
+            assert_eq!(control_bytes_len * 4, nums_encoded);
             assert_eq!(
-                cumulative_encoded_len(&encoded[0..control_bytes_written]),
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
                 bytes_written
             );
 
-            // the last control byte written may not have populated all 16 output bytes with
-            // encoded nums, depending on the length required. Any unused
-            // trailing bytes will have had 0 written, but nothing beyond that
-            // 16 should be touched.
-
-            let length_before_final_control_byte =
-                cumulative_encoded_len(&encoded[0..control_bytes_written.saturating_sub(1)]);
-
-            let bytes_written_for_final_control_byte =
-                bytes_written - length_before_final_control_byte;
-            let trailing_zero_len = if control_bytes_written > 0 {
-                16 - bytes_written_for_final_control_byte
-            } else {
-                0
-            };
-
-            assert!(&encoded[control_bytes_len + bytes_written
-                ..control_bytes_len + bytes_written + trailing_zero_len]
-                .iter()
-                .all(|&i| i == 0));
-            assert!(
-                &encoded[control_bytes_len + bytes_written + trailing_zero_len..]
-                    .iter()
-                    .all(|&i| i == 0xFF)
-            );
+            let mut expected = vec![0xFF; nums.len() * 5];
+            encode::encode::<scalar::Scalar>(&nums[0..4 * control_bytes_len], &mut expected);
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
         }
     }
 }
@@ -1,12 +1,13 @@
-use std::{
-    arch::x86_64::{
-        __m128i, _mm_loadu_si128, _mm_min_epu8, _mm_mullo_epi32, _mm_shuffle_epi8, _mm_storeu_si128,
-    },
-    simd,
+use std::arch::x86_64::{
+    __m128i, _mm_extract_epi8, _mm_loadu_si128, _mm_min_epu8, _mm_mullo_epi32, _mm_shuffle_epi8,
+    _mm_storeu_si128,
 };
 
-use super::Encoder;
-use crate::tables;
+use super::{DeltaEncodeQuad, EncodeQuadTransform, EncodedLenQuads, Encoder, ZigzagEncodeQuad};
+use crate::{
+    tables,
+    x86::{delta_encode_quad_ssse3, encoded_len_quads_sse2, zigzag_encode_quad_sse2},
+};
 
 /// Encoder using SSE4.1 instructions.
 pub struct Sse41;
@@ -20,14 +21,14 @@ const SHIFTS: [u32; 4] = [SHIFT, SHIFT, SHIFT, SHIFT];
 // These are concatenated into the control byte, and also used to sum to find
 // the total length. The ordering of these codes is determined by how the
 // bytemap is calculated; see comments below.
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 const LANECODES: [u8; 16] = [
     0, 3, 2, 3,
     1, 3, 2, 3,
     128, 128, 128, 128,
     128, 128, 128, 128];
 // gather high bytes from each lane, 2 copies
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 const GATHER_HI: [u8; 16] = [
     15, 11, 7, 3,
     15, 11, 7, 3,
@@ -41,152 +42,205 @@ const SUM: u32 = 1 | 1 << 8 | 1 << 16 | 1 << 24;
 const AGGREGATORS: [u32; 4] = [CONCAT, SUM, 0, 0];
 
 impl Encoder for Sse41 {
-    fn encode_quads(input: &[u32], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
-        let mut nums_encoded: usize = 0;
-        let mut bytes_encoded: usize = 0;
-
-        // TODO these load unaligned once https://github.com/rust-lang/rust/issues/33626
-        // hits stable
-        let ones = unsafe { _mm_loadu_si128(ONES.as_ptr() as *const __m128i) };
-        let shifts = unsafe { _mm_loadu_si128(SHIFTS.as_ptr() as *const __m128i) };
-        let lanecodes = unsafe { _mm_loadu_si128(LANECODES.as_ptr() as *const __m128i) };
-        let gather_hi = unsafe { _mm_loadu_si128(GATHER_HI.as_ptr() as *const __m128i) };
-        let aggregators = unsafe { _mm_loadu_si128(AGGREGATORS.as_ptr() as *const __m128i) };
-
-        // Encoding writes 16 bytes at a time, but if numbers are encoded with 1 byte
-        // each, that means the last 3 quads could write past what is actually
-        // necessary. So, don't process the last few control bytes.
-        let control_byte_limit = control_bytes.len().saturating_sub(3);
-
-        for control_byte in &mut control_bytes[0..control_byte_limit].iter_mut() {
-            let to_encode = unsafe {
-                _mm_loadu_si128(input[nums_encoded..(nums_encoded + 4)].as_ptr() as *const __m128i)
-            };
+    type InputQuad = __m128i;
+
+    fn encode_quads<T: EncodeQuadTransform<Self>>(
+        input: &[u32],
+        control_bytes: &mut [u8],
+        output: &mut [u8],
+        transform: &mut T,
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("sse4.1") {
+            return (0, 0);
+        }
+
+        unsafe { encode_quads_sse41(input, control_bytes, output, transform) }
+    }
+}
 
-            // clamp each byte to 1 if nonzero
-            let mins = unsafe { _mm_min_epu8(to_encode, ones) };
-
-            // Apply shifts to clamped bytes. e.g. u32::max_value() would be (little
-            // endian): 00000001 00000001 00000001 00000001
-            // and after multiplication aka shifting:
-            // 00000001 00000011 00000111 00000111
-            // 1 << 16 | 1 would be:
-            // 00000001 00000000 00000001 00000000
-            // and shifted:
-            // 00000001 00000010 00000101 00000010
-            // At most the bottom 3 bits of each byte will be set by shifting.
-            // What we care about is the bottom 3 bits of the high byte in each num.
-            // A 1-byte number (clamped to 0x01000000) will accumulate to 0x00 in the top
-            // byte because there isn't a 3-byte shift to get that set bit into
-            // the top byte. A 2-byte number (clamped to 0x00010000) will
-            // accumulate to 0x04 in the top byte because the set bit would have
-            // been shifted 2 bytes + 2 bits higher. A 3-byte number will have
-            // the 0x02 bit set in the top byte, and possibly the 0x04
-            // bit set as well if the 2nd byte was non-zero.
-            // A 4-byte number will have the 0x01 bit set in the top byte, and possibly 0x02
-            // and 0x04.
-            // In summary, byte lengths -> high byte:
-            // 1-byte -> 0x00
-            // 2-byte -> 0x04
-            // 3-byte -> 0x02, 0x06
-            // 4-byte -> 0x01, 0x05, 0x03, 0x07
-            let bytemaps = unsafe { _mm_mullo_epi32(mins, shifts) };
-
-            // Map high bytes to the corresponding lane codes. (Other bytes are mapped as
-            // well but are not used.)
-            let shuffled_lanecodes = unsafe { _mm_shuffle_epi8(lanecodes, bytemaps) };
-
-            // Assemble 2 copies of the high byte from each of the 4 numbers.
-            // The first copy will be used to calculate the control byte, the second the
-            // length.
-            let hi_bytes = unsafe { _mm_shuffle_epi8(shuffled_lanecodes, gather_hi) };
-
-            // use CONCAT to shift the lane code bits from bytes 0-3 into 1 byte (byte 3)
-            // use SUM to sum lane code bits from bytes 4-7 into 1 byte (byte 7)
-            let code_and_length = unsafe { _mm_mullo_epi32(hi_bytes, aggregators) };
-
-            let bytes = simd::u8x16::from(code_and_length);
-            let code = bytes[3];
-            let length = bytes[7] + 4;
-
-            let mask_bytes = tables::X86_ENCODE_SHUFFLE_TABLE[code as usize];
-            let encode_mask = unsafe { _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i) };
-
-            let encoded = unsafe { _mm_shuffle_epi8(to_encode, encode_mask) };
-
-            unsafe {
-                _mm_storeu_si128(
-                    output[bytes_encoded..(bytes_encoded + 16)].as_ptr() as *mut __m128i,
-                    encoded,
-                );
-            }
-
-            *control_byte = code;
-
-            bytes_encoded += length as usize;
-            nums_encoded += 4;
+/// The body of `Sse41::encode_quads()`, compiled with SSE4.1 enabled so that
+/// the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports SSE4.1.
+#[target_feature(enable = "sse4.1")]
+unsafe fn encode_quads_sse41<T: EncodeQuadTransform<Sse41>>(
+    input: &[u32],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+    transform: &mut T,
+) -> (usize, usize) {
+    let mut nums_encoded: usize = 0;
+    let mut bytes_encoded: usize = 0;
+
+    // Encoding writes 16 bytes at a time, but if numbers are encoded with 1 byte
+    // each, that means the last 3 quads could write past what is actually
+    // necessary. So, those are left for a second loop that writes them here
+    // first, and only copies their encoded bytes to `output`.
+    let direct_len = control_bytes.len().saturating_sub(3);
+    let (direct_control_bytes, tail_control_bytes) = control_bytes.split_at_mut(direct_len);
+
+    for control_byte in direct_control_bytes.iter_mut() {
+        let to_encode = transform.transform_quad(unsafe {
+            _mm_loadu_si128(input[nums_encoded..(nums_encoded + 4)].as_ptr() as *const __m128i)
+        });
+        let (encoded, code, length) = encode_quad_sse41(to_encode);
+
+        unsafe {
+            _mm_storeu_si128(
+                output[bytes_encoded..(bytes_encoded + 16)].as_ptr() as *mut __m128i,
+                encoded,
+            );
         }
 
-        (nums_encoded, bytes_encoded)
+        *control_byte = code;
+
+        bytes_encoded += length;
+        nums_encoded += 4;
+    }
+
+    let mut tail = [0_u8; 16];
+
+    for control_byte in tail_control_bytes.iter_mut() {
+        let to_encode = transform.transform_quad(unsafe {
+            _mm_loadu_si128(input[nums_encoded..(nums_encoded + 4)].as_ptr() as *const __m128i)
+        });
+        let (encoded, code, length) = encode_quad_sse41(to_encode);
+
+        unsafe { _mm_storeu_si128(tail.as_mut_ptr() as *mut __m128i, encoded) };
+        output[bytes_encoded..(bytes_encoded + length)].copy_from_slice(&tail[0..length]);
+
+        *control_byte = code;
+
+        bytes_encoded += length;
+        nums_encoded += 4;
+    }
+
+    (nums_encoded, bytes_encoded)
+}
+
+/// Shuffle a quad into its encoded bytes, returning them along with its control
+/// byte and encoded length. Only the first `length` bytes are part of the
+/// encoding.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn encode_quad_sse41(to_encode: __m128i) -> (__m128i, u8, usize) {
+    // TODO these load unaligned once https://github.com/rust-lang/rust/issues/33626
+    // hits stable
+    let ones = unsafe { _mm_loadu_si128(ONES.as_ptr() as *const __m128i) };
+    let shifts = unsafe { _mm_loadu_si128(SHIFTS.as_ptr() as *const __m128i) };
+    let lanecodes = unsafe { _mm_loadu_si128(LANECODES.as_ptr() as *const __m128i) };
+    let gather_hi = unsafe { _mm_loadu_si128(GATHER_HI.as_ptr() as *const __m128i) };
+    let aggregators = unsafe { _mm_loadu_si128(AGGREGATORS.as_ptr() as *const __m128i) };
+
+    // clamp each byte to 1 if nonzero
+    let mins = _mm_min_epu8(to_encode, ones);
+
+    // Apply shifts to clamped bytes. e.g. u32::max_value() would be (little
+    // endian): 00000001 00000001 00000001 00000001
+    // and after multiplication aka shifting:
+    // 00000001 00000011 00000111 00000111
+    // 1 << 16 | 1 would be:
+    // 00000001 00000000 00000001 00000000
+    // and shifted:
+    // 00000001 00000010 00000101 00000010
+    // At most the bottom 3 bits of each byte will be set by shifting.
+    // What we care about is the bottom 3 bits of the high byte in each num.
+    // A 1-byte number (clamped to 0x01000000) will accumulate to 0x00 in the top
+    // byte because there isn't a 3-byte shift to get that set bit into
+    // the top byte. A 2-byte number (clamped to 0x00010000) will
+    // accumulate to 0x04 in the top byte because the set bit would have
+    // been shifted 2 bytes + 2 bits higher. A 3-byte number will have
+    // the 0x02 bit set in the top byte, and possibly the 0x04
+    // bit set as well if the 2nd byte was non-zero.
+    // A 4-byte number will have the 0x01 bit set in the top byte, and possibly 0x02
+    // and 0x04.
+    // In summary, byte lengths -> high byte:
+    // 1-byte -> 0x00
+    // 2-byte -> 0x04
+    // 3-byte -> 0x02, 0x06
+    // 4-byte -> 0x01, 0x05, 0x03, 0x07
+    let bytemaps = _mm_mullo_epi32(mins, shifts);
+
+    // Map high bytes to the corresponding lane codes. (Other bytes are mapped as
+    // well but are not used.)
+    let shuffled_lanecodes = _mm_shuffle_epi8(lanecodes, bytemaps);
+
+    // Assemble 2 copies of the high byte from each of the 4 numbers.
+    // The first copy will be used to calculate the control byte, the second the
+    // length.
+    let hi_bytes = _mm_shuffle_epi8(shuffled_lanecodes, gather_hi);
+
+    // use CONCAT to shift the lane code bits from bytes 0-3 into 1 byte (byte 3)
+    // use SUM to sum lane code bits from bytes 4-7 into 1 byte (byte 7)
+    let code_and_length = _mm_mullo_epi32(hi_bytes, aggregators);
+
+    let code = _mm_extract_epi8(code_and_length, 3) as u8;
+    let length = _mm_extract_epi8(code_and_length, 7) as usize + 4;
+
+    let mask_bytes = tables::X86_ENCODE_SHUFFLE_TABLE[code as usize];
+    let encode_mask = unsafe { _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i) };
+
+    (_mm_shuffle_epi8(to_encode, encode_mask), code, length)
+}
+
+impl EncodedLenQuads for Sse41 {
+    fn encoded_len_quads(input: &[u32]) -> (usize, usize) {
+        // every x86_64 CPU has SSE2
+        unsafe { encoded_len_quads_sse2(input) }
+    }
+}
+
+impl DeltaEncodeQuad for Sse41 {
+    #[inline]
+    fn delta_encode_quad(quad: Self::InputQuad, prev: u32) -> (Self::InputQuad, u32) {
+        unsafe { delta_encode_quad_ssse3(quad, prev) }
+    }
+}
+
+impl ZigzagEncodeQuad for Sse41 {
+    #[inline]
+    fn zigzag_encode_quad(quad: Self::InputQuad) -> Self::InputQuad {
+        unsafe { zigzag_encode_quad_sse2(quad) }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::*;
+    use crate::{encode::NoTransform, *};
 
     #[test]
-    fn encodes_all_but_last_3_control_bytes() {
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
         // cover the whole byte length range
         let nums: Vec<u32> = (0..32).map(|i| 1 << i).collect();
         let mut encoded = Vec::new();
-        let mut decoded: Vec<u32> = Vec::new();
 
         for control_bytes_len in 0..(nums.len() / 4 + 1) {
             encoded.clear();
             encoded.resize(nums.len() * 5, 0xFF);
-            decoded.clear();
-            decoded.resize(nums.len(), 54321);
 
             let (nums_encoded, bytes_written) = {
                 let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
 
-                Sse41::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes)
+                Sse41::encode_quads(
+                    &nums[0..4 * control_bytes_len],
+                    control_bytes,
+                    num_bytes,
+                    &mut NoTransform,
+                )
             };
 
-            let control_bytes_written = nums_encoded / 4;
-
+            assert_eq!(control_bytes_len * 4, nums_encoded);
             assert_eq!(
-                cumulative_encoded_len(&encoded[0..control_bytes_written]),
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
                 bytes_written
             );
 
-            // the last control byte written may not have populated all 16 output bytes with
-            // encoded nums, depending on the length required. Any unused
-            // trailing bytes will have had 0 written, but nothing beyond that
-            // 16 should be touched.
-
-            let length_before_final_control_byte =
-                cumulative_encoded_len(&encoded[0..control_bytes_written.saturating_sub(1)]);
-
-            let bytes_written_for_final_control_byte =
-                bytes_written - length_before_final_control_byte;
-            let trailing_zero_len = if control_bytes_written > 0 {
-                16 - bytes_written_for_final_control_byte
-            } else {
-                0
-            };
-
-            assert!(&encoded[control_bytes_len + bytes_written
-                ..control_bytes_len + bytes_written + trailing_zero_len]
-                .iter()
-                .all(|&i| i == 0));
-            assert!(
-                &encoded[control_bytes_len + bytes_written + trailing_zero_len..]
-                    .iter()
-                    .all(|&i| i == 0xFF)
-            );
+            let mut expected = vec![0xFF; nums.len() * 5];
+            encode::encode::<scalar::Scalar>(&nums[0..4 * control_bytes_len], &mut expected);
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
         }
     }
 }
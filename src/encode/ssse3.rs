@@ -0,0 +1,183 @@
+use std::arch::x86_64::{
+    __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_setzero_si128,
+    _mm_shuffle_epi8, _mm_storeu_si128,
+};
+
+use super::{DeltaEncodeQuad, EncodeQuadTransform, EncodedLenQuads, Encoder, ZigzagEncodeQuad};
+use crate::{
+    tables,
+    x86::{delta_encode_quad_ssse3, encoded_len_quads_sse2, zigzag_encode_quad_sse2, Ssse3},
+};
+
+// translate a 4-bit map of which bytes in a number are nonzero into its lane
+// code, which is the index of the highest nonzero byte (or 0 if there isn't
+// one)
+#[rustfmt::skip]
+const LANECODES: [u8; 16] = [
+    0, 0, 1, 1,
+    2, 2, 2, 2,
+    3, 3, 3, 3,
+    3, 3, 3, 3];
+
+impl Encoder for Ssse3 {
+    type InputQuad = __m128i;
+
+    fn encode_quads<T: EncodeQuadTransform<Self>>(
+        input: &[u32],
+        control_bytes: &mut [u8],
+        output: &mut [u8],
+        transform: &mut T,
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("ssse3") {
+            return (0, 0);
+        }
+
+        unsafe { encode_quads_ssse3(input, control_bytes, output, transform) }
+    }
+}
+
+/// The body of `Ssse3::encode_quads()`, compiled with SSSE3 enabled so that
+/// the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports SSSE3.
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_quads_ssse3<T: EncodeQuadTransform<Ssse3>>(
+    input: &[u32],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+    transform: &mut T,
+) -> (usize, usize) {
+    let mut nums_encoded: usize = 0;
+    let mut bytes_encoded: usize = 0;
+
+    // Encoding writes 16 bytes at a time, but if numbers are encoded with 1 byte
+    // each, that means the last 3 quads could write past what is actually
+    // necessary. So, those are left for a second loop that writes them here
+    // first, and only copies their encoded bytes to `output`.
+    let direct_len = control_bytes.len().saturating_sub(3);
+    let (direct_control_bytes, tail_control_bytes) = control_bytes.split_at_mut(direct_len);
+
+    for control_byte in direct_control_bytes.iter_mut() {
+        let to_encode = transform.transform_quad(unsafe {
+            _mm_loadu_si128(input[nums_encoded..(nums_encoded + 4)].as_ptr() as *const __m128i)
+        });
+        let (encoded, code, length) = encode_quad_ssse3(to_encode);
+
+        unsafe {
+            _mm_storeu_si128(
+                output[bytes_encoded..(bytes_encoded + 16)].as_ptr() as *mut __m128i,
+                encoded,
+            );
+        }
+
+        *control_byte = code;
+
+        bytes_encoded += length;
+        nums_encoded += 4;
+    }
+
+    let mut tail = [0_u8; 16];
+
+    for control_byte in tail_control_bytes.iter_mut() {
+        let to_encode = transform.transform_quad(unsafe {
+            _mm_loadu_si128(input[nums_encoded..(nums_encoded + 4)].as_ptr() as *const __m128i)
+        });
+        let (encoded, code, length) = encode_quad_ssse3(to_encode);
+
+        unsafe { _mm_storeu_si128(tail.as_mut_ptr() as *mut __m128i, encoded) };
+        output[bytes_encoded..(bytes_encoded + length)].copy_from_slice(&tail[0..length]);
+
+        *control_byte = code;
+
+        bytes_encoded += length;
+        nums_encoded += 4;
+    }
+
+    (nums_encoded, bytes_encoded)
+}
+
+/// Shuffle a quad into its encoded bytes, returning them along with its control
+/// byte and encoded length. Only the first `length` bytes are part of the
+/// encoding.
+#[inline]
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_quad_ssse3(to_encode: __m128i) -> (__m128i, u8, usize) {
+    let zeros = _mm_setzero_si128();
+
+    // Without SSE4.1's 32-bit multiply, find each number's length from a map of
+    // which of its bytes are nonzero instead: 4 bits per number, low byte first.
+    let nonzero_bytes = !_mm_movemask_epi8(_mm_cmpeq_epi8(to_encode, zeros)) as usize;
+
+    let code = LANECODES[nonzero_bytes & 0xF]
+        | LANECODES[(nonzero_bytes >> 4) & 0xF] << 2
+        | LANECODES[(nonzero_bytes >> 8) & 0xF] << 4
+        | LANECODES[(nonzero_bytes >> 12) & 0xF] << 6;
+    let length = tables::DECODE_LENGTH_PER_QUAD_TABLE[code as usize] as usize;
+
+    let mask_bytes = tables::X86_ENCODE_SHUFFLE_TABLE[code as usize];
+    let encode_mask = unsafe { _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i) };
+
+    (_mm_shuffle_epi8(to_encode, encode_mask), code, length)
+}
+
+impl EncodedLenQuads for Ssse3 {
+    fn encoded_len_quads(input: &[u32]) -> (usize, usize) {
+        // every x86_64 CPU has SSE2
+        unsafe { encoded_len_quads_sse2(input) }
+    }
+}
+
+impl DeltaEncodeQuad for Ssse3 {
+    #[inline]
+    fn delta_encode_quad(quad: Self::InputQuad, prev: u32) -> (Self::InputQuad, u32) {
+        unsafe { delta_encode_quad_ssse3(quad, prev) }
+    }
+}
+
+impl ZigzagEncodeQuad for Ssse3 {
+    #[inline]
+    fn zigzag_encode_quad(quad: Self::InputQuad) -> Self::InputQuad {
+        unsafe { zigzag_encode_quad_sse2(quad) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{encode::NoTransform, *};
+
+    #[test]
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
+        // cover the whole byte length range
+        let nums: Vec<u32> = (0..32).map(|i| 1 << i).collect();
+        let mut encoded = Vec::new();
+
+        for control_bytes_len in 0..(nums.len() / 4 + 1) {
+            encoded.clear();
+            encoded.resize(nums.len() * 5, 0xFF);
+
+            let (nums_encoded, bytes_written) = {
+                let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
+
+                Ssse3::encode_quads(
+                    &nums[0..4 * control_bytes_len],
+                    control_bytes,
+                    num_bytes,
+                    &mut NoTransform,
+                )
+            };
+
+            assert_eq!(control_bytes_len * 4, nums_encoded);
+            assert_eq!(
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
+                bytes_written
+            );
+
+            let mut expected = vec![0xFF; nums.len() * 5];
+            encode::encode::<scalar::Scalar>(&nums[0..4 * control_bytes_len], &mut expected);
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
+        }
+    }
+}
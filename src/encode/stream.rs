@@ -0,0 +1,162 @@
+use std::{cmp, marker::PhantomData};
+
+use super::{append_with, encode_split, encoded_num_len, Encoder, NoTransform, ITER_BATCH_LEN};
+use crate::encoded_shape;
+
+/// Encodes numbers that arrive a few at a time.
+///
+/// The format puts all the control bytes before all the encoded numbers, so
+/// they're kept in separate buffers until `finish()` puts them together.
+/// Numbers are encoded with `E` as soon as there's a batch of them, so only a
+/// batch's worth is ever held back unencoded.
+///
+/// # Examples
+///
+/// ```
+/// use stream_vbyte::{decode::decode_to_vec, encode::stream::StreamEncoder, scalar::Scalar};
+///
+/// let mut encoder = StreamEncoder::<Scalar>::new();
+/// encoder.push(1);
+/// encoder.extend(&[300, 70_000]);
+/// assert_eq!(3, encoder.len());
+/// assert_eq!(7, encoder.encoded_len_so_far());
+///
+/// let encoded = encoder.finish();
+/// assert_eq!(vec![1, 300, 70_000], decode_to_vec::<Scalar>(&encoded, 3));
+/// ```
+#[derive(Debug)]
+pub struct StreamEncoder<E> {
+    control_bytes: Vec<u8>,
+    encoded_nums: Vec<u8>,
+    /// Numbers that haven't been encoded yet
+    pending: [u32; ITER_BATCH_LEN],
+    pending_len: usize,
+    /// The count of numbers that have been encoded, not counting `pending`
+    nums_encoded: usize,
+    encoder: PhantomData<E>,
+}
+
+impl<E: Encoder> StreamEncoder<E> {
+    /// Create an encoder with no numbers.
+    pub fn new() -> StreamEncoder<E> {
+        StreamEncoder {
+            control_bytes: Vec::new(),
+            encoded_nums: Vec::new(),
+            pending: [0; ITER_BATCH_LEN],
+            pending_len: 0,
+            nums_encoded: 0,
+            encoder: PhantomData,
+        }
+    }
+
+    /// Add one number.
+    pub fn push(&mut self, num: u32) {
+        self.pending[self.pending_len] = num;
+        self.pending_len += 1;
+
+        // a full batch is always complete quads
+        if self.pending_len == ITER_BATCH_LEN {
+            self.encode_pending();
+        }
+    }
+
+    /// Add all the numbers in `nums`.
+    ///
+    /// Complete quads are encoded straight from `nums` once any numbers held
+    /// back from earlier calls have been encoded.
+    pub fn extend(&mut self, mut nums: &[u32]) {
+        if self.pending_len > 0 {
+            let len = cmp::min(ITER_BATCH_LEN - self.pending_len, nums.len());
+            self.pending[self.pending_len..(self.pending_len + len)].copy_from_slice(&nums[0..len]);
+            self.pending_len += len;
+            nums = &nums[len..];
+
+            if self.pending_len < ITER_BATCH_LEN {
+                // ran out of numbers before filling the batch
+                return;
+            }
+            self.encode_pending();
+        }
+
+        let quads_len = nums.len() / 4 * 4;
+        self.encode_complete_quads(&nums[0..quads_len]);
+
+        let leftover = &nums[quads_len..];
+        self.pending[0..leftover.len()].copy_from_slice(leftover);
+        self.pending_len = leftover.len();
+    }
+
+    /// The count of numbers added so far.
+    pub fn len(&self) -> usize {
+        self.nums_encoded + self.pending_len
+    }
+
+    /// True iff no numbers have been added.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The length of the encoding that `finish()` would return now.
+    pub fn encoded_len_so_far(&self) -> usize {
+        let pending = &self.pending[0..self.pending_len];
+        let pending_len: usize = pending.iter().map(|&num| encoded_num_len(num)).sum();
+
+        self.control_bytes.len()
+            + self.encoded_nums.len()
+            + encoded_shape(pending.len()).control_bytes_len
+            + pending_len
+    }
+
+    /// Encode any numbers that have been held back, and return the encoding of
+    /// all the numbers.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.encode_pending();
+
+        let mut output = self.control_bytes;
+        output.extend_from_slice(&self.encoded_nums);
+
+        output
+    }
+
+    /// Encode the numbers in `pending`, which may end in a partial quad only if
+    /// no more numbers will be added.
+    fn encode_pending(&mut self) {
+        append_split::<E>(
+            &self.pending[0..self.pending_len],
+            &mut self.control_bytes,
+            &mut self.encoded_nums,
+        );
+        self.nums_encoded += self.pending_len;
+        self.pending_len = 0;
+    }
+
+    fn encode_complete_quads(&mut self, nums: &[u32]) {
+        append_split::<E>(nums, &mut self.control_bytes, &mut self.encoded_nums);
+        self.nums_encoded += nums.len();
+    }
+}
+
+impl<E: Encoder> Default for StreamEncoder<E> {
+    fn default() -> StreamEncoder<E> {
+        StreamEncoder::new()
+    }
+}
+
+/// Encode `nums`, appending their control bytes to `control_bytes` and their
+/// encoded numbers to `encoded_nums`.
+fn append_split<E: Encoder>(nums: &[u32], control_bytes: &mut Vec<u8>, encoded_nums: &mut Vec<u8>) {
+    let control_bytes_start = control_bytes.len();
+    control_bytes.resize(
+        control_bytes_start + encoded_shape(nums.len()).control_bytes_len,
+        0,
+    );
+
+    append_with(encoded_nums, 4 * nums.len(), |out| {
+        encode_split::<E, _>(
+            nums,
+            &mut control_bytes[control_bytes_start..],
+            out,
+            &mut NoTransform,
+        )
+    });
+}
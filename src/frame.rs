@@ -0,0 +1,550 @@
+//! A self-describing frame around an encoded stream, so that the count of
+//! numbers doesn't have to be stored separately.
+//!
+//! A frame is:
+//!
+//! - the 4 magic bytes `SVBF`
+//! - a format version byte, currently 1
+//! - the count of numbers, as an unsigned LEB128 varint
+//! - a flags byte: bit 0 is set if the numbers are delta encoded (as with
+//!   `encode::encode_delta()`, starting from 0), and bit 1 if they are signed
+//!   and zigzag encoded (as with `encode::encode_zigzag()`), and bit 2 if the
+//!   frame has a checksum. Other bits must be 0.
+//! - the control bytes and encoded numbers, just like `encode::encode()` writes
+//!   them
+//! - if the frame has a checksum, the CRC32C of the control bytes and encoded
+//!   numbers, as 4 little-endian bytes
+//!
+//! A bit flip in a control byte changes the length of every number after it,
+//! so the checksum is worth having for data that might be corrupted at rest.
+//! Decoding checks it before decoding anything, and `verify_framed()` checks
+//! it without decoding at all.
+//!
+//! # Examples
+//!
+//! ```
+//! use stream_vbyte::{
+//!     frame::{decode_framed, encode_framed, verify_framed, FrameOptions},
+//!     scalar::Scalar
+//! };
+//!
+//! let nums: Vec<u32> = (0..12_345).map(|i| i * 3).collect();
+//!
+//! let mut framed = Vec::new();
+//! // sorted input is smaller as deltas
+//! let options = FrameOptions {
+//!     delta: true,
+//!     checksum: true,
+//! };
+//! encode_framed::<Scalar>(&nums, options, &mut framed);
+//! assert_eq!(framed.len(), verify_framed(&framed).unwrap());
+//!
+//! // no need to remember the count
+//! assert_eq!(nums, decode_framed::<Scalar>(&framed).unwrap());
+//! ```
+
+use std::{error, fmt};
+
+use crate::{
+    crc32c::crc32c,
+    decode::{
+        cursor::{DecodeCursor, DiscardSink},
+        required_encoded_nums_len, DecodeError, DecodeQuadSink, DeltaDecodeQuad, DeltaSink,
+        SliceDecodeSink, WriteQuadToSlice, ZigzagDecodeQuad, ZigzagSink,
+    },
+    encode::{
+        append_with, encode, encode_delta, encode_zigzag, encode_zigzag_delta, max_encoded_len,
+        DeltaEncodeQuad, ZigzagEncodeQuad,
+    },
+    encoded_shape,
+    scalar::Scalar,
+    varint::{read_varint, write_varint, VarintError},
+};
+
+const MAGIC: [u8; 4] = *b"SVBF";
+const VERSION: u8 = 1;
+
+const FLAG_DELTA: u8 = 0x01;
+const FLAG_ZIGZAG: u8 = 0x02;
+const FLAG_CHECKSUM: u8 = 0x04;
+const KNOWN_FLAGS: u8 = FLAG_DELTA | FLAG_ZIGZAG | FLAG_CHECKSUM;
+
+const CHECKSUM_LEN: usize = 4;
+
+/// How to encode the numbers in a frame.
+///
+/// Whether numbers are signed is decided by which `encode_framed` function is
+/// used, rather than here.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameOptions {
+    /// Encode the differences between consecutive numbers, which is smaller
+    /// for sorted input
+    pub delta: bool,
+    /// Append a CRC32C of the encoded numbers, so that corruption is detected
+    /// when decoding
+    pub checksum: bool,
+}
+
+impl FrameOptions {
+    fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.delta {
+            flags |= FLAG_DELTA;
+        }
+        if self.checksum {
+            flags |= FLAG_CHECKSUM;
+        }
+
+        flags
+    }
+}
+
+/// The header at the start of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameHeader {
+    count: usize,
+    flags: u8,
+    len: usize,
+}
+
+impl FrameHeader {
+    /// Read the header at the start of `input`.
+    pub fn read(input: &[u8]) -> Result<FrameHeader, FrameError> {
+        if input.len() < MAGIC.len() + 1 {
+            return Err(FrameError::TruncatedHeader);
+        }
+
+        if input[0..MAGIC.len()] != MAGIC {
+            return Err(FrameError::BadMagic);
+        }
+
+        let version = input[MAGIC.len()];
+        if version != VERSION {
+            return Err(FrameError::UnsupportedVersion(version));
+        }
+
+        let (count, count_len) = read_varint(&input[(MAGIC.len() + 1)..]).map_err(|e| match e {
+            VarintError::Truncated => FrameError::TruncatedHeader,
+            VarintError::Overflow => FrameError::CountOverflow,
+        })?;
+        let flags_offset = MAGIC.len() + 1 + count_len;
+
+        let flags = *input.get(flags_offset).ok_or(FrameError::TruncatedHeader)?;
+        if flags & !KNOWN_FLAGS != 0 {
+            return Err(FrameError::UnsupportedFlags(flags));
+        }
+
+        Ok(FrameHeader {
+            count,
+            flags,
+            len: flags_offset + 1,
+        })
+    }
+
+    /// The number of numbers in the frame.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// True iff the numbers are delta encoded.
+    pub fn is_delta(&self) -> bool {
+        self.flags & FLAG_DELTA != 0
+    }
+
+    /// True iff the numbers are signed and zigzag encoded.
+    pub fn is_zigzag(&self) -> bool {
+        self.flags & FLAG_ZIGZAG != 0
+    }
+
+    /// True iff the encoded numbers are followed by a checksum.
+    pub fn has_checksum(&self) -> bool {
+        self.flags & FLAG_CHECKSUM != 0
+    }
+
+    /// The length of the header, which is where the encoded stream starts.
+    pub fn header_len(&self) -> usize {
+        self.len
+    }
+
+    fn checksum_len(&self) -> usize {
+        if self.has_checksum() {
+            CHECKSUM_LEN
+        } else {
+            0
+        }
+    }
+}
+
+/// Encode `input` as a frame, appending it to `output`.
+///
+/// Returns the number of bytes appended to `output`.
+pub fn encode_framed<E: DeltaEncodeQuad>(
+    input: &[u32],
+    options: FrameOptions,
+    output: &mut Vec<u8>,
+) -> usize {
+    append_frame(input.len(), options.flags(), output, |stream| {
+        if options.delta {
+            encode_delta::<E>(input, 0, stream)
+        } else {
+            encode::<E>(input, stream)
+        }
+    })
+}
+
+/// Encode signed `input` as a frame, appending it to `output`.
+///
+/// Returns the number of bytes appended to `output`.
+pub fn encode_framed_zigzag<E: ZigzagEncodeQuad + DeltaEncodeQuad>(
+    input: &[i32],
+    options: FrameOptions,
+    output: &mut Vec<u8>,
+) -> usize {
+    append_frame(
+        input.len(),
+        FLAG_ZIGZAG | options.flags(),
+        output,
+        |stream| {
+            if options.delta {
+                encode_zigzag_delta::<E>(input, 0, stream)
+            } else {
+                encode_zigzag::<E>(input, stream)
+            }
+        },
+    )
+}
+
+/// Write a header to `output`, then make room for the stream and have
+/// `encode_stream` write it, then append the checksum if `flags` asks for one.
+fn append_frame<F: FnOnce(&mut [u8]) -> usize>(
+    count: usize,
+    flags: u8,
+    output: &mut Vec<u8>,
+    encode_stream: F,
+) -> usize {
+    let start = output.len();
+
+    output.extend_from_slice(&MAGIC);
+    output.push(VERSION);
+    write_varint(count as u64, output);
+    output.push(flags);
+
+    let stream_start = output.len();
+    append_with(output, max_encoded_len(count), encode_stream);
+
+    if flags & FLAG_CHECKSUM != 0 {
+        let checksum = crc32c(&output[stream_start..]);
+        output.extend_from_slice(&checksum.to_le_bytes());
+    }
+
+    output.len() - start
+}
+
+/// Check that the frame at the start of `input` is well formed, and that its
+/// checksum matches if it has one, without decoding any numbers.
+///
+/// Returns the length of the frame, so that any data after it can be found.
+pub fn verify_framed(input: &[u8]) -> Result<usize, FrameError> {
+    let (header, stream) = read_frame(input)?;
+
+    Ok(header.header_len() + stream.len() + header.checksum_len())
+}
+
+/// Read the header of the frame at the start of `input`, check that the
+/// encoded stream is all there and that its checksum matches.
+///
+/// Returns the header and the encoded stream, without the checksum.
+fn read_frame(input: &[u8]) -> Result<(FrameHeader, &[u8]), FrameError> {
+    let header = FrameHeader::read(input)?;
+    let after_header = &input[header.header_len()..];
+
+    // Every 4 numbers need a control byte, so a count with more control bytes
+    // than there is input left is corrupt, and too large to size anything by.
+    if header.count() / 4 > after_header.len() {
+        return Err(FrameError::CountOverflow);
+    }
+
+    let shape = encoded_shape(header.count());
+    let required_nums_len = required_encoded_nums_len(after_header, &shape)?;
+    let actual_nums_len = after_header.len() - shape.control_bytes_len;
+    if actual_nums_len < required_nums_len {
+        return Err(DecodeError::TruncatedData {
+            required: required_nums_len,
+            actual: actual_nums_len,
+        }
+        .into());
+    }
+
+    let (stream, rest) = after_header.split_at(shape.control_bytes_len + required_nums_len);
+
+    if header.has_checksum() {
+        if rest.len() < CHECKSUM_LEN {
+            return Err(FrameError::TruncatedChecksum);
+        }
+
+        let mut checksum_bytes = [0; CHECKSUM_LEN];
+        checksum_bytes.copy_from_slice(&rest[0..CHECKSUM_LEN]);
+        let expected = u32::from_le_bytes(checksum_bytes);
+        let actual = crc32c(stream);
+
+        if expected != actual {
+            return Err(FrameError::ChecksumMismatch { expected, actual });
+        }
+    }
+
+    Ok((header, stream))
+}
+
+/// Decode a frame encoded with `encode_framed()`.
+///
+/// Returns the decoded numbers, or an error if the frame is malformed or
+/// holds signed numbers.
+pub fn decode_framed<D>(input: &[u8]) -> Result<Vec<u32>, FrameError>
+where
+    D: DeltaDecodeQuad + ZigzagDecodeQuad + WriteQuadToSlice,
+{
+    let mut cursor = FramedDecodeCursor::new(input)?;
+
+    if cursor.header().is_zigzag() {
+        return Err(FrameError::SignednessMismatch { zigzag: true });
+    }
+
+    let mut output = vec![0; cursor.header().count()];
+    cursor.decode_slice::<D>(&mut output);
+
+    Ok(output)
+}
+
+/// Decode a frame encoded with `encode_framed_zigzag()`.
+///
+/// Returns the decoded numbers, or an error if the frame is malformed or
+/// holds unsigned numbers.
+pub fn decode_framed_zigzag<D>(input: &[u8]) -> Result<Vec<i32>, FrameError>
+where
+    D: DeltaDecodeQuad + ZigzagDecodeQuad + WriteQuadToSlice,
+{
+    let mut cursor = FramedDecodeCursor::new(input)?;
+
+    if !cursor.header().is_zigzag() {
+        return Err(FrameError::SignednessMismatch { zigzag: false });
+    }
+
+    let mut output = vec![0; cursor.header().count()];
+    cursor.decode_slice::<D>(&mut output);
+
+    Ok(output.into_iter().map(|num| num as i32).collect())
+}
+
+/// A `DecodeCursor` for a frame, which reads the count of numbers and how
+/// they were encoded from the frame's header.
+///
+/// For frames of signed numbers, decoded numbers are `i32`s represented as
+/// `u32`s, just like `ZigzagSink` produces.
+#[derive(Debug)]
+pub struct FramedDecodeCursor<'a> {
+    header: FrameHeader,
+    cursor: DecodeCursor<'a>,
+    /// The running sum for delta frames
+    prev: u32,
+}
+
+impl<'a> FramedDecodeCursor<'a> {
+    /// Create a new cursor for the frame at the start of `input`, first
+    /// checking that the header is valid, that `input` is long enough to hold
+    /// all of the frame's encoded numbers, and that the checksum matches if
+    /// the frame has one.
+    pub fn new(input: &'a [u8]) -> Result<FramedDecodeCursor<'a>, FrameError> {
+        let (header, stream) = read_frame(input)?;
+        let cursor = DecodeCursor::new(stream, header.count());
+
+        Ok(FramedDecodeCursor {
+            header,
+            cursor,
+            prev: 0,
+        })
+    }
+
+    /// The frame's header.
+    pub fn header(&self) -> &FrameHeader {
+        &self.header
+    }
+
+    /// Skip `to_skip` numbers, with the same restrictions as
+    /// `DecodeCursor::skip()`.
+    ///
+    /// For delta frames, the skipped numbers must be decoded to keep the
+    /// running sum up to date, so this is not much faster than decoding.
+    pub fn skip(&mut self, to_skip: usize) {
+        if !self.header.is_delta() {
+            self.cursor.skip(to_skip);
+            return;
+        }
+
+        assert!(
+            to_skip <= self.header.count() - self.cursor.position(),
+            "Can't skip past the end of the input"
+        );
+
+        // zigzag mapping is undone before deltas are summed
+        let mut discard_sink = DiscardSink;
+        let mut delta_sink = DeltaSink::new(&mut discard_sink, self.prev);
+        if self.header.is_zigzag() {
+            let mut zigzag_sink = ZigzagSink::new(&mut delta_sink);
+            self.cursor
+                .decode_sink_exact::<Scalar, _>(&mut zigzag_sink, to_skip);
+        } else {
+            self.cursor
+                .decode_sink_exact::<Scalar, _>(&mut delta_sink, to_skip);
+        }
+        self.prev = delta_sink.prev();
+    }
+
+    /// Decode into the `output` buffer. See `DecodeCursor::decode_slice()`.
+    pub fn decode_slice<D>(&mut self, output: &mut [u32]) -> usize
+    where
+        D: DeltaDecodeQuad + ZigzagDecodeQuad + WriteQuadToSlice,
+    {
+        let output_len = output.len();
+
+        let mut sink = SliceDecodeSink::new(output);
+
+        self.decode_sink::<D, SliceDecodeSink>(&mut sink, output_len)
+    }
+
+    /// Decode at most `max_numbers_to_decode` numbers from the input and hand
+    /// them to `sink`. See `DecodeCursor::decode_sink()`.
+    pub fn decode_sink<D, S>(&mut self, sink: &mut S, max_numbers_to_decode: usize) -> usize
+    where
+        D: DeltaDecodeQuad + ZigzagDecodeQuad,
+        S: DecodeQuadSink<D> + DecodeQuadSink<Scalar>,
+    {
+        // zigzag mapping is undone before deltas are summed
+        match (self.header.is_zigzag(), self.header.is_delta()) {
+            (false, false) => self.cursor.decode_sink::<D, _>(sink, max_numbers_to_decode),
+            (false, true) => {
+                let mut delta_sink = DeltaSink::new(sink, self.prev);
+                let nums_decoded = self
+                    .cursor
+                    .decode_sink::<D, _>(&mut delta_sink, max_numbers_to_decode);
+                self.prev = delta_sink.prev();
+
+                nums_decoded
+            }
+            (true, false) => {
+                let mut zigzag_sink = ZigzagSink::new(sink);
+                self.cursor
+                    .decode_sink::<D, _>(&mut zigzag_sink, max_numbers_to_decode)
+            }
+            (true, true) => {
+                let mut delta_sink = DeltaSink::new(sink, self.prev);
+                let nums_decoded = {
+                    let mut zigzag_sink = ZigzagSink::new(&mut delta_sink);
+                    self.cursor
+                        .decode_sink::<D, _>(&mut zigzag_sink, max_numbers_to_decode)
+                };
+                self.prev = delta_sink.prev();
+
+                nums_decoded
+            }
+        }
+    }
+
+    /// Returns the total length of input scanned so far, including the
+    /// header. Once everything is decoded, this is the length of the frame,
+    /// including the checksum.
+    pub fn input_consumed(&self) -> usize {
+        let checksum_len = if self.has_more() {
+            0
+        } else {
+            self.header.checksum_len()
+        };
+
+        self.header.header_len() + self.cursor.input_consumed() + checksum_len
+    }
+
+    /// Returns true iff there are more numbers to be decoded.
+    pub fn has_more(&self) -> bool {
+        self.cursor.has_more()
+    }
+}
+
+/// Errors that can occur when reading a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FrameError {
+    /// The input doesn't start with the frame magic bytes.
+    BadMagic,
+    /// The frame's format version isn't one this version of the crate can
+    /// read.
+    UnsupportedVersion(u8),
+    /// The input ends partway through the header.
+    TruncatedHeader,
+    /// The count of numbers is too large, either for a `usize` or for the
+    /// rest of the input to hold, or its varint is too long.
+    CountOverflow,
+    /// The flags byte has bits set that this version of the crate doesn't
+    /// know about.
+    UnsupportedFlags(u8),
+    /// The frame holds signed numbers and was read as unsigned, or vice
+    /// versa.
+    SignednessMismatch {
+        /// Whether the frame holds signed numbers
+        zigzag: bool,
+    },
+    /// The encoded numbers after the header are malformed.
+    Decode(DecodeError),
+    /// The input ends partway through the checksum after the encoded numbers.
+    TruncatedChecksum,
+    /// The checksum of the encoded numbers doesn't match the one in the
+    /// frame, so the frame is corrupt.
+    ChecksumMismatch {
+        /// The checksum stored in the frame
+        expected: u32,
+        /// The checksum of the encoded numbers as read
+        actual: u32,
+    },
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FrameError::BadMagic => write!(f, "Input does not start with a frame header"),
+            FrameError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported frame version {}", version)
+            }
+            FrameError::TruncatedHeader => write!(f, "Input ends partway through frame header"),
+            FrameError::CountOverflow => write!(f, "Frame count is too large"),
+            FrameError::UnsupportedFlags(flags) => {
+                write!(f, "Unsupported frame flags 0x{:02X}", flags)
+            }
+            FrameError::SignednessMismatch { zigzag: true } => {
+                write!(f, "Frame holds signed numbers, but was read as unsigned")
+            }
+            FrameError::SignednessMismatch { zigzag: false } => {
+                write!(f, "Frame holds unsigned numbers, but was read as signed")
+            }
+            FrameError::Decode(e) => write!(f, "Invalid frame contents: {}", e),
+            FrameError::TruncatedChecksum => write!(f, "Input ends partway through frame checksum"),
+            FrameError::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "Frame checksum mismatch: expected 0x{:08X}, got 0x{:08X}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl error::Error for FrameError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            FrameError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for FrameError {
+    fn from(e: DecodeError) -> FrameError {
+        FrameError::Decode(e)
+    }
+}
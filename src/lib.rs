@@ -2,8 +2,26 @@
 //! format.
 //!
 //! To encode all your numbers to a `&[u8]`, or decode all your bytes to a
-//! `&[u32]`, see `encode()` and `decode()` respectively. For more sophisticated
-//! decoding functionality, see `DecodeCursor`.
+//! `&[u32]`, see `encode()` and `decode()` respectively, or `encode_to_vec()`
+//! and `decode_to_vec()` to have them allocate the output for you. To size
+//! buffers yourself, see `max_encoded_len()` and `encoded_len()`. For more
+//! sophisticated decoding functionality, see `DecodeCursor`, or
+//! `StreamVbyteSlice` to read numbers by position.
+//!
+//! If your numbers aren't all in one slice, `encode::encode_iter()` encodes
+//! them from an iterator, and `encode::stream::StreamEncoder` encodes them as
+//! they arrive.
+//!
+//! To store the count of numbers along with them, see the `frame` module, and
+//! for fast random access into long streams, see the `blocked` module. To
+//! encode straight to a file or socket without holding the whole encoding in
+//! memory, see `chunked::StreamVbyteWriter`.
+//!
+//! For `u16`s, the `svb16` module has a variant of the format that encodes each
+//! number in 1 or 2 bytes, and for `u64`s, the `svb64` module has one that
+//! encodes each number in 1, 2, 4 or 8 bytes. For `u32`s that are mostly
+//! zeros, the `svb0124` module has the reference implementation's "0124"
+//! variant, where zeros take no bytes.
 //!
 //! There are two traits, `Encoder` and `Decoder`, that allow you to choose what
 //! logic to use in the inner hot loops.
@@ -19,43 +37,47 @@
 //!
 //! # The more complex, really fast way
 //!
-//! If you can use nightly Rust (currently needed for SIMD) and you know which
-//! hardware you'll be running on, or you can add runtime detection of CPU
-//! features, you can choose to use an implementation that takes advantage of
-//! your hardware. Something like [raw-cpuid](https://crates.io/crates/raw-cpuid) or [auxv](https://crates.io/crates/auxv) will
-//! probably be useful for runtime CPU feature detection.
+//! If you know which hardware you'll be running on, you can choose to use an
+//! implementation that takes advantage of your hardware.
+//!
+//! If you don't know which hardware you'll be running on, enable the SIMD
+//! `feature`s you're interested in and use `encode::encode_auto()` and
+//! `decode::decode_auto()`, which check the CPU's features at runtime and use
+//! the fastest implementation it supports.
 //!
 //! Performance numbers are calculated on an E5-1650v3 on encoding/decoding 1
 //! million random numbers at a time. You can run the benchmarks yourself to see
 //! how your hardware does.
 //!
-//! Both `feature`s and `target_feature`s are used because `target_feature` is
-//! not in stable Rust yet and this library should remain usable by stable Rust,
-//! so non-stable-friendly things are hidden behind `feature`s.
+//! SIMD implementations are only compiled when the corresponding `feature` is
+//! enabled, and work on stable Rust. Each one checks at runtime that the CPU
+//! supports the instructions it uses and otherwise leaves all the work to
+//! `Scalar`, so enabling a `feature` is always safe. Enabling the matching
+//! `target_feature`s as well lets the compiler skip that check.
 //!
 //! ## Encoders
 //!
-//! | Type         | Performance    | Hardware                                 |
-//! `target_feature` | `feature`   | | ------------ | -------------- |
-//! ---------------------------------------- | ---------------- | ----------- |
-//! | `Scalar`     | ≈140 million/s | All                                      |
-//! none             | none        | | `x86::Sse41` | ≈1 billion/s   | x86 with
-//! SSE4.1 (Penryn and above, 2008) | `sse4.1`         | `x86_sse41` |
+//! | Type                 | Performance    | Hardware                                         | `target_feature`                    | `feature`    |
+//! | -------------------- | -------------- | ------------------------------------------------ | ----------------------------------- | ------------ |
+//! | `Scalar`             | ≈140 million/s | All                                              | none                                | none         |
+//! | `x86::Sse41`         | ≈1 billion/s   | x86 with SSE4.1 (Penryn and above, 2008)         | `sse4.1`                            | `x86_sse41`  |
+//! | `x86::Ssse3`         | not measured   | x86 with SSSE3 (Woodcrest and above, 2006)       | `ssse3`                             | `x86_ssse3`  |
+//! | `x86::Avx512Encoder` | not measured   | x86 with AVX-512 VBMI (Ice Lake and above, 2019) | `avx512f`, `avx512bw`, `avx512vbmi` | `x86_avx512` |
 //!
 //! ## Decoders
 //!
-//! | Type         | Performance    | Hardware
-//! | `target_feature` | `feature`   | | ------------ | -------------- |
-//! ------------------------------------------ | ---------------- | -----------
-//! | | `Scalar`     | ≈140 million/s | All
-//! | none             | none        | | `x86::Ssse3` | ≈2.7 billion/s | x86
-//! with SSSE3 (Woodcrest and above, 2006) | `ssse3`          | `x86_ssse3` |
+//! | Type                 | Performance    | Hardware                                         | `target_feature`                    | `feature`    |
+//! | -------------------- | -------------- | ------------------------------------------------ | ----------------------------------- | ------------ |
+//! | `Scalar`             | ≈140 million/s | All                                              | none                                | none         |
+//! | `x86::Ssse3`         | ≈2.7 billion/s | x86 with SSSE3 (Woodcrest and above, 2006)       | `ssse3`                             | `x86_ssse3`  |
+//! | `x86::Avx2`          | not measured   | x86 with AVX2 (Haswell and above, 2013)          | `avx2`                              | `x86_avx2`   |
+//! | `x86::Avx512Decoder` | not measured   | x86 with AVX-512 VBMI (Ice Lake and above, 2019) | `avx512f`, `avx512bw`, `avx512vbmi` | `x86_avx512` |
 //!
 //! If you have a modern x86 and you want to use the all SIMD accelerated
 //! versions, you would use `target_feature` in a compiler invocation like this:
 //!
 //! ```sh
-//! RUSTFLAGS='-C target-feature=+ssse3,+sse4.1' cargo ...
+//! RUSTFLAGS='-C target-feature=+ssse3,+sse4.1,+avx2' cargo ...
 //! ```
 //!
 //! Meanwhile, `feature`s for your dependency on this crate are specified
@@ -67,21 +89,23 @@
 //!
 //! ```
 //! use stream_vbyte::{
-//!     encode::encode,
-//!     decode::{decode, cursor::DecodeCursor},
+//!     encode::encode_to_vec,
+//!     decode::{decode, decode_to_vec, cursor::DecodeCursor},
 //!     scalar::Scalar
 //! };
 //!
 //! let nums: Vec<u32> = (0..12_345).collect();
-//! let mut encoded_data = Vec::new();
-//! // make some space to encode into
-//! encoded_data.resize(5 * nums.len(), 0x0);
 //!
 //! // use Scalar implementation that works on any hardware
-//! let encoded_len = encode::<Scalar>(&nums, &mut encoded_data);
+//! let encoded_data = encode_to_vec::<Scalar>(&nums);
+//! let encoded_len = encoded_data.len();
 //! println!("Encoded {} u32s into {} bytes", nums.len(), encoded_len);
 //!
 //! // decode all the numbers at once
+//! let decoded_nums = decode_to_vec::<Scalar>(&encoded_data, nums.len());
+//! assert_eq!(nums, decoded_nums);
+//!
+//! // or into a buffer you already have
 //! let mut decoded_nums = Vec::new();
 //! decoded_nums.resize(nums.len(), 0);
 //! let bytes_decoded = decode::<Scalar>(&encoded_data, nums.len(), &mut decoded_nums);
@@ -104,11 +128,18 @@
 //! If you use undersized slices (e.g. encoding 10 numbers into 5 bytes), you
 //! will get the normal slice bounds check panics.
 //!
+//! If the encoded input comes from somewhere you don't trust (a file that
+//! might be truncated, the network, etc), use `decode::try_decode()` or
+//! `DecodeCursor::try_new()` instead. They check that the input is long enough
+//! for `count` numbers up front and return a `DecodeError` rather than
+//! panicking.
+//!
 //! # Safety
 //!
 //! SIMD code uses unsafe internally because many of the SIMD intrinsics are
 //! unsafe. However, SIMD intrinsics are used only on appropriately sized slices
-//! to essentially manually apply slice index checking before use.
+//! to essentially manually apply slice index checking before use, and only
+//! after checking that the CPU supports them.
 //!
 //! Since this is human-maintained code, it could do the bounds checking
 //! incorrectly, of course. To mitigate those risks, there are various forms of
@@ -116,26 +147,34 @@
 //!
 //! The `Scalar` codec does not use unsafe.
 
-#![cfg_attr(
-    any(feature = "x86_ssse3", feature = "x86_sse41", feature = "aarch64_neon"),
-    feature(portable_simd)
-)]
-
+mod crc32c;
 mod tables;
+mod varint;
 
+pub mod blocked;
+pub mod chunked;
 pub mod decode;
 pub mod encode;
+pub mod frame;
+pub mod svb0124;
+pub mod svb16;
+pub mod svb64;
 
 #[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
 pub mod aarch64;
 pub mod scalar;
 #[cfg(all(
-    any(feature = "x86_ssse3", feature = "x86_sse41"),
+    any(
+        feature = "x86_ssse3",
+        feature = "x86_sse41",
+        feature = "x86_avx2",
+        feature = "x86_avx512"
+    ),
     target_arch = "x86_64"
 ))]
 pub mod x86;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 struct EncodedShape {
     control_bytes_len: usize,
     complete_control_bytes_len: usize,
@@ -144,7 +183,8 @@ struct EncodedShape {
 
 fn encoded_shape(count: usize) -> EncodedShape {
     EncodedShape {
-        control_bytes_len: (count + 3) / 4,
+        // round up without overflowing for huge counts
+        control_bytes_len: count / 4 + if count % 4 == 0 { 0 } else { 1 },
         complete_control_bytes_len: count / 4,
         leftover_numbers: count % 4,
     }
@@ -160,6 +200,26 @@ fn cumulative_encoded_len(control_bytes: &[u8]) -> usize {
         .sum()
 }
 
+/// The length of all encoded numbers described by `control_bytes`, including
+/// any trailing partial quad.
+///
+/// `control_bytes` must be exactly `shape.control_bytes_len` long.
+fn encoded_nums_len(control_bytes: &[u8], shape: &EncodedShape) -> usize {
+    let complete_len =
+        cumulative_encoded_len(&control_bytes[0..shape.complete_control_bytes_len]);
+
+    if shape.leftover_numbers == 0 {
+        return complete_len;
+    }
+
+    let control_byte = control_bytes[shape.complete_control_bytes_len];
+    let leftover_len: usize = (0..shape.leftover_numbers)
+        .map(|i| ((control_byte >> (i * 2)) & 0x03) as usize + 1)
+        .sum();
+
+    complete_len + leftover_len
+}
+
 #[cfg(test)]
 pub mod random_varint;
 #[cfg(test)]
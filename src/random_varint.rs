@@ -22,7 +22,7 @@ impl<R: Rng> RandomVarintEncodedLengthIter<R> {
                 Range::new(0, 1 << 8),
                 Range::new(1 << 8, 1 << 16),
                 Range::new(1 << 16, 1 << 24),
-                Range::new(1 << 24, u32::max_value()), // this won't ever emit the max value, sadly
+                Range::new(1 << 24, u32::MAX), // this won't ever emit the max value, sadly
             ],
             range_for_picking_range: Range::new(0, 4),
             rng,
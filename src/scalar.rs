@@ -1,6 +1,12 @@
 use crate::{
-    decode::{decode_num_scalar, DecodeQuadSink, Decoder, WriteQuadToSlice},
-    encode::{encode_num_scalar, Encoder},
+    decode::{
+        decode_num_scalar, DecodeQuadSink, Decoder, DeltaDecodeQuad, WriteQuadToSlice,
+        ZigzagDecodeQuad,
+    },
+    encode::{
+        encode_num_scalar, encoded_num_len, DeltaEncodeQuad, EncodeQuadTransform, EncodedLenQuads,
+        Encoder, ZigzagEncodeQuad,
+    },
     tables,
 };
 
@@ -9,20 +15,24 @@ use crate::{
 pub struct Scalar;
 
 impl Encoder for Scalar {
+    // Numbers are encoded one at a time, so they are transformed one at a time too
+    type InputQuad = UnusedQuad;
+
     // This implementation encodes all provided input numbers.
-    fn encode_quads(
+    fn encode_quads<T: EncodeQuadTransform<Self>>(
         input: &[u32],
         control_bytes: &mut [u8],
         encoded_nums: &mut [u8],
+        transform: &mut T,
     ) -> (usize, usize) {
         let mut bytes_written = 0;
         let mut nums_encoded = 0;
 
         for quads_encoded in control_bytes {
-            let num0 = input[nums_encoded];
-            let num1 = input[nums_encoded + 1];
-            let num2 = input[nums_encoded + 2];
-            let num3 = input[nums_encoded + 3];
+            let num0 = transform.transform_number(input[nums_encoded]);
+            let num1 = transform.transform_number(input[nums_encoded + 1]);
+            let num2 = transform.transform_number(input[nums_encoded + 2]);
+            let num3 = transform.transform_number(input[nums_encoded + 3]);
 
             let len0 = encode_num_scalar(num0, &mut encoded_nums[bytes_written..]);
             let len1 = encode_num_scalar(num1, &mut encoded_nums[bytes_written + len0..]);
@@ -45,6 +55,29 @@ impl Encoder for Scalar {
     }
 }
 
+impl EncodedLenQuads for Scalar {
+    // This implementation measures all provided input numbers.
+    fn encoded_len_quads(input: &[u32]) -> (usize, usize) {
+        let len = input.iter().map(|&num| encoded_num_len(num)).sum();
+
+        (input.len(), len)
+    }
+}
+
+impl DeltaEncodeQuad for Scalar {
+    fn delta_encode_quad(_quad: Self::InputQuad, _prev: u32) -> (Self::InputQuad, u32) {
+        // scalar encoding doesn't use quads, so this will never be called
+        unreachable!()
+    }
+}
+
+impl ZigzagEncodeQuad for Scalar {
+    fn zigzag_encode_quad(_quad: Self::InputQuad) -> Self::InputQuad {
+        // scalar encoding doesn't use quads, so this will never be called
+        unreachable!()
+    }
+}
+
 impl Decoder for Scalar {
     // Quads are decoded one at a time anyway so no need to bundle them up only to
     // un-bundle them. Instead, we just call on_number for each decoded number.
@@ -102,9 +135,27 @@ impl WriteQuadToSlice for Scalar {
     }
 }
 
+impl DeltaDecodeQuad for Scalar {
+    fn delta_decode_quad(_quad: Self::DecodedQuad, _prev: u32) -> (Self::DecodedQuad, u32) {
+        // scalar decoding doesn't use quads, so this will never be called
+        unreachable!()
+    }
+}
+
+impl ZigzagDecodeQuad for Scalar {
+    fn zigzag_decode_quad(_quad: Self::DecodedQuad) -> Self::DecodedQuad {
+        // scalar decoding doesn't use quads, so this will never be called
+        unreachable!()
+    }
+}
+
 /// `Scalar` decoder produces numbers one by one, so there is no quad to
 /// unbundle. Any implementations of `DecodedQuadSink<EmptyQuad>` can safely use
 /// `unreachable!()` or equivalent.
+///
+/// Likewise, `Scalar` encoder consumes numbers one by one, so any
+/// implementations of `EncodeQuadTransform<Scalar>` will only be asked to
+/// transform single numbers.
 pub struct UnusedQuad;
 
 /// The Scalar decoder doesn't use quads, but the type checker requires that
@@ -0,0 +1,306 @@
+//! The "0124" variant of Stream VByte, where the 2-bit length codes mean 0, 1,
+//! 2 or 4 bytes, so zeros take no space at all after their control bits.
+//!
+//! The layout is the same as the `u32` format's: each control byte describes a
+//! quad of 4 numbers with a 2-bit code each, starting from the low bits, and
+//! all the control bytes come before all the encoded numbers. Only the meaning
+//! of the codes differs, so a number that needs 3 bytes takes 4. This is the
+//! format of the reference C implementation's `streamvbyte_encode_0124()` and
+//! `streamvbyte_decode_0124()`.
+//!
+//! This suits numbers that are mostly zeros, like sparse counts, where the
+//! `u32` format would spend a byte on each zero.
+//!
+//! The `Encoder0124` and `Decoder0124` traits are implemented by the same types
+//! as `Encoder` and `Decoder`, so `Scalar` works everywhere and the SIMD
+//! implementations are enabled by the same `feature`s.
+//!
+//! # Examples
+//!
+//! ```
+//! use stream_vbyte::{scalar::Scalar, svb0124};
+//!
+//! let nums: Vec<u32> = vec![0, 0, 7, 0, 0, 0, 300, 0];
+//! let encoded = svb0124::encode_0124_to_vec::<Scalar>(&nums);
+//! // 2 control bytes, then 1 + 2 bytes of numbers
+//! assert_eq!(2 + 3, encoded.len());
+//!
+//! assert_eq!(nums, svb0124::decode_0124_to_vec::<Scalar>(&encoded, nums.len()));
+//! ```
+
+pub mod cursor;
+
+mod scalar;
+
+#[cfg(all(
+    any(feature = "x86_ssse3", feature = "x86_sse41"),
+    target_arch = "x86_64"
+))]
+mod ssse3;
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
+mod neon;
+
+use crate::{encoded_shape, tables, EncodedShape};
+
+/// Encode `u32`s to bytes in the 0124 format.
+pub trait Encoder0124 {
+    /// Encode complete quads of input numbers.
+    ///
+    /// `control_bytes` will be exactly as long as the number of complete
+    /// 4-number quads in `input`.
+    ///
+    /// Control bytes are written to `control_bytes` and encoded numbers to
+    /// `output`.
+    ///
+    /// Implementations may choose to encode fewer than the full provided input,
+    /// but any writes done must be for full quads.
+    ///
+    /// Implementations must not write to `output` outside of the area that will
+    /// be populated by encoded numbers when all control bytes are processed.
+    ///
+    /// Returns the number of numbers encoded and the number of bytes written to
+    /// `output`.
+    fn encode_quads(input: &[u32], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize);
+}
+
+/// Decode bytes in the 0124 format to `u32`s.
+pub trait Decoder0124 {
+    /// Decode the complete quads described by `control_bytes`.
+    ///
+    /// `output` will be exactly 4 times as long as `control_bytes`.
+    ///
+    /// Implementations may choose to decode fewer than all the control bytes,
+    /// but must decode full quads.
+    ///
+    /// Returns the number of numbers decoded and the number of bytes read from
+    /// `encoded_nums`.
+    fn decode_quads(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u32],
+    ) -> (usize, usize);
+}
+
+/// The most bytes that encoding `count` numbers could take: 4 bytes per number,
+/// plus a control byte for every 4 numbers, including any trailing partial
+/// quad.
+pub fn max_encoded_len(count: usize) -> usize {
+    encoded_shape(count).control_bytes_len + 4 * count
+}
+
+/// Encode the `input` slice into the `output` slice.
+///
+/// `output` must be at least `max_encoded_len(input.len())` long unless you
+/// know the input well enough to size it more tightly.
+///
+/// Returns the number of bytes written to the `output` slice.
+pub fn encode_0124<E: Encoder0124>(input: &[u32], output: &mut [u8]) -> usize {
+    let shape = encoded_shape(input.len());
+    let complete_nums = shape.complete_control_bytes_len * 4;
+
+    let (control_bytes, encoded_bytes) = output.split_at_mut(shape.control_bytes_len);
+
+    let (nums_encoded, mut num_bytes_written) = E::encode_quads(
+        &input[0..complete_nums],
+        &mut control_bytes[0..shape.complete_control_bytes_len],
+        encoded_bytes,
+    );
+
+    // may be some complete quads left, use Scalar to finish them
+    let (more_nums_encoded, more_bytes_written) = crate::scalar::Scalar::encode_quads(
+        &input[nums_encoded..complete_nums],
+        &mut control_bytes[(nums_encoded / 4)..shape.complete_control_bytes_len],
+        &mut encoded_bytes[num_bytes_written..],
+    );
+    num_bytes_written += more_bytes_written;
+
+    debug_assert_eq!(complete_nums, nums_encoded + more_nums_encoded);
+
+    // last control byte, if there were leftovers
+    if shape.leftover_numbers > 0 {
+        let mut control_byte = 0;
+
+        for (i, &num) in input[complete_nums..].iter().enumerate() {
+            let code = length_code(num);
+            num_bytes_written +=
+                encode_num_scalar(num, code, &mut encoded_bytes[num_bytes_written..]);
+
+            control_byte |= code << (i * 2);
+        }
+        control_bytes[shape.complete_control_bytes_len] = control_byte;
+    }
+
+    shape.control_bytes_len + num_bytes_written
+}
+
+/// Encode the `input` slice into a new `Vec` that is exactly as long as the
+/// encoded bytes.
+pub fn encode_0124_to_vec<E: Encoder0124>(input: &[u32]) -> Vec<u8> {
+    let mut output = vec![0; max_encoded_len(input.len())];
+    let encoded_len = encode_0124::<E>(input, &mut output);
+    output.truncate(encoded_len);
+
+    output
+}
+
+/// Decode `count` numbers from `input`, writing them to `output`.
+///
+/// The `count` must be the same as the number of items originally encoded.
+///
+/// `output` must be large enough for all `count` numbers.
+///
+/// Returns the number of bytes read from `input`.
+pub fn decode_0124<D: Decoder0124>(input: &[u8], count: usize, output: &mut [u32]) -> usize {
+    let mut cursor = cursor::DecodeCursor0124::new(input, count);
+
+    assert_eq!(
+        count,
+        cursor.decode_slice::<D>(output),
+        "output buffer was not large enough"
+    );
+
+    cursor.input_consumed()
+}
+
+/// Decode `count` numbers from `input` into a new `Vec` that is exactly
+/// `count` long.
+///
+/// The `count` must be the same as the number of items originally encoded.
+pub fn decode_0124_to_vec<D: Decoder0124>(input: &[u8], count: usize) -> Vec<u32> {
+    let mut output = vec![0; count];
+    decode_0124::<D>(input, count, &mut output);
+
+    output
+}
+
+/// The 2-bit code for the length `num` is encoded in.
+#[inline]
+fn length_code(num: u32) -> u8 {
+    if num == 0 {
+        0
+    } else if num <= 0xFF {
+        1
+    } else if num <= 0xFFFF {
+        2
+    } else {
+        3
+    }
+}
+
+/// The length of a number with the 2-bit length `code`.
+#[inline]
+fn code_len(code: u8) -> usize {
+    // the lengths 0, 1, 2 and 4, a nibble each
+    (0x4210 >> (code * 4)) & 0xF
+}
+
+/// Write the low bytes of `num` that its length `code` says to.
+///
+/// Returns the number of bytes written.
+#[inline]
+fn encode_num_scalar(num: u32, code: u8, output: &mut [u8]) -> usize {
+    let len = code_len(code);
+    output[..len].copy_from_slice(&num.to_le_bytes()[..len]);
+
+    len
+}
+
+#[inline]
+fn decode_num_scalar(len: usize, input: &[u8]) -> u32 {
+    let mut buf = [0_u8; 4];
+    buf[0..len].copy_from_slice(&input[0..len]);
+
+    u32::from_le_bytes(buf)
+}
+
+/// The length of all encoded numbers described by complete quads'
+/// `control_bytes`, not counting the control bytes.
+fn cumulative_encoded_len(control_bytes: &[u8]) -> usize {
+    control_bytes
+        .iter()
+        .map(|&b| tables::DECODE_0124_LENGTH_PER_QUAD_TABLE[b as usize] as usize)
+        .sum()
+}
+
+/// The number of leading quads described by `control_bytes` that have at least
+/// 16 encoded bytes from their start to the end of the last quad, so SIMD code
+/// can read or write 16 bytes for each of them without going past the end.
+///
+/// A quad of zeros is encoded in no bytes at all, so unlike in the `u32`
+/// format, this isn't a fixed number of quads short of the end.
+#[cfg(any(
+    all(
+        any(feature = "x86_ssse3", feature = "x86_sse41"),
+        target_arch = "x86_64"
+    ),
+    all(feature = "aarch64_neon", target_arch = "aarch64")
+))]
+fn direct_quads_len(control_bytes: &[u8]) -> usize {
+    let mut bytes_after = 0;
+
+    for (i, &control_byte) in control_bytes.iter().enumerate().rev() {
+        bytes_after += tables::DECODE_0124_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+
+        if bytes_after >= 16 {
+            // every quad before this one has at least as many bytes after it
+            return i + 1;
+        }
+    }
+
+    0
+}
+
+/// The length of all encoded numbers described by `control_bytes`, including
+/// any trailing partial quad.
+///
+/// `control_bytes` must be exactly `shape.control_bytes_len` long.
+fn encoded_nums_len(control_bytes: &[u8], shape: &EncodedShape) -> usize {
+    let complete_len = cumulative_encoded_len(&control_bytes[0..shape.complete_control_bytes_len]);
+
+    if shape.leftover_numbers == 0 {
+        return complete_len;
+    }
+
+    let control_byte = control_bytes[shape.complete_control_bytes_len];
+    let leftover_len: usize = (0..shape.leftover_numbers)
+        .map(|i| code_len((control_byte >> (i * 2)) & 0x03))
+        .sum();
+
+    complete_len + leftover_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_code_boundaries() {
+        assert_eq!(0, length_code(0));
+        assert_eq!(1, length_code(1));
+        assert_eq!(1, length_code(0xFF));
+        assert_eq!(2, length_code(0x100));
+        assert_eq!(2, length_code(0xFFFF));
+        assert_eq!(3, length_code(0x1_0000));
+        assert_eq!(3, length_code(u32::MAX));
+    }
+
+    #[test]
+    fn code_lens() {
+        assert_eq!(
+            [0, 1, 2, 4],
+            [code_len(0), code_len(1), code_len(2), code_len(3)]
+        );
+    }
+
+    #[test]
+    fn encoded_nums_len_includes_partial_quad() {
+        // lengths 0, 1, 2, 4 then 4, 0, 1
+        let control_bytes = [0b11_10_01_00, 0b00_01_00_11];
+
+        assert_eq!(7, encoded_nums_len(&control_bytes[0..1], &encoded_shape(4)));
+        assert_eq!(7 + 4, encoded_nums_len(&control_bytes, &encoded_shape(5)));
+        assert_eq!(7 + 4, encoded_nums_len(&control_bytes, &encoded_shape(6)));
+        assert_eq!(7 + 5, encoded_nums_len(&control_bytes, &encoded_shape(7)));
+    }
+}
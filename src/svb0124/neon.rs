@@ -0,0 +1,270 @@
+use std::arch::aarch64::{
+    uint8x16_t, vaddvq_u32, vcgtq_u32, vdupq_n_u32, vld1q_s32, vld1q_u32, vld1q_u8, vqtbl1q_u8,
+    vreinterpretq_u8_u32, vshlq_u32, vst1q_u8, vsubq_u32,
+};
+
+use super::{direct_quads_len, Decoder0124, Encoder0124};
+use crate::{decode::neon::NeonDecoder, encode::neon::NeonEncoder, tables};
+
+// how far to shift each lane's code to its place in the control byte
+const LANE_SHIFTS: [i32; 4] = [0, 2, 4, 6];
+
+impl Encoder0124 for NeonEncoder {
+    fn encode_quads(input: &[u32], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !std::arch::is_aarch64_feature_detected!("neon") {
+            return (0, 0);
+        }
+
+        unsafe { encode_quads_neon(input, control_bytes, output) }
+    }
+}
+
+/// The body of `NeonEncoder::encode_quads()`, compiled with NEON enabled so
+/// that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports NEON.
+#[target_feature(enable = "neon")]
+unsafe fn encode_quads_neon(
+    input: &[u32],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+) -> (usize, usize) {
+    let zeros = unsafe { vdupq_n_u32(0) };
+    let max_1_byte = unsafe { vdupq_n_u32(0xFF) };
+    let max_2_bytes = unsafe { vdupq_n_u32(0xFFFF) };
+    let lane_shifts = unsafe { vld1q_s32(LANE_SHIFTS.as_ptr()) };
+
+    for (control_byte, quad) in control_bytes.iter_mut().zip(input.chunks_exact(4)) {
+        *control_byte = unsafe {
+            let nums = vld1q_u32(quad.as_ptr());
+
+            // lanes that compare greater are all 1s, which is -1, so subtracting each
+            // comparison counts the limits each number is over
+            let codes = vsubq_u32(zeros, vcgtq_u32(nums, zeros));
+            let codes = vsubq_u32(codes, vcgtq_u32(nums, max_1_byte));
+            let codes = vsubq_u32(codes, vcgtq_u32(nums, max_2_bytes));
+
+            vaddvq_u32(vshlq_u32(codes, lane_shifts))
+        } as u8;
+    }
+
+    // Encoding writes 16 bytes at a time, but a quad of zeros is encoded in no
+    // bytes at all, so no number of quads after this one is sure to overwrite
+    // the extra bytes. Instead, with all the control bytes known, write
+    // directly only the quads with 16 bytes left from their start, and leave
+    // the rest for a second loop that writes them here first, and only copies
+    // their encoded bytes to `output`.
+    let direct_len = direct_quads_len(control_bytes);
+    let (direct_input, tail_input) = input.split_at(direct_len * 4);
+    let mut bytes_encoded: usize = 0;
+
+    for (&control_byte, quad) in control_bytes[0..direct_len]
+        .iter()
+        .zip(direct_input.chunks_exact(4))
+    {
+        let (encoded, length) = encode_quad_neon(control_byte, quad);
+
+        unsafe {
+            vst1q_u8(
+                output[bytes_encoded..(bytes_encoded + 16)].as_mut_ptr(),
+                encoded,
+            )
+        };
+
+        bytes_encoded += length;
+    }
+
+    let mut tail = [0_u8; 16];
+
+    for (&control_byte, quad) in control_bytes[direct_len..]
+        .iter()
+        .zip(tail_input.chunks_exact(4))
+    {
+        let (encoded, length) = encode_quad_neon(control_byte, quad);
+
+        unsafe { vst1q_u8(tail.as_mut_ptr(), encoded) };
+        output[bytes_encoded..(bytes_encoded + length)].copy_from_slice(&tail[0..length]);
+
+        bytes_encoded += length;
+    }
+
+    (control_bytes.len() * 4, bytes_encoded)
+}
+
+/// Shuffle a quad into its encoded bytes as its `control_byte` says, returning
+/// them along with its encoded length. Only the first `length` bytes are part
+/// of the encoding.
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn encode_quad_neon(control_byte: u8, quad: &[u32]) -> (uint8x16_t, usize) {
+    let length = tables::DECODE_0124_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+
+    let encoded = unsafe {
+        let encoding_shuffle =
+            vld1q_u8(tables::ENCODE_0124_SHUFFLE_TABLE[control_byte as usize].as_ptr());
+
+        vqtbl1q_u8(
+            vreinterpretq_u8_u32(vld1q_u32(quad[0..4].as_ptr())),
+            encoding_shuffle,
+        )
+    };
+
+    (encoded, length)
+}
+
+impl Decoder0124 for NeonDecoder {
+    fn decode_quads(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u32],
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !std::arch::is_aarch64_feature_detected!("neon") {
+            return (0, 0);
+        }
+
+        unsafe { decode_quads_neon(control_bytes, encoded_nums, output) }
+    }
+}
+
+/// The body of `NeonDecoder::decode_quads()`, compiled with NEON enabled so
+/// that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports NEON.
+#[target_feature(enable = "neon")]
+unsafe fn decode_quads_neon(
+    control_bytes: &[u8],
+    encoded_nums: &[u8],
+    output: &mut [u32],
+) -> (usize, usize) {
+    let mut bytes_read: usize = 0;
+
+    // Decoding reads 16 bytes at a time from input, so only the quads with 16
+    // bytes left from their start are read directly.
+    let direct_len = direct_quads_len(control_bytes);
+    let (direct_output, tail_output) = output.split_at_mut(direct_len * 4);
+
+    for (&control_byte, quad) in control_bytes[0..direct_len]
+        .iter()
+        .zip(direct_output.chunks_exact_mut(4))
+    {
+        let length = tables::DECODE_0124_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+        let mask_bytes = tables::DECODE_0124_SHUFFLE_TABLE[control_byte as usize];
+        let next_16 = &encoded_nums[bytes_read..(bytes_read + 16)];
+
+        unsafe {
+            let mask = vld1q_u8(mask_bytes.as_ptr());
+            let data = vld1q_u8(next_16.as_ptr());
+
+            vst1q_u8(quad.as_mut_ptr() as *mut u8, vqtbl1q_u8(data, mask));
+        }
+
+        bytes_read += length;
+    }
+
+    // The last quads may be encoded in fewer than 16 bytes with nothing after
+    // them, so they're copied here first so that there are always 16 bytes to
+    // read. The shuffle ignores anything after the first `length` bytes.
+    let mut tail = [0_u8; 16];
+
+    for (&control_byte, quad) in control_bytes[direct_len..]
+        .iter()
+        .zip(tail_output.chunks_exact_mut(4))
+    {
+        let length = tables::DECODE_0124_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+        let mask_bytes = tables::DECODE_0124_SHUFFLE_TABLE[control_byte as usize];
+        tail[0..length].copy_from_slice(&encoded_nums[bytes_read..(bytes_read + length)]);
+
+        unsafe {
+            let mask = vld1q_u8(mask_bytes.as_ptr());
+            let data = vld1q_u8(tail.as_ptr());
+
+            vst1q_u8(quad.as_mut_ptr() as *mut u8, vqtbl1q_u8(data, mask));
+        }
+
+        bytes_read += length;
+    }
+
+    (control_bytes.len() * 4, bytes_read)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{scalar::Scalar, svb0124::cumulative_encoded_len};
+
+    /// Mostly zeros, with numbers of the other lengths in different patterns
+    fn mostly_zeros() -> Vec<u32> {
+        (0..64_u32)
+            .map(|i| match i % 7 {
+                0 => 1 + i,
+                3 => 1000 + i,
+                5 => u32::MAX - i,
+                _ => 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
+        let nums = mostly_zeros();
+        let mut encoded = Vec::new();
+
+        for control_bytes_len in 0..(nums.len() / 4 + 1) {
+            encoded.clear();
+            encoded.resize(nums.len() * 5, 0xFF);
+
+            let (nums_encoded, bytes_written) = {
+                let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
+
+                NeonEncoder::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes)
+            };
+
+            assert_eq!(control_bytes_len * 4, nums_encoded);
+            assert_eq!(
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
+                bytes_written
+            );
+
+            let mut expected = vec![0xFF; nums.len() * 5];
+            {
+                let (control_bytes, num_bytes) = expected.split_at_mut(control_bytes_len);
+                Scalar::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes);
+            }
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
+        }
+    }
+
+    #[test]
+    fn reads_all_control_bytes_without_extra_input_bytes() {
+        let nums = mostly_zeros();
+        let encoded = crate::svb0124::encode_0124_to_vec::<Scalar>(&nums);
+        let mut decoded: Vec<u32> = Vec::new();
+
+        // 16 control bytes
+        let control_bytes = &encoded[0..16];
+        // no trailing bytes to read past the end into, so the last quads have to go
+        // through the tail buffer
+        let encoded_nums = &encoded[16..];
+
+        for control_bytes_len in 0..17 {
+            decoded.clear();
+            decoded.resize(nums.len(), 54321);
+
+            let (nums_decoded, bytes_read) = NeonDecoder::decode_quads(
+                &control_bytes[0..control_bytes_len],
+                encoded_nums,
+                &mut decoded[0..(4 * control_bytes_len)],
+            );
+            assert_eq!(control_bytes_len * 4, nums_decoded);
+            assert_eq!(
+                cumulative_encoded_len(&control_bytes[0..control_bytes_len]),
+                bytes_read
+            );
+            assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
+            assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u32));
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use super::{
+    code_len, decode_num_scalar, encode_num_scalar, length_code, Decoder0124, Encoder0124,
+};
+use crate::scalar::Scalar;
+
+impl Encoder0124 for Scalar {
+    // This implementation encodes all provided input numbers.
+    fn encode_quads(input: &[u32], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        let mut bytes_written = 0;
+
+        for (control_byte, quad) in control_bytes.iter_mut().zip(input.chunks_exact(4)) {
+            let mut code = 0;
+
+            for (i, &num) in quad.iter().enumerate() {
+                let num_code = length_code(num);
+                bytes_written += encode_num_scalar(num, num_code, &mut output[bytes_written..]);
+
+                code |= num_code << (i * 2);
+            }
+
+            *control_byte = code;
+        }
+
+        (control_bytes.len() * 4, bytes_written)
+    }
+}
+
+impl Decoder0124 for Scalar {
+    // This implementation decodes all provided encoded data.
+    fn decode_quads(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u32],
+    ) -> (usize, usize) {
+        let mut bytes_read = 0;
+
+        for (&control_byte, quad) in control_bytes.iter().zip(output.chunks_exact_mut(4)) {
+            for (i, num) in quad.iter_mut().enumerate() {
+                let len = code_len((control_byte >> (i * 2)) & 0x03);
+                *num = decode_num_scalar(len, &encoded_nums[bytes_read..]);
+
+                bytes_read += len;
+            }
+        }
+
+        (control_bytes.len() * 4, bytes_read)
+    }
+}
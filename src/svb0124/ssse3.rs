@@ -0,0 +1,329 @@
+use std::arch::x86_64::{
+    __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_setzero_si128,
+    _mm_shuffle_epi8, _mm_storeu_si128,
+};
+
+#[cfg(feature = "x86_ssse3")]
+use super::Decoder0124;
+use super::{direct_quads_len, Encoder0124};
+#[cfg(feature = "x86_sse41")]
+use crate::encode::sse41::Sse41;
+use crate::tables;
+#[cfg(feature = "x86_ssse3")]
+use crate::x86::Ssse3;
+
+// translate a 4-bit map of which bytes in a number are nonzero into its lane
+// code: 0 for no nonzero bytes, then 1, 2 or 4 bytes up to the highest one
+#[rustfmt::skip]
+const LANECODES: [u8; 16] = [
+    0, 1, 2, 2,
+    3, 3, 3, 3,
+    3, 3, 3, 3,
+    3, 3, 3, 3];
+
+#[cfg(feature = "x86_ssse3")]
+impl Encoder0124 for Ssse3 {
+    fn encode_quads(input: &[u32], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("ssse3") {
+            return (0, 0);
+        }
+
+        unsafe { encode_quads_ssse3(input, control_bytes, output) }
+    }
+}
+
+/// SSE4.1's 32-bit multiply doesn't help with the 0124 lengths, so `Sse41`
+/// uses the same code as `Ssse3`, and is only an `Encoder0124` so that
+/// `StreamVbyteEncoder` always is.
+#[cfg(feature = "x86_sse41")]
+impl Encoder0124 for Sse41 {
+    fn encode_quads(input: &[u32], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("sse4.1") {
+            return (0, 0);
+        }
+
+        // every CPU with SSE4.1 has SSSE3
+        unsafe { encode_quads_ssse3(input, control_bytes, output) }
+    }
+}
+
+/// The body of `Ssse3::encode_quads()`, compiled with SSSE3 enabled so that
+/// the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports SSSE3.
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_quads_ssse3(
+    input: &[u32],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+) -> (usize, usize) {
+    let zeros = _mm_setzero_si128();
+
+    for (control_byte, quad) in control_bytes.iter_mut().zip(input.chunks_exact(4)) {
+        let nums = unsafe { _mm_loadu_si128(quad.as_ptr() as *const __m128i) };
+
+        // 4 bits per number of which of its bytes are nonzero, low byte first
+        let nonzero_bytes = !_mm_movemask_epi8(_mm_cmpeq_epi8(nums, zeros)) as usize;
+
+        *control_byte = LANECODES[nonzero_bytes & 0xF]
+            | LANECODES[(nonzero_bytes >> 4) & 0xF] << 2
+            | LANECODES[(nonzero_bytes >> 8) & 0xF] << 4
+            | LANECODES[(nonzero_bytes >> 12) & 0xF] << 6;
+    }
+
+    // Encoding writes 16 bytes at a time, but a quad of zeros is encoded in no
+    // bytes at all, so no number of quads after this one is sure to overwrite
+    // the extra bytes. Instead, with all the control bytes known, write
+    // directly only the quads with 16 bytes left from their start, and leave
+    // the rest for a second loop that writes them here first, and only copies
+    // their encoded bytes to `output`.
+    let direct_len = direct_quads_len(control_bytes);
+    let (direct_input, tail_input) = input.split_at(direct_len * 4);
+    let mut bytes_encoded: usize = 0;
+
+    for (&control_byte, quad) in control_bytes[0..direct_len]
+        .iter()
+        .zip(direct_input.chunks_exact(4))
+    {
+        let (encoded, length) = encode_quad_ssse3(control_byte, quad);
+
+        unsafe {
+            _mm_storeu_si128(
+                output[bytes_encoded..(bytes_encoded + 16)].as_mut_ptr() as *mut __m128i,
+                encoded,
+            );
+        }
+
+        bytes_encoded += length;
+    }
+
+    let mut tail = [0_u8; 16];
+
+    for (&control_byte, quad) in control_bytes[direct_len..]
+        .iter()
+        .zip(tail_input.chunks_exact(4))
+    {
+        let (encoded, length) = encode_quad_ssse3(control_byte, quad);
+
+        unsafe { _mm_storeu_si128(tail.as_mut_ptr() as *mut __m128i, encoded) };
+        output[bytes_encoded..(bytes_encoded + length)].copy_from_slice(&tail[0..length]);
+
+        bytes_encoded += length;
+    }
+
+    (control_bytes.len() * 4, bytes_encoded)
+}
+
+/// Shuffle a quad into its encoded bytes as its `control_byte` says, returning
+/// them along with its encoded length. Only the first `length` bytes are part
+/// of the encoding.
+#[inline]
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_quad_ssse3(control_byte: u8, quad: &[u32]) -> (__m128i, usize) {
+    let length = tables::DECODE_0124_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+
+    let mask_bytes = tables::ENCODE_0124_SHUFFLE_TABLE[control_byte as usize];
+    let encoded = unsafe {
+        let encode_mask = _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i);
+        let nums = _mm_loadu_si128(quad[0..4].as_ptr() as *const __m128i);
+
+        _mm_shuffle_epi8(nums, encode_mask)
+    };
+
+    (encoded, length)
+}
+
+#[cfg(feature = "x86_ssse3")]
+impl Decoder0124 for Ssse3 {
+    fn decode_quads(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u32],
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("ssse3") {
+            return (0, 0);
+        }
+
+        unsafe { decode_quads_ssse3(control_bytes, encoded_nums, output) }
+    }
+}
+
+/// The body of `Ssse3::decode_quads()`, compiled with SSSE3 enabled so that
+/// the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports SSSE3.
+#[cfg(feature = "x86_ssse3")]
+#[target_feature(enable = "ssse3")]
+unsafe fn decode_quads_ssse3(
+    control_bytes: &[u8],
+    encoded_nums: &[u8],
+    output: &mut [u32],
+) -> (usize, usize) {
+    let mut bytes_read: usize = 0;
+
+    // Decoding reads 16 bytes at a time from input, so only the quads with 16
+    // bytes left from their start are read directly.
+    let direct_len = direct_quads_len(control_bytes);
+    let (direct_output, tail_output) = output.split_at_mut(direct_len * 4);
+
+    for (&control_byte, quad) in control_bytes[0..direct_len]
+        .iter()
+        .zip(direct_output.chunks_exact_mut(4))
+    {
+        let length = tables::DECODE_0124_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+        let mask_bytes = tables::DECODE_0124_SHUFFLE_TABLE[control_byte as usize];
+        let next_16 = &encoded_nums[bytes_read..(bytes_read + 16)];
+
+        unsafe {
+            let mask = _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i);
+            let data = _mm_loadu_si128(next_16.as_ptr() as *const __m128i);
+
+            _mm_storeu_si128(
+                quad.as_mut_ptr() as *mut __m128i,
+                _mm_shuffle_epi8(data, mask),
+            );
+        }
+
+        bytes_read += length;
+    }
+
+    // The last quads may be encoded in fewer than 16 bytes with nothing after
+    // them, so they're copied here first so that there are always 16 bytes to
+    // read. The shuffle ignores anything after the first `length` bytes.
+    let mut tail = [0_u8; 16];
+
+    for (&control_byte, quad) in control_bytes[direct_len..]
+        .iter()
+        .zip(tail_output.chunks_exact_mut(4))
+    {
+        let length = tables::DECODE_0124_LENGTH_PER_QUAD_TABLE[control_byte as usize] as usize;
+        let mask_bytes = tables::DECODE_0124_SHUFFLE_TABLE[control_byte as usize];
+        tail[0..length].copy_from_slice(&encoded_nums[bytes_read..(bytes_read + length)]);
+
+        unsafe {
+            let mask = _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i);
+            let data = _mm_loadu_si128(tail.as_ptr() as *const __m128i);
+
+            _mm_storeu_si128(
+                quad.as_mut_ptr() as *mut __m128i,
+                _mm_shuffle_epi8(data, mask),
+            );
+        }
+
+        bytes_read += length;
+    }
+
+    (control_bytes.len() * 4, bytes_read)
+}
+
+#[cfg(all(test, feature = "x86_ssse3"))]
+mod tests {
+    use super::*;
+    use crate::{scalar::Scalar, svb0124::cumulative_encoded_len};
+
+    /// Mostly zeros, with numbers of the other lengths in different patterns
+    fn mostly_zeros() -> Vec<u32> {
+        (0..64_u32)
+            .map(|i| match i % 7 {
+                0 => 1 + i,
+                3 => 1000 + i,
+                5 => u32::MAX - i,
+                _ => 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
+        let nums = mostly_zeros();
+        let mut encoded = Vec::new();
+
+        for control_bytes_len in 0..(nums.len() / 4 + 1) {
+            encoded.clear();
+            encoded.resize(nums.len() * 5, 0xFF);
+
+            let (nums_encoded, bytes_written) = {
+                let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
+
+                Ssse3::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes)
+            };
+
+            assert_eq!(control_bytes_len * 4, nums_encoded);
+            assert_eq!(
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
+                bytes_written
+            );
+
+            let mut expected = vec![0xFF; nums.len() * 5];
+            {
+                let (control_bytes, num_bytes) = expected.split_at_mut(control_bytes_len);
+                Scalar::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes);
+            }
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
+        }
+    }
+
+    #[test]
+    fn direct_quads_have_16_bytes_left() {
+        // lengths 0, 4, 8, 12, 16
+        let control_bytes = [0x00, 0xC0, 0xF0, 0xFC, 0xFF];
+
+        assert_eq!(0, direct_quads_len(&[]));
+        assert_eq!(0, direct_quads_len(&control_bytes[0..3]));
+        // 12 bytes from the start of the last quad, 20 from the one before
+        assert_eq!(3, direct_quads_len(&control_bytes[0..4]));
+        assert_eq!(5, direct_quads_len(&control_bytes));
+        // trailing zeros don't count towards the 16 bytes
+        assert_eq!(4, direct_quads_len(&[0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00]));
+    }
+
+    #[test]
+    fn encodes_all_zeros_without_writing() {
+        let nums = [0; 64];
+        let mut encoded = [0xFF; 16 + 64 * 4];
+
+        let (control_bytes, num_bytes) = encoded.split_at_mut(16);
+        assert_eq!(
+            (64, 0),
+            Ssse3::encode_quads(&nums, control_bytes, num_bytes)
+        );
+
+        assert!(encoded[0..16].iter().all(|&b| b == 0));
+        assert!(encoded[16..].iter().all(|&b| b == 0xFF));
+    }
+
+    #[test]
+    fn reads_all_control_bytes_without_extra_input_bytes() {
+        let nums = mostly_zeros();
+        let encoded = crate::svb0124::encode_0124_to_vec::<Scalar>(&nums);
+        let mut decoded: Vec<u32> = Vec::new();
+
+        // 16 control bytes
+        let control_bytes = &encoded[0..16];
+        // no trailing bytes to read past the end into, so the last quads have to go
+        // through the tail buffer
+        let encoded_nums = &encoded[16..];
+
+        for control_bytes_len in 0..17 {
+            decoded.clear();
+            decoded.resize(nums.len(), 54321);
+
+            let (nums_decoded, bytes_read) = Ssse3::decode_quads(
+                &control_bytes[0..control_bytes_len],
+                encoded_nums,
+                &mut decoded[0..(4 * control_bytes_len)],
+            );
+            assert_eq!(control_bytes_len * 4, nums_decoded);
+            assert_eq!(
+                cumulative_encoded_len(&control_bytes[0..control_bytes_len]),
+                bytes_read
+            );
+            assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
+            assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u32));
+        }
+    }
+}
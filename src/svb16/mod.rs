@@ -0,0 +1,263 @@
+//! A 16-bit variant of Stream VByte for `u16`s, which are encoded in 1 or 2
+//! bytes.
+//!
+//! With only two lengths to choose from, each number needs just 1 bit in a
+//! control byte, so a control byte describes 8 numbers instead of 4. Bit `i`
+//! (counting from the low bit) is set if the `i`th of those 8 numbers takes 2
+//! bytes. As with the `u32` format, all the control bytes come first, followed
+//! by all the encoded numbers, and a trailing partial group of fewer than 8
+//! numbers gets a control byte with its unused high bits clear.
+//!
+//! This suits numbers that usually fit in a byte but sometimes don't, like term
+//! frequencies or field lengths, where the `u32` format's 2-bit codes would
+//! spend a bit per number on lengths that can't happen.
+//!
+//! The `Encoder16` and `Decoder16` traits are implemented by the same types as
+//! `Encoder` and `Decoder`, so `Scalar` works everywhere and the SIMD
+//! implementations are enabled by the same `feature`s.
+//!
+//! # Examples
+//!
+//! ```
+//! use stream_vbyte::{scalar::Scalar, svb16};
+//!
+//! let nums: Vec<u16> = (0..1000).collect();
+//! let encoded = svb16::encode_to_vec::<Scalar>(&nums);
+//! // 125 control bytes, then 256 1-byte numbers and 744 2-byte numbers
+//! assert_eq!(125 + 256 + 2 * 744, encoded.len());
+//!
+//! assert_eq!(nums, svb16::decode_to_vec::<Scalar>(&encoded, nums.len()));
+//! ```
+
+mod scalar;
+
+#[cfg(all(
+    any(feature = "x86_ssse3", feature = "x86_sse41"),
+    target_arch = "x86_64"
+))]
+mod ssse3;
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
+mod neon;
+
+/// Encode `u16`s to bytes in the 16-bit format.
+pub trait Encoder16 {
+    /// Encode complete groups of 8 input numbers.
+    ///
+    /// `control_bytes` will be exactly as long as the number of complete
+    /// 8-number groups in `input`.
+    ///
+    /// Control bytes are written to `control_bytes` and encoded numbers to
+    /// `output`.
+    ///
+    /// Implementations may choose to encode fewer than the full provided input,
+    /// but any writes done must be for full groups.
+    ///
+    /// Implementations must not write to `output` outside of the area that will
+    /// be populated by encoded numbers when all control bytes are processed.
+    ///
+    /// Returns the number of numbers encoded and the number of bytes written to
+    /// `output`.
+    fn encode_groups(input: &[u16], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize);
+}
+
+/// Decode bytes in the 16-bit format to `u16`s.
+pub trait Decoder16 {
+    /// Decode the complete groups of 8 numbers described by `control_bytes`.
+    ///
+    /// `output` will be exactly 8 times as long as `control_bytes`.
+    ///
+    /// Implementations may choose to decode fewer than all the control bytes,
+    /// but must decode full groups.
+    ///
+    /// Returns the number of numbers decoded and the number of bytes read from
+    /// `encoded_nums`.
+    fn decode_groups(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u16],
+    ) -> (usize, usize);
+}
+
+/// The count of control bytes for `count` numbers, including one for any
+/// trailing partial group.
+fn control_bytes_len(count: usize) -> usize {
+    (count + 7) / 8
+}
+
+/// The most bytes that encoding `count` numbers could take: 2 bytes per number,
+/// plus a control byte for every 8 numbers, including any trailing partial
+/// group.
+pub fn max_encoded_len(count: usize) -> usize {
+    control_bytes_len(count) + 2 * count
+}
+
+/// Encode the `input` slice into the `output` slice.
+///
+/// `output` must be at least `max_encoded_len(input.len())` long unless you
+/// know the input well enough to size it more tightly.
+///
+/// Returns the number of bytes written to the `output` slice.
+pub fn encode<E: Encoder16>(input: &[u16], output: &mut [u8]) -> usize {
+    let control_bytes_len = control_bytes_len(input.len());
+    let complete_groups = input.len() / 8;
+
+    let (control_bytes, encoded_bytes) = output.split_at_mut(control_bytes_len);
+
+    let (nums_encoded, mut num_bytes_written) = E::encode_groups(
+        &input[0..(complete_groups * 8)],
+        &mut control_bytes[0..complete_groups],
+        encoded_bytes,
+    );
+
+    // may be some complete groups left, use Scalar to finish them
+    let (more_nums_encoded, more_bytes_written) = crate::scalar::Scalar::encode_groups(
+        &input[nums_encoded..(complete_groups * 8)],
+        &mut control_bytes[(nums_encoded / 8)..complete_groups],
+        &mut encoded_bytes[num_bytes_written..],
+    );
+    num_bytes_written += more_bytes_written;
+
+    debug_assert_eq!(complete_groups * 8, nums_encoded + more_nums_encoded);
+
+    // last control byte, if there were leftovers
+    let leftovers = &input[(complete_groups * 8)..];
+    if !leftovers.is_empty() {
+        let mut control_byte = 0;
+
+        for (i, &num) in leftovers.iter().enumerate() {
+            let len = encode_num_scalar(num, &mut encoded_bytes[num_bytes_written..]);
+
+            control_byte |= ((len - 1) as u8) << i;
+
+            num_bytes_written += len;
+        }
+        control_bytes[complete_groups] = control_byte;
+    }
+
+    control_bytes_len + num_bytes_written
+}
+
+/// Encode the `input` slice into a new `Vec` that is exactly as long as the
+/// encoded bytes.
+pub fn encode_to_vec<E: Encoder16>(input: &[u16]) -> Vec<u8> {
+    let mut output = vec![0; max_encoded_len(input.len())];
+    let encoded_len = encode::<E>(input, &mut output);
+    output.truncate(encoded_len);
+
+    output
+}
+
+/// Decode `count` numbers from `input`, writing them to `output`.
+///
+/// The `count` must be the same as the number of items originally encoded.
+///
+/// `output` must be large enough for all `count` numbers.
+///
+/// Returns the number of bytes read from `input`.
+pub fn decode<D: Decoder16>(input: &[u8], count: usize, output: &mut [u16]) -> usize {
+    assert!(output.len() >= count, "output buffer was not large enough");
+
+    let control_bytes_len = control_bytes_len(count);
+    let complete_groups = count / 8;
+
+    let (control_bytes, encoded_nums) = input.split_at(control_bytes_len);
+
+    let (nums_decoded, mut bytes_read) = D::decode_groups(
+        &control_bytes[0..complete_groups],
+        encoded_nums,
+        &mut output[0..(complete_groups * 8)],
+    );
+
+    // may be some complete groups left, use Scalar to finish them
+    let (more_nums_decoded, more_bytes_read) = crate::scalar::Scalar::decode_groups(
+        &control_bytes[(nums_decoded / 8)..complete_groups],
+        &encoded_nums[bytes_read..],
+        &mut output[nums_decoded..(complete_groups * 8)],
+    );
+    bytes_read += more_bytes_read;
+
+    debug_assert_eq!(complete_groups * 8, nums_decoded + more_nums_decoded);
+
+    let leftover_count = count - complete_groups * 8;
+    if leftover_count > 0 {
+        let control_byte = control_bytes[complete_groups];
+
+        for (i, num) in output[(complete_groups * 8)..count].iter_mut().enumerate() {
+            let len = ((control_byte >> i) & 1) as usize + 1;
+            *num = decode_num_scalar(len, &encoded_nums[bytes_read..]);
+
+            bytes_read += len;
+        }
+    }
+
+    control_bytes_len + bytes_read
+}
+
+/// Decode `count` numbers from `input` into a new `Vec` that is exactly
+/// `count` long.
+///
+/// The `count` must be the same as the number of items originally encoded.
+pub fn decode_to_vec<D: Decoder16>(input: &[u8], count: usize) -> Vec<u16> {
+    let mut output = vec![0; count];
+    decode::<D>(input, count, &mut output);
+
+    output
+}
+
+/// The length of all encoded numbers described by complete groups'
+/// `control_bytes`, not counting the control bytes.
+#[cfg(test)]
+fn cumulative_encoded_len(control_bytes: &[u8]) -> usize {
+    control_bytes
+        .iter()
+        .map(|&b| 8 + b.count_ones() as usize)
+        .sum()
+}
+
+#[inline]
+fn encode_num_scalar(num: u16, output: &mut [u8]) -> usize {
+    let len = encoded_num_len(num);
+    output[..len].copy_from_slice(&num.to_le_bytes()[..len]);
+
+    len
+}
+
+/// The number of bytes `num` is encoded in.
+#[inline]
+fn encoded_num_len(num: u16) -> usize {
+    if num > 0xFF {
+        2
+    } else {
+        1
+    }
+}
+
+#[inline]
+fn decode_num_scalar(len: usize, input: &[u8]) -> u16 {
+    let mut buf = [0_u8; 2];
+    buf[0..len].copy_from_slice(&input[0..len]);
+
+    u16::from_le_bytes(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoded_num_len_boundaries() {
+        assert_eq!(1, encoded_num_len(0));
+        assert_eq!(1, encoded_num_len(0xFF));
+        assert_eq!(2, encoded_num_len(0x100));
+        assert_eq!(2, encoded_num_len(u16::MAX));
+    }
+
+    #[test]
+    fn cumulative_encoded_len_adds_a_byte_per_set_bit() {
+        assert_eq!(0, cumulative_encoded_len(&[]));
+        assert_eq!(8, cumulative_encoded_len(&[0x00]));
+        assert_eq!(16, cumulative_encoded_len(&[0xFF]));
+        assert_eq!(8 + 11, cumulative_encoded_len(&[0x00, 0b1010_0001]));
+    }
+}
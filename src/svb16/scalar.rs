@@ -0,0 +1,46 @@
+use super::{decode_num_scalar, encode_num_scalar, Decoder16, Encoder16};
+use crate::scalar::Scalar;
+
+impl Encoder16 for Scalar {
+    // This implementation encodes all provided input numbers.
+    fn encode_groups(input: &[u16], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        let mut bytes_written = 0;
+
+        for (control_byte, group) in control_bytes.iter_mut().zip(input.chunks_exact(8)) {
+            let mut code = 0;
+
+            for (i, &num) in group.iter().enumerate() {
+                let len = encode_num_scalar(num, &mut output[bytes_written..]);
+                code |= ((len - 1) as u8) << i;
+
+                bytes_written += len;
+            }
+
+            *control_byte = code;
+        }
+
+        (control_bytes.len() * 8, bytes_written)
+    }
+}
+
+impl Decoder16 for Scalar {
+    // This implementation decodes all provided encoded data.
+    fn decode_groups(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u16],
+    ) -> (usize, usize) {
+        let mut bytes_read = 0;
+
+        for (&control_byte, group) in control_bytes.iter().zip(output.chunks_exact_mut(8)) {
+            for (i, num) in group.iter_mut().enumerate() {
+                let len = ((control_byte >> i) & 1) as usize + 1;
+                *num = decode_num_scalar(len, &encoded_nums[bytes_read..]);
+
+                bytes_read += len;
+            }
+        }
+
+        (control_bytes.len() * 8, bytes_read)
+    }
+}
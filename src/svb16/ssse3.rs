@@ -0,0 +1,277 @@
+use std::arch::x86_64::{
+    __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_packus_epi16,
+    _mm_setzero_si128, _mm_shuffle_epi8, _mm_srli_epi16, _mm_storeu_si128,
+};
+
+#[cfg(feature = "x86_ssse3")]
+use super::Decoder16;
+use super::Encoder16;
+#[cfg(feature = "x86_sse41")]
+use crate::encode::sse41::Sse41;
+use crate::tables;
+#[cfg(feature = "x86_ssse3")]
+use crate::x86::Ssse3;
+
+#[cfg(feature = "x86_ssse3")]
+impl Encoder16 for Ssse3 {
+    fn encode_groups(input: &[u16], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("ssse3") {
+            return (0, 0);
+        }
+
+        unsafe { encode_groups_ssse3(input, control_bytes, output) }
+    }
+}
+
+/// There's nothing in SSE4.1 that helps with 16-bit numbers, so `Sse41` uses
+/// the same code as `Ssse3`, and is only an `Encoder16` so that
+/// `StreamVbyteEncoder` always is.
+#[cfg(feature = "x86_sse41")]
+impl Encoder16 for Sse41 {
+    fn encode_groups(input: &[u16], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("sse4.1") {
+            return (0, 0);
+        }
+
+        // every CPU with SSE4.1 has SSSE3
+        unsafe { encode_groups_ssse3(input, control_bytes, output) }
+    }
+}
+
+/// The body of `Ssse3::encode_groups()`, compiled with SSSE3 enabled so that
+/// the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports SSSE3.
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_groups_ssse3(
+    input: &[u16],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+) -> (usize, usize) {
+    let mut bytes_encoded: usize = 0;
+
+    // Encoding writes 16 bytes at a time, but a group can be encoded in as few as
+    // 8, so the last group could write past what is actually necessary. Every
+    // group is at least 8 bytes, so any other group's extra bytes are
+    // overwritten by the group after it. The last group is left for a second
+    // loop that writes it here first, and only copies its encoded bytes to
+    // `output`.
+    let direct_len = control_bytes.len().saturating_sub(1);
+    let (direct_control_bytes, tail_control_bytes) = control_bytes.split_at_mut(direct_len);
+    let (direct_input, tail_input) = input.split_at(direct_len * 8);
+
+    for (control_byte, group) in direct_control_bytes
+        .iter_mut()
+        .zip(direct_input.chunks_exact(8))
+    {
+        let (encoded, code, length) = encode_group_ssse3(group);
+
+        unsafe {
+            _mm_storeu_si128(
+                output[bytes_encoded..(bytes_encoded + 16)].as_mut_ptr() as *mut __m128i,
+                encoded,
+            );
+        }
+
+        *control_byte = code;
+
+        bytes_encoded += length;
+    }
+
+    let mut tail = [0_u8; 16];
+
+    for (control_byte, group) in tail_control_bytes
+        .iter_mut()
+        .zip(tail_input.chunks_exact(8))
+    {
+        let (encoded, code, length) = encode_group_ssse3(group);
+
+        unsafe { _mm_storeu_si128(tail.as_mut_ptr() as *mut __m128i, encoded) };
+        output[bytes_encoded..(bytes_encoded + length)].copy_from_slice(&tail[0..length]);
+
+        *control_byte = code;
+
+        bytes_encoded += length;
+    }
+
+    (control_bytes.len() * 8, bytes_encoded)
+}
+
+/// Shuffle a group of 8 numbers into their encoded bytes, returning them along
+/// with the group's control byte and encoded length. Only the first `length`
+/// bytes are part of the encoding.
+#[inline]
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_group_ssse3(group: &[u16]) -> (__m128i, u8, usize) {
+    let zeros = _mm_setzero_si128();
+    let nums = unsafe { _mm_loadu_si128(group[0..8].as_ptr() as *const __m128i) };
+
+    // gather the high byte of each number into the low 8 bytes, then find the
+    // numbers whose high byte is zero
+    let high_bytes = _mm_packus_epi16(_mm_srli_epi16(nums, 8), zeros);
+    let one_byte_nums = _mm_movemask_epi8(_mm_cmpeq_epi8(high_bytes, zeros)) as u8;
+
+    let code = !one_byte_nums;
+    let length = 8 + code.count_ones() as usize;
+
+    let mask_bytes = tables::SVB16_ENCODE_SHUFFLE_TABLE[code as usize];
+    let encode_mask = unsafe { _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i) };
+
+    (_mm_shuffle_epi8(nums, encode_mask), code, length)
+}
+
+#[cfg(feature = "x86_ssse3")]
+impl Decoder16 for Ssse3 {
+    fn decode_groups(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u16],
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("ssse3") {
+            return (0, 0);
+        }
+
+        unsafe { decode_groups_ssse3(control_bytes, encoded_nums, output) }
+    }
+}
+
+/// The body of `Ssse3::decode_groups()`, compiled with SSSE3 enabled so that
+/// the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports SSSE3.
+#[cfg(feature = "x86_ssse3")]
+#[target_feature(enable = "ssse3")]
+unsafe fn decode_groups_ssse3(
+    control_bytes: &[u8],
+    encoded_nums: &[u8],
+    output: &mut [u16],
+) -> (usize, usize) {
+    let mut bytes_read: usize = 0;
+
+    // Decoding reads 16 bytes at a time from input, so stop 1 control byte short
+    // of the end. Every group before that has at least 8 bytes of its own and 8
+    // of the next group's, so its 16 bytes are always available.
+    let direct_len = control_bytes.len().saturating_sub(1);
+    let (direct_output, tail_output) = output.split_at_mut(direct_len * 8);
+
+    for (&control_byte, group) in control_bytes[0..direct_len]
+        .iter()
+        .zip(direct_output.chunks_exact_mut(8))
+    {
+        let length = 8 + control_byte.count_ones() as usize;
+        let mask_bytes = tables::SVB16_DECODE_SHUFFLE_TABLE[control_byte as usize];
+        let next_8 = &encoded_nums[bytes_read..(bytes_read + 16)];
+
+        unsafe {
+            let mask = _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i);
+            let data = _mm_loadu_si128(next_8.as_ptr() as *const __m128i);
+
+            _mm_storeu_si128(
+                group.as_mut_ptr() as *mut __m128i,
+                _mm_shuffle_epi8(data, mask),
+            );
+        }
+
+        bytes_read += length;
+    }
+
+    // The last group may be encoded in fewer than 16 bytes with nothing after it,
+    // so it's copied here first so that there are always 16 bytes to read. The
+    // shuffle ignores anything after the first `length` bytes.
+    let mut tail = [0_u8; 16];
+
+    for (&control_byte, group) in control_bytes[direct_len..]
+        .iter()
+        .zip(tail_output.chunks_exact_mut(8))
+    {
+        let length = 8 + control_byte.count_ones() as usize;
+        let mask_bytes = tables::SVB16_DECODE_SHUFFLE_TABLE[control_byte as usize];
+        tail[0..length].copy_from_slice(&encoded_nums[bytes_read..(bytes_read + length)]);
+
+        unsafe {
+            let mask = _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i);
+            let data = _mm_loadu_si128(tail.as_ptr() as *const __m128i);
+
+            _mm_storeu_si128(
+                group.as_mut_ptr() as *mut __m128i,
+                _mm_shuffle_epi8(data, mask),
+            );
+        }
+
+        bytes_read += length;
+    }
+
+    (control_bytes.len() * 8, bytes_read)
+}
+
+#[cfg(all(test, feature = "x86_ssse3"))]
+mod tests {
+    use super::*;
+    use crate::{scalar::Scalar, svb16::cumulative_encoded_len};
+
+    #[test]
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
+        // alternate between the two lengths in different patterns
+        let nums: Vec<u16> = (0..64).map(|i| if i % 3 == 0 { 1000 } else { i }).collect();
+        let mut encoded = Vec::new();
+
+        for control_bytes_len in 0..(nums.len() / 8 + 1) {
+            encoded.clear();
+            encoded.resize(nums.len() * 3, 0xFF);
+
+            let (nums_encoded, bytes_written) = {
+                let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
+
+                Ssse3::encode_groups(&nums[0..8 * control_bytes_len], control_bytes, num_bytes)
+            };
+
+            assert_eq!(control_bytes_len * 8, nums_encoded);
+            assert_eq!(
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
+                bytes_written
+            );
+
+            let mut expected = vec![0xFF; nums.len() * 3];
+            {
+                let (control_bytes, num_bytes) = expected.split_at_mut(control_bytes_len);
+                Scalar::encode_groups(&nums[0..8 * control_bytes_len], control_bytes, num_bytes);
+            }
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
+        }
+    }
+
+    #[test]
+    fn reads_all_control_bytes_without_extra_input_bytes() {
+        let nums: Vec<u16> = (0..64).map(|i| i * 10).collect();
+        let encoded = crate::svb16::encode_to_vec::<Scalar>(&nums);
+        let mut decoded: Vec<u16> = Vec::new();
+
+        // 8 control bytes
+        let control_bytes = &encoded[0..8];
+        // no trailing bytes to read past the end into, so the last groups have to go
+        // through the tail buffer
+        let encoded_nums = &encoded[8..];
+
+        for control_bytes_len in 0..9 {
+            decoded.clear();
+            decoded.resize(nums.len(), 54321);
+
+            let (nums_decoded, bytes_read) = Ssse3::decode_groups(
+                &control_bytes[0..control_bytes_len],
+                encoded_nums,
+                &mut decoded[0..(8 * control_bytes_len)],
+            );
+            assert_eq!(control_bytes_len * 8, nums_decoded);
+            assert_eq!(
+                cumulative_encoded_len(&control_bytes[0..control_bytes_len]),
+                bytes_read
+            );
+            assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
+            assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u16));
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::cmp;
+
+use super::{code_len, cumulative_encoded_len, decode_num_scalar, encoded_nums_len, Decoder64};
+use crate::{decode::DecodeError, encoded_shape, scalar::Scalar, EncodedShape};
+
+/// Offers more flexible decoding of the 64-bit format than the top-level
+/// `svb64::decode()`, in the same way as `DecodeCursor` does for `u32`s.
+///
+/// You can skip numbers you don't need with `skip()`, and decode the parts of
+/// your input you need with `decode_slice()`.
+///
+/// # Examples
+///
+/// ```
+/// use stream_vbyte::{scalar::Scalar, svb64::{self, cursor::DecodeCursor64}};
+///
+/// let nums: Vec<u64> = (0..100).map(|i| i << 30).collect();
+/// let encoded = svb64::encode_to_vec::<Scalar>(&nums);
+///
+/// let mut cursor = DecodeCursor64::try_new(&encoded, nums.len()).unwrap();
+/// cursor.skip(90);
+///
+/// let mut decoded = [0; 10];
+/// assert_eq!(10, cursor.decode_slice::<Scalar>(&mut decoded));
+/// assert_eq!(&nums[90..], &decoded[..]);
+/// assert!(!cursor.has_more());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecodeCursor64<'a> {
+    control_bytes: &'a [u8],
+    encoded_nums: &'a [u8],
+    encoded_shape: EncodedShape,
+    total_nums: usize,
+    nums_decoded: usize,
+    encoded_bytes_read: usize,
+}
+
+impl<'a> DecodeCursor64<'a> {
+    /// Create a new cursor.
+    ///
+    /// This does not inspect the encoded numbers, so if `input` is truncated,
+    /// decoding will panic when it runs off the end. Use `try_new()` for input
+    /// you don't trust.
+    pub fn new(input: &'a [u8], count: usize) -> DecodeCursor64<'a> {
+        let shape = encoded_shape(count);
+
+        DecodeCursor64 {
+            control_bytes: &input[0..shape.control_bytes_len],
+            encoded_nums: &input[shape.control_bytes_len..],
+            encoded_shape: shape,
+            total_nums: count,
+            nums_decoded: 0,
+            encoded_bytes_read: 0,
+        }
+    }
+
+    /// Create a new cursor, first checking that `input` is long enough to hold
+    /// `count` encoded numbers.
+    ///
+    /// Once it succeeds, decoding with any `Decoder64` will not run off the end
+    /// of `input`.
+    pub fn try_new(input: &'a [u8], count: usize) -> Result<DecodeCursor64<'a>, DecodeError> {
+        let shape = encoded_shape(count);
+
+        if input.len() < shape.control_bytes_len {
+            return Err(DecodeError::TruncatedControlBytes {
+                required: shape.control_bytes_len,
+                actual: input.len(),
+            });
+        }
+
+        let required_nums_len = encoded_nums_len(&input[0..shape.control_bytes_len], &shape);
+        let actual_nums_len = input.len() - shape.control_bytes_len;
+
+        if actual_nums_len < required_nums_len {
+            return Err(DecodeError::TruncatedData {
+                required: required_nums_len,
+                actual: actual_nums_len,
+            });
+        }
+
+        Ok(DecodeCursor64::new(input, count))
+    }
+
+    /// Skip `to_skip` numbers, which must not be more than the count of
+    /// remaining numbers.
+    ///
+    /// Skipping can end partway through a quad, in which case the next decode
+    /// starts with the rest of that quad.
+    pub fn skip(&mut self, to_skip: usize) {
+        assert!(
+            to_skip <= self.total_nums - self.nums_decoded,
+            "Can't skip past the end of the input"
+        );
+
+        let mut remaining = to_skip;
+
+        // finish off the current quad if we're partway through it
+        while remaining > 0 && self.nums_decoded % 4 != 0 {
+            self.decode_one();
+            remaining -= 1;
+        }
+
+        // Whole quads can be skipped by their control bytes alone. These are all
+        // complete quads because there are at least 4 numbers left for each one.
+        let quad = self.nums_decoded / 4;
+        let quads_to_skip = remaining / 4;
+        self.encoded_bytes_read +=
+            cumulative_encoded_len(&self.control_bytes[quad..(quad + quads_to_skip)]);
+        self.nums_decoded += quads_to_skip * 4;
+        remaining -= quads_to_skip * 4;
+
+        for _ in 0..remaining {
+            self.decode_one();
+        }
+    }
+
+    /// Move to `index`, so that the next number decoded is the one at that
+    /// position, either forwards or back from the current position.
+    ///
+    /// Moving forwards only skips from the current position, but moving back
+    /// skips from the start of the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the count of numbers.
+    pub fn seek_to(&mut self, index: usize) {
+        assert!(
+            index <= self.total_nums,
+            "Can't seek past the end of the input"
+        );
+
+        if index < self.nums_decoded {
+            self.nums_decoded = 0;
+            self.encoded_bytes_read = 0;
+        }
+
+        self.skip(index - self.nums_decoded);
+    }
+
+    /// Returns the position of the next number to be decoded, which is the
+    /// count of numbers decoded or skipped so far.
+    pub fn position(&self) -> usize {
+        self.nums_decoded
+    }
+
+    /// Decode into the `output` buffer.
+    ///
+    /// Any size of buffer works: this decodes as many of the remaining numbers
+    /// as fit, using `D` for the complete quads in the middle and decoding the
+    /// rest one at a time.
+    ///
+    /// Returns the number of numbers decoded by this invocation, which is less
+    /// than the size of the buffer only at the end of the input.
+    pub fn decode_slice<D: Decoder64>(&mut self, output: &mut [u64]) -> usize {
+        let to_decode = cmp::min(output.len(), self.total_nums - self.nums_decoded);
+        let mut decoded = 0;
+
+        // finish off the current quad if a skip left us partway through it
+        while decoded < to_decode && self.nums_decoded % 4 != 0 {
+            output[decoded] = self.decode_one();
+            decoded += 1;
+        }
+
+        // decode complete quads
+        let quad = self.nums_decoded / 4;
+        let quads = cmp::min(
+            (to_decode - decoded) / 4,
+            self.encoded_shape
+                .complete_control_bytes_len
+                .saturating_sub(quad),
+        );
+        let quads_output = &mut output[decoded..(decoded + quads * 4)];
+
+        let (nums_decoded, bytes_read) = D::decode_quads(
+            &self.control_bytes[quad..(quad + quads)],
+            &self.encoded_nums[self.encoded_bytes_read..],
+            quads_output,
+        );
+
+        // handle any remaining full quads if the provided Decoder64 did not consume
+        // all the control bytes
+        let (more_nums_decoded, more_bytes_read) = Scalar::decode_quads(
+            &self.control_bytes[(quad + nums_decoded / 4)..(quad + quads)],
+            &self.encoded_nums[(self.encoded_bytes_read + bytes_read)..],
+            &mut quads_output[nums_decoded..],
+        );
+
+        debug_assert_eq!(quads * 4, nums_decoded + more_nums_decoded);
+
+        self.encoded_bytes_read += bytes_read + more_bytes_read;
+        self.nums_decoded += quads * 4;
+        decoded += quads * 4;
+
+        // the final partial quad, or the start of a quad there isn't room for all of
+        while decoded < to_decode {
+            output[decoded] = self.decode_one();
+            decoded += 1;
+        }
+
+        decoded
+    }
+
+    /// Returns the total length of input scanned so far: the complete block of
+    /// control bytes, plus any encoded numbers decoded.
+    pub fn input_consumed(&self) -> usize {
+        self.encoded_shape.control_bytes_len + self.encoded_bytes_read
+    }
+
+    /// Returns true iff there are more numbers to be decoded.
+    pub fn has_more(&self) -> bool {
+        self.nums_decoded < self.total_nums
+    }
+
+    /// Decode the number at the current position with scalar code.
+    fn decode_one(&mut self) -> u64 {
+        let control_byte = self.control_bytes[self.nums_decoded / 4];
+        let len = code_len((control_byte >> ((self.nums_decoded % 4) * 2)) & 0x03);
+        let num = decode_num_scalar(len, &self.encoded_nums[self.encoded_bytes_read..]);
+
+        self.encoded_bytes_read += len;
+        self.nums_decoded += 1;
+
+        num
+    }
+}
@@ -0,0 +1,270 @@
+//! A 64-bit variant of Stream VByte for `u64`s, which are encoded in 1, 2, 4
+//! or 8 bytes.
+//!
+//! Like the `u32` format, each control byte describes a quad of 4 numbers with
+//! a 2-bit code each, starting from the low bits, and all the control bytes
+//! come before all the encoded numbers. The codes 0, 1, 2 and 3 mean that a
+//! number takes 1, 2, 4 or 8 bytes, so a number that needs 3 bytes takes 4,
+//! and one that needs 5 to 7 takes 8. That keeps the control bytes the same
+//! size as the `u32` format's, which suits numbers like timestamps and file
+//! offsets that are usually either small deltas or full width.
+//!
+//! The `Encoder64` and `Decoder64` traits are implemented by the same types as
+//! `Encoder` and `Decoder`, so `Scalar` works everywhere and the SIMD
+//! implementations are enabled by the same `feature`s.
+//!
+//! # Examples
+//!
+//! ```
+//! use stream_vbyte::{scalar::Scalar, svb64};
+//!
+//! let nums: Vec<u64> = vec![1, 300, 70_000, 1 << 40, 5];
+//! let encoded = svb64::encode_to_vec::<Scalar>(&nums);
+//! // 2 control bytes, then 1 + 2 + 4 + 8 + 1 bytes of numbers
+//! assert_eq!(2 + 16, encoded.len());
+//!
+//! assert_eq!(nums, svb64::decode_to_vec::<Scalar>(&encoded, nums.len()));
+//! ```
+
+pub mod cursor;
+
+mod scalar;
+
+#[cfg(all(
+    any(feature = "x86_ssse3", feature = "x86_sse41"),
+    target_arch = "x86_64"
+))]
+mod ssse3;
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64",))]
+mod neon;
+
+use crate::{encoded_shape, tables, EncodedShape};
+
+/// Encode `u64`s to bytes in the 64-bit format.
+pub trait Encoder64 {
+    /// Encode complete quads of input numbers.
+    ///
+    /// `control_bytes` will be exactly as long as the number of complete
+    /// 4-number quads in `input`.
+    ///
+    /// Control bytes are written to `control_bytes` and encoded numbers to
+    /// `output`.
+    ///
+    /// Implementations may choose to encode fewer than the full provided input,
+    /// but any writes done must be for full quads.
+    ///
+    /// Implementations must not write to `output` outside of the area that will
+    /// be populated by encoded numbers when all control bytes are processed.
+    ///
+    /// Returns the number of numbers encoded and the number of bytes written to
+    /// `output`.
+    fn encode_quads(input: &[u64], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize);
+}
+
+/// Decode bytes in the 64-bit format to `u64`s.
+pub trait Decoder64 {
+    /// Decode the complete quads described by `control_bytes`.
+    ///
+    /// `output` will be exactly 4 times as long as `control_bytes`.
+    ///
+    /// Implementations may choose to decode fewer than all the control bytes,
+    /// but must decode full quads.
+    ///
+    /// Returns the number of numbers decoded and the number of bytes read from
+    /// `encoded_nums`.
+    fn decode_quads(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u64],
+    ) -> (usize, usize);
+}
+
+/// The most bytes that encoding `count` numbers could take: 8 bytes per number,
+/// plus a control byte for every 4 numbers, including any trailing partial
+/// quad.
+pub fn max_encoded_len(count: usize) -> usize {
+    encoded_shape(count).control_bytes_len + 8 * count
+}
+
+/// Encode the `input` slice into the `output` slice.
+///
+/// `output` must be at least `max_encoded_len(input.len())` long unless you
+/// know the input well enough to size it more tightly.
+///
+/// Returns the number of bytes written to the `output` slice.
+pub fn encode<E: Encoder64>(input: &[u64], output: &mut [u8]) -> usize {
+    let shape = encoded_shape(input.len());
+    let complete_nums = shape.complete_control_bytes_len * 4;
+
+    let (control_bytes, encoded_bytes) = output.split_at_mut(shape.control_bytes_len);
+
+    let (nums_encoded, mut num_bytes_written) = E::encode_quads(
+        &input[0..complete_nums],
+        &mut control_bytes[0..shape.complete_control_bytes_len],
+        encoded_bytes,
+    );
+
+    // may be some complete quads left, use Scalar to finish them
+    let (more_nums_encoded, more_bytes_written) = crate::scalar::Scalar::encode_quads(
+        &input[nums_encoded..complete_nums],
+        &mut control_bytes[(nums_encoded / 4)..shape.complete_control_bytes_len],
+        &mut encoded_bytes[num_bytes_written..],
+    );
+    num_bytes_written += more_bytes_written;
+
+    debug_assert_eq!(complete_nums, nums_encoded + more_nums_encoded);
+
+    // last control byte, if there were leftovers
+    if shape.leftover_numbers > 0 {
+        let mut control_byte = 0;
+
+        for (i, &num) in input[complete_nums..].iter().enumerate() {
+            let code = length_code(num);
+            num_bytes_written +=
+                encode_num_scalar(num, code, &mut encoded_bytes[num_bytes_written..]);
+
+            control_byte |= code << (i * 2);
+        }
+        control_bytes[shape.complete_control_bytes_len] = control_byte;
+    }
+
+    shape.control_bytes_len + num_bytes_written
+}
+
+/// Encode the `input` slice into a new `Vec` that is exactly as long as the
+/// encoded bytes.
+pub fn encode_to_vec<E: Encoder64>(input: &[u64]) -> Vec<u8> {
+    let mut output = vec![0; max_encoded_len(input.len())];
+    let encoded_len = encode::<E>(input, &mut output);
+    output.truncate(encoded_len);
+
+    output
+}
+
+/// Decode `count` numbers from `input`, writing them to `output`.
+///
+/// The `count` must be the same as the number of items originally encoded.
+///
+/// `output` must be large enough for all `count` numbers.
+///
+/// Returns the number of bytes read from `input`.
+pub fn decode<D: Decoder64>(input: &[u8], count: usize, output: &mut [u64]) -> usize {
+    let mut cursor = cursor::DecodeCursor64::new(input, count);
+
+    assert_eq!(
+        count,
+        cursor.decode_slice::<D>(output),
+        "output buffer was not large enough"
+    );
+
+    cursor.input_consumed()
+}
+
+/// Decode `count` numbers from `input` into a new `Vec` that is exactly
+/// `count` long.
+///
+/// The `count` must be the same as the number of items originally encoded.
+pub fn decode_to_vec<D: Decoder64>(input: &[u8], count: usize) -> Vec<u64> {
+    let mut output = vec![0; count];
+    decode::<D>(input, count, &mut output);
+
+    output
+}
+
+/// The 2-bit code for the length `num` is encoded in.
+#[inline]
+fn length_code(num: u64) -> u8 {
+    if num <= 0xFF {
+        0
+    } else if num <= 0xFFFF {
+        1
+    } else if num <= 0xFFFF_FFFF {
+        2
+    } else {
+        3
+    }
+}
+
+/// The length of a number with the 2-bit length `code`.
+#[inline]
+fn code_len(code: u8) -> usize {
+    1 << code
+}
+
+/// Write the low bytes of `num` that its length `code` says to.
+///
+/// Returns the number of bytes written.
+#[inline]
+fn encode_num_scalar(num: u64, code: u8, output: &mut [u8]) -> usize {
+    let len = code_len(code);
+    output[..len].copy_from_slice(&num.to_le_bytes()[..len]);
+
+    len
+}
+
+#[inline]
+fn decode_num_scalar(len: usize, input: &[u8]) -> u64 {
+    let mut buf = [0_u8; 8];
+    buf[0..len].copy_from_slice(&input[0..len]);
+
+    u64::from_le_bytes(buf)
+}
+
+/// The length of all encoded numbers described by complete quads'
+/// `control_bytes`, not counting the control bytes.
+fn cumulative_encoded_len(control_bytes: &[u8]) -> usize {
+    control_bytes
+        .iter()
+        .map(|&b| tables::SVB64_DECODE_LENGTH_PER_QUAD_TABLE[b as usize] as usize)
+        .sum()
+}
+
+/// The length of all encoded numbers described by `control_bytes`, including
+/// any trailing partial quad.
+///
+/// `control_bytes` must be exactly `shape.control_bytes_len` long.
+fn encoded_nums_len(control_bytes: &[u8], shape: &EncodedShape) -> usize {
+    let complete_len = cumulative_encoded_len(&control_bytes[0..shape.complete_control_bytes_len]);
+
+    if shape.leftover_numbers == 0 {
+        return complete_len;
+    }
+
+    let control_byte = control_bytes[shape.complete_control_bytes_len];
+    let leftover_len: usize = (0..shape.leftover_numbers)
+        .map(|i| code_len((control_byte >> (i * 2)) & 0x03))
+        .sum();
+
+    complete_len + leftover_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_code_boundaries() {
+        assert_eq!(0, length_code(0));
+        assert_eq!(0, length_code(0xFF));
+        assert_eq!(1, length_code(0x100));
+        assert_eq!(1, length_code(0xFFFF));
+        assert_eq!(2, length_code(0x1_0000));
+        assert_eq!(2, length_code(0xFFFF_FFFF));
+        assert_eq!(3, length_code(0x1_0000_0000));
+        assert_eq!(3, length_code(u64::MAX));
+    }
+
+    #[test]
+    fn encoded_nums_len_includes_partial_quad() {
+        // lengths 1, 2, 4, 8 then 8, 2
+        let control_bytes = [0b11_10_01_00, 0b00_00_01_11];
+
+        assert_eq!(
+            15,
+            encoded_nums_len(&control_bytes[0..1], &encoded_shape(4))
+        );
+        assert_eq!(15 + 8, encoded_nums_len(&control_bytes, &encoded_shape(5)));
+        assert_eq!(15 + 10, encoded_nums_len(&control_bytes, &encoded_shape(6)));
+    }
+}
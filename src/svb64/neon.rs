@@ -0,0 +1,289 @@
+use std::arch::aarch64::{
+    uint8x16_t, vcgtq_u64, vdupq_n_u64, vgetq_lane_u64, vld1q_u64, vld1q_u8, vqtbl1q_u8,
+    vreinterpretq_u8_u64, vst1q_u8, vsubq_u64,
+};
+
+use super::{code_len, Decoder64, Encoder64};
+use crate::{decode::neon::NeonDecoder, encode::neon::NeonEncoder, tables};
+
+impl Encoder64 for NeonEncoder {
+    fn encode_quads(input: &[u64], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !std::arch::is_aarch64_feature_detected!("neon") {
+            return (0, 0);
+        }
+
+        unsafe { encode_quads_neon(input, control_bytes, output) }
+    }
+}
+
+/// The body of `NeonEncoder::encode_quads()`, compiled with NEON enabled so
+/// that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports NEON.
+#[target_feature(enable = "neon")]
+unsafe fn encode_quads_neon(
+    input: &[u64],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+) -> (usize, usize) {
+    let mut bytes_encoded: usize = 0;
+
+    // A register only holds a pair of numbers, so each quad is encoded a pair at
+    // a time, writing 16 bytes for a pair that can be encoded in as few as 2.
+    // Every quad is at least 4 bytes, so when there are 4 more quads after this
+    // one, they will overwrite any extra bytes. The last 4 quads are left for a
+    // second loop that writes their pairs here first, and only copies their
+    // encoded bytes to `output`.
+    let direct_len = control_bytes.len().saturating_sub(4);
+    let (direct_control_bytes, tail_control_bytes) = control_bytes.split_at_mut(direct_len);
+    let (direct_input, tail_input) = input.split_at(direct_len * 4);
+
+    for (control_byte, quad) in direct_control_bytes
+        .iter_mut()
+        .zip(direct_input.chunks_exact(4))
+    {
+        let mut code = 0;
+
+        for (p, pair) in quad.chunks_exact(2).enumerate() {
+            let (encoded, pair_code, length) = encode_pair_neon(pair);
+
+            unsafe {
+                vst1q_u8(
+                    output[bytes_encoded..(bytes_encoded + 16)].as_mut_ptr(),
+                    encoded,
+                )
+            };
+
+            code |= pair_code << (p * 4);
+
+            bytes_encoded += length;
+        }
+
+        *control_byte = code;
+    }
+
+    let mut tail = [0_u8; 16];
+
+    for (control_byte, quad) in tail_control_bytes
+        .iter_mut()
+        .zip(tail_input.chunks_exact(4))
+    {
+        let mut code = 0;
+
+        for (p, pair) in quad.chunks_exact(2).enumerate() {
+            let (encoded, pair_code, length) = encode_pair_neon(pair);
+
+            unsafe { vst1q_u8(tail.as_mut_ptr(), encoded) };
+            output[bytes_encoded..(bytes_encoded + length)].copy_from_slice(&tail[0..length]);
+
+            code |= pair_code << (p * 4);
+
+            bytes_encoded += length;
+        }
+
+        *control_byte = code;
+    }
+
+    (control_bytes.len() * 4, bytes_encoded)
+}
+
+/// Shuffle a pair of numbers into their encoded bytes, returning them along
+/// with the pair's 4 bits of control byte and encoded length. Only the first
+/// `length` bytes are part of the encoding.
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn encode_pair_neon(pair: &[u64]) -> (uint8x16_t, u8, usize) {
+    let max_1_byte = unsafe { vdupq_n_u64(0xFF) };
+    let max_2_bytes = unsafe { vdupq_n_u64(0xFFFF) };
+    let max_4_bytes = unsafe { vdupq_n_u64(0xFFFF_FFFF) };
+    let zeros = unsafe { vdupq_n_u64(0) };
+
+    let nums = unsafe { vld1q_u64(pair[0..2].as_ptr()) };
+
+    // lanes that compare greater are all 1s, which is -1, so subtracting each
+    // comparison counts the limits each number is over
+    let codes = unsafe {
+        let codes = vsubq_u64(zeros, vcgtq_u64(nums, max_1_byte));
+        let codes = vsubq_u64(codes, vcgtq_u64(nums, max_2_bytes));
+        vsubq_u64(codes, vcgtq_u64(nums, max_4_bytes))
+    };
+    let low_code = unsafe { vgetq_lane_u64(codes, 0) } as u8;
+    let high_code = unsafe { vgetq_lane_u64(codes, 1) } as u8;
+
+    let pair_code = low_code | (high_code << 2);
+    let length = code_len(low_code) + code_len(high_code);
+
+    let encoded = unsafe {
+        let encoding_shuffle =
+            vld1q_u8(tables::SVB64_ENCODE_PAIR_SHUFFLE_TABLE[pair_code as usize].as_ptr());
+
+        vqtbl1q_u8(vreinterpretq_u8_u64(nums), encoding_shuffle)
+    };
+
+    (encoded, pair_code, length)
+}
+
+impl Decoder64 for NeonDecoder {
+    fn decode_quads(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u64],
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !std::arch::is_aarch64_feature_detected!("neon") {
+            return (0, 0);
+        }
+
+        unsafe { decode_quads_neon(control_bytes, encoded_nums, output) }
+    }
+}
+
+/// The body of `NeonDecoder::decode_quads()`, compiled with NEON enabled so
+/// that the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports NEON.
+#[target_feature(enable = "neon")]
+unsafe fn decode_quads_neon(
+    control_bytes: &[u8],
+    encoded_nums: &[u8],
+    output: &mut [u64],
+) -> (usize, usize) {
+    let mut bytes_read: usize = 0;
+
+    // Decoding reads 16 bytes at a time from input, so stop 4 control bytes short
+    // of the end. Every pair before that has at least 4 quads of at least 4 bytes
+    // each after it, so its 16 bytes are always available.
+    let direct_len = control_bytes.len().saturating_sub(4);
+    let (direct_output, tail_output) = output.split_at_mut(direct_len * 4);
+
+    for (&control_byte, quad) in control_bytes[0..direct_len]
+        .iter()
+        .zip(direct_output.chunks_exact_mut(4))
+    {
+        for (p, pair) in quad.chunks_exact_mut(2).enumerate() {
+            let pair_code = (control_byte >> (p * 4)) & 0x0F;
+            let length = code_len(pair_code & 0x03) + code_len(pair_code >> 2);
+            let mask_bytes = tables::SVB64_DECODE_PAIR_SHUFFLE_TABLE[pair_code as usize];
+            let next_16 = &encoded_nums[bytes_read..(bytes_read + 16)];
+
+            unsafe {
+                let mask = vld1q_u8(mask_bytes.as_ptr());
+                let data = vld1q_u8(next_16.as_ptr());
+
+                vst1q_u8(pair.as_mut_ptr() as *mut u8, vqtbl1q_u8(data, mask));
+            }
+
+            bytes_read += length;
+        }
+    }
+
+    // The last pairs may be encoded in fewer than 16 bytes with nothing after
+    // them, so they're copied here first so that there are always 16 bytes to
+    // read. The shuffle ignores anything after the first `length` bytes.
+    let mut tail = [0_u8; 16];
+
+    for (&control_byte, quad) in control_bytes[direct_len..]
+        .iter()
+        .zip(tail_output.chunks_exact_mut(4))
+    {
+        for (p, pair) in quad.chunks_exact_mut(2).enumerate() {
+            let pair_code = (control_byte >> (p * 4)) & 0x0F;
+            let length = code_len(pair_code & 0x03) + code_len(pair_code >> 2);
+            let mask_bytes = tables::SVB64_DECODE_PAIR_SHUFFLE_TABLE[pair_code as usize];
+            tail[0..length].copy_from_slice(&encoded_nums[bytes_read..(bytes_read + length)]);
+
+            unsafe {
+                let mask = vld1q_u8(mask_bytes.as_ptr());
+                let data = vld1q_u8(tail.as_ptr());
+
+                vst1q_u8(pair.as_mut_ptr() as *mut u8, vqtbl1q_u8(data, mask));
+            }
+
+            bytes_read += length;
+        }
+    }
+
+    (control_bytes.len() * 4, bytes_read)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{scalar::Scalar, svb64::cumulative_encoded_len};
+
+    /// Numbers of every length in different patterns
+    fn mixed_lengths() -> Vec<u64> {
+        (0..64_u64)
+            .map(|i| match i % 5 {
+                0 => i,
+                1 => 1000 + i,
+                2 => 100_000 + i,
+                3 => u64::MAX - i,
+                _ => 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
+        let nums = mixed_lengths();
+        let mut encoded = Vec::new();
+
+        for control_bytes_len in 0..(nums.len() / 4 + 1) {
+            encoded.clear();
+            encoded.resize(nums.len() * 9, 0xFF);
+
+            let (nums_encoded, bytes_written) = {
+                let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
+
+                NeonEncoder::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes)
+            };
+
+            assert_eq!(control_bytes_len * 4, nums_encoded);
+            assert_eq!(
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
+                bytes_written
+            );
+
+            let mut expected = vec![0xFF; nums.len() * 9];
+            {
+                let (control_bytes, num_bytes) = expected.split_at_mut(control_bytes_len);
+                Scalar::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes);
+            }
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
+        }
+    }
+
+    #[test]
+    fn reads_all_control_bytes_without_extra_input_bytes() {
+        let nums = mixed_lengths();
+        let encoded = crate::svb64::encode_to_vec::<Scalar>(&nums);
+        let mut decoded: Vec<u64> = Vec::new();
+
+        // 16 control bytes
+        let control_bytes = &encoded[0..16];
+        // no trailing bytes to read past the end into, so the last pairs have to go
+        // through the tail buffer
+        let encoded_nums = &encoded[16..];
+
+        for control_bytes_len in 0..17 {
+            decoded.clear();
+            decoded.resize(nums.len(), 54321);
+
+            let (nums_decoded, bytes_read) = NeonDecoder::decode_quads(
+                &control_bytes[0..control_bytes_len],
+                encoded_nums,
+                &mut decoded[0..(4 * control_bytes_len)],
+            );
+            assert_eq!(control_bytes_len * 4, nums_decoded);
+            assert_eq!(
+                cumulative_encoded_len(&control_bytes[0..control_bytes_len]),
+                bytes_read
+            );
+            assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
+            assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u64));
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use std::arch::x86_64::{
+    __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_setzero_si128,
+    _mm_shuffle_epi8, _mm_storeu_si128,
+};
+
+#[cfg(feature = "x86_ssse3")]
+use super::Decoder64;
+use super::{code_len, Encoder64};
+#[cfg(feature = "x86_sse41")]
+use crate::encode::sse41::Sse41;
+use crate::tables;
+#[cfg(feature = "x86_ssse3")]
+use crate::x86::Ssse3;
+
+/// The length code for a number whose highest nonzero byte is at index `i - 1`,
+/// or which is zero for `i == 0`.
+const CODE_FOR_BYTES_USED: [u8; 9] = [0, 0, 1, 2, 2, 3, 3, 3, 3];
+
+#[cfg(feature = "x86_ssse3")]
+impl Encoder64 for Ssse3 {
+    fn encode_quads(input: &[u64], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("ssse3") {
+            return (0, 0);
+        }
+
+        unsafe { encode_quads_ssse3(input, control_bytes, output) }
+    }
+}
+
+/// There's nothing in SSE4.1 that helps with 64-bit numbers, so `Sse41` uses
+/// the same code as `Ssse3`, and is only an `Encoder64` so that
+/// `StreamVbyteEncoder` always is.
+#[cfg(feature = "x86_sse41")]
+impl Encoder64 for Sse41 {
+    fn encode_quads(input: &[u64], control_bytes: &mut [u8], output: &mut [u8]) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("sse4.1") {
+            return (0, 0);
+        }
+
+        // every CPU with SSE4.1 has SSSE3
+        unsafe { encode_quads_ssse3(input, control_bytes, output) }
+    }
+}
+
+/// The body of `Ssse3::encode_quads()`, compiled with SSSE3 enabled so that
+/// the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports SSSE3.
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_quads_ssse3(
+    input: &[u64],
+    control_bytes: &mut [u8],
+    output: &mut [u8],
+) -> (usize, usize) {
+    let mut bytes_encoded: usize = 0;
+
+    // A register only holds a pair of numbers, so each quad is encoded a pair at
+    // a time, writing 16 bytes for a pair that can be encoded in as few as 2.
+    // Every quad is at least 4 bytes, so when there are 4 more quads after this
+    // one, they will overwrite any extra bytes. The last 4 quads are left for a
+    // second loop that writes their pairs here first, and only copies their
+    // encoded bytes to `output`.
+    let direct_len = control_bytes.len().saturating_sub(4);
+    let (direct_control_bytes, tail_control_bytes) = control_bytes.split_at_mut(direct_len);
+    let (direct_input, tail_input) = input.split_at(direct_len * 4);
+
+    for (control_byte, quad) in direct_control_bytes
+        .iter_mut()
+        .zip(direct_input.chunks_exact(4))
+    {
+        let mut code = 0;
+
+        for (p, pair) in quad.chunks_exact(2).enumerate() {
+            let (encoded, pair_code, length) = encode_pair_ssse3(pair);
+
+            unsafe {
+                _mm_storeu_si128(
+                    output[bytes_encoded..(bytes_encoded + 16)].as_mut_ptr() as *mut __m128i,
+                    encoded,
+                );
+            }
+
+            code |= pair_code << (p * 4);
+
+            bytes_encoded += length;
+        }
+
+        *control_byte = code;
+    }
+
+    let mut tail = [0_u8; 16];
+
+    for (control_byte, quad) in tail_control_bytes
+        .iter_mut()
+        .zip(tail_input.chunks_exact(4))
+    {
+        let mut code = 0;
+
+        for (p, pair) in quad.chunks_exact(2).enumerate() {
+            let (encoded, pair_code, length) = encode_pair_ssse3(pair);
+
+            unsafe { _mm_storeu_si128(tail.as_mut_ptr() as *mut __m128i, encoded) };
+            output[bytes_encoded..(bytes_encoded + length)].copy_from_slice(&tail[0..length]);
+
+            code |= pair_code << (p * 4);
+
+            bytes_encoded += length;
+        }
+
+        *control_byte = code;
+    }
+
+    (control_bytes.len() * 4, bytes_encoded)
+}
+
+/// Shuffle a pair of numbers into their encoded bytes, returning them along
+/// with the pair's 4 bits of control byte and encoded length. Only the first
+/// `length` bytes are part of the encoding.
+#[inline]
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_pair_ssse3(pair: &[u64]) -> (__m128i, u8, usize) {
+    let zeros = _mm_setzero_si128();
+    let nums = unsafe { _mm_loadu_si128(pair[0..2].as_ptr() as *const __m128i) };
+
+    // a bit for each nonzero byte, 8 bits per number
+    let nonzero_bytes = !_mm_movemask_epi8(_mm_cmpeq_epi8(nums, zeros)) as u32;
+    let low_code = CODE_FOR_BYTES_USED[bytes_used(nonzero_bytes & 0xFF)];
+    let high_code = CODE_FOR_BYTES_USED[bytes_used((nonzero_bytes >> 8) & 0xFF)];
+
+    let pair_code = low_code | (high_code << 2);
+    let length = code_len(low_code) + code_len(high_code);
+
+    let mask_bytes = tables::SVB64_ENCODE_PAIR_SHUFFLE_TABLE[pair_code as usize];
+    let encode_mask = unsafe { _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i) };
+
+    (_mm_shuffle_epi8(nums, encode_mask), pair_code, length)
+}
+
+/// The number of bytes up to and including the highest set bit in a number's
+/// 8-bit mask of nonzero bytes.
+#[inline]
+fn bytes_used(nonzero_bytes: u32) -> usize {
+    (32 - nonzero_bytes.leading_zeros()) as usize
+}
+
+#[cfg(feature = "x86_ssse3")]
+impl Decoder64 for Ssse3 {
+    fn decode_quads(
+        control_bytes: &[u8],
+        encoded_nums: &[u8],
+        output: &mut [u64],
+    ) -> (usize, usize) {
+        // leave everything to the Scalar fallback if the CPU can't run this
+        if !is_x86_feature_detected!("ssse3") {
+            return (0, 0);
+        }
+
+        unsafe { decode_quads_ssse3(control_bytes, encoded_nums, output) }
+    }
+}
+
+/// The body of `Ssse3::decode_quads()`, compiled with SSSE3 enabled so that
+/// the intrinsics are inlined.
+///
+/// Callers must ensure that the CPU supports SSSE3.
+#[cfg(feature = "x86_ssse3")]
+#[target_feature(enable = "ssse3")]
+unsafe fn decode_quads_ssse3(
+    control_bytes: &[u8],
+    encoded_nums: &[u8],
+    output: &mut [u64],
+) -> (usize, usize) {
+    let mut bytes_read: usize = 0;
+
+    // Decoding reads 16 bytes at a time from input, so stop 4 control bytes short
+    // of the end. Every pair before that has at least 4 quads of at least 4 bytes
+    // each after it, so its 16 bytes are always available.
+    let direct_len = control_bytes.len().saturating_sub(4);
+    let (direct_output, tail_output) = output.split_at_mut(direct_len * 4);
+
+    for (&control_byte, quad) in control_bytes[0..direct_len]
+        .iter()
+        .zip(direct_output.chunks_exact_mut(4))
+    {
+        for (p, pair) in quad.chunks_exact_mut(2).enumerate() {
+            let pair_code = (control_byte >> (p * 4)) & 0x0F;
+            let length = code_len(pair_code & 0x03) + code_len(pair_code >> 2);
+            let mask_bytes = tables::SVB64_DECODE_PAIR_SHUFFLE_TABLE[pair_code as usize];
+            let next_16 = &encoded_nums[bytes_read..(bytes_read + 16)];
+
+            unsafe {
+                let mask = _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i);
+                let data = _mm_loadu_si128(next_16.as_ptr() as *const __m128i);
+
+                _mm_storeu_si128(
+                    pair.as_mut_ptr() as *mut __m128i,
+                    _mm_shuffle_epi8(data, mask),
+                );
+            }
+
+            bytes_read += length;
+        }
+    }
+
+    // The last pairs may be encoded in fewer than 16 bytes with nothing after
+    // them, so they're copied here first so that there are always 16 bytes to
+    // read. The shuffle ignores anything after the first `length` bytes.
+    let mut tail = [0_u8; 16];
+
+    for (&control_byte, quad) in control_bytes[direct_len..]
+        .iter()
+        .zip(tail_output.chunks_exact_mut(4))
+    {
+        for (p, pair) in quad.chunks_exact_mut(2).enumerate() {
+            let pair_code = (control_byte >> (p * 4)) & 0x0F;
+            let length = code_len(pair_code & 0x03) + code_len(pair_code >> 2);
+            let mask_bytes = tables::SVB64_DECODE_PAIR_SHUFFLE_TABLE[pair_code as usize];
+            tail[0..length].copy_from_slice(&encoded_nums[bytes_read..(bytes_read + length)]);
+
+            unsafe {
+                let mask = _mm_loadu_si128(mask_bytes.as_ptr() as *const __m128i);
+                let data = _mm_loadu_si128(tail.as_ptr() as *const __m128i);
+
+                _mm_storeu_si128(
+                    pair.as_mut_ptr() as *mut __m128i,
+                    _mm_shuffle_epi8(data, mask),
+                );
+            }
+
+            bytes_read += length;
+        }
+    }
+
+    (control_bytes.len() * 4, bytes_read)
+}
+
+#[cfg(all(test, feature = "x86_ssse3"))]
+mod tests {
+    use super::*;
+    use crate::{scalar::Scalar, svb64::cumulative_encoded_len};
+
+    /// Numbers of every length in different patterns
+    fn mixed_lengths() -> Vec<u64> {
+        (0..64_u64)
+            .map(|i| match i % 5 {
+                0 => i,
+                1 => 1000 + i,
+                2 => 100_000 + i,
+                3 => u64::MAX - i,
+                _ => 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bytes_used_for_masks() {
+        assert_eq!(0, bytes_used(0));
+        assert_eq!(1, bytes_used(0b1));
+        assert_eq!(3, bytes_used(0b101));
+        assert_eq!(8, bytes_used(0xFF));
+    }
+
+    #[test]
+    fn encodes_all_control_bytes_without_writing_past_encoded_nums() {
+        let nums = mixed_lengths();
+        let mut encoded = Vec::new();
+
+        for control_bytes_len in 0..(nums.len() / 4 + 1) {
+            encoded.clear();
+            encoded.resize(nums.len() * 9, 0xFF);
+
+            let (nums_encoded, bytes_written) = {
+                let (control_bytes, num_bytes) = encoded.split_at_mut(control_bytes_len);
+
+                Ssse3::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes)
+            };
+
+            assert_eq!(control_bytes_len * 4, nums_encoded);
+            assert_eq!(
+                cumulative_encoded_len(&encoded[0..control_bytes_len]),
+                bytes_written
+            );
+
+            let mut expected = vec![0xFF; nums.len() * 9];
+            {
+                let (control_bytes, num_bytes) = expected.split_at_mut(control_bytes_len);
+                Scalar::encode_quads(&nums[0..4 * control_bytes_len], control_bytes, num_bytes);
+            }
+            // everything after the encoded nums is untouched
+            assert_eq!(expected, encoded);
+        }
+    }
+
+    #[test]
+    fn reads_all_control_bytes_without_extra_input_bytes() {
+        let nums = mixed_lengths();
+        let encoded = crate::svb64::encode_to_vec::<Scalar>(&nums);
+        let mut decoded: Vec<u64> = Vec::new();
+
+        // 16 control bytes
+        let control_bytes = &encoded[0..16];
+        // no trailing bytes to read past the end into, so the last pairs have to go
+        // through the tail buffer
+        let encoded_nums = &encoded[16..];
+
+        for control_bytes_len in 0..17 {
+            decoded.clear();
+            decoded.resize(nums.len(), 54321);
+
+            let (nums_decoded, bytes_read) = Ssse3::decode_quads(
+                &control_bytes[0..control_bytes_len],
+                encoded_nums,
+                &mut decoded[0..(4 * control_bytes_len)],
+            );
+            assert_eq!(control_bytes_len * 4, nums_decoded);
+            assert_eq!(
+                cumulative_encoded_len(&control_bytes[0..control_bytes_len]),
+                bytes_read
+            );
+            assert_eq!(&nums[0..nums_decoded], &decoded[0..nums_decoded]);
+            assert!(&decoded[nums_decoded..].iter().all(|&i| i == 54321_u64));
+        }
+    }
+}
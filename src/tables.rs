@@ -519,7 +519,12 @@ pub const DECODE_LENGTH_PER_QUAD_TABLE: &[u8; 256] = &[
 ];
 
 #[rustfmt::skip]
-#[cfg(any(feature = "x86_ssse3", feature = "aarch64_neon"))]
+#[cfg(any(
+    feature = "x86_ssse3",
+    feature = "x86_avx2",
+    feature = "x86_avx512",
+    feature = "aarch64_neon"
+))]
 pub const DECODE_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[
     // 0 = 0x0 = 0b00000000, lengths 1 1 1 1
     [   0, 128, 128, 128,   1, 128, 128, 128,   2, 128, 128, 128,   3, 128, 128, 128],
@@ -1036,7 +1041,10 @@ pub const DECODE_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[
 ];
 
 #[rustfmt::skip]
-#[cfg(all(feature = "x86_sse41", target_arch = "x86_64"))]
+#[cfg(all(
+    any(feature = "x86_ssse3", feature = "x86_sse41", feature = "x86_avx512"),
+    target_arch = "x86_64"
+))]
 pub const X86_ENCODE_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[
     // 0 = 0x0 = 0b00000000, lengths 1 1 1 1
     [   0,   4,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
@@ -1812,3 +1820,2665 @@ pub const NEON_ENCODE_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[
     [  0,  1,  2,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15, 128 ],    // 3444
     [  0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15 ],    // 4444
 ];
+
+#[rustfmt::skip]
+#[cfg(any(feature = "x86_ssse3", feature = "aarch64_neon"))]
+pub const SVB16_DECODE_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[
+    // 0 = 0x0 = 0b00000000, lengths 1 1 1 1 1 1 1 1
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4, 128,   5, 128,   6, 128,   7, 128],
+    // 1 = 0x1 = 0b00000001, lengths 2 1 1 1 1 1 1 1
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5, 128,   6, 128,   7, 128,   8, 128],
+    // 2 = 0x2 = 0b00000010, lengths 1 2 1 1 1 1 1 1
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5, 128,   6, 128,   7, 128,   8, 128],
+    // 3 = 0x3 = 0b00000011, lengths 2 2 1 1 1 1 1 1
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6, 128,   7, 128,   8, 128,   9, 128],
+    // 4 = 0x4 = 0b00000100, lengths 1 1 2 1 1 1 1 1
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5, 128,   6, 128,   7, 128,   8, 128],
+    // 5 = 0x5 = 0b00000101, lengths 2 1 2 1 1 1 1 1
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6, 128,   7, 128,   8, 128,   9, 128],
+    // 6 = 0x6 = 0b00000110, lengths 1 2 2 1 1 1 1 1
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6, 128,   7, 128,   8, 128,   9, 128],
+    // 7 = 0x7 = 0b00000111, lengths 2 2 2 1 1 1 1 1
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7, 128,   8, 128,   9, 128,  10, 128],
+    // 8 = 0x8 = 0b00001000, lengths 1 1 1 2 1 1 1 1
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5, 128,   6, 128,   7, 128,   8, 128],
+    // 9 = 0x9 = 0b00001001, lengths 2 1 1 2 1 1 1 1
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6, 128,   7, 128,   8, 128,   9, 128],
+    // 10 = 0xA = 0b00001010, lengths 1 2 1 2 1 1 1 1
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6, 128,   7, 128,   8, 128,   9, 128],
+    // 11 = 0xB = 0b00001011, lengths 2 2 1 2 1 1 1 1
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7, 128,   8, 128,   9, 128,  10, 128],
+    // 12 = 0xC = 0b00001100, lengths 1 1 2 2 1 1 1 1
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6, 128,   7, 128,   8, 128,   9, 128],
+    // 13 = 0xD = 0b00001101, lengths 2 1 2 2 1 1 1 1
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7, 128,   8, 128,   9, 128,  10, 128],
+    // 14 = 0xE = 0b00001110, lengths 1 2 2 2 1 1 1 1
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7, 128,   8, 128,   9, 128,  10, 128],
+    // 15 = 0xF = 0b00001111, lengths 2 2 2 2 1 1 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128,   9, 128,  10, 128,  11, 128],
+    // 16 = 0x10 = 0b00010000, lengths 1 1 1 1 2 1 1 1
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4,   5,   6, 128,   7, 128,   8, 128],
+    // 17 = 0x11 = 0b00010001, lengths 2 1 1 1 2 1 1 1
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5,   6,   7, 128,   8, 128,   9, 128],
+    // 18 = 0x12 = 0b00010010, lengths 1 2 1 1 2 1 1 1
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5,   6,   7, 128,   8, 128,   9, 128],
+    // 19 = 0x13 = 0b00010011, lengths 2 2 1 1 2 1 1 1
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6,   7,   8, 128,   9, 128,  10, 128],
+    // 20 = 0x14 = 0b00010100, lengths 1 1 2 1 2 1 1 1
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5,   6,   7, 128,   8, 128,   9, 128],
+    // 21 = 0x15 = 0b00010101, lengths 2 1 2 1 2 1 1 1
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6,   7,   8, 128,   9, 128,  10, 128],
+    // 22 = 0x16 = 0b00010110, lengths 1 2 2 1 2 1 1 1
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6,   7,   8, 128,   9, 128,  10, 128],
+    // 23 = 0x17 = 0b00010111, lengths 2 2 2 1 2 1 1 1
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7,   8,   9, 128,  10, 128,  11, 128],
+    // 24 = 0x18 = 0b00011000, lengths 1 1 1 2 2 1 1 1
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5,   6,   7, 128,   8, 128,   9, 128],
+    // 25 = 0x19 = 0b00011001, lengths 2 1 1 2 2 1 1 1
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6,   7,   8, 128,   9, 128,  10, 128],
+    // 26 = 0x1A = 0b00011010, lengths 1 2 1 2 2 1 1 1
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6,   7,   8, 128,   9, 128,  10, 128],
+    // 27 = 0x1B = 0b00011011, lengths 2 2 1 2 2 1 1 1
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7,   8,   9, 128,  10, 128,  11, 128],
+    // 28 = 0x1C = 0b00011100, lengths 1 1 2 2 2 1 1 1
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6,   7,   8, 128,   9, 128,  10, 128],
+    // 29 = 0x1D = 0b00011101, lengths 2 1 2 2 2 1 1 1
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7,   8,   9, 128,  10, 128,  11, 128],
+    // 30 = 0x1E = 0b00011110, lengths 1 2 2 2 2 1 1 1
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128,  10, 128,  11, 128],
+    // 31 = 0x1F = 0b00011111, lengths 2 2 2 2 2 1 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10, 128,  11, 128,  12, 128],
+    // 32 = 0x20 = 0b00100000, lengths 1 1 1 1 1 2 1 1
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4, 128,   5,   6,   7, 128,   8, 128],
+    // 33 = 0x21 = 0b00100001, lengths 2 1 1 1 1 2 1 1
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5, 128,   6,   7,   8, 128,   9, 128],
+    // 34 = 0x22 = 0b00100010, lengths 1 2 1 1 1 2 1 1
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5, 128,   6,   7,   8, 128,   9, 128],
+    // 35 = 0x23 = 0b00100011, lengths 2 2 1 1 1 2 1 1
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6, 128,   7,   8,   9, 128,  10, 128],
+    // 36 = 0x24 = 0b00100100, lengths 1 1 2 1 1 2 1 1
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5, 128,   6,   7,   8, 128,   9, 128],
+    // 37 = 0x25 = 0b00100101, lengths 2 1 2 1 1 2 1 1
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6, 128,   7,   8,   9, 128,  10, 128],
+    // 38 = 0x26 = 0b00100110, lengths 1 2 2 1 1 2 1 1
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6, 128,   7,   8,   9, 128,  10, 128],
+    // 39 = 0x27 = 0b00100111, lengths 2 2 2 1 1 2 1 1
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7, 128,   8,   9,  10, 128,  11, 128],
+    // 40 = 0x28 = 0b00101000, lengths 1 1 1 2 1 2 1 1
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5, 128,   6,   7,   8, 128,   9, 128],
+    // 41 = 0x29 = 0b00101001, lengths 2 1 1 2 1 2 1 1
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6, 128,   7,   8,   9, 128,  10, 128],
+    // 42 = 0x2A = 0b00101010, lengths 1 2 1 2 1 2 1 1
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6, 128,   7,   8,   9, 128,  10, 128],
+    // 43 = 0x2B = 0b00101011, lengths 2 2 1 2 1 2 1 1
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7, 128,   8,   9,  10, 128,  11, 128],
+    // 44 = 0x2C = 0b00101100, lengths 1 1 2 2 1 2 1 1
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6, 128,   7,   8,   9, 128,  10, 128],
+    // 45 = 0x2D = 0b00101101, lengths 2 1 2 2 1 2 1 1
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7, 128,   8,   9,  10, 128,  11, 128],
+    // 46 = 0x2E = 0b00101110, lengths 1 2 2 2 1 2 1 1
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7, 128,   8,   9,  10, 128,  11, 128],
+    // 47 = 0x2F = 0b00101111, lengths 2 2 2 2 1 2 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128,   9,  10,  11, 128,  12, 128],
+    // 48 = 0x30 = 0b00110000, lengths 1 1 1 1 2 2 1 1
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4,   5,   6,   7,   8, 128,   9, 128],
+    // 49 = 0x31 = 0b00110001, lengths 2 1 1 1 2 2 1 1
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5,   6,   7,   8,   9, 128,  10, 128],
+    // 50 = 0x32 = 0b00110010, lengths 1 2 1 1 2 2 1 1
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5,   6,   7,   8,   9, 128,  10, 128],
+    // 51 = 0x33 = 0b00110011, lengths 2 2 1 1 2 2 1 1
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6,   7,   8,   9,  10, 128,  11, 128],
+    // 52 = 0x34 = 0b00110100, lengths 1 1 2 1 2 2 1 1
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5,   6,   7,   8,   9, 128,  10, 128],
+    // 53 = 0x35 = 0b00110101, lengths 2 1 2 1 2 2 1 1
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6,   7,   8,   9,  10, 128,  11, 128],
+    // 54 = 0x36 = 0b00110110, lengths 1 2 2 1 2 2 1 1
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6,   7,   8,   9,  10, 128,  11, 128],
+    // 55 = 0x37 = 0b00110111, lengths 2 2 2 1 2 2 1 1
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7,   8,   9,  10,  11, 128,  12, 128],
+    // 56 = 0x38 = 0b00111000, lengths 1 1 1 2 2 2 1 1
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5,   6,   7,   8,   9, 128,  10, 128],
+    // 57 = 0x39 = 0b00111001, lengths 2 1 1 2 2 2 1 1
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6,   7,   8,   9,  10, 128,  11, 128],
+    // 58 = 0x3A = 0b00111010, lengths 1 2 1 2 2 2 1 1
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6,   7,   8,   9,  10, 128,  11, 128],
+    // 59 = 0x3B = 0b00111011, lengths 2 2 1 2 2 2 1 1
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7,   8,   9,  10,  11, 128,  12, 128],
+    // 60 = 0x3C = 0b00111100, lengths 1 1 2 2 2 2 1 1
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6,   7,   8,   9,  10, 128,  11, 128],
+    // 61 = 0x3D = 0b00111101, lengths 2 1 2 2 2 2 1 1
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128,  12, 128],
+    // 62 = 0x3E = 0b00111110, lengths 1 2 2 2 2 2 1 1
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128,  12, 128],
+    // 63 = 0x3F = 0b00111111, lengths 2 2 2 2 2 2 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128,  13, 128],
+    // 64 = 0x40 = 0b01000000, lengths 1 1 1 1 1 1 2 1
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4, 128,   5, 128,   6,   7,   8, 128],
+    // 65 = 0x41 = 0b01000001, lengths 2 1 1 1 1 1 2 1
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5, 128,   6, 128,   7,   8,   9, 128],
+    // 66 = 0x42 = 0b01000010, lengths 1 2 1 1 1 1 2 1
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5, 128,   6, 128,   7,   8,   9, 128],
+    // 67 = 0x43 = 0b01000011, lengths 2 2 1 1 1 1 2 1
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6, 128,   7, 128,   8,   9,  10, 128],
+    // 68 = 0x44 = 0b01000100, lengths 1 1 2 1 1 1 2 1
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5, 128,   6, 128,   7,   8,   9, 128],
+    // 69 = 0x45 = 0b01000101, lengths 2 1 2 1 1 1 2 1
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6, 128,   7, 128,   8,   9,  10, 128],
+    // 70 = 0x46 = 0b01000110, lengths 1 2 2 1 1 1 2 1
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6, 128,   7, 128,   8,   9,  10, 128],
+    // 71 = 0x47 = 0b01000111, lengths 2 2 2 1 1 1 2 1
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7, 128,   8, 128,   9,  10,  11, 128],
+    // 72 = 0x48 = 0b01001000, lengths 1 1 1 2 1 1 2 1
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5, 128,   6, 128,   7,   8,   9, 128],
+    // 73 = 0x49 = 0b01001001, lengths 2 1 1 2 1 1 2 1
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6, 128,   7, 128,   8,   9,  10, 128],
+    // 74 = 0x4A = 0b01001010, lengths 1 2 1 2 1 1 2 1
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6, 128,   7, 128,   8,   9,  10, 128],
+    // 75 = 0x4B = 0b01001011, lengths 2 2 1 2 1 1 2 1
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7, 128,   8, 128,   9,  10,  11, 128],
+    // 76 = 0x4C = 0b01001100, lengths 1 1 2 2 1 1 2 1
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6, 128,   7, 128,   8,   9,  10, 128],
+    // 77 = 0x4D = 0b01001101, lengths 2 1 2 2 1 1 2 1
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7, 128,   8, 128,   9,  10,  11, 128],
+    // 78 = 0x4E = 0b01001110, lengths 1 2 2 2 1 1 2 1
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7, 128,   8, 128,   9,  10,  11, 128],
+    // 79 = 0x4F = 0b01001111, lengths 2 2 2 2 1 1 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128,   9, 128,  10,  11,  12, 128],
+    // 80 = 0x50 = 0b01010000, lengths 1 1 1 1 2 1 2 1
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4,   5,   6, 128,   7,   8,   9, 128],
+    // 81 = 0x51 = 0b01010001, lengths 2 1 1 1 2 1 2 1
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5,   6,   7, 128,   8,   9,  10, 128],
+    // 82 = 0x52 = 0b01010010, lengths 1 2 1 1 2 1 2 1
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5,   6,   7, 128,   8,   9,  10, 128],
+    // 83 = 0x53 = 0b01010011, lengths 2 2 1 1 2 1 2 1
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6,   7,   8, 128,   9,  10,  11, 128],
+    // 84 = 0x54 = 0b01010100, lengths 1 1 2 1 2 1 2 1
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5,   6,   7, 128,   8,   9,  10, 128],
+    // 85 = 0x55 = 0b01010101, lengths 2 1 2 1 2 1 2 1
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6,   7,   8, 128,   9,  10,  11, 128],
+    // 86 = 0x56 = 0b01010110, lengths 1 2 2 1 2 1 2 1
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6,   7,   8, 128,   9,  10,  11, 128],
+    // 87 = 0x57 = 0b01010111, lengths 2 2 2 1 2 1 2 1
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7,   8,   9, 128,  10,  11,  12, 128],
+    // 88 = 0x58 = 0b01011000, lengths 1 1 1 2 2 1 2 1
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5,   6,   7, 128,   8,   9,  10, 128],
+    // 89 = 0x59 = 0b01011001, lengths 2 1 1 2 2 1 2 1
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6,   7,   8, 128,   9,  10,  11, 128],
+    // 90 = 0x5A = 0b01011010, lengths 1 2 1 2 2 1 2 1
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6,   7,   8, 128,   9,  10,  11, 128],
+    // 91 = 0x5B = 0b01011011, lengths 2 2 1 2 2 1 2 1
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7,   8,   9, 128,  10,  11,  12, 128],
+    // 92 = 0x5C = 0b01011100, lengths 1 1 2 2 2 1 2 1
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6,   7,   8, 128,   9,  10,  11, 128],
+    // 93 = 0x5D = 0b01011101, lengths 2 1 2 2 2 1 2 1
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7,   8,   9, 128,  10,  11,  12, 128],
+    // 94 = 0x5E = 0b01011110, lengths 1 2 2 2 2 1 2 1
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128,  10,  11,  12, 128],
+    // 95 = 0x5F = 0b01011111, lengths 2 2 2 2 2 1 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10, 128,  11,  12,  13, 128],
+    // 96 = 0x60 = 0b01100000, lengths 1 1 1 1 1 2 2 1
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4, 128,   5,   6,   7,   8,   9, 128],
+    // 97 = 0x61 = 0b01100001, lengths 2 1 1 1 1 2 2 1
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5, 128,   6,   7,   8,   9,  10, 128],
+    // 98 = 0x62 = 0b01100010, lengths 1 2 1 1 1 2 2 1
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5, 128,   6,   7,   8,   9,  10, 128],
+    // 99 = 0x63 = 0b01100011, lengths 2 2 1 1 1 2 2 1
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6, 128,   7,   8,   9,  10,  11, 128],
+    // 100 = 0x64 = 0b01100100, lengths 1 1 2 1 1 2 2 1
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5, 128,   6,   7,   8,   9,  10, 128],
+    // 101 = 0x65 = 0b01100101, lengths 2 1 2 1 1 2 2 1
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6, 128,   7,   8,   9,  10,  11, 128],
+    // 102 = 0x66 = 0b01100110, lengths 1 2 2 1 1 2 2 1
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6, 128,   7,   8,   9,  10,  11, 128],
+    // 103 = 0x67 = 0b01100111, lengths 2 2 2 1 1 2 2 1
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7, 128,   8,   9,  10,  11,  12, 128],
+    // 104 = 0x68 = 0b01101000, lengths 1 1 1 2 1 2 2 1
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5, 128,   6,   7,   8,   9,  10, 128],
+    // 105 = 0x69 = 0b01101001, lengths 2 1 1 2 1 2 2 1
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6, 128,   7,   8,   9,  10,  11, 128],
+    // 106 = 0x6A = 0b01101010, lengths 1 2 1 2 1 2 2 1
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6, 128,   7,   8,   9,  10,  11, 128],
+    // 107 = 0x6B = 0b01101011, lengths 2 2 1 2 1 2 2 1
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7, 128,   8,   9,  10,  11,  12, 128],
+    // 108 = 0x6C = 0b01101100, lengths 1 1 2 2 1 2 2 1
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6, 128,   7,   8,   9,  10,  11, 128],
+    // 109 = 0x6D = 0b01101101, lengths 2 1 2 2 1 2 2 1
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7, 128,   8,   9,  10,  11,  12, 128],
+    // 110 = 0x6E = 0b01101110, lengths 1 2 2 2 1 2 2 1
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7, 128,   8,   9,  10,  11,  12, 128],
+    // 111 = 0x6F = 0b01101111, lengths 2 2 2 2 1 2 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128,   9,  10,  11,  12,  13, 128],
+    // 112 = 0x70 = 0b01110000, lengths 1 1 1 1 2 2 2 1
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4,   5,   6,   7,   8,   9,  10, 128],
+    // 113 = 0x71 = 0b01110001, lengths 2 1 1 1 2 2 2 1
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5,   6,   7,   8,   9,  10,  11, 128],
+    // 114 = 0x72 = 0b01110010, lengths 1 2 1 1 2 2 2 1
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5,   6,   7,   8,   9,  10,  11, 128],
+    // 115 = 0x73 = 0b01110011, lengths 2 2 1 1 2 2 2 1
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6,   7,   8,   9,  10,  11,  12, 128],
+    // 116 = 0x74 = 0b01110100, lengths 1 1 2 1 2 2 2 1
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5,   6,   7,   8,   9,  10,  11, 128],
+    // 117 = 0x75 = 0b01110101, lengths 2 1 2 1 2 2 2 1
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6,   7,   8,   9,  10,  11,  12, 128],
+    // 118 = 0x76 = 0b01110110, lengths 1 2 2 1 2 2 2 1
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6,   7,   8,   9,  10,  11,  12, 128],
+    // 119 = 0x77 = 0b01110111, lengths 2 2 2 1 2 2 2 1
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7,   8,   9,  10,  11,  12,  13, 128],
+    // 120 = 0x78 = 0b01111000, lengths 1 1 1 2 2 2 2 1
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128],
+    // 121 = 0x79 = 0b01111001, lengths 2 1 1 2 2 2 2 1
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128],
+    // 122 = 0x7A = 0b01111010, lengths 1 2 1 2 2 2 2 1
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128],
+    // 123 = 0x7B = 0b01111011, lengths 2 2 1 2 2 2 2 1
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7,   8,   9,  10,  11,  12,  13, 128],
+    // 124 = 0x7C = 0b01111100, lengths 1 1 2 2 2 2 2 1
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128],
+    // 125 = 0x7D = 0b01111101, lengths 2 1 2 2 2 2 2 1
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13, 128],
+    // 126 = 0x7E = 0b01111110, lengths 1 2 2 2 2 2 2 1
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13, 128],
+    // 127 = 0x7F = 0b01111111, lengths 2 2 2 2 2 2 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128],
+    // 128 = 0x80 = 0b10000000, lengths 1 1 1 1 1 1 1 2
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4, 128,   5, 128,   6, 128,   7,   8],
+    // 129 = 0x81 = 0b10000001, lengths 2 1 1 1 1 1 1 2
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5, 128,   6, 128,   7, 128,   8,   9],
+    // 130 = 0x82 = 0b10000010, lengths 1 2 1 1 1 1 1 2
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5, 128,   6, 128,   7, 128,   8,   9],
+    // 131 = 0x83 = 0b10000011, lengths 2 2 1 1 1 1 1 2
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6, 128,   7, 128,   8, 128,   9,  10],
+    // 132 = 0x84 = 0b10000100, lengths 1 1 2 1 1 1 1 2
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5, 128,   6, 128,   7, 128,   8,   9],
+    // 133 = 0x85 = 0b10000101, lengths 2 1 2 1 1 1 1 2
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6, 128,   7, 128,   8, 128,   9,  10],
+    // 134 = 0x86 = 0b10000110, lengths 1 2 2 1 1 1 1 2
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6, 128,   7, 128,   8, 128,   9,  10],
+    // 135 = 0x87 = 0b10000111, lengths 2 2 2 1 1 1 1 2
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7, 128,   8, 128,   9, 128,  10,  11],
+    // 136 = 0x88 = 0b10001000, lengths 1 1 1 2 1 1 1 2
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5, 128,   6, 128,   7, 128,   8,   9],
+    // 137 = 0x89 = 0b10001001, lengths 2 1 1 2 1 1 1 2
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6, 128,   7, 128,   8, 128,   9,  10],
+    // 138 = 0x8A = 0b10001010, lengths 1 2 1 2 1 1 1 2
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6, 128,   7, 128,   8, 128,   9,  10],
+    // 139 = 0x8B = 0b10001011, lengths 2 2 1 2 1 1 1 2
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7, 128,   8, 128,   9, 128,  10,  11],
+    // 140 = 0x8C = 0b10001100, lengths 1 1 2 2 1 1 1 2
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6, 128,   7, 128,   8, 128,   9,  10],
+    // 141 = 0x8D = 0b10001101, lengths 2 1 2 2 1 1 1 2
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7, 128,   8, 128,   9, 128,  10,  11],
+    // 142 = 0x8E = 0b10001110, lengths 1 2 2 2 1 1 1 2
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7, 128,   8, 128,   9, 128,  10,  11],
+    // 143 = 0x8F = 0b10001111, lengths 2 2 2 2 1 1 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128,   9, 128,  10, 128,  11,  12],
+    // 144 = 0x90 = 0b10010000, lengths 1 1 1 1 2 1 1 2
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4,   5,   6, 128,   7, 128,   8,   9],
+    // 145 = 0x91 = 0b10010001, lengths 2 1 1 1 2 1 1 2
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5,   6,   7, 128,   8, 128,   9,  10],
+    // 146 = 0x92 = 0b10010010, lengths 1 2 1 1 2 1 1 2
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5,   6,   7, 128,   8, 128,   9,  10],
+    // 147 = 0x93 = 0b10010011, lengths 2 2 1 1 2 1 1 2
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6,   7,   8, 128,   9, 128,  10,  11],
+    // 148 = 0x94 = 0b10010100, lengths 1 1 2 1 2 1 1 2
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5,   6,   7, 128,   8, 128,   9,  10],
+    // 149 = 0x95 = 0b10010101, lengths 2 1 2 1 2 1 1 2
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6,   7,   8, 128,   9, 128,  10,  11],
+    // 150 = 0x96 = 0b10010110, lengths 1 2 2 1 2 1 1 2
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6,   7,   8, 128,   9, 128,  10,  11],
+    // 151 = 0x97 = 0b10010111, lengths 2 2 2 1 2 1 1 2
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7,   8,   9, 128,  10, 128,  11,  12],
+    // 152 = 0x98 = 0b10011000, lengths 1 1 1 2 2 1 1 2
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5,   6,   7, 128,   8, 128,   9,  10],
+    // 153 = 0x99 = 0b10011001, lengths 2 1 1 2 2 1 1 2
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6,   7,   8, 128,   9, 128,  10,  11],
+    // 154 = 0x9A = 0b10011010, lengths 1 2 1 2 2 1 1 2
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6,   7,   8, 128,   9, 128,  10,  11],
+    // 155 = 0x9B = 0b10011011, lengths 2 2 1 2 2 1 1 2
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7,   8,   9, 128,  10, 128,  11,  12],
+    // 156 = 0x9C = 0b10011100, lengths 1 1 2 2 2 1 1 2
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6,   7,   8, 128,   9, 128,  10,  11],
+    // 157 = 0x9D = 0b10011101, lengths 2 1 2 2 2 1 1 2
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7,   8,   9, 128,  10, 128,  11,  12],
+    // 158 = 0x9E = 0b10011110, lengths 1 2 2 2 2 1 1 2
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128,  10, 128,  11,  12],
+    // 159 = 0x9F = 0b10011111, lengths 2 2 2 2 2 1 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10, 128,  11, 128,  12,  13],
+    // 160 = 0xA0 = 0b10100000, lengths 1 1 1 1 1 2 1 2
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4, 128,   5,   6,   7, 128,   8,   9],
+    // 161 = 0xA1 = 0b10100001, lengths 2 1 1 1 1 2 1 2
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5, 128,   6,   7,   8, 128,   9,  10],
+    // 162 = 0xA2 = 0b10100010, lengths 1 2 1 1 1 2 1 2
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5, 128,   6,   7,   8, 128,   9,  10],
+    // 163 = 0xA3 = 0b10100011, lengths 2 2 1 1 1 2 1 2
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6, 128,   7,   8,   9, 128,  10,  11],
+    // 164 = 0xA4 = 0b10100100, lengths 1 1 2 1 1 2 1 2
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5, 128,   6,   7,   8, 128,   9,  10],
+    // 165 = 0xA5 = 0b10100101, lengths 2 1 2 1 1 2 1 2
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6, 128,   7,   8,   9, 128,  10,  11],
+    // 166 = 0xA6 = 0b10100110, lengths 1 2 2 1 1 2 1 2
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6, 128,   7,   8,   9, 128,  10,  11],
+    // 167 = 0xA7 = 0b10100111, lengths 2 2 2 1 1 2 1 2
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7, 128,   8,   9,  10, 128,  11,  12],
+    // 168 = 0xA8 = 0b10101000, lengths 1 1 1 2 1 2 1 2
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5, 128,   6,   7,   8, 128,   9,  10],
+    // 169 = 0xA9 = 0b10101001, lengths 2 1 1 2 1 2 1 2
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6, 128,   7,   8,   9, 128,  10,  11],
+    // 170 = 0xAA = 0b10101010, lengths 1 2 1 2 1 2 1 2
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6, 128,   7,   8,   9, 128,  10,  11],
+    // 171 = 0xAB = 0b10101011, lengths 2 2 1 2 1 2 1 2
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7, 128,   8,   9,  10, 128,  11,  12],
+    // 172 = 0xAC = 0b10101100, lengths 1 1 2 2 1 2 1 2
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6, 128,   7,   8,   9, 128,  10,  11],
+    // 173 = 0xAD = 0b10101101, lengths 2 1 2 2 1 2 1 2
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7, 128,   8,   9,  10, 128,  11,  12],
+    // 174 = 0xAE = 0b10101110, lengths 1 2 2 2 1 2 1 2
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7, 128,   8,   9,  10, 128,  11,  12],
+    // 175 = 0xAF = 0b10101111, lengths 2 2 2 2 1 2 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128,   9,  10,  11, 128,  12,  13],
+    // 176 = 0xB0 = 0b10110000, lengths 1 1 1 1 2 2 1 2
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4,   5,   6,   7,   8, 128,   9,  10],
+    // 177 = 0xB1 = 0b10110001, lengths 2 1 1 1 2 2 1 2
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5,   6,   7,   8,   9, 128,  10,  11],
+    // 178 = 0xB2 = 0b10110010, lengths 1 2 1 1 2 2 1 2
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5,   6,   7,   8,   9, 128,  10,  11],
+    // 179 = 0xB3 = 0b10110011, lengths 2 2 1 1 2 2 1 2
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6,   7,   8,   9,  10, 128,  11,  12],
+    // 180 = 0xB4 = 0b10110100, lengths 1 1 2 1 2 2 1 2
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5,   6,   7,   8,   9, 128,  10,  11],
+    // 181 = 0xB5 = 0b10110101, lengths 2 1 2 1 2 2 1 2
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6,   7,   8,   9,  10, 128,  11,  12],
+    // 182 = 0xB6 = 0b10110110, lengths 1 2 2 1 2 2 1 2
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6,   7,   8,   9,  10, 128,  11,  12],
+    // 183 = 0xB7 = 0b10110111, lengths 2 2 2 1 2 2 1 2
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7,   8,   9,  10,  11, 128,  12,  13],
+    // 184 = 0xB8 = 0b10111000, lengths 1 1 1 2 2 2 1 2
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5,   6,   7,   8,   9, 128,  10,  11],
+    // 185 = 0xB9 = 0b10111001, lengths 2 1 1 2 2 2 1 2
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6,   7,   8,   9,  10, 128,  11,  12],
+    // 186 = 0xBA = 0b10111010, lengths 1 2 1 2 2 2 1 2
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6,   7,   8,   9,  10, 128,  11,  12],
+    // 187 = 0xBB = 0b10111011, lengths 2 2 1 2 2 2 1 2
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7,   8,   9,  10,  11, 128,  12,  13],
+    // 188 = 0xBC = 0b10111100, lengths 1 1 2 2 2 2 1 2
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6,   7,   8,   9,  10, 128,  11,  12],
+    // 189 = 0xBD = 0b10111101, lengths 2 1 2 2 2 2 1 2
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128,  12,  13],
+    // 190 = 0xBE = 0b10111110, lengths 1 2 2 2 2 2 1 2
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128,  12,  13],
+    // 191 = 0xBF = 0b10111111, lengths 2 2 2 2 2 2 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128,  13,  14],
+    // 192 = 0xC0 = 0b11000000, lengths 1 1 1 1 1 1 2 2
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4, 128,   5, 128,   6,   7,   8,   9],
+    // 193 = 0xC1 = 0b11000001, lengths 2 1 1 1 1 1 2 2
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5, 128,   6, 128,   7,   8,   9,  10],
+    // 194 = 0xC2 = 0b11000010, lengths 1 2 1 1 1 1 2 2
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5, 128,   6, 128,   7,   8,   9,  10],
+    // 195 = 0xC3 = 0b11000011, lengths 2 2 1 1 1 1 2 2
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6, 128,   7, 128,   8,   9,  10,  11],
+    // 196 = 0xC4 = 0b11000100, lengths 1 1 2 1 1 1 2 2
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5, 128,   6, 128,   7,   8,   9,  10],
+    // 197 = 0xC5 = 0b11000101, lengths 2 1 2 1 1 1 2 2
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6, 128,   7, 128,   8,   9,  10,  11],
+    // 198 = 0xC6 = 0b11000110, lengths 1 2 2 1 1 1 2 2
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6, 128,   7, 128,   8,   9,  10,  11],
+    // 199 = 0xC7 = 0b11000111, lengths 2 2 2 1 1 1 2 2
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7, 128,   8, 128,   9,  10,  11,  12],
+    // 200 = 0xC8 = 0b11001000, lengths 1 1 1 2 1 1 2 2
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5, 128,   6, 128,   7,   8,   9,  10],
+    // 201 = 0xC9 = 0b11001001, lengths 2 1 1 2 1 1 2 2
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6, 128,   7, 128,   8,   9,  10,  11],
+    // 202 = 0xCA = 0b11001010, lengths 1 2 1 2 1 1 2 2
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6, 128,   7, 128,   8,   9,  10,  11],
+    // 203 = 0xCB = 0b11001011, lengths 2 2 1 2 1 1 2 2
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7, 128,   8, 128,   9,  10,  11,  12],
+    // 204 = 0xCC = 0b11001100, lengths 1 1 2 2 1 1 2 2
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6, 128,   7, 128,   8,   9,  10,  11],
+    // 205 = 0xCD = 0b11001101, lengths 2 1 2 2 1 1 2 2
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7, 128,   8, 128,   9,  10,  11,  12],
+    // 206 = 0xCE = 0b11001110, lengths 1 2 2 2 1 1 2 2
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7, 128,   8, 128,   9,  10,  11,  12],
+    // 207 = 0xCF = 0b11001111, lengths 2 2 2 2 1 1 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128,   9, 128,  10,  11,  12,  13],
+    // 208 = 0xD0 = 0b11010000, lengths 1 1 1 1 2 1 2 2
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4,   5,   6, 128,   7,   8,   9,  10],
+    // 209 = 0xD1 = 0b11010001, lengths 2 1 1 1 2 1 2 2
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5,   6,   7, 128,   8,   9,  10,  11],
+    // 210 = 0xD2 = 0b11010010, lengths 1 2 1 1 2 1 2 2
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5,   6,   7, 128,   8,   9,  10,  11],
+    // 211 = 0xD3 = 0b11010011, lengths 2 2 1 1 2 1 2 2
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6,   7,   8, 128,   9,  10,  11,  12],
+    // 212 = 0xD4 = 0b11010100, lengths 1 1 2 1 2 1 2 2
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5,   6,   7, 128,   8,   9,  10,  11],
+    // 213 = 0xD5 = 0b11010101, lengths 2 1 2 1 2 1 2 2
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6,   7,   8, 128,   9,  10,  11,  12],
+    // 214 = 0xD6 = 0b11010110, lengths 1 2 2 1 2 1 2 2
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6,   7,   8, 128,   9,  10,  11,  12],
+    // 215 = 0xD7 = 0b11010111, lengths 2 2 2 1 2 1 2 2
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7,   8,   9, 128,  10,  11,  12,  13],
+    // 216 = 0xD8 = 0b11011000, lengths 1 1 1 2 2 1 2 2
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5,   6,   7, 128,   8,   9,  10,  11],
+    // 217 = 0xD9 = 0b11011001, lengths 2 1 1 2 2 1 2 2
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6,   7,   8, 128,   9,  10,  11,  12],
+    // 218 = 0xDA = 0b11011010, lengths 1 2 1 2 2 1 2 2
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6,   7,   8, 128,   9,  10,  11,  12],
+    // 219 = 0xDB = 0b11011011, lengths 2 2 1 2 2 1 2 2
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7,   8,   9, 128,  10,  11,  12,  13],
+    // 220 = 0xDC = 0b11011100, lengths 1 1 2 2 2 1 2 2
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6,   7,   8, 128,   9,  10,  11,  12],
+    // 221 = 0xDD = 0b11011101, lengths 2 1 2 2 2 1 2 2
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7,   8,   9, 128,  10,  11,  12,  13],
+    // 222 = 0xDE = 0b11011110, lengths 1 2 2 2 2 1 2 2
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128,  10,  11,  12,  13],
+    // 223 = 0xDF = 0b11011111, lengths 2 2 2 2 2 1 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10, 128,  11,  12,  13,  14],
+    // 224 = 0xE0 = 0b11100000, lengths 1 1 1 1 1 2 2 2
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4, 128,   5,   6,   7,   8,   9,  10],
+    // 225 = 0xE1 = 0b11100001, lengths 2 1 1 1 1 2 2 2
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5, 128,   6,   7,   8,   9,  10,  11],
+    // 226 = 0xE2 = 0b11100010, lengths 1 2 1 1 1 2 2 2
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5, 128,   6,   7,   8,   9,  10,  11],
+    // 227 = 0xE3 = 0b11100011, lengths 2 2 1 1 1 2 2 2
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6, 128,   7,   8,   9,  10,  11,  12],
+    // 228 = 0xE4 = 0b11100100, lengths 1 1 2 1 1 2 2 2
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5, 128,   6,   7,   8,   9,  10,  11],
+    // 229 = 0xE5 = 0b11100101, lengths 2 1 2 1 1 2 2 2
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6, 128,   7,   8,   9,  10,  11,  12],
+    // 230 = 0xE6 = 0b11100110, lengths 1 2 2 1 1 2 2 2
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6, 128,   7,   8,   9,  10,  11,  12],
+    // 231 = 0xE7 = 0b11100111, lengths 2 2 2 1 1 2 2 2
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7, 128,   8,   9,  10,  11,  12,  13],
+    // 232 = 0xE8 = 0b11101000, lengths 1 1 1 2 1 2 2 2
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5, 128,   6,   7,   8,   9,  10,  11],
+    // 233 = 0xE9 = 0b11101001, lengths 2 1 1 2 1 2 2 2
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6, 128,   7,   8,   9,  10,  11,  12],
+    // 234 = 0xEA = 0b11101010, lengths 1 2 1 2 1 2 2 2
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6, 128,   7,   8,   9,  10,  11,  12],
+    // 235 = 0xEB = 0b11101011, lengths 2 2 1 2 1 2 2 2
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7, 128,   8,   9,  10,  11,  12,  13],
+    // 236 = 0xEC = 0b11101100, lengths 1 1 2 2 1 2 2 2
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6, 128,   7,   8,   9,  10,  11,  12],
+    // 237 = 0xED = 0b11101101, lengths 2 1 2 2 1 2 2 2
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7, 128,   8,   9,  10,  11,  12,  13],
+    // 238 = 0xEE = 0b11101110, lengths 1 2 2 2 1 2 2 2
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7, 128,   8,   9,  10,  11,  12,  13],
+    // 239 = 0xEF = 0b11101111, lengths 2 2 2 2 1 2 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128,   9,  10,  11,  12,  13,  14],
+    // 240 = 0xF0 = 0b11110000, lengths 1 1 1 1 2 2 2 2
+    [   0, 128,   1, 128,   2, 128,   3, 128,   4,   5,   6,   7,   8,   9,  10,  11],
+    // 241 = 0xF1 = 0b11110001, lengths 2 1 1 1 2 2 2 2
+    [   0,   1,   2, 128,   3, 128,   4, 128,   5,   6,   7,   8,   9,  10,  11,  12],
+    // 242 = 0xF2 = 0b11110010, lengths 1 2 1 1 2 2 2 2
+    [   0, 128,   1,   2,   3, 128,   4, 128,   5,   6,   7,   8,   9,  10,  11,  12],
+    // 243 = 0xF3 = 0b11110011, lengths 2 2 1 1 2 2 2 2
+    [   0,   1,   2,   3,   4, 128,   5, 128,   6,   7,   8,   9,  10,  11,  12,  13],
+    // 244 = 0xF4 = 0b11110100, lengths 1 1 2 1 2 2 2 2
+    [   0, 128,   1, 128,   2,   3,   4, 128,   5,   6,   7,   8,   9,  10,  11,  12],
+    // 245 = 0xF5 = 0b11110101, lengths 2 1 2 1 2 2 2 2
+    [   0,   1,   2, 128,   3,   4,   5, 128,   6,   7,   8,   9,  10,  11,  12,  13],
+    // 246 = 0xF6 = 0b11110110, lengths 1 2 2 1 2 2 2 2
+    [   0, 128,   1,   2,   3,   4,   5, 128,   6,   7,   8,   9,  10,  11,  12,  13],
+    // 247 = 0xF7 = 0b11110111, lengths 2 2 2 1 2 2 2 2
+    [   0,   1,   2,   3,   4,   5,   6, 128,   7,   8,   9,  10,  11,  12,  13,  14],
+    // 248 = 0xF8 = 0b11111000, lengths 1 1 1 2 2 2 2 2
+    [   0, 128,   1, 128,   2, 128,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12],
+    // 249 = 0xF9 = 0b11111001, lengths 2 1 1 2 2 2 2 2
+    [   0,   1,   2, 128,   3, 128,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13],
+    // 250 = 0xFA = 0b11111010, lengths 1 2 1 2 2 2 2 2
+    [   0, 128,   1,   2,   3, 128,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13],
+    // 251 = 0xFB = 0b11111011, lengths 2 2 1 2 2 2 2 2
+    [   0,   1,   2,   3,   4, 128,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14],
+    // 252 = 0xFC = 0b11111100, lengths 1 1 2 2 2 2 2 2
+    [   0, 128,   1, 128,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13],
+    // 253 = 0xFD = 0b11111101, lengths 2 1 2 2 2 2 2 2
+    [   0,   1,   2, 128,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14],
+    // 254 = 0xFE = 0b11111110, lengths 1 2 2 2 2 2 2 2
+    [   0, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14],
+    // 255 = 0xFF = 0b11111111, lengths 2 2 2 2 2 2 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15],
+];
+
+#[rustfmt::skip]
+#[cfg(any(feature = "x86_ssse3", feature = "x86_sse41", feature = "aarch64_neon"))]
+pub const SVB16_ENCODE_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[
+    // 0 = 0x0 = 0b00000000, lengths 1 1 1 1 1 1 1 1
+    [   0,   2,   4,   6,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 1 = 0x1 = 0b00000001, lengths 2 1 1 1 1 1 1 1
+    [   0,   1,   2,   4,   6,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128, 128],
+    // 2 = 0x2 = 0b00000010, lengths 1 2 1 1 1 1 1 1
+    [   0,   2,   3,   4,   6,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128, 128],
+    // 3 = 0x3 = 0b00000011, lengths 2 2 1 1 1 1 1 1
+    [   0,   1,   2,   3,   4,   6,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 4 = 0x4 = 0b00000100, lengths 1 1 2 1 1 1 1 1
+    [   0,   2,   4,   5,   6,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128, 128],
+    // 5 = 0x5 = 0b00000101, lengths 2 1 2 1 1 1 1 1
+    [   0,   1,   2,   4,   5,   6,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 6 = 0x6 = 0b00000110, lengths 1 2 2 1 1 1 1 1
+    [   0,   2,   3,   4,   5,   6,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 7 = 0x7 = 0b00000111, lengths 2 2 2 1 1 1 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   8,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 8 = 0x8 = 0b00001000, lengths 1 1 1 2 1 1 1 1
+    [   0,   2,   4,   6,   7,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128, 128],
+    // 9 = 0x9 = 0b00001001, lengths 2 1 1 2 1 1 1 1
+    [   0,   1,   2,   4,   6,   7,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 10 = 0xA = 0b00001010, lengths 1 2 1 2 1 1 1 1
+    [   0,   2,   3,   4,   6,   7,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 11 = 0xB = 0b00001011, lengths 2 2 1 2 1 1 1 1
+    [   0,   1,   2,   3,   4,   6,   7,   8,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 12 = 0xC = 0b00001100, lengths 1 1 2 2 1 1 1 1
+    [   0,   2,   4,   5,   6,   7,   8,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 13 = 0xD = 0b00001101, lengths 2 1 2 2 1 1 1 1
+    [   0,   1,   2,   4,   5,   6,   7,   8,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 14 = 0xE = 0b00001110, lengths 1 2 2 2 1 1 1 1
+    [   0,   2,   3,   4,   5,   6,   7,   8,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 15 = 0xF = 0b00001111, lengths 2 2 2 2 1 1 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  10,  12,  14, 128, 128, 128, 128],
+    // 16 = 0x10 = 0b00010000, lengths 1 1 1 1 2 1 1 1
+    [   0,   2,   4,   6,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128, 128, 128],
+    // 17 = 0x11 = 0b00010001, lengths 2 1 1 1 2 1 1 1
+    [   0,   1,   2,   4,   6,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 18 = 0x12 = 0b00010010, lengths 1 2 1 1 2 1 1 1
+    [   0,   2,   3,   4,   6,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 19 = 0x13 = 0b00010011, lengths 2 2 1 1 2 1 1 1
+    [   0,   1,   2,   3,   4,   6,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 20 = 0x14 = 0b00010100, lengths 1 1 2 1 2 1 1 1
+    [   0,   2,   4,   5,   6,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 21 = 0x15 = 0b00010101, lengths 2 1 2 1 2 1 1 1
+    [   0,   1,   2,   4,   5,   6,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 22 = 0x16 = 0b00010110, lengths 1 2 2 1 2 1 1 1
+    [   0,   2,   3,   4,   5,   6,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 23 = 0x17 = 0b00010111, lengths 2 2 2 1 2 1 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   8,   9,  10,  12,  14, 128, 128, 128, 128],
+    // 24 = 0x18 = 0b00011000, lengths 1 1 1 2 2 1 1 1
+    [   0,   2,   4,   6,   7,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 25 = 0x19 = 0b00011001, lengths 2 1 1 2 2 1 1 1
+    [   0,   1,   2,   4,   6,   7,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 26 = 0x1A = 0b00011010, lengths 1 2 1 2 2 1 1 1
+    [   0,   2,   3,   4,   6,   7,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 27 = 0x1B = 0b00011011, lengths 2 2 1 2 2 1 1 1
+    [   0,   1,   2,   3,   4,   6,   7,   8,   9,  10,  12,  14, 128, 128, 128, 128],
+    // 28 = 0x1C = 0b00011100, lengths 1 1 2 2 2 1 1 1
+    [   0,   2,   4,   5,   6,   7,   8,   9,  10,  12,  14, 128, 128, 128, 128, 128],
+    // 29 = 0x1D = 0b00011101, lengths 2 1 2 2 2 1 1 1
+    [   0,   1,   2,   4,   5,   6,   7,   8,   9,  10,  12,  14, 128, 128, 128, 128],
+    // 30 = 0x1E = 0b00011110, lengths 1 2 2 2 2 1 1 1
+    [   0,   2,   3,   4,   5,   6,   7,   8,   9,  10,  12,  14, 128, 128, 128, 128],
+    // 31 = 0x1F = 0b00011111, lengths 2 2 2 2 2 1 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  12,  14, 128, 128, 128],
+    // 32 = 0x20 = 0b00100000, lengths 1 1 1 1 1 2 1 1
+    [   0,   2,   4,   6,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128, 128, 128],
+    // 33 = 0x21 = 0b00100001, lengths 2 1 1 1 1 2 1 1
+    [   0,   1,   2,   4,   6,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 34 = 0x22 = 0b00100010, lengths 1 2 1 1 1 2 1 1
+    [   0,   2,   3,   4,   6,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 35 = 0x23 = 0b00100011, lengths 2 2 1 1 1 2 1 1
+    [   0,   1,   2,   3,   4,   6,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 36 = 0x24 = 0b00100100, lengths 1 1 2 1 1 2 1 1
+    [   0,   2,   4,   5,   6,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 37 = 0x25 = 0b00100101, lengths 2 1 2 1 1 2 1 1
+    [   0,   1,   2,   4,   5,   6,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 38 = 0x26 = 0b00100110, lengths 1 2 2 1 1 2 1 1
+    [   0,   2,   3,   4,   5,   6,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 39 = 0x27 = 0b00100111, lengths 2 2 2 1 1 2 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   8,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 40 = 0x28 = 0b00101000, lengths 1 1 1 2 1 2 1 1
+    [   0,   2,   4,   6,   7,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 41 = 0x29 = 0b00101001, lengths 2 1 1 2 1 2 1 1
+    [   0,   1,   2,   4,   6,   7,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 42 = 0x2A = 0b00101010, lengths 1 2 1 2 1 2 1 1
+    [   0,   2,   3,   4,   6,   7,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 43 = 0x2B = 0b00101011, lengths 2 2 1 2 1 2 1 1
+    [   0,   1,   2,   3,   4,   6,   7,   8,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 44 = 0x2C = 0b00101100, lengths 1 1 2 2 1 2 1 1
+    [   0,   2,   4,   5,   6,   7,   8,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 45 = 0x2D = 0b00101101, lengths 2 1 2 2 1 2 1 1
+    [   0,   1,   2,   4,   5,   6,   7,   8,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 46 = 0x2E = 0b00101110, lengths 1 2 2 2 1 2 1 1
+    [   0,   2,   3,   4,   5,   6,   7,   8,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 47 = 0x2F = 0b00101111, lengths 2 2 2 2 1 2 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  10,  11,  12,  14, 128, 128, 128],
+    // 48 = 0x30 = 0b00110000, lengths 1 1 1 1 2 2 1 1
+    [   0,   2,   4,   6,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128, 128, 128],
+    // 49 = 0x31 = 0b00110001, lengths 2 1 1 1 2 2 1 1
+    [   0,   1,   2,   4,   6,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 50 = 0x32 = 0b00110010, lengths 1 2 1 1 2 2 1 1
+    [   0,   2,   3,   4,   6,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 51 = 0x33 = 0b00110011, lengths 2 2 1 1 2 2 1 1
+    [   0,   1,   2,   3,   4,   6,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 52 = 0x34 = 0b00110100, lengths 1 1 2 1 2 2 1 1
+    [   0,   2,   4,   5,   6,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 53 = 0x35 = 0b00110101, lengths 2 1 2 1 2 2 1 1
+    [   0,   1,   2,   4,   5,   6,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 54 = 0x36 = 0b00110110, lengths 1 2 2 1 2 2 1 1
+    [   0,   2,   3,   4,   5,   6,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 55 = 0x37 = 0b00110111, lengths 2 2 2 1 2 2 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   8,   9,  10,  11,  12,  14, 128, 128, 128],
+    // 56 = 0x38 = 0b00111000, lengths 1 1 1 2 2 2 1 1
+    [   0,   2,   4,   6,   7,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128, 128],
+    // 57 = 0x39 = 0b00111001, lengths 2 1 1 2 2 2 1 1
+    [   0,   1,   2,   4,   6,   7,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 58 = 0x3A = 0b00111010, lengths 1 2 1 2 2 2 1 1
+    [   0,   2,   3,   4,   6,   7,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 59 = 0x3B = 0b00111011, lengths 2 2 1 2 2 2 1 1
+    [   0,   1,   2,   3,   4,   6,   7,   8,   9,  10,  11,  12,  14, 128, 128, 128],
+    // 60 = 0x3C = 0b00111100, lengths 1 1 2 2 2 2 1 1
+    [   0,   2,   4,   5,   6,   7,   8,   9,  10,  11,  12,  14, 128, 128, 128, 128],
+    // 61 = 0x3D = 0b00111101, lengths 2 1 2 2 2 2 1 1
+    [   0,   1,   2,   4,   5,   6,   7,   8,   9,  10,  11,  12,  14, 128, 128, 128],
+    // 62 = 0x3E = 0b00111110, lengths 1 2 2 2 2 2 1 1
+    [   0,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  14, 128, 128, 128],
+    // 63 = 0x3F = 0b00111111, lengths 2 2 2 2 2 2 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  14, 128, 128],
+    // 64 = 0x40 = 0b01000000, lengths 1 1 1 1 1 1 2 1
+    [   0,   2,   4,   6,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128, 128, 128],
+    // 65 = 0x41 = 0b01000001, lengths 2 1 1 1 1 1 2 1
+    [   0,   1,   2,   4,   6,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128, 128],
+    // 66 = 0x42 = 0b01000010, lengths 1 2 1 1 1 1 2 1
+    [   0,   2,   3,   4,   6,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128, 128],
+    // 67 = 0x43 = 0b01000011, lengths 2 2 1 1 1 1 2 1
+    [   0,   1,   2,   3,   4,   6,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 68 = 0x44 = 0b01000100, lengths 1 1 2 1 1 1 2 1
+    [   0,   2,   4,   5,   6,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128, 128],
+    // 69 = 0x45 = 0b01000101, lengths 2 1 2 1 1 1 2 1
+    [   0,   1,   2,   4,   5,   6,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 70 = 0x46 = 0b01000110, lengths 1 2 2 1 1 1 2 1
+    [   0,   2,   3,   4,   5,   6,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 71 = 0x47 = 0b01000111, lengths 2 2 2 1 1 1 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   8,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 72 = 0x48 = 0b01001000, lengths 1 1 1 2 1 1 2 1
+    [   0,   2,   4,   6,   7,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128, 128],
+    // 73 = 0x49 = 0b01001001, lengths 2 1 1 2 1 1 2 1
+    [   0,   1,   2,   4,   6,   7,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 74 = 0x4A = 0b01001010, lengths 1 2 1 2 1 1 2 1
+    [   0,   2,   3,   4,   6,   7,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 75 = 0x4B = 0b01001011, lengths 2 2 1 2 1 1 2 1
+    [   0,   1,   2,   3,   4,   6,   7,   8,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 76 = 0x4C = 0b01001100, lengths 1 1 2 2 1 1 2 1
+    [   0,   2,   4,   5,   6,   7,   8,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 77 = 0x4D = 0b01001101, lengths 2 1 2 2 1 1 2 1
+    [   0,   1,   2,   4,   5,   6,   7,   8,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 78 = 0x4E = 0b01001110, lengths 1 2 2 2 1 1 2 1
+    [   0,   2,   3,   4,   5,   6,   7,   8,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 79 = 0x4F = 0b01001111, lengths 2 2 2 2 1 1 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  10,  12,  13,  14, 128, 128, 128],
+    // 80 = 0x50 = 0b01010000, lengths 1 1 1 1 2 1 2 1
+    [   0,   2,   4,   6,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128, 128, 128],
+    // 81 = 0x51 = 0b01010001, lengths 2 1 1 1 2 1 2 1
+    [   0,   1,   2,   4,   6,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 82 = 0x52 = 0b01010010, lengths 1 2 1 1 2 1 2 1
+    [   0,   2,   3,   4,   6,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 83 = 0x53 = 0b01010011, lengths 2 2 1 1 2 1 2 1
+    [   0,   1,   2,   3,   4,   6,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 84 = 0x54 = 0b01010100, lengths 1 1 2 1 2 1 2 1
+    [   0,   2,   4,   5,   6,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 85 = 0x55 = 0b01010101, lengths 2 1 2 1 2 1 2 1
+    [   0,   1,   2,   4,   5,   6,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 86 = 0x56 = 0b01010110, lengths 1 2 2 1 2 1 2 1
+    [   0,   2,   3,   4,   5,   6,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 87 = 0x57 = 0b01010111, lengths 2 2 2 1 2 1 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   8,   9,  10,  12,  13,  14, 128, 128, 128],
+    // 88 = 0x58 = 0b01011000, lengths 1 1 1 2 2 1 2 1
+    [   0,   2,   4,   6,   7,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 89 = 0x59 = 0b01011001, lengths 2 1 1 2 2 1 2 1
+    [   0,   1,   2,   4,   6,   7,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 90 = 0x5A = 0b01011010, lengths 1 2 1 2 2 1 2 1
+    [   0,   2,   3,   4,   6,   7,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 91 = 0x5B = 0b01011011, lengths 2 2 1 2 2 1 2 1
+    [   0,   1,   2,   3,   4,   6,   7,   8,   9,  10,  12,  13,  14, 128, 128, 128],
+    // 92 = 0x5C = 0b01011100, lengths 1 1 2 2 2 1 2 1
+    [   0,   2,   4,   5,   6,   7,   8,   9,  10,  12,  13,  14, 128, 128, 128, 128],
+    // 93 = 0x5D = 0b01011101, lengths 2 1 2 2 2 1 2 1
+    [   0,   1,   2,   4,   5,   6,   7,   8,   9,  10,  12,  13,  14, 128, 128, 128],
+    // 94 = 0x5E = 0b01011110, lengths 1 2 2 2 2 1 2 1
+    [   0,   2,   3,   4,   5,   6,   7,   8,   9,  10,  12,  13,  14, 128, 128, 128],
+    // 95 = 0x5F = 0b01011111, lengths 2 2 2 2 2 1 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  12,  13,  14, 128, 128],
+    // 96 = 0x60 = 0b01100000, lengths 1 1 1 1 1 2 2 1
+    [   0,   2,   4,   6,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128, 128, 128],
+    // 97 = 0x61 = 0b01100001, lengths 2 1 1 1 1 2 2 1
+    [   0,   1,   2,   4,   6,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 98 = 0x62 = 0b01100010, lengths 1 2 1 1 1 2 2 1
+    [   0,   2,   3,   4,   6,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 99 = 0x63 = 0b01100011, lengths 2 2 1 1 1 2 2 1
+    [   0,   1,   2,   3,   4,   6,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 100 = 0x64 = 0b01100100, lengths 1 1 2 1 1 2 2 1
+    [   0,   2,   4,   5,   6,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 101 = 0x65 = 0b01100101, lengths 2 1 2 1 1 2 2 1
+    [   0,   1,   2,   4,   5,   6,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 102 = 0x66 = 0b01100110, lengths 1 2 2 1 1 2 2 1
+    [   0,   2,   3,   4,   5,   6,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 103 = 0x67 = 0b01100111, lengths 2 2 2 1 1 2 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   8,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 104 = 0x68 = 0b01101000, lengths 1 1 1 2 1 2 2 1
+    [   0,   2,   4,   6,   7,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 105 = 0x69 = 0b01101001, lengths 2 1 1 2 1 2 2 1
+    [   0,   1,   2,   4,   6,   7,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 106 = 0x6A = 0b01101010, lengths 1 2 1 2 1 2 2 1
+    [   0,   2,   3,   4,   6,   7,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 107 = 0x6B = 0b01101011, lengths 2 2 1 2 1 2 2 1
+    [   0,   1,   2,   3,   4,   6,   7,   8,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 108 = 0x6C = 0b01101100, lengths 1 1 2 2 1 2 2 1
+    [   0,   2,   4,   5,   6,   7,   8,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 109 = 0x6D = 0b01101101, lengths 2 1 2 2 1 2 2 1
+    [   0,   1,   2,   4,   5,   6,   7,   8,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 110 = 0x6E = 0b01101110, lengths 1 2 2 2 1 2 2 1
+    [   0,   2,   3,   4,   5,   6,   7,   8,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 111 = 0x6F = 0b01101111, lengths 2 2 2 2 1 2 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  10,  11,  12,  13,  14, 128, 128],
+    // 112 = 0x70 = 0b01110000, lengths 1 1 1 1 2 2 2 1
+    [   0,   2,   4,   6,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128, 128, 128],
+    // 113 = 0x71 = 0b01110001, lengths 2 1 1 1 2 2 2 1
+    [   0,   1,   2,   4,   6,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 114 = 0x72 = 0b01110010, lengths 1 2 1 1 2 2 2 1
+    [   0,   2,   3,   4,   6,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 115 = 0x73 = 0b01110011, lengths 2 2 1 1 2 2 2 1
+    [   0,   1,   2,   3,   4,   6,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 116 = 0x74 = 0b01110100, lengths 1 1 2 1 2 2 2 1
+    [   0,   2,   4,   5,   6,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 117 = 0x75 = 0b01110101, lengths 2 1 2 1 2 2 2 1
+    [   0,   1,   2,   4,   5,   6,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 118 = 0x76 = 0b01110110, lengths 1 2 2 1 2 2 2 1
+    [   0,   2,   3,   4,   5,   6,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 119 = 0x77 = 0b01110111, lengths 2 2 2 1 2 2 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   8,   9,  10,  11,  12,  13,  14, 128, 128],
+    // 120 = 0x78 = 0b01111000, lengths 1 1 1 2 2 2 2 1
+    [   0,   2,   4,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128, 128],
+    // 121 = 0x79 = 0b01111001, lengths 2 1 1 2 2 2 2 1
+    [   0,   1,   2,   4,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 122 = 0x7A = 0b01111010, lengths 1 2 1 2 2 2 2 1
+    [   0,   2,   3,   4,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 123 = 0x7B = 0b01111011, lengths 2 2 1 2 2 2 2 1
+    [   0,   1,   2,   3,   4,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128, 128],
+    // 124 = 0x7C = 0b01111100, lengths 1 1 2 2 2 2 2 1
+    [   0,   2,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128, 128, 128],
+    // 125 = 0x7D = 0b01111101, lengths 2 1 2 2 2 2 2 1
+    [   0,   1,   2,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128, 128],
+    // 126 = 0x7E = 0b01111110, lengths 1 2 2 2 2 2 2 1
+    [   0,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128, 128],
+    // 127 = 0x7F = 0b01111111, lengths 2 2 2 2 2 2 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14, 128],
+    // 128 = 0x80 = 0b10000000, lengths 1 1 1 1 1 1 1 2
+    [   0,   2,   4,   6,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 129 = 0x81 = 0b10000001, lengths 2 1 1 1 1 1 1 2
+    [   0,   1,   2,   4,   6,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 130 = 0x82 = 0b10000010, lengths 1 2 1 1 1 1 1 2
+    [   0,   2,   3,   4,   6,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 131 = 0x83 = 0b10000011, lengths 2 2 1 1 1 1 1 2
+    [   0,   1,   2,   3,   4,   6,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 132 = 0x84 = 0b10000100, lengths 1 1 2 1 1 1 1 2
+    [   0,   2,   4,   5,   6,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 133 = 0x85 = 0b10000101, lengths 2 1 2 1 1 1 1 2
+    [   0,   1,   2,   4,   5,   6,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 134 = 0x86 = 0b10000110, lengths 1 2 2 1 1 1 1 2
+    [   0,   2,   3,   4,   5,   6,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 135 = 0x87 = 0b10000111, lengths 2 2 2 1 1 1 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   8,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 136 = 0x88 = 0b10001000, lengths 1 1 1 2 1 1 1 2
+    [   0,   2,   4,   6,   7,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 137 = 0x89 = 0b10001001, lengths 2 1 1 2 1 1 1 2
+    [   0,   1,   2,   4,   6,   7,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 138 = 0x8A = 0b10001010, lengths 1 2 1 2 1 1 1 2
+    [   0,   2,   3,   4,   6,   7,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 139 = 0x8B = 0b10001011, lengths 2 2 1 2 1 1 1 2
+    [   0,   1,   2,   3,   4,   6,   7,   8,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 140 = 0x8C = 0b10001100, lengths 1 1 2 2 1 1 1 2
+    [   0,   2,   4,   5,   6,   7,   8,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 141 = 0x8D = 0b10001101, lengths 2 1 2 2 1 1 1 2
+    [   0,   1,   2,   4,   5,   6,   7,   8,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 142 = 0x8E = 0b10001110, lengths 1 2 2 2 1 1 1 2
+    [   0,   2,   3,   4,   5,   6,   7,   8,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 143 = 0x8F = 0b10001111, lengths 2 2 2 2 1 1 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  10,  12,  14,  15, 128, 128, 128],
+    // 144 = 0x90 = 0b10010000, lengths 1 1 1 1 2 1 1 2
+    [   0,   2,   4,   6,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 145 = 0x91 = 0b10010001, lengths 2 1 1 1 2 1 1 2
+    [   0,   1,   2,   4,   6,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 146 = 0x92 = 0b10010010, lengths 1 2 1 1 2 1 1 2
+    [   0,   2,   3,   4,   6,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 147 = 0x93 = 0b10010011, lengths 2 2 1 1 2 1 1 2
+    [   0,   1,   2,   3,   4,   6,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 148 = 0x94 = 0b10010100, lengths 1 1 2 1 2 1 1 2
+    [   0,   2,   4,   5,   6,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 149 = 0x95 = 0b10010101, lengths 2 1 2 1 2 1 1 2
+    [   0,   1,   2,   4,   5,   6,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 150 = 0x96 = 0b10010110, lengths 1 2 2 1 2 1 1 2
+    [   0,   2,   3,   4,   5,   6,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 151 = 0x97 = 0b10010111, lengths 2 2 2 1 2 1 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   8,   9,  10,  12,  14,  15, 128, 128, 128],
+    // 152 = 0x98 = 0b10011000, lengths 1 1 1 2 2 1 1 2
+    [   0,   2,   4,   6,   7,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 153 = 0x99 = 0b10011001, lengths 2 1 1 2 2 1 1 2
+    [   0,   1,   2,   4,   6,   7,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 154 = 0x9A = 0b10011010, lengths 1 2 1 2 2 1 1 2
+    [   0,   2,   3,   4,   6,   7,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 155 = 0x9B = 0b10011011, lengths 2 2 1 2 2 1 1 2
+    [   0,   1,   2,   3,   4,   6,   7,   8,   9,  10,  12,  14,  15, 128, 128, 128],
+    // 156 = 0x9C = 0b10011100, lengths 1 1 2 2 2 1 1 2
+    [   0,   2,   4,   5,   6,   7,   8,   9,  10,  12,  14,  15, 128, 128, 128, 128],
+    // 157 = 0x9D = 0b10011101, lengths 2 1 2 2 2 1 1 2
+    [   0,   1,   2,   4,   5,   6,   7,   8,   9,  10,  12,  14,  15, 128, 128, 128],
+    // 158 = 0x9E = 0b10011110, lengths 1 2 2 2 2 1 1 2
+    [   0,   2,   3,   4,   5,   6,   7,   8,   9,  10,  12,  14,  15, 128, 128, 128],
+    // 159 = 0x9F = 0b10011111, lengths 2 2 2 2 2 1 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  12,  14,  15, 128, 128],
+    // 160 = 0xA0 = 0b10100000, lengths 1 1 1 1 1 2 1 2
+    [   0,   2,   4,   6,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 161 = 0xA1 = 0b10100001, lengths 2 1 1 1 1 2 1 2
+    [   0,   1,   2,   4,   6,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 162 = 0xA2 = 0b10100010, lengths 1 2 1 1 1 2 1 2
+    [   0,   2,   3,   4,   6,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 163 = 0xA3 = 0b10100011, lengths 2 2 1 1 1 2 1 2
+    [   0,   1,   2,   3,   4,   6,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 164 = 0xA4 = 0b10100100, lengths 1 1 2 1 1 2 1 2
+    [   0,   2,   4,   5,   6,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 165 = 0xA5 = 0b10100101, lengths 2 1 2 1 1 2 1 2
+    [   0,   1,   2,   4,   5,   6,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 166 = 0xA6 = 0b10100110, lengths 1 2 2 1 1 2 1 2
+    [   0,   2,   3,   4,   5,   6,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 167 = 0xA7 = 0b10100111, lengths 2 2 2 1 1 2 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   8,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 168 = 0xA8 = 0b10101000, lengths 1 1 1 2 1 2 1 2
+    [   0,   2,   4,   6,   7,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 169 = 0xA9 = 0b10101001, lengths 2 1 1 2 1 2 1 2
+    [   0,   1,   2,   4,   6,   7,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 170 = 0xAA = 0b10101010, lengths 1 2 1 2 1 2 1 2
+    [   0,   2,   3,   4,   6,   7,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 171 = 0xAB = 0b10101011, lengths 2 2 1 2 1 2 1 2
+    [   0,   1,   2,   3,   4,   6,   7,   8,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 172 = 0xAC = 0b10101100, lengths 1 1 2 2 1 2 1 2
+    [   0,   2,   4,   5,   6,   7,   8,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 173 = 0xAD = 0b10101101, lengths 2 1 2 2 1 2 1 2
+    [   0,   1,   2,   4,   5,   6,   7,   8,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 174 = 0xAE = 0b10101110, lengths 1 2 2 2 1 2 1 2
+    [   0,   2,   3,   4,   5,   6,   7,   8,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 175 = 0xAF = 0b10101111, lengths 2 2 2 2 1 2 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  10,  11,  12,  14,  15, 128, 128],
+    // 176 = 0xB0 = 0b10110000, lengths 1 1 1 1 2 2 1 2
+    [   0,   2,   4,   6,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128, 128, 128],
+    // 177 = 0xB1 = 0b10110001, lengths 2 1 1 1 2 2 1 2
+    [   0,   1,   2,   4,   6,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 178 = 0xB2 = 0b10110010, lengths 1 2 1 1 2 2 1 2
+    [   0,   2,   3,   4,   6,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 179 = 0xB3 = 0b10110011, lengths 2 2 1 1 2 2 1 2
+    [   0,   1,   2,   3,   4,   6,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 180 = 0xB4 = 0b10110100, lengths 1 1 2 1 2 2 1 2
+    [   0,   2,   4,   5,   6,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 181 = 0xB5 = 0b10110101, lengths 2 1 2 1 2 2 1 2
+    [   0,   1,   2,   4,   5,   6,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 182 = 0xB6 = 0b10110110, lengths 1 2 2 1 2 2 1 2
+    [   0,   2,   3,   4,   5,   6,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 183 = 0xB7 = 0b10110111, lengths 2 2 2 1 2 2 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   8,   9,  10,  11,  12,  14,  15, 128, 128],
+    // 184 = 0xB8 = 0b10111000, lengths 1 1 1 2 2 2 1 2
+    [   0,   2,   4,   6,   7,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128, 128],
+    // 185 = 0xB9 = 0b10111001, lengths 2 1 1 2 2 2 1 2
+    [   0,   1,   2,   4,   6,   7,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 186 = 0xBA = 0b10111010, lengths 1 2 1 2 2 2 1 2
+    [   0,   2,   3,   4,   6,   7,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 187 = 0xBB = 0b10111011, lengths 2 2 1 2 2 2 1 2
+    [   0,   1,   2,   3,   4,   6,   7,   8,   9,  10,  11,  12,  14,  15, 128, 128],
+    // 188 = 0xBC = 0b10111100, lengths 1 1 2 2 2 2 1 2
+    [   0,   2,   4,   5,   6,   7,   8,   9,  10,  11,  12,  14,  15, 128, 128, 128],
+    // 189 = 0xBD = 0b10111101, lengths 2 1 2 2 2 2 1 2
+    [   0,   1,   2,   4,   5,   6,   7,   8,   9,  10,  11,  12,  14,  15, 128, 128],
+    // 190 = 0xBE = 0b10111110, lengths 1 2 2 2 2 2 1 2
+    [   0,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  14,  15, 128, 128],
+    // 191 = 0xBF = 0b10111111, lengths 2 2 2 2 2 2 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  14,  15, 128],
+    // 192 = 0xC0 = 0b11000000, lengths 1 1 1 1 1 1 2 2
+    [   0,   2,   4,   6,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 193 = 0xC1 = 0b11000001, lengths 2 1 1 1 1 1 2 2
+    [   0,   1,   2,   4,   6,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 194 = 0xC2 = 0b11000010, lengths 1 2 1 1 1 1 2 2
+    [   0,   2,   3,   4,   6,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 195 = 0xC3 = 0b11000011, lengths 2 2 1 1 1 1 2 2
+    [   0,   1,   2,   3,   4,   6,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 196 = 0xC4 = 0b11000100, lengths 1 1 2 1 1 1 2 2
+    [   0,   2,   4,   5,   6,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 197 = 0xC5 = 0b11000101, lengths 2 1 2 1 1 1 2 2
+    [   0,   1,   2,   4,   5,   6,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 198 = 0xC6 = 0b11000110, lengths 1 2 2 1 1 1 2 2
+    [   0,   2,   3,   4,   5,   6,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 199 = 0xC7 = 0b11000111, lengths 2 2 2 1 1 1 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   8,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 200 = 0xC8 = 0b11001000, lengths 1 1 1 2 1 1 2 2
+    [   0,   2,   4,   6,   7,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 201 = 0xC9 = 0b11001001, lengths 2 1 1 2 1 1 2 2
+    [   0,   1,   2,   4,   6,   7,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 202 = 0xCA = 0b11001010, lengths 1 2 1 2 1 1 2 2
+    [   0,   2,   3,   4,   6,   7,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 203 = 0xCB = 0b11001011, lengths 2 2 1 2 1 1 2 2
+    [   0,   1,   2,   3,   4,   6,   7,   8,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 204 = 0xCC = 0b11001100, lengths 1 1 2 2 1 1 2 2
+    [   0,   2,   4,   5,   6,   7,   8,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 205 = 0xCD = 0b11001101, lengths 2 1 2 2 1 1 2 2
+    [   0,   1,   2,   4,   5,   6,   7,   8,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 206 = 0xCE = 0b11001110, lengths 1 2 2 2 1 1 2 2
+    [   0,   2,   3,   4,   5,   6,   7,   8,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 207 = 0xCF = 0b11001111, lengths 2 2 2 2 1 1 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  10,  12,  13,  14,  15, 128, 128],
+    // 208 = 0xD0 = 0b11010000, lengths 1 1 1 1 2 1 2 2
+    [   0,   2,   4,   6,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 209 = 0xD1 = 0b11010001, lengths 2 1 1 1 2 1 2 2
+    [   0,   1,   2,   4,   6,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 210 = 0xD2 = 0b11010010, lengths 1 2 1 1 2 1 2 2
+    [   0,   2,   3,   4,   6,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 211 = 0xD3 = 0b11010011, lengths 2 2 1 1 2 1 2 2
+    [   0,   1,   2,   3,   4,   6,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 212 = 0xD4 = 0b11010100, lengths 1 1 2 1 2 1 2 2
+    [   0,   2,   4,   5,   6,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 213 = 0xD5 = 0b11010101, lengths 2 1 2 1 2 1 2 2
+    [   0,   1,   2,   4,   5,   6,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 214 = 0xD6 = 0b11010110, lengths 1 2 2 1 2 1 2 2
+    [   0,   2,   3,   4,   5,   6,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 215 = 0xD7 = 0b11010111, lengths 2 2 2 1 2 1 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   8,   9,  10,  12,  13,  14,  15, 128, 128],
+    // 216 = 0xD8 = 0b11011000, lengths 1 1 1 2 2 1 2 2
+    [   0,   2,   4,   6,   7,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 217 = 0xD9 = 0b11011001, lengths 2 1 1 2 2 1 2 2
+    [   0,   1,   2,   4,   6,   7,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 218 = 0xDA = 0b11011010, lengths 1 2 1 2 2 1 2 2
+    [   0,   2,   3,   4,   6,   7,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 219 = 0xDB = 0b11011011, lengths 2 2 1 2 2 1 2 2
+    [   0,   1,   2,   3,   4,   6,   7,   8,   9,  10,  12,  13,  14,  15, 128, 128],
+    // 220 = 0xDC = 0b11011100, lengths 1 1 2 2 2 1 2 2
+    [   0,   2,   4,   5,   6,   7,   8,   9,  10,  12,  13,  14,  15, 128, 128, 128],
+    // 221 = 0xDD = 0b11011101, lengths 2 1 2 2 2 1 2 2
+    [   0,   1,   2,   4,   5,   6,   7,   8,   9,  10,  12,  13,  14,  15, 128, 128],
+    // 222 = 0xDE = 0b11011110, lengths 1 2 2 2 2 1 2 2
+    [   0,   2,   3,   4,   5,   6,   7,   8,   9,  10,  12,  13,  14,  15, 128, 128],
+    // 223 = 0xDF = 0b11011111, lengths 2 2 2 2 2 1 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  12,  13,  14,  15, 128],
+    // 224 = 0xE0 = 0b11100000, lengths 1 1 1 1 1 2 2 2
+    [   0,   2,   4,   6,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 225 = 0xE1 = 0b11100001, lengths 2 1 1 1 1 2 2 2
+    [   0,   1,   2,   4,   6,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 226 = 0xE2 = 0b11100010, lengths 1 2 1 1 1 2 2 2
+    [   0,   2,   3,   4,   6,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 227 = 0xE3 = 0b11100011, lengths 2 2 1 1 1 2 2 2
+    [   0,   1,   2,   3,   4,   6,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 228 = 0xE4 = 0b11100100, lengths 1 1 2 1 1 2 2 2
+    [   0,   2,   4,   5,   6,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 229 = 0xE5 = 0b11100101, lengths 2 1 2 1 1 2 2 2
+    [   0,   1,   2,   4,   5,   6,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 230 = 0xE6 = 0b11100110, lengths 1 2 2 1 1 2 2 2
+    [   0,   2,   3,   4,   5,   6,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 231 = 0xE7 = 0b11100111, lengths 2 2 2 1 1 2 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   8,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 232 = 0xE8 = 0b11101000, lengths 1 1 1 2 1 2 2 2
+    [   0,   2,   4,   6,   7,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 233 = 0xE9 = 0b11101001, lengths 2 1 1 2 1 2 2 2
+    [   0,   1,   2,   4,   6,   7,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 234 = 0xEA = 0b11101010, lengths 1 2 1 2 1 2 2 2
+    [   0,   2,   3,   4,   6,   7,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 235 = 0xEB = 0b11101011, lengths 2 2 1 2 1 2 2 2
+    [   0,   1,   2,   3,   4,   6,   7,   8,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 236 = 0xEC = 0b11101100, lengths 1 1 2 2 1 2 2 2
+    [   0,   2,   4,   5,   6,   7,   8,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 237 = 0xED = 0b11101101, lengths 2 1 2 2 1 2 2 2
+    [   0,   1,   2,   4,   5,   6,   7,   8,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 238 = 0xEE = 0b11101110, lengths 1 2 2 2 1 2 2 2
+    [   0,   2,   3,   4,   5,   6,   7,   8,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 239 = 0xEF = 0b11101111, lengths 2 2 2 2 1 2 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  10,  11,  12,  13,  14,  15, 128],
+    // 240 = 0xF0 = 0b11110000, lengths 1 1 1 1 2 2 2 2
+    [   0,   2,   4,   6,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 241 = 0xF1 = 0b11110001, lengths 2 1 1 1 2 2 2 2
+    [   0,   1,   2,   4,   6,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 242 = 0xF2 = 0b11110010, lengths 1 2 1 1 2 2 2 2
+    [   0,   2,   3,   4,   6,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 243 = 0xF3 = 0b11110011, lengths 2 2 1 1 2 2 2 2
+    [   0,   1,   2,   3,   4,   6,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 244 = 0xF4 = 0b11110100, lengths 1 1 2 1 2 2 2 2
+    [   0,   2,   4,   5,   6,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 245 = 0xF5 = 0b11110101, lengths 2 1 2 1 2 2 2 2
+    [   0,   1,   2,   4,   5,   6,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 246 = 0xF6 = 0b11110110, lengths 1 2 2 1 2 2 2 2
+    [   0,   2,   3,   4,   5,   6,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 247 = 0xF7 = 0b11110111, lengths 2 2 2 1 2 2 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   8,   9,  10,  11,  12,  13,  14,  15, 128],
+    // 248 = 0xF8 = 0b11111000, lengths 1 1 1 2 2 2 2 2
+    [   0,   2,   4,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 249 = 0xF9 = 0b11111001, lengths 2 1 1 2 2 2 2 2
+    [   0,   1,   2,   4,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 250 = 0xFA = 0b11111010, lengths 1 2 1 2 2 2 2 2
+    [   0,   2,   3,   4,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 251 = 0xFB = 0b11111011, lengths 2 2 1 2 2 2 2 2
+    [   0,   1,   2,   3,   4,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128],
+    // 252 = 0xFC = 0b11111100, lengths 1 1 2 2 2 2 2 2
+    [   0,   2,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 253 = 0xFD = 0b11111101, lengths 2 1 2 2 2 2 2 2
+    [   0,   1,   2,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128],
+    // 254 = 0xFE = 0b11111110, lengths 1 2 2 2 2 2 2 2
+    [   0,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128],
+    // 255 = 0xFF = 0b11111111, lengths 2 2 2 2 2 2 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15],
+];
+
+#[rustfmt::skip]
+pub const SVB64_DECODE_LENGTH_PER_QUAD_TABLE: &[u8; 256] = &[
+    4, // 0 = 0x0 = 0b00000000, lengths 1 1 1 1
+    5, // 1 = 0x1 = 0b00000001, lengths 2 1 1 1
+    7, // 2 = 0x2 = 0b00000010, lengths 4 1 1 1
+    11, // 3 = 0x3 = 0b00000011, lengths 8 1 1 1
+    5, // 4 = 0x4 = 0b00000100, lengths 1 2 1 1
+    6, // 5 = 0x5 = 0b00000101, lengths 2 2 1 1
+    8, // 6 = 0x6 = 0b00000110, lengths 4 2 1 1
+    12, // 7 = 0x7 = 0b00000111, lengths 8 2 1 1
+    7, // 8 = 0x8 = 0b00001000, lengths 1 4 1 1
+    8, // 9 = 0x9 = 0b00001001, lengths 2 4 1 1
+    10, // 10 = 0xA = 0b00001010, lengths 4 4 1 1
+    14, // 11 = 0xB = 0b00001011, lengths 8 4 1 1
+    11, // 12 = 0xC = 0b00001100, lengths 1 8 1 1
+    12, // 13 = 0xD = 0b00001101, lengths 2 8 1 1
+    14, // 14 = 0xE = 0b00001110, lengths 4 8 1 1
+    18, // 15 = 0xF = 0b00001111, lengths 8 8 1 1
+    5, // 16 = 0x10 = 0b00010000, lengths 1 1 2 1
+    6, // 17 = 0x11 = 0b00010001, lengths 2 1 2 1
+    8, // 18 = 0x12 = 0b00010010, lengths 4 1 2 1
+    12, // 19 = 0x13 = 0b00010011, lengths 8 1 2 1
+    6, // 20 = 0x14 = 0b00010100, lengths 1 2 2 1
+    7, // 21 = 0x15 = 0b00010101, lengths 2 2 2 1
+    9, // 22 = 0x16 = 0b00010110, lengths 4 2 2 1
+    13, // 23 = 0x17 = 0b00010111, lengths 8 2 2 1
+    8, // 24 = 0x18 = 0b00011000, lengths 1 4 2 1
+    9, // 25 = 0x19 = 0b00011001, lengths 2 4 2 1
+    11, // 26 = 0x1A = 0b00011010, lengths 4 4 2 1
+    15, // 27 = 0x1B = 0b00011011, lengths 8 4 2 1
+    12, // 28 = 0x1C = 0b00011100, lengths 1 8 2 1
+    13, // 29 = 0x1D = 0b00011101, lengths 2 8 2 1
+    15, // 30 = 0x1E = 0b00011110, lengths 4 8 2 1
+    19, // 31 = 0x1F = 0b00011111, lengths 8 8 2 1
+    7, // 32 = 0x20 = 0b00100000, lengths 1 1 4 1
+    8, // 33 = 0x21 = 0b00100001, lengths 2 1 4 1
+    10, // 34 = 0x22 = 0b00100010, lengths 4 1 4 1
+    14, // 35 = 0x23 = 0b00100011, lengths 8 1 4 1
+    8, // 36 = 0x24 = 0b00100100, lengths 1 2 4 1
+    9, // 37 = 0x25 = 0b00100101, lengths 2 2 4 1
+    11, // 38 = 0x26 = 0b00100110, lengths 4 2 4 1
+    15, // 39 = 0x27 = 0b00100111, lengths 8 2 4 1
+    10, // 40 = 0x28 = 0b00101000, lengths 1 4 4 1
+    11, // 41 = 0x29 = 0b00101001, lengths 2 4 4 1
+    13, // 42 = 0x2A = 0b00101010, lengths 4 4 4 1
+    17, // 43 = 0x2B = 0b00101011, lengths 8 4 4 1
+    14, // 44 = 0x2C = 0b00101100, lengths 1 8 4 1
+    15, // 45 = 0x2D = 0b00101101, lengths 2 8 4 1
+    17, // 46 = 0x2E = 0b00101110, lengths 4 8 4 1
+    21, // 47 = 0x2F = 0b00101111, lengths 8 8 4 1
+    11, // 48 = 0x30 = 0b00110000, lengths 1 1 8 1
+    12, // 49 = 0x31 = 0b00110001, lengths 2 1 8 1
+    14, // 50 = 0x32 = 0b00110010, lengths 4 1 8 1
+    18, // 51 = 0x33 = 0b00110011, lengths 8 1 8 1
+    12, // 52 = 0x34 = 0b00110100, lengths 1 2 8 1
+    13, // 53 = 0x35 = 0b00110101, lengths 2 2 8 1
+    15, // 54 = 0x36 = 0b00110110, lengths 4 2 8 1
+    19, // 55 = 0x37 = 0b00110111, lengths 8 2 8 1
+    14, // 56 = 0x38 = 0b00111000, lengths 1 4 8 1
+    15, // 57 = 0x39 = 0b00111001, lengths 2 4 8 1
+    17, // 58 = 0x3A = 0b00111010, lengths 4 4 8 1
+    21, // 59 = 0x3B = 0b00111011, lengths 8 4 8 1
+    18, // 60 = 0x3C = 0b00111100, lengths 1 8 8 1
+    19, // 61 = 0x3D = 0b00111101, lengths 2 8 8 1
+    21, // 62 = 0x3E = 0b00111110, lengths 4 8 8 1
+    25, // 63 = 0x3F = 0b00111111, lengths 8 8 8 1
+    5, // 64 = 0x40 = 0b01000000, lengths 1 1 1 2
+    6, // 65 = 0x41 = 0b01000001, lengths 2 1 1 2
+    8, // 66 = 0x42 = 0b01000010, lengths 4 1 1 2
+    12, // 67 = 0x43 = 0b01000011, lengths 8 1 1 2
+    6, // 68 = 0x44 = 0b01000100, lengths 1 2 1 2
+    7, // 69 = 0x45 = 0b01000101, lengths 2 2 1 2
+    9, // 70 = 0x46 = 0b01000110, lengths 4 2 1 2
+    13, // 71 = 0x47 = 0b01000111, lengths 8 2 1 2
+    8, // 72 = 0x48 = 0b01001000, lengths 1 4 1 2
+    9, // 73 = 0x49 = 0b01001001, lengths 2 4 1 2
+    11, // 74 = 0x4A = 0b01001010, lengths 4 4 1 2
+    15, // 75 = 0x4B = 0b01001011, lengths 8 4 1 2
+    12, // 76 = 0x4C = 0b01001100, lengths 1 8 1 2
+    13, // 77 = 0x4D = 0b01001101, lengths 2 8 1 2
+    15, // 78 = 0x4E = 0b01001110, lengths 4 8 1 2
+    19, // 79 = 0x4F = 0b01001111, lengths 8 8 1 2
+    6, // 80 = 0x50 = 0b01010000, lengths 1 1 2 2
+    7, // 81 = 0x51 = 0b01010001, lengths 2 1 2 2
+    9, // 82 = 0x52 = 0b01010010, lengths 4 1 2 2
+    13, // 83 = 0x53 = 0b01010011, lengths 8 1 2 2
+    7, // 84 = 0x54 = 0b01010100, lengths 1 2 2 2
+    8, // 85 = 0x55 = 0b01010101, lengths 2 2 2 2
+    10, // 86 = 0x56 = 0b01010110, lengths 4 2 2 2
+    14, // 87 = 0x57 = 0b01010111, lengths 8 2 2 2
+    9, // 88 = 0x58 = 0b01011000, lengths 1 4 2 2
+    10, // 89 = 0x59 = 0b01011001, lengths 2 4 2 2
+    12, // 90 = 0x5A = 0b01011010, lengths 4 4 2 2
+    16, // 91 = 0x5B = 0b01011011, lengths 8 4 2 2
+    13, // 92 = 0x5C = 0b01011100, lengths 1 8 2 2
+    14, // 93 = 0x5D = 0b01011101, lengths 2 8 2 2
+    16, // 94 = 0x5E = 0b01011110, lengths 4 8 2 2
+    20, // 95 = 0x5F = 0b01011111, lengths 8 8 2 2
+    8, // 96 = 0x60 = 0b01100000, lengths 1 1 4 2
+    9, // 97 = 0x61 = 0b01100001, lengths 2 1 4 2
+    11, // 98 = 0x62 = 0b01100010, lengths 4 1 4 2
+    15, // 99 = 0x63 = 0b01100011, lengths 8 1 4 2
+    9, // 100 = 0x64 = 0b01100100, lengths 1 2 4 2
+    10, // 101 = 0x65 = 0b01100101, lengths 2 2 4 2
+    12, // 102 = 0x66 = 0b01100110, lengths 4 2 4 2
+    16, // 103 = 0x67 = 0b01100111, lengths 8 2 4 2
+    11, // 104 = 0x68 = 0b01101000, lengths 1 4 4 2
+    12, // 105 = 0x69 = 0b01101001, lengths 2 4 4 2
+    14, // 106 = 0x6A = 0b01101010, lengths 4 4 4 2
+    18, // 107 = 0x6B = 0b01101011, lengths 8 4 4 2
+    15, // 108 = 0x6C = 0b01101100, lengths 1 8 4 2
+    16, // 109 = 0x6D = 0b01101101, lengths 2 8 4 2
+    18, // 110 = 0x6E = 0b01101110, lengths 4 8 4 2
+    22, // 111 = 0x6F = 0b01101111, lengths 8 8 4 2
+    12, // 112 = 0x70 = 0b01110000, lengths 1 1 8 2
+    13, // 113 = 0x71 = 0b01110001, lengths 2 1 8 2
+    15, // 114 = 0x72 = 0b01110010, lengths 4 1 8 2
+    19, // 115 = 0x73 = 0b01110011, lengths 8 1 8 2
+    13, // 116 = 0x74 = 0b01110100, lengths 1 2 8 2
+    14, // 117 = 0x75 = 0b01110101, lengths 2 2 8 2
+    16, // 118 = 0x76 = 0b01110110, lengths 4 2 8 2
+    20, // 119 = 0x77 = 0b01110111, lengths 8 2 8 2
+    15, // 120 = 0x78 = 0b01111000, lengths 1 4 8 2
+    16, // 121 = 0x79 = 0b01111001, lengths 2 4 8 2
+    18, // 122 = 0x7A = 0b01111010, lengths 4 4 8 2
+    22, // 123 = 0x7B = 0b01111011, lengths 8 4 8 2
+    19, // 124 = 0x7C = 0b01111100, lengths 1 8 8 2
+    20, // 125 = 0x7D = 0b01111101, lengths 2 8 8 2
+    22, // 126 = 0x7E = 0b01111110, lengths 4 8 8 2
+    26, // 127 = 0x7F = 0b01111111, lengths 8 8 8 2
+    7, // 128 = 0x80 = 0b10000000, lengths 1 1 1 4
+    8, // 129 = 0x81 = 0b10000001, lengths 2 1 1 4
+    10, // 130 = 0x82 = 0b10000010, lengths 4 1 1 4
+    14, // 131 = 0x83 = 0b10000011, lengths 8 1 1 4
+    8, // 132 = 0x84 = 0b10000100, lengths 1 2 1 4
+    9, // 133 = 0x85 = 0b10000101, lengths 2 2 1 4
+    11, // 134 = 0x86 = 0b10000110, lengths 4 2 1 4
+    15, // 135 = 0x87 = 0b10000111, lengths 8 2 1 4
+    10, // 136 = 0x88 = 0b10001000, lengths 1 4 1 4
+    11, // 137 = 0x89 = 0b10001001, lengths 2 4 1 4
+    13, // 138 = 0x8A = 0b10001010, lengths 4 4 1 4
+    17, // 139 = 0x8B = 0b10001011, lengths 8 4 1 4
+    14, // 140 = 0x8C = 0b10001100, lengths 1 8 1 4
+    15, // 141 = 0x8D = 0b10001101, lengths 2 8 1 4
+    17, // 142 = 0x8E = 0b10001110, lengths 4 8 1 4
+    21, // 143 = 0x8F = 0b10001111, lengths 8 8 1 4
+    8, // 144 = 0x90 = 0b10010000, lengths 1 1 2 4
+    9, // 145 = 0x91 = 0b10010001, lengths 2 1 2 4
+    11, // 146 = 0x92 = 0b10010010, lengths 4 1 2 4
+    15, // 147 = 0x93 = 0b10010011, lengths 8 1 2 4
+    9, // 148 = 0x94 = 0b10010100, lengths 1 2 2 4
+    10, // 149 = 0x95 = 0b10010101, lengths 2 2 2 4
+    12, // 150 = 0x96 = 0b10010110, lengths 4 2 2 4
+    16, // 151 = 0x97 = 0b10010111, lengths 8 2 2 4
+    11, // 152 = 0x98 = 0b10011000, lengths 1 4 2 4
+    12, // 153 = 0x99 = 0b10011001, lengths 2 4 2 4
+    14, // 154 = 0x9A = 0b10011010, lengths 4 4 2 4
+    18, // 155 = 0x9B = 0b10011011, lengths 8 4 2 4
+    15, // 156 = 0x9C = 0b10011100, lengths 1 8 2 4
+    16, // 157 = 0x9D = 0b10011101, lengths 2 8 2 4
+    18, // 158 = 0x9E = 0b10011110, lengths 4 8 2 4
+    22, // 159 = 0x9F = 0b10011111, lengths 8 8 2 4
+    10, // 160 = 0xA0 = 0b10100000, lengths 1 1 4 4
+    11, // 161 = 0xA1 = 0b10100001, lengths 2 1 4 4
+    13, // 162 = 0xA2 = 0b10100010, lengths 4 1 4 4
+    17, // 163 = 0xA3 = 0b10100011, lengths 8 1 4 4
+    11, // 164 = 0xA4 = 0b10100100, lengths 1 2 4 4
+    12, // 165 = 0xA5 = 0b10100101, lengths 2 2 4 4
+    14, // 166 = 0xA6 = 0b10100110, lengths 4 2 4 4
+    18, // 167 = 0xA7 = 0b10100111, lengths 8 2 4 4
+    13, // 168 = 0xA8 = 0b10101000, lengths 1 4 4 4
+    14, // 169 = 0xA9 = 0b10101001, lengths 2 4 4 4
+    16, // 170 = 0xAA = 0b10101010, lengths 4 4 4 4
+    20, // 171 = 0xAB = 0b10101011, lengths 8 4 4 4
+    17, // 172 = 0xAC = 0b10101100, lengths 1 8 4 4
+    18, // 173 = 0xAD = 0b10101101, lengths 2 8 4 4
+    20, // 174 = 0xAE = 0b10101110, lengths 4 8 4 4
+    24, // 175 = 0xAF = 0b10101111, lengths 8 8 4 4
+    14, // 176 = 0xB0 = 0b10110000, lengths 1 1 8 4
+    15, // 177 = 0xB1 = 0b10110001, lengths 2 1 8 4
+    17, // 178 = 0xB2 = 0b10110010, lengths 4 1 8 4
+    21, // 179 = 0xB3 = 0b10110011, lengths 8 1 8 4
+    15, // 180 = 0xB4 = 0b10110100, lengths 1 2 8 4
+    16, // 181 = 0xB5 = 0b10110101, lengths 2 2 8 4
+    18, // 182 = 0xB6 = 0b10110110, lengths 4 2 8 4
+    22, // 183 = 0xB7 = 0b10110111, lengths 8 2 8 4
+    17, // 184 = 0xB8 = 0b10111000, lengths 1 4 8 4
+    18, // 185 = 0xB9 = 0b10111001, lengths 2 4 8 4
+    20, // 186 = 0xBA = 0b10111010, lengths 4 4 8 4
+    24, // 187 = 0xBB = 0b10111011, lengths 8 4 8 4
+    21, // 188 = 0xBC = 0b10111100, lengths 1 8 8 4
+    22, // 189 = 0xBD = 0b10111101, lengths 2 8 8 4
+    24, // 190 = 0xBE = 0b10111110, lengths 4 8 8 4
+    28, // 191 = 0xBF = 0b10111111, lengths 8 8 8 4
+    11, // 192 = 0xC0 = 0b11000000, lengths 1 1 1 8
+    12, // 193 = 0xC1 = 0b11000001, lengths 2 1 1 8
+    14, // 194 = 0xC2 = 0b11000010, lengths 4 1 1 8
+    18, // 195 = 0xC3 = 0b11000011, lengths 8 1 1 8
+    12, // 196 = 0xC4 = 0b11000100, lengths 1 2 1 8
+    13, // 197 = 0xC5 = 0b11000101, lengths 2 2 1 8
+    15, // 198 = 0xC6 = 0b11000110, lengths 4 2 1 8
+    19, // 199 = 0xC7 = 0b11000111, lengths 8 2 1 8
+    14, // 200 = 0xC8 = 0b11001000, lengths 1 4 1 8
+    15, // 201 = 0xC9 = 0b11001001, lengths 2 4 1 8
+    17, // 202 = 0xCA = 0b11001010, lengths 4 4 1 8
+    21, // 203 = 0xCB = 0b11001011, lengths 8 4 1 8
+    18, // 204 = 0xCC = 0b11001100, lengths 1 8 1 8
+    19, // 205 = 0xCD = 0b11001101, lengths 2 8 1 8
+    21, // 206 = 0xCE = 0b11001110, lengths 4 8 1 8
+    25, // 207 = 0xCF = 0b11001111, lengths 8 8 1 8
+    12, // 208 = 0xD0 = 0b11010000, lengths 1 1 2 8
+    13, // 209 = 0xD1 = 0b11010001, lengths 2 1 2 8
+    15, // 210 = 0xD2 = 0b11010010, lengths 4 1 2 8
+    19, // 211 = 0xD3 = 0b11010011, lengths 8 1 2 8
+    13, // 212 = 0xD4 = 0b11010100, lengths 1 2 2 8
+    14, // 213 = 0xD5 = 0b11010101, lengths 2 2 2 8
+    16, // 214 = 0xD6 = 0b11010110, lengths 4 2 2 8
+    20, // 215 = 0xD7 = 0b11010111, lengths 8 2 2 8
+    15, // 216 = 0xD8 = 0b11011000, lengths 1 4 2 8
+    16, // 217 = 0xD9 = 0b11011001, lengths 2 4 2 8
+    18, // 218 = 0xDA = 0b11011010, lengths 4 4 2 8
+    22, // 219 = 0xDB = 0b11011011, lengths 8 4 2 8
+    19, // 220 = 0xDC = 0b11011100, lengths 1 8 2 8
+    20, // 221 = 0xDD = 0b11011101, lengths 2 8 2 8
+    22, // 222 = 0xDE = 0b11011110, lengths 4 8 2 8
+    26, // 223 = 0xDF = 0b11011111, lengths 8 8 2 8
+    14, // 224 = 0xE0 = 0b11100000, lengths 1 1 4 8
+    15, // 225 = 0xE1 = 0b11100001, lengths 2 1 4 8
+    17, // 226 = 0xE2 = 0b11100010, lengths 4 1 4 8
+    21, // 227 = 0xE3 = 0b11100011, lengths 8 1 4 8
+    15, // 228 = 0xE4 = 0b11100100, lengths 1 2 4 8
+    16, // 229 = 0xE5 = 0b11100101, lengths 2 2 4 8
+    18, // 230 = 0xE6 = 0b11100110, lengths 4 2 4 8
+    22, // 231 = 0xE7 = 0b11100111, lengths 8 2 4 8
+    17, // 232 = 0xE8 = 0b11101000, lengths 1 4 4 8
+    18, // 233 = 0xE9 = 0b11101001, lengths 2 4 4 8
+    20, // 234 = 0xEA = 0b11101010, lengths 4 4 4 8
+    24, // 235 = 0xEB = 0b11101011, lengths 8 4 4 8
+    21, // 236 = 0xEC = 0b11101100, lengths 1 8 4 8
+    22, // 237 = 0xED = 0b11101101, lengths 2 8 4 8
+    24, // 238 = 0xEE = 0b11101110, lengths 4 8 4 8
+    28, // 239 = 0xEF = 0b11101111, lengths 8 8 4 8
+    18, // 240 = 0xF0 = 0b11110000, lengths 1 1 8 8
+    19, // 241 = 0xF1 = 0b11110001, lengths 2 1 8 8
+    21, // 242 = 0xF2 = 0b11110010, lengths 4 1 8 8
+    25, // 243 = 0xF3 = 0b11110011, lengths 8 1 8 8
+    19, // 244 = 0xF4 = 0b11110100, lengths 1 2 8 8
+    20, // 245 = 0xF5 = 0b11110101, lengths 2 2 8 8
+    22, // 246 = 0xF6 = 0b11110110, lengths 4 2 8 8
+    26, // 247 = 0xF7 = 0b11110111, lengths 8 2 8 8
+    21, // 248 = 0xF8 = 0b11111000, lengths 1 4 8 8
+    22, // 249 = 0xF9 = 0b11111001, lengths 2 4 8 8
+    24, // 250 = 0xFA = 0b11111010, lengths 4 4 8 8
+    28, // 251 = 0xFB = 0b11111011, lengths 8 4 8 8
+    25, // 252 = 0xFC = 0b11111100, lengths 1 8 8 8
+    26, // 253 = 0xFD = 0b11111101, lengths 2 8 8 8
+    28, // 254 = 0xFE = 0b11111110, lengths 4 8 8 8
+    32, // 255 = 0xFF = 0b11111111, lengths 8 8 8 8
+];
+
+#[rustfmt::skip]
+#[cfg(any(feature = "x86_ssse3", feature = "aarch64_neon"))]
+pub const SVB64_DECODE_PAIR_SHUFFLE_TABLE: &[[u8; 16]; 16] = &[
+    // 0 = 0x0 = 0b0000, lengths 1 1
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1, 128, 128, 128, 128, 128, 128, 128],
+    // 1 = 0x1 = 0b0001, lengths 2 1
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2, 128, 128, 128, 128, 128, 128, 128],
+    // 2 = 0x2 = 0b0010, lengths 4 1
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4, 128, 128, 128, 128, 128, 128, 128],
+    // 3 = 0x3 = 0b0011, lengths 8 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128, 128, 128, 128, 128],
+    // 4 = 0x4 = 0b0100, lengths 1 2
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2, 128, 128, 128, 128, 128, 128],
+    // 5 = 0x5 = 0b0101, lengths 2 2
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3, 128, 128, 128, 128, 128, 128],
+    // 6 = 0x6 = 0b0110, lengths 4 2
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5, 128, 128, 128, 128, 128, 128],
+    // 7 = 0x7 = 0b0111, lengths 8 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128, 128, 128, 128, 128],
+    // 8 = 0x8 = 0b1000, lengths 1 4
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2,   3,   4, 128, 128, 128, 128],
+    // 9 = 0x9 = 0b1001, lengths 2 4
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5, 128, 128, 128, 128],
+    // 10 = 0xA = 0b1010, lengths 4 4
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5,   6,   7, 128, 128, 128, 128],
+    // 11 = 0xB = 0b1011, lengths 8 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128, 128, 128, 128],
+    // 12 = 0xC = 0b1100, lengths 1 8
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2,   3,   4,   5,   6,   7,   8],
+    // 13 = 0xD = 0b1101, lengths 2 8
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5,   6,   7,   8,   9],
+    // 14 = 0xE = 0b1110, lengths 4 8
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5,   6,   7,   8,   9,  10,  11],
+    // 15 = 0xF = 0b1111, lengths 8 8
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15],
+];
+
+#[rustfmt::skip]
+#[cfg(any(feature = "x86_ssse3", feature = "x86_sse41", feature = "aarch64_neon"))]
+pub const SVB64_ENCODE_PAIR_SHUFFLE_TABLE: &[[u8; 16]; 16] = &[
+    // 0 = 0x0 = 0b0000, lengths 1 1
+    [   0,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 1 = 0x1 = 0b0001, lengths 2 1
+    [   0,   1,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 2 = 0x2 = 0b0010, lengths 4 1
+    [   0,   1,   2,   3,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 3 = 0x3 = 0b0011, lengths 8 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128, 128, 128, 128, 128],
+    // 4 = 0x4 = 0b0100, lengths 1 2
+    [   0,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 5 = 0x5 = 0b0101, lengths 2 2
+    [   0,   1,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 6 = 0x6 = 0b0110, lengths 4 2
+    [   0,   1,   2,   3,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 7 = 0x7 = 0b0111, lengths 8 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128, 128, 128, 128, 128],
+    // 8 = 0x8 = 0b1000, lengths 1 4
+    [   0,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 9 = 0x9 = 0b1001, lengths 2 4
+    [   0,   1,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 10 = 0xA = 0b1010, lengths 4 4
+    [   0,   1,   2,   3,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 11 = 0xB = 0b1011, lengths 8 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128, 128, 128, 128],
+    // 12 = 0xC = 0b1100, lengths 1 8
+    [   0,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 13 = 0xD = 0b1101, lengths 2 8
+    [   0,   1,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 14 = 0xE = 0b1110, lengths 4 8
+    [   0,   1,   2,   3,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 15 = 0xF = 0b1111, lengths 8 8
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15],
+];
+
+#[rustfmt::skip]
+pub const DECODE_0124_LENGTH_PER_QUAD_TABLE: &[u8; 256] = &[
+    0, // 0 = 0x0 = 0b00000000, lengths 0 0 0 0
+    1, // 1 = 0x1 = 0b00000001, lengths 1 0 0 0
+    2, // 2 = 0x2 = 0b00000010, lengths 2 0 0 0
+    4, // 3 = 0x3 = 0b00000011, lengths 4 0 0 0
+    1, // 4 = 0x4 = 0b00000100, lengths 0 1 0 0
+    2, // 5 = 0x5 = 0b00000101, lengths 1 1 0 0
+    3, // 6 = 0x6 = 0b00000110, lengths 2 1 0 0
+    5, // 7 = 0x7 = 0b00000111, lengths 4 1 0 0
+    2, // 8 = 0x8 = 0b00001000, lengths 0 2 0 0
+    3, // 9 = 0x9 = 0b00001001, lengths 1 2 0 0
+    4, // 10 = 0xA = 0b00001010, lengths 2 2 0 0
+    6, // 11 = 0xB = 0b00001011, lengths 4 2 0 0
+    4, // 12 = 0xC = 0b00001100, lengths 0 4 0 0
+    5, // 13 = 0xD = 0b00001101, lengths 1 4 0 0
+    6, // 14 = 0xE = 0b00001110, lengths 2 4 0 0
+    8, // 15 = 0xF = 0b00001111, lengths 4 4 0 0
+    1, // 16 = 0x10 = 0b00010000, lengths 0 0 1 0
+    2, // 17 = 0x11 = 0b00010001, lengths 1 0 1 0
+    3, // 18 = 0x12 = 0b00010010, lengths 2 0 1 0
+    5, // 19 = 0x13 = 0b00010011, lengths 4 0 1 0
+    2, // 20 = 0x14 = 0b00010100, lengths 0 1 1 0
+    3, // 21 = 0x15 = 0b00010101, lengths 1 1 1 0
+    4, // 22 = 0x16 = 0b00010110, lengths 2 1 1 0
+    6, // 23 = 0x17 = 0b00010111, lengths 4 1 1 0
+    3, // 24 = 0x18 = 0b00011000, lengths 0 2 1 0
+    4, // 25 = 0x19 = 0b00011001, lengths 1 2 1 0
+    5, // 26 = 0x1A = 0b00011010, lengths 2 2 1 0
+    7, // 27 = 0x1B = 0b00011011, lengths 4 2 1 0
+    5, // 28 = 0x1C = 0b00011100, lengths 0 4 1 0
+    6, // 29 = 0x1D = 0b00011101, lengths 1 4 1 0
+    7, // 30 = 0x1E = 0b00011110, lengths 2 4 1 0
+    9, // 31 = 0x1F = 0b00011111, lengths 4 4 1 0
+    2, // 32 = 0x20 = 0b00100000, lengths 0 0 2 0
+    3, // 33 = 0x21 = 0b00100001, lengths 1 0 2 0
+    4, // 34 = 0x22 = 0b00100010, lengths 2 0 2 0
+    6, // 35 = 0x23 = 0b00100011, lengths 4 0 2 0
+    3, // 36 = 0x24 = 0b00100100, lengths 0 1 2 0
+    4, // 37 = 0x25 = 0b00100101, lengths 1 1 2 0
+    5, // 38 = 0x26 = 0b00100110, lengths 2 1 2 0
+    7, // 39 = 0x27 = 0b00100111, lengths 4 1 2 0
+    4, // 40 = 0x28 = 0b00101000, lengths 0 2 2 0
+    5, // 41 = 0x29 = 0b00101001, lengths 1 2 2 0
+    6, // 42 = 0x2A = 0b00101010, lengths 2 2 2 0
+    8, // 43 = 0x2B = 0b00101011, lengths 4 2 2 0
+    6, // 44 = 0x2C = 0b00101100, lengths 0 4 2 0
+    7, // 45 = 0x2D = 0b00101101, lengths 1 4 2 0
+    8, // 46 = 0x2E = 0b00101110, lengths 2 4 2 0
+    10, // 47 = 0x2F = 0b00101111, lengths 4 4 2 0
+    4, // 48 = 0x30 = 0b00110000, lengths 0 0 4 0
+    5, // 49 = 0x31 = 0b00110001, lengths 1 0 4 0
+    6, // 50 = 0x32 = 0b00110010, lengths 2 0 4 0
+    8, // 51 = 0x33 = 0b00110011, lengths 4 0 4 0
+    5, // 52 = 0x34 = 0b00110100, lengths 0 1 4 0
+    6, // 53 = 0x35 = 0b00110101, lengths 1 1 4 0
+    7, // 54 = 0x36 = 0b00110110, lengths 2 1 4 0
+    9, // 55 = 0x37 = 0b00110111, lengths 4 1 4 0
+    6, // 56 = 0x38 = 0b00111000, lengths 0 2 4 0
+    7, // 57 = 0x39 = 0b00111001, lengths 1 2 4 0
+    8, // 58 = 0x3A = 0b00111010, lengths 2 2 4 0
+    10, // 59 = 0x3B = 0b00111011, lengths 4 2 4 0
+    8, // 60 = 0x3C = 0b00111100, lengths 0 4 4 0
+    9, // 61 = 0x3D = 0b00111101, lengths 1 4 4 0
+    10, // 62 = 0x3E = 0b00111110, lengths 2 4 4 0
+    12, // 63 = 0x3F = 0b00111111, lengths 4 4 4 0
+    1, // 64 = 0x40 = 0b01000000, lengths 0 0 0 1
+    2, // 65 = 0x41 = 0b01000001, lengths 1 0 0 1
+    3, // 66 = 0x42 = 0b01000010, lengths 2 0 0 1
+    5, // 67 = 0x43 = 0b01000011, lengths 4 0 0 1
+    2, // 68 = 0x44 = 0b01000100, lengths 0 1 0 1
+    3, // 69 = 0x45 = 0b01000101, lengths 1 1 0 1
+    4, // 70 = 0x46 = 0b01000110, lengths 2 1 0 1
+    6, // 71 = 0x47 = 0b01000111, lengths 4 1 0 1
+    3, // 72 = 0x48 = 0b01001000, lengths 0 2 0 1
+    4, // 73 = 0x49 = 0b01001001, lengths 1 2 0 1
+    5, // 74 = 0x4A = 0b01001010, lengths 2 2 0 1
+    7, // 75 = 0x4B = 0b01001011, lengths 4 2 0 1
+    5, // 76 = 0x4C = 0b01001100, lengths 0 4 0 1
+    6, // 77 = 0x4D = 0b01001101, lengths 1 4 0 1
+    7, // 78 = 0x4E = 0b01001110, lengths 2 4 0 1
+    9, // 79 = 0x4F = 0b01001111, lengths 4 4 0 1
+    2, // 80 = 0x50 = 0b01010000, lengths 0 0 1 1
+    3, // 81 = 0x51 = 0b01010001, lengths 1 0 1 1
+    4, // 82 = 0x52 = 0b01010010, lengths 2 0 1 1
+    6, // 83 = 0x53 = 0b01010011, lengths 4 0 1 1
+    3, // 84 = 0x54 = 0b01010100, lengths 0 1 1 1
+    4, // 85 = 0x55 = 0b01010101, lengths 1 1 1 1
+    5, // 86 = 0x56 = 0b01010110, lengths 2 1 1 1
+    7, // 87 = 0x57 = 0b01010111, lengths 4 1 1 1
+    4, // 88 = 0x58 = 0b01011000, lengths 0 2 1 1
+    5, // 89 = 0x59 = 0b01011001, lengths 1 2 1 1
+    6, // 90 = 0x5A = 0b01011010, lengths 2 2 1 1
+    8, // 91 = 0x5B = 0b01011011, lengths 4 2 1 1
+    6, // 92 = 0x5C = 0b01011100, lengths 0 4 1 1
+    7, // 93 = 0x5D = 0b01011101, lengths 1 4 1 1
+    8, // 94 = 0x5E = 0b01011110, lengths 2 4 1 1
+    10, // 95 = 0x5F = 0b01011111, lengths 4 4 1 1
+    3, // 96 = 0x60 = 0b01100000, lengths 0 0 2 1
+    4, // 97 = 0x61 = 0b01100001, lengths 1 0 2 1
+    5, // 98 = 0x62 = 0b01100010, lengths 2 0 2 1
+    7, // 99 = 0x63 = 0b01100011, lengths 4 0 2 1
+    4, // 100 = 0x64 = 0b01100100, lengths 0 1 2 1
+    5, // 101 = 0x65 = 0b01100101, lengths 1 1 2 1
+    6, // 102 = 0x66 = 0b01100110, lengths 2 1 2 1
+    8, // 103 = 0x67 = 0b01100111, lengths 4 1 2 1
+    5, // 104 = 0x68 = 0b01101000, lengths 0 2 2 1
+    6, // 105 = 0x69 = 0b01101001, lengths 1 2 2 1
+    7, // 106 = 0x6A = 0b01101010, lengths 2 2 2 1
+    9, // 107 = 0x6B = 0b01101011, lengths 4 2 2 1
+    7, // 108 = 0x6C = 0b01101100, lengths 0 4 2 1
+    8, // 109 = 0x6D = 0b01101101, lengths 1 4 2 1
+    9, // 110 = 0x6E = 0b01101110, lengths 2 4 2 1
+    11, // 111 = 0x6F = 0b01101111, lengths 4 4 2 1
+    5, // 112 = 0x70 = 0b01110000, lengths 0 0 4 1
+    6, // 113 = 0x71 = 0b01110001, lengths 1 0 4 1
+    7, // 114 = 0x72 = 0b01110010, lengths 2 0 4 1
+    9, // 115 = 0x73 = 0b01110011, lengths 4 0 4 1
+    6, // 116 = 0x74 = 0b01110100, lengths 0 1 4 1
+    7, // 117 = 0x75 = 0b01110101, lengths 1 1 4 1
+    8, // 118 = 0x76 = 0b01110110, lengths 2 1 4 1
+    10, // 119 = 0x77 = 0b01110111, lengths 4 1 4 1
+    7, // 120 = 0x78 = 0b01111000, lengths 0 2 4 1
+    8, // 121 = 0x79 = 0b01111001, lengths 1 2 4 1
+    9, // 122 = 0x7A = 0b01111010, lengths 2 2 4 1
+    11, // 123 = 0x7B = 0b01111011, lengths 4 2 4 1
+    9, // 124 = 0x7C = 0b01111100, lengths 0 4 4 1
+    10, // 125 = 0x7D = 0b01111101, lengths 1 4 4 1
+    11, // 126 = 0x7E = 0b01111110, lengths 2 4 4 1
+    13, // 127 = 0x7F = 0b01111111, lengths 4 4 4 1
+    2, // 128 = 0x80 = 0b10000000, lengths 0 0 0 2
+    3, // 129 = 0x81 = 0b10000001, lengths 1 0 0 2
+    4, // 130 = 0x82 = 0b10000010, lengths 2 0 0 2
+    6, // 131 = 0x83 = 0b10000011, lengths 4 0 0 2
+    3, // 132 = 0x84 = 0b10000100, lengths 0 1 0 2
+    4, // 133 = 0x85 = 0b10000101, lengths 1 1 0 2
+    5, // 134 = 0x86 = 0b10000110, lengths 2 1 0 2
+    7, // 135 = 0x87 = 0b10000111, lengths 4 1 0 2
+    4, // 136 = 0x88 = 0b10001000, lengths 0 2 0 2
+    5, // 137 = 0x89 = 0b10001001, lengths 1 2 0 2
+    6, // 138 = 0x8A = 0b10001010, lengths 2 2 0 2
+    8, // 139 = 0x8B = 0b10001011, lengths 4 2 0 2
+    6, // 140 = 0x8C = 0b10001100, lengths 0 4 0 2
+    7, // 141 = 0x8D = 0b10001101, lengths 1 4 0 2
+    8, // 142 = 0x8E = 0b10001110, lengths 2 4 0 2
+    10, // 143 = 0x8F = 0b10001111, lengths 4 4 0 2
+    3, // 144 = 0x90 = 0b10010000, lengths 0 0 1 2
+    4, // 145 = 0x91 = 0b10010001, lengths 1 0 1 2
+    5, // 146 = 0x92 = 0b10010010, lengths 2 0 1 2
+    7, // 147 = 0x93 = 0b10010011, lengths 4 0 1 2
+    4, // 148 = 0x94 = 0b10010100, lengths 0 1 1 2
+    5, // 149 = 0x95 = 0b10010101, lengths 1 1 1 2
+    6, // 150 = 0x96 = 0b10010110, lengths 2 1 1 2
+    8, // 151 = 0x97 = 0b10010111, lengths 4 1 1 2
+    5, // 152 = 0x98 = 0b10011000, lengths 0 2 1 2
+    6, // 153 = 0x99 = 0b10011001, lengths 1 2 1 2
+    7, // 154 = 0x9A = 0b10011010, lengths 2 2 1 2
+    9, // 155 = 0x9B = 0b10011011, lengths 4 2 1 2
+    7, // 156 = 0x9C = 0b10011100, lengths 0 4 1 2
+    8, // 157 = 0x9D = 0b10011101, lengths 1 4 1 2
+    9, // 158 = 0x9E = 0b10011110, lengths 2 4 1 2
+    11, // 159 = 0x9F = 0b10011111, lengths 4 4 1 2
+    4, // 160 = 0xA0 = 0b10100000, lengths 0 0 2 2
+    5, // 161 = 0xA1 = 0b10100001, lengths 1 0 2 2
+    6, // 162 = 0xA2 = 0b10100010, lengths 2 0 2 2
+    8, // 163 = 0xA3 = 0b10100011, lengths 4 0 2 2
+    5, // 164 = 0xA4 = 0b10100100, lengths 0 1 2 2
+    6, // 165 = 0xA5 = 0b10100101, lengths 1 1 2 2
+    7, // 166 = 0xA6 = 0b10100110, lengths 2 1 2 2
+    9, // 167 = 0xA7 = 0b10100111, lengths 4 1 2 2
+    6, // 168 = 0xA8 = 0b10101000, lengths 0 2 2 2
+    7, // 169 = 0xA9 = 0b10101001, lengths 1 2 2 2
+    8, // 170 = 0xAA = 0b10101010, lengths 2 2 2 2
+    10, // 171 = 0xAB = 0b10101011, lengths 4 2 2 2
+    8, // 172 = 0xAC = 0b10101100, lengths 0 4 2 2
+    9, // 173 = 0xAD = 0b10101101, lengths 1 4 2 2
+    10, // 174 = 0xAE = 0b10101110, lengths 2 4 2 2
+    12, // 175 = 0xAF = 0b10101111, lengths 4 4 2 2
+    6, // 176 = 0xB0 = 0b10110000, lengths 0 0 4 2
+    7, // 177 = 0xB1 = 0b10110001, lengths 1 0 4 2
+    8, // 178 = 0xB2 = 0b10110010, lengths 2 0 4 2
+    10, // 179 = 0xB3 = 0b10110011, lengths 4 0 4 2
+    7, // 180 = 0xB4 = 0b10110100, lengths 0 1 4 2
+    8, // 181 = 0xB5 = 0b10110101, lengths 1 1 4 2
+    9, // 182 = 0xB6 = 0b10110110, lengths 2 1 4 2
+    11, // 183 = 0xB7 = 0b10110111, lengths 4 1 4 2
+    8, // 184 = 0xB8 = 0b10111000, lengths 0 2 4 2
+    9, // 185 = 0xB9 = 0b10111001, lengths 1 2 4 2
+    10, // 186 = 0xBA = 0b10111010, lengths 2 2 4 2
+    12, // 187 = 0xBB = 0b10111011, lengths 4 2 4 2
+    10, // 188 = 0xBC = 0b10111100, lengths 0 4 4 2
+    11, // 189 = 0xBD = 0b10111101, lengths 1 4 4 2
+    12, // 190 = 0xBE = 0b10111110, lengths 2 4 4 2
+    14, // 191 = 0xBF = 0b10111111, lengths 4 4 4 2
+    4, // 192 = 0xC0 = 0b11000000, lengths 0 0 0 4
+    5, // 193 = 0xC1 = 0b11000001, lengths 1 0 0 4
+    6, // 194 = 0xC2 = 0b11000010, lengths 2 0 0 4
+    8, // 195 = 0xC3 = 0b11000011, lengths 4 0 0 4
+    5, // 196 = 0xC4 = 0b11000100, lengths 0 1 0 4
+    6, // 197 = 0xC5 = 0b11000101, lengths 1 1 0 4
+    7, // 198 = 0xC6 = 0b11000110, lengths 2 1 0 4
+    9, // 199 = 0xC7 = 0b11000111, lengths 4 1 0 4
+    6, // 200 = 0xC8 = 0b11001000, lengths 0 2 0 4
+    7, // 201 = 0xC9 = 0b11001001, lengths 1 2 0 4
+    8, // 202 = 0xCA = 0b11001010, lengths 2 2 0 4
+    10, // 203 = 0xCB = 0b11001011, lengths 4 2 0 4
+    8, // 204 = 0xCC = 0b11001100, lengths 0 4 0 4
+    9, // 205 = 0xCD = 0b11001101, lengths 1 4 0 4
+    10, // 206 = 0xCE = 0b11001110, lengths 2 4 0 4
+    12, // 207 = 0xCF = 0b11001111, lengths 4 4 0 4
+    5, // 208 = 0xD0 = 0b11010000, lengths 0 0 1 4
+    6, // 209 = 0xD1 = 0b11010001, lengths 1 0 1 4
+    7, // 210 = 0xD2 = 0b11010010, lengths 2 0 1 4
+    9, // 211 = 0xD3 = 0b11010011, lengths 4 0 1 4
+    6, // 212 = 0xD4 = 0b11010100, lengths 0 1 1 4
+    7, // 213 = 0xD5 = 0b11010101, lengths 1 1 1 4
+    8, // 214 = 0xD6 = 0b11010110, lengths 2 1 1 4
+    10, // 215 = 0xD7 = 0b11010111, lengths 4 1 1 4
+    7, // 216 = 0xD8 = 0b11011000, lengths 0 2 1 4
+    8, // 217 = 0xD9 = 0b11011001, lengths 1 2 1 4
+    9, // 218 = 0xDA = 0b11011010, lengths 2 2 1 4
+    11, // 219 = 0xDB = 0b11011011, lengths 4 2 1 4
+    9, // 220 = 0xDC = 0b11011100, lengths 0 4 1 4
+    10, // 221 = 0xDD = 0b11011101, lengths 1 4 1 4
+    11, // 222 = 0xDE = 0b11011110, lengths 2 4 1 4
+    13, // 223 = 0xDF = 0b11011111, lengths 4 4 1 4
+    6, // 224 = 0xE0 = 0b11100000, lengths 0 0 2 4
+    7, // 225 = 0xE1 = 0b11100001, lengths 1 0 2 4
+    8, // 226 = 0xE2 = 0b11100010, lengths 2 0 2 4
+    10, // 227 = 0xE3 = 0b11100011, lengths 4 0 2 4
+    7, // 228 = 0xE4 = 0b11100100, lengths 0 1 2 4
+    8, // 229 = 0xE5 = 0b11100101, lengths 1 1 2 4
+    9, // 230 = 0xE6 = 0b11100110, lengths 2 1 2 4
+    11, // 231 = 0xE7 = 0b11100111, lengths 4 1 2 4
+    8, // 232 = 0xE8 = 0b11101000, lengths 0 2 2 4
+    9, // 233 = 0xE9 = 0b11101001, lengths 1 2 2 4
+    10, // 234 = 0xEA = 0b11101010, lengths 2 2 2 4
+    12, // 235 = 0xEB = 0b11101011, lengths 4 2 2 4
+    10, // 236 = 0xEC = 0b11101100, lengths 0 4 2 4
+    11, // 237 = 0xED = 0b11101101, lengths 1 4 2 4
+    12, // 238 = 0xEE = 0b11101110, lengths 2 4 2 4
+    14, // 239 = 0xEF = 0b11101111, lengths 4 4 2 4
+    8, // 240 = 0xF0 = 0b11110000, lengths 0 0 4 4
+    9, // 241 = 0xF1 = 0b11110001, lengths 1 0 4 4
+    10, // 242 = 0xF2 = 0b11110010, lengths 2 0 4 4
+    12, // 243 = 0xF3 = 0b11110011, lengths 4 0 4 4
+    9, // 244 = 0xF4 = 0b11110100, lengths 0 1 4 4
+    10, // 245 = 0xF5 = 0b11110101, lengths 1 1 4 4
+    11, // 246 = 0xF6 = 0b11110110, lengths 2 1 4 4
+    13, // 247 = 0xF7 = 0b11110111, lengths 4 1 4 4
+    10, // 248 = 0xF8 = 0b11111000, lengths 0 2 4 4
+    11, // 249 = 0xF9 = 0b11111001, lengths 1 2 4 4
+    12, // 250 = 0xFA = 0b11111010, lengths 2 2 4 4
+    14, // 251 = 0xFB = 0b11111011, lengths 4 2 4 4
+    12, // 252 = 0xFC = 0b11111100, lengths 0 4 4 4
+    13, // 253 = 0xFD = 0b11111101, lengths 1 4 4 4
+    14, // 254 = 0xFE = 0b11111110, lengths 2 4 4 4
+    16, // 255 = 0xFF = 0b11111111, lengths 4 4 4 4
+];
+
+#[rustfmt::skip]
+#[cfg(any(feature = "x86_ssse3", feature = "aarch64_neon"))]
+pub const DECODE_0124_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[
+    // 0 = 0x0 = 0b00000000, lengths 0 0 0 0
+    [ 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 1 = 0x1 = 0b00000001, lengths 1 0 0 0
+    [   0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 2 = 0x2 = 0b00000010, lengths 2 0 0 0
+    [   0,   1, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 3 = 0x3 = 0b00000011, lengths 4 0 0 0
+    [   0,   1,   2,   3, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 4 = 0x4 = 0b00000100, lengths 0 1 0 0
+    [ 128, 128, 128, 128,   0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 5 = 0x5 = 0b00000101, lengths 1 1 0 0
+    [   0, 128, 128, 128,   1, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 6 = 0x6 = 0b00000110, lengths 2 1 0 0
+    [   0,   1, 128, 128,   2, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 7 = 0x7 = 0b00000111, lengths 4 1 0 0
+    [   0,   1,   2,   3,   4, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 8 = 0x8 = 0b00001000, lengths 0 2 0 0
+    [ 128, 128, 128, 128,   0,   1, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 9 = 0x9 = 0b00001001, lengths 1 2 0 0
+    [   0, 128, 128, 128,   1,   2, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 10 = 0xA = 0b00001010, lengths 2 2 0 0
+    [   0,   1, 128, 128,   2,   3, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 11 = 0xB = 0b00001011, lengths 4 2 0 0
+    [   0,   1,   2,   3,   4,   5, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 12 = 0xC = 0b00001100, lengths 0 4 0 0
+    [ 128, 128, 128, 128,   0,   1,   2,   3, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 13 = 0xD = 0b00001101, lengths 1 4 0 0
+    [   0, 128, 128, 128,   1,   2,   3,   4, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 14 = 0xE = 0b00001110, lengths 2 4 0 0
+    [   0,   1, 128, 128,   2,   3,   4,   5, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 15 = 0xF = 0b00001111, lengths 4 4 0 0
+    [   0,   1,   2,   3,   4,   5,   6,   7, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 16 = 0x10 = 0b00010000, lengths 0 0 1 0
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0, 128, 128, 128, 128, 128, 128, 128],
+    // 17 = 0x11 = 0b00010001, lengths 1 0 1 0
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1, 128, 128, 128, 128, 128, 128, 128],
+    // 18 = 0x12 = 0b00010010, lengths 2 0 1 0
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2, 128, 128, 128, 128, 128, 128, 128],
+    // 19 = 0x13 = 0b00010011, lengths 4 0 1 0
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4, 128, 128, 128, 128, 128, 128, 128],
+    // 20 = 0x14 = 0b00010100, lengths 0 1 1 0
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1, 128, 128, 128, 128, 128, 128, 128],
+    // 21 = 0x15 = 0b00010101, lengths 1 1 1 0
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2, 128, 128, 128, 128, 128, 128, 128],
+    // 22 = 0x16 = 0b00010110, lengths 2 1 1 0
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3, 128, 128, 128, 128, 128, 128, 128],
+    // 23 = 0x17 = 0b00010111, lengths 4 1 1 0
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5, 128, 128, 128, 128, 128, 128, 128],
+    // 24 = 0x18 = 0b00011000, lengths 0 2 1 0
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2, 128, 128, 128, 128, 128, 128, 128],
+    // 25 = 0x19 = 0b00011001, lengths 1 2 1 0
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3, 128, 128, 128, 128, 128, 128, 128],
+    // 26 = 0x1A = 0b00011010, lengths 2 2 1 0
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4, 128, 128, 128, 128, 128, 128, 128],
+    // 27 = 0x1B = 0b00011011, lengths 4 2 1 0
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6, 128, 128, 128, 128, 128, 128, 128],
+    // 28 = 0x1C = 0b00011100, lengths 0 4 1 0
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4, 128, 128, 128, 128, 128, 128, 128],
+    // 29 = 0x1D = 0b00011101, lengths 1 4 1 0
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5, 128, 128, 128, 128, 128, 128, 128],
+    // 30 = 0x1E = 0b00011110, lengths 2 4 1 0
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6, 128, 128, 128, 128, 128, 128, 128],
+    // 31 = 0x1F = 0b00011111, lengths 4 4 1 0
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128, 128, 128, 128, 128],
+    // 32 = 0x20 = 0b00100000, lengths 0 0 2 0
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0,   1, 128, 128, 128, 128, 128, 128],
+    // 33 = 0x21 = 0b00100001, lengths 1 0 2 0
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2, 128, 128, 128, 128, 128, 128],
+    // 34 = 0x22 = 0b00100010, lengths 2 0 2 0
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3, 128, 128, 128, 128, 128, 128],
+    // 35 = 0x23 = 0b00100011, lengths 4 0 2 0
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5, 128, 128, 128, 128, 128, 128],
+    // 36 = 0x24 = 0b00100100, lengths 0 1 2 0
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1,   2, 128, 128, 128, 128, 128, 128],
+    // 37 = 0x25 = 0b00100101, lengths 1 1 2 0
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2,   3, 128, 128, 128, 128, 128, 128],
+    // 38 = 0x26 = 0b00100110, lengths 2 1 2 0
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3,   4, 128, 128, 128, 128, 128, 128],
+    // 39 = 0x27 = 0b00100111, lengths 4 1 2 0
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5,   6, 128, 128, 128, 128, 128, 128],
+    // 40 = 0x28 = 0b00101000, lengths 0 2 2 0
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2,   3, 128, 128, 128, 128, 128, 128],
+    // 41 = 0x29 = 0b00101001, lengths 1 2 2 0
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3,   4, 128, 128, 128, 128, 128, 128],
+    // 42 = 0x2A = 0b00101010, lengths 2 2 2 0
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4,   5, 128, 128, 128, 128, 128, 128],
+    // 43 = 0x2B = 0b00101011, lengths 4 2 2 0
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6,   7, 128, 128, 128, 128, 128, 128],
+    // 44 = 0x2C = 0b00101100, lengths 0 4 2 0
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4,   5, 128, 128, 128, 128, 128, 128],
+    // 45 = 0x2D = 0b00101101, lengths 1 4 2 0
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5,   6, 128, 128, 128, 128, 128, 128],
+    // 46 = 0x2E = 0b00101110, lengths 2 4 2 0
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6,   7, 128, 128, 128, 128, 128, 128],
+    // 47 = 0x2F = 0b00101111, lengths 4 4 2 0
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128, 128, 128, 128, 128],
+    // 48 = 0x30 = 0b00110000, lengths 0 0 4 0
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0,   1,   2,   3, 128, 128, 128, 128],
+    // 49 = 0x31 = 0b00110001, lengths 1 0 4 0
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2,   3,   4, 128, 128, 128, 128],
+    // 50 = 0x32 = 0b00110010, lengths 2 0 4 0
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5, 128, 128, 128, 128],
+    // 51 = 0x33 = 0b00110011, lengths 4 0 4 0
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5,   6,   7, 128, 128, 128, 128],
+    // 52 = 0x34 = 0b00110100, lengths 0 1 4 0
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1,   2,   3,   4, 128, 128, 128, 128],
+    // 53 = 0x35 = 0b00110101, lengths 1 1 4 0
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2,   3,   4,   5, 128, 128, 128, 128],
+    // 54 = 0x36 = 0b00110110, lengths 2 1 4 0
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3,   4,   5,   6, 128, 128, 128, 128],
+    // 55 = 0x37 = 0b00110111, lengths 4 1 4 0
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5,   6,   7,   8, 128, 128, 128, 128],
+    // 56 = 0x38 = 0b00111000, lengths 0 2 4 0
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2,   3,   4,   5, 128, 128, 128, 128],
+    // 57 = 0x39 = 0b00111001, lengths 1 2 4 0
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3,   4,   5,   6, 128, 128, 128, 128],
+    // 58 = 0x3A = 0b00111010, lengths 2 2 4 0
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4,   5,   6,   7, 128, 128, 128, 128],
+    // 59 = 0x3B = 0b00111011, lengths 4 2 4 0
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6,   7,   8,   9, 128, 128, 128, 128],
+    // 60 = 0x3C = 0b00111100, lengths 0 4 4 0
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4,   5,   6,   7, 128, 128, 128, 128],
+    // 61 = 0x3D = 0b00111101, lengths 1 4 4 0
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128, 128],
+    // 62 = 0x3E = 0b00111110, lengths 2 4 4 0
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128, 128, 128],
+    // 63 = 0x3F = 0b00111111, lengths 4 4 4 0
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128, 128, 128, 128],
+    // 64 = 0x40 = 0b01000000, lengths 0 0 0 1
+    [ 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   0, 128, 128, 128],
+    // 65 = 0x41 = 0b01000001, lengths 1 0 0 1
+    [   0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   1, 128, 128, 128],
+    // 66 = 0x42 = 0b01000010, lengths 2 0 0 1
+    [   0,   1, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   2, 128, 128, 128],
+    // 67 = 0x43 = 0b01000011, lengths 4 0 0 1
+    [   0,   1,   2,   3, 128, 128, 128, 128, 128, 128, 128, 128,   4, 128, 128, 128],
+    // 68 = 0x44 = 0b01000100, lengths 0 1 0 1
+    [ 128, 128, 128, 128,   0, 128, 128, 128, 128, 128, 128, 128,   1, 128, 128, 128],
+    // 69 = 0x45 = 0b01000101, lengths 1 1 0 1
+    [   0, 128, 128, 128,   1, 128, 128, 128, 128, 128, 128, 128,   2, 128, 128, 128],
+    // 70 = 0x46 = 0b01000110, lengths 2 1 0 1
+    [   0,   1, 128, 128,   2, 128, 128, 128, 128, 128, 128, 128,   3, 128, 128, 128],
+    // 71 = 0x47 = 0b01000111, lengths 4 1 0 1
+    [   0,   1,   2,   3,   4, 128, 128, 128, 128, 128, 128, 128,   5, 128, 128, 128],
+    // 72 = 0x48 = 0b01001000, lengths 0 2 0 1
+    [ 128, 128, 128, 128,   0,   1, 128, 128, 128, 128, 128, 128,   2, 128, 128, 128],
+    // 73 = 0x49 = 0b01001001, lengths 1 2 0 1
+    [   0, 128, 128, 128,   1,   2, 128, 128, 128, 128, 128, 128,   3, 128, 128, 128],
+    // 74 = 0x4A = 0b01001010, lengths 2 2 0 1
+    [   0,   1, 128, 128,   2,   3, 128, 128, 128, 128, 128, 128,   4, 128, 128, 128],
+    // 75 = 0x4B = 0b01001011, lengths 4 2 0 1
+    [   0,   1,   2,   3,   4,   5, 128, 128, 128, 128, 128, 128,   6, 128, 128, 128],
+    // 76 = 0x4C = 0b01001100, lengths 0 4 0 1
+    [ 128, 128, 128, 128,   0,   1,   2,   3, 128, 128, 128, 128,   4, 128, 128, 128],
+    // 77 = 0x4D = 0b01001101, lengths 1 4 0 1
+    [   0, 128, 128, 128,   1,   2,   3,   4, 128, 128, 128, 128,   5, 128, 128, 128],
+    // 78 = 0x4E = 0b01001110, lengths 2 4 0 1
+    [   0,   1, 128, 128,   2,   3,   4,   5, 128, 128, 128, 128,   6, 128, 128, 128],
+    // 79 = 0x4F = 0b01001111, lengths 4 4 0 1
+    [   0,   1,   2,   3,   4,   5,   6,   7, 128, 128, 128, 128,   8, 128, 128, 128],
+    // 80 = 0x50 = 0b01010000, lengths 0 0 1 1
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0, 128, 128, 128,   1, 128, 128, 128],
+    // 81 = 0x51 = 0b01010001, lengths 1 0 1 1
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1, 128, 128, 128,   2, 128, 128, 128],
+    // 82 = 0x52 = 0b01010010, lengths 2 0 1 1
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2, 128, 128, 128,   3, 128, 128, 128],
+    // 83 = 0x53 = 0b01010011, lengths 4 0 1 1
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4, 128, 128, 128,   5, 128, 128, 128],
+    // 84 = 0x54 = 0b01010100, lengths 0 1 1 1
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1, 128, 128, 128,   2, 128, 128, 128],
+    // 85 = 0x55 = 0b01010101, lengths 1 1 1 1
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2, 128, 128, 128,   3, 128, 128, 128],
+    // 86 = 0x56 = 0b01010110, lengths 2 1 1 1
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3, 128, 128, 128,   4, 128, 128, 128],
+    // 87 = 0x57 = 0b01010111, lengths 4 1 1 1
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5, 128, 128, 128,   6, 128, 128, 128],
+    // 88 = 0x58 = 0b01011000, lengths 0 2 1 1
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2, 128, 128, 128,   3, 128, 128, 128],
+    // 89 = 0x59 = 0b01011001, lengths 1 2 1 1
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3, 128, 128, 128,   4, 128, 128, 128],
+    // 90 = 0x5A = 0b01011010, lengths 2 2 1 1
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4, 128, 128, 128,   5, 128, 128, 128],
+    // 91 = 0x5B = 0b01011011, lengths 4 2 1 1
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6, 128, 128, 128,   7, 128, 128, 128],
+    // 92 = 0x5C = 0b01011100, lengths 0 4 1 1
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4, 128, 128, 128,   5, 128, 128, 128],
+    // 93 = 0x5D = 0b01011101, lengths 1 4 1 1
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5, 128, 128, 128,   6, 128, 128, 128],
+    // 94 = 0x5E = 0b01011110, lengths 2 4 1 1
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6, 128, 128, 128,   7, 128, 128, 128],
+    // 95 = 0x5F = 0b01011111, lengths 4 4 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128,   9, 128, 128, 128],
+    // 96 = 0x60 = 0b01100000, lengths 0 0 2 1
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0,   1, 128, 128,   2, 128, 128, 128],
+    // 97 = 0x61 = 0b01100001, lengths 1 0 2 1
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2, 128, 128,   3, 128, 128, 128],
+    // 98 = 0x62 = 0b01100010, lengths 2 0 2 1
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3, 128, 128,   4, 128, 128, 128],
+    // 99 = 0x63 = 0b01100011, lengths 4 0 2 1
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5, 128, 128,   6, 128, 128, 128],
+    // 100 = 0x64 = 0b01100100, lengths 0 1 2 1
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1,   2, 128, 128,   3, 128, 128, 128],
+    // 101 = 0x65 = 0b01100101, lengths 1 1 2 1
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2,   3, 128, 128,   4, 128, 128, 128],
+    // 102 = 0x66 = 0b01100110, lengths 2 1 2 1
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3,   4, 128, 128,   5, 128, 128, 128],
+    // 103 = 0x67 = 0b01100111, lengths 4 1 2 1
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5,   6, 128, 128,   7, 128, 128, 128],
+    // 104 = 0x68 = 0b01101000, lengths 0 2 2 1
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2,   3, 128, 128,   4, 128, 128, 128],
+    // 105 = 0x69 = 0b01101001, lengths 1 2 2 1
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3,   4, 128, 128,   5, 128, 128, 128],
+    // 106 = 0x6A = 0b01101010, lengths 2 2 2 1
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4,   5, 128, 128,   6, 128, 128, 128],
+    // 107 = 0x6B = 0b01101011, lengths 4 2 2 1
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6,   7, 128, 128,   8, 128, 128, 128],
+    // 108 = 0x6C = 0b01101100, lengths 0 4 2 1
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4,   5, 128, 128,   6, 128, 128, 128],
+    // 109 = 0x6D = 0b01101101, lengths 1 4 2 1
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5,   6, 128, 128,   7, 128, 128, 128],
+    // 110 = 0x6E = 0b01101110, lengths 2 4 2 1
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6,   7, 128, 128,   8, 128, 128, 128],
+    // 111 = 0x6F = 0b01101111, lengths 4 4 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128,  10, 128, 128, 128],
+    // 112 = 0x70 = 0b01110000, lengths 0 0 4 1
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0,   1,   2,   3,   4, 128, 128, 128],
+    // 113 = 0x71 = 0b01110001, lengths 1 0 4 1
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2,   3,   4,   5, 128, 128, 128],
+    // 114 = 0x72 = 0b01110010, lengths 2 0 4 1
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5,   6, 128, 128, 128],
+    // 115 = 0x73 = 0b01110011, lengths 4 0 4 1
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5,   6,   7,   8, 128, 128, 128],
+    // 116 = 0x74 = 0b01110100, lengths 0 1 4 1
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1,   2,   3,   4,   5, 128, 128, 128],
+    // 117 = 0x75 = 0b01110101, lengths 1 1 4 1
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2,   3,   4,   5,   6, 128, 128, 128],
+    // 118 = 0x76 = 0b01110110, lengths 2 1 4 1
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3,   4,   5,   6,   7, 128, 128, 128],
+    // 119 = 0x77 = 0b01110111, lengths 4 1 4 1
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5,   6,   7,   8,   9, 128, 128, 128],
+    // 120 = 0x78 = 0b01111000, lengths 0 2 4 1
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2,   3,   4,   5,   6, 128, 128, 128],
+    // 121 = 0x79 = 0b01111001, lengths 1 2 4 1
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3,   4,   5,   6,   7, 128, 128, 128],
+    // 122 = 0x7A = 0b01111010, lengths 2 2 4 1
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4,   5,   6,   7,   8, 128, 128, 128],
+    // 123 = 0x7B = 0b01111011, lengths 4 2 4 1
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6,   7,   8,   9,  10, 128, 128, 128],
+    // 124 = 0x7C = 0b01111100, lengths 0 4 4 1
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128],
+    // 125 = 0x7D = 0b01111101, lengths 1 4 4 1
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128, 128],
+    // 126 = 0x7E = 0b01111110, lengths 2 4 4 1
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6,   7,   8,   9,  10, 128, 128, 128],
+    // 127 = 0x7F = 0b01111111, lengths 4 4 4 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128, 128, 128],
+    // 128 = 0x80 = 0b10000000, lengths 0 0 0 2
+    [ 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   0,   1, 128, 128],
+    // 129 = 0x81 = 0b10000001, lengths 1 0 0 2
+    [   0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   1,   2, 128, 128],
+    // 130 = 0x82 = 0b10000010, lengths 2 0 0 2
+    [   0,   1, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   2,   3, 128, 128],
+    // 131 = 0x83 = 0b10000011, lengths 4 0 0 2
+    [   0,   1,   2,   3, 128, 128, 128, 128, 128, 128, 128, 128,   4,   5, 128, 128],
+    // 132 = 0x84 = 0b10000100, lengths 0 1 0 2
+    [ 128, 128, 128, 128,   0, 128, 128, 128, 128, 128, 128, 128,   1,   2, 128, 128],
+    // 133 = 0x85 = 0b10000101, lengths 1 1 0 2
+    [   0, 128, 128, 128,   1, 128, 128, 128, 128, 128, 128, 128,   2,   3, 128, 128],
+    // 134 = 0x86 = 0b10000110, lengths 2 1 0 2
+    [   0,   1, 128, 128,   2, 128, 128, 128, 128, 128, 128, 128,   3,   4, 128, 128],
+    // 135 = 0x87 = 0b10000111, lengths 4 1 0 2
+    [   0,   1,   2,   3,   4, 128, 128, 128, 128, 128, 128, 128,   5,   6, 128, 128],
+    // 136 = 0x88 = 0b10001000, lengths 0 2 0 2
+    [ 128, 128, 128, 128,   0,   1, 128, 128, 128, 128, 128, 128,   2,   3, 128, 128],
+    // 137 = 0x89 = 0b10001001, lengths 1 2 0 2
+    [   0, 128, 128, 128,   1,   2, 128, 128, 128, 128, 128, 128,   3,   4, 128, 128],
+    // 138 = 0x8A = 0b10001010, lengths 2 2 0 2
+    [   0,   1, 128, 128,   2,   3, 128, 128, 128, 128, 128, 128,   4,   5, 128, 128],
+    // 139 = 0x8B = 0b10001011, lengths 4 2 0 2
+    [   0,   1,   2,   3,   4,   5, 128, 128, 128, 128, 128, 128,   6,   7, 128, 128],
+    // 140 = 0x8C = 0b10001100, lengths 0 4 0 2
+    [ 128, 128, 128, 128,   0,   1,   2,   3, 128, 128, 128, 128,   4,   5, 128, 128],
+    // 141 = 0x8D = 0b10001101, lengths 1 4 0 2
+    [   0, 128, 128, 128,   1,   2,   3,   4, 128, 128, 128, 128,   5,   6, 128, 128],
+    // 142 = 0x8E = 0b10001110, lengths 2 4 0 2
+    [   0,   1, 128, 128,   2,   3,   4,   5, 128, 128, 128, 128,   6,   7, 128, 128],
+    // 143 = 0x8F = 0b10001111, lengths 4 4 0 2
+    [   0,   1,   2,   3,   4,   5,   6,   7, 128, 128, 128, 128,   8,   9, 128, 128],
+    // 144 = 0x90 = 0b10010000, lengths 0 0 1 2
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0, 128, 128, 128,   1,   2, 128, 128],
+    // 145 = 0x91 = 0b10010001, lengths 1 0 1 2
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1, 128, 128, 128,   2,   3, 128, 128],
+    // 146 = 0x92 = 0b10010010, lengths 2 0 1 2
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2, 128, 128, 128,   3,   4, 128, 128],
+    // 147 = 0x93 = 0b10010011, lengths 4 0 1 2
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4, 128, 128, 128,   5,   6, 128, 128],
+    // 148 = 0x94 = 0b10010100, lengths 0 1 1 2
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1, 128, 128, 128,   2,   3, 128, 128],
+    // 149 = 0x95 = 0b10010101, lengths 1 1 1 2
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2, 128, 128, 128,   3,   4, 128, 128],
+    // 150 = 0x96 = 0b10010110, lengths 2 1 1 2
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3, 128, 128, 128,   4,   5, 128, 128],
+    // 151 = 0x97 = 0b10010111, lengths 4 1 1 2
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5, 128, 128, 128,   6,   7, 128, 128],
+    // 152 = 0x98 = 0b10011000, lengths 0 2 1 2
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2, 128, 128, 128,   3,   4, 128, 128],
+    // 153 = 0x99 = 0b10011001, lengths 1 2 1 2
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3, 128, 128, 128,   4,   5, 128, 128],
+    // 154 = 0x9A = 0b10011010, lengths 2 2 1 2
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4, 128, 128, 128,   5,   6, 128, 128],
+    // 155 = 0x9B = 0b10011011, lengths 4 2 1 2
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6, 128, 128, 128,   7,   8, 128, 128],
+    // 156 = 0x9C = 0b10011100, lengths 0 4 1 2
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4, 128, 128, 128,   5,   6, 128, 128],
+    // 157 = 0x9D = 0b10011101, lengths 1 4 1 2
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5, 128, 128, 128,   6,   7, 128, 128],
+    // 158 = 0x9E = 0b10011110, lengths 2 4 1 2
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6, 128, 128, 128,   7,   8, 128, 128],
+    // 159 = 0x9F = 0b10011111, lengths 4 4 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128,   9,  10, 128, 128],
+    // 160 = 0xA0 = 0b10100000, lengths 0 0 2 2
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0,   1, 128, 128,   2,   3, 128, 128],
+    // 161 = 0xA1 = 0b10100001, lengths 1 0 2 2
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2, 128, 128,   3,   4, 128, 128],
+    // 162 = 0xA2 = 0b10100010, lengths 2 0 2 2
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3, 128, 128,   4,   5, 128, 128],
+    // 163 = 0xA3 = 0b10100011, lengths 4 0 2 2
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5, 128, 128,   6,   7, 128, 128],
+    // 164 = 0xA4 = 0b10100100, lengths 0 1 2 2
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1,   2, 128, 128,   3,   4, 128, 128],
+    // 165 = 0xA5 = 0b10100101, lengths 1 1 2 2
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2,   3, 128, 128,   4,   5, 128, 128],
+    // 166 = 0xA6 = 0b10100110, lengths 2 1 2 2
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3,   4, 128, 128,   5,   6, 128, 128],
+    // 167 = 0xA7 = 0b10100111, lengths 4 1 2 2
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5,   6, 128, 128,   7,   8, 128, 128],
+    // 168 = 0xA8 = 0b10101000, lengths 0 2 2 2
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2,   3, 128, 128,   4,   5, 128, 128],
+    // 169 = 0xA9 = 0b10101001, lengths 1 2 2 2
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3,   4, 128, 128,   5,   6, 128, 128],
+    // 170 = 0xAA = 0b10101010, lengths 2 2 2 2
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4,   5, 128, 128,   6,   7, 128, 128],
+    // 171 = 0xAB = 0b10101011, lengths 4 2 2 2
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6,   7, 128, 128,   8,   9, 128, 128],
+    // 172 = 0xAC = 0b10101100, lengths 0 4 2 2
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4,   5, 128, 128,   6,   7, 128, 128],
+    // 173 = 0xAD = 0b10101101, lengths 1 4 2 2
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5,   6, 128, 128,   7,   8, 128, 128],
+    // 174 = 0xAE = 0b10101110, lengths 2 4 2 2
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6,   7, 128, 128,   8,   9, 128, 128],
+    // 175 = 0xAF = 0b10101111, lengths 4 4 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128,  10,  11, 128, 128],
+    // 176 = 0xB0 = 0b10110000, lengths 0 0 4 2
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0,   1,   2,   3,   4,   5, 128, 128],
+    // 177 = 0xB1 = 0b10110001, lengths 1 0 4 2
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2,   3,   4,   5,   6, 128, 128],
+    // 178 = 0xB2 = 0b10110010, lengths 2 0 4 2
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5,   6,   7, 128, 128],
+    // 179 = 0xB3 = 0b10110011, lengths 4 0 4 2
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5,   6,   7,   8,   9, 128, 128],
+    // 180 = 0xB4 = 0b10110100, lengths 0 1 4 2
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1,   2,   3,   4,   5,   6, 128, 128],
+    // 181 = 0xB5 = 0b10110101, lengths 1 1 4 2
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2,   3,   4,   5,   6,   7, 128, 128],
+    // 182 = 0xB6 = 0b10110110, lengths 2 1 4 2
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3,   4,   5,   6,   7,   8, 128, 128],
+    // 183 = 0xB7 = 0b10110111, lengths 4 1 4 2
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5,   6,   7,   8,   9,  10, 128, 128],
+    // 184 = 0xB8 = 0b10111000, lengths 0 2 4 2
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2,   3,   4,   5,   6,   7, 128, 128],
+    // 185 = 0xB9 = 0b10111001, lengths 1 2 4 2
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3,   4,   5,   6,   7,   8, 128, 128],
+    // 186 = 0xBA = 0b10111010, lengths 2 2 4 2
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4,   5,   6,   7,   8,   9, 128, 128],
+    // 187 = 0xBB = 0b10111011, lengths 4 2 4 2
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6,   7,   8,   9,  10,  11, 128, 128],
+    // 188 = 0xBC = 0b10111100, lengths 0 4 4 2
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128],
+    // 189 = 0xBD = 0b10111101, lengths 1 4 4 2
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10, 128, 128],
+    // 190 = 0xBE = 0b10111110, lengths 2 4 4 2
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128, 128],
+    // 191 = 0xBF = 0b10111111, lengths 4 4 4 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13, 128, 128],
+    // 192 = 0xC0 = 0b11000000, lengths 0 0 0 4
+    [ 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   0,   1,   2,   3],
+    // 193 = 0xC1 = 0b11000001, lengths 1 0 0 4
+    [   0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   1,   2,   3,   4],
+    // 194 = 0xC2 = 0b11000010, lengths 2 0 0 4
+    [   0,   1, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5],
+    // 195 = 0xC3 = 0b11000011, lengths 4 0 0 4
+    [   0,   1,   2,   3, 128, 128, 128, 128, 128, 128, 128, 128,   4,   5,   6,   7],
+    // 196 = 0xC4 = 0b11000100, lengths 0 1 0 4
+    [ 128, 128, 128, 128,   0, 128, 128, 128, 128, 128, 128, 128,   1,   2,   3,   4],
+    // 197 = 0xC5 = 0b11000101, lengths 1 1 0 4
+    [   0, 128, 128, 128,   1, 128, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5],
+    // 198 = 0xC6 = 0b11000110, lengths 2 1 0 4
+    [   0,   1, 128, 128,   2, 128, 128, 128, 128, 128, 128, 128,   3,   4,   5,   6],
+    // 199 = 0xC7 = 0b11000111, lengths 4 1 0 4
+    [   0,   1,   2,   3,   4, 128, 128, 128, 128, 128, 128, 128,   5,   6,   7,   8],
+    // 200 = 0xC8 = 0b11001000, lengths 0 2 0 4
+    [ 128, 128, 128, 128,   0,   1, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5],
+    // 201 = 0xC9 = 0b11001001, lengths 1 2 0 4
+    [   0, 128, 128, 128,   1,   2, 128, 128, 128, 128, 128, 128,   3,   4,   5,   6],
+    // 202 = 0xCA = 0b11001010, lengths 2 2 0 4
+    [   0,   1, 128, 128,   2,   3, 128, 128, 128, 128, 128, 128,   4,   5,   6,   7],
+    // 203 = 0xCB = 0b11001011, lengths 4 2 0 4
+    [   0,   1,   2,   3,   4,   5, 128, 128, 128, 128, 128, 128,   6,   7,   8,   9],
+    // 204 = 0xCC = 0b11001100, lengths 0 4 0 4
+    [ 128, 128, 128, 128,   0,   1,   2,   3, 128, 128, 128, 128,   4,   5,   6,   7],
+    // 205 = 0xCD = 0b11001101, lengths 1 4 0 4
+    [   0, 128, 128, 128,   1,   2,   3,   4, 128, 128, 128, 128,   5,   6,   7,   8],
+    // 206 = 0xCE = 0b11001110, lengths 2 4 0 4
+    [   0,   1, 128, 128,   2,   3,   4,   5, 128, 128, 128, 128,   6,   7,   8,   9],
+    // 207 = 0xCF = 0b11001111, lengths 4 4 0 4
+    [   0,   1,   2,   3,   4,   5,   6,   7, 128, 128, 128, 128,   8,   9,  10,  11],
+    // 208 = 0xD0 = 0b11010000, lengths 0 0 1 4
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0, 128, 128, 128,   1,   2,   3,   4],
+    // 209 = 0xD1 = 0b11010001, lengths 1 0 1 4
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1, 128, 128, 128,   2,   3,   4,   5],
+    // 210 = 0xD2 = 0b11010010, lengths 2 0 1 4
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2, 128, 128, 128,   3,   4,   5,   6],
+    // 211 = 0xD3 = 0b11010011, lengths 4 0 1 4
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4, 128, 128, 128,   5,   6,   7,   8],
+    // 212 = 0xD4 = 0b11010100, lengths 0 1 1 4
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1, 128, 128, 128,   2,   3,   4,   5],
+    // 213 = 0xD5 = 0b11010101, lengths 1 1 1 4
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2, 128, 128, 128,   3,   4,   5,   6],
+    // 214 = 0xD6 = 0b11010110, lengths 2 1 1 4
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3, 128, 128, 128,   4,   5,   6,   7],
+    // 215 = 0xD7 = 0b11010111, lengths 4 1 1 4
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5, 128, 128, 128,   6,   7,   8,   9],
+    // 216 = 0xD8 = 0b11011000, lengths 0 2 1 4
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2, 128, 128, 128,   3,   4,   5,   6],
+    // 217 = 0xD9 = 0b11011001, lengths 1 2 1 4
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3, 128, 128, 128,   4,   5,   6,   7],
+    // 218 = 0xDA = 0b11011010, lengths 2 2 1 4
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4, 128, 128, 128,   5,   6,   7,   8],
+    // 219 = 0xDB = 0b11011011, lengths 4 2 1 4
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6, 128, 128, 128,   7,   8,   9,  10],
+    // 220 = 0xDC = 0b11011100, lengths 0 4 1 4
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4, 128, 128, 128,   5,   6,   7,   8],
+    // 221 = 0xDD = 0b11011101, lengths 1 4 1 4
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5, 128, 128, 128,   6,   7,   8,   9],
+    // 222 = 0xDE = 0b11011110, lengths 2 4 1 4
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6, 128, 128, 128,   7,   8,   9,  10],
+    // 223 = 0xDF = 0b11011111, lengths 4 4 1 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128,   9,  10,  11,  12],
+    // 224 = 0xE0 = 0b11100000, lengths 0 0 2 4
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0,   1, 128, 128,   2,   3,   4,   5],
+    // 225 = 0xE1 = 0b11100001, lengths 1 0 2 4
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2, 128, 128,   3,   4,   5,   6],
+    // 226 = 0xE2 = 0b11100010, lengths 2 0 2 4
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3, 128, 128,   4,   5,   6,   7],
+    // 227 = 0xE3 = 0b11100011, lengths 4 0 2 4
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5, 128, 128,   6,   7,   8,   9],
+    // 228 = 0xE4 = 0b11100100, lengths 0 1 2 4
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1,   2, 128, 128,   3,   4,   5,   6],
+    // 229 = 0xE5 = 0b11100101, lengths 1 1 2 4
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2,   3, 128, 128,   4,   5,   6,   7],
+    // 230 = 0xE6 = 0b11100110, lengths 2 1 2 4
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3,   4, 128, 128,   5,   6,   7,   8],
+    // 231 = 0xE7 = 0b11100111, lengths 4 1 2 4
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5,   6, 128, 128,   7,   8,   9,  10],
+    // 232 = 0xE8 = 0b11101000, lengths 0 2 2 4
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2,   3, 128, 128,   4,   5,   6,   7],
+    // 233 = 0xE9 = 0b11101001, lengths 1 2 2 4
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3,   4, 128, 128,   5,   6,   7,   8],
+    // 234 = 0xEA = 0b11101010, lengths 2 2 2 4
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4,   5, 128, 128,   6,   7,   8,   9],
+    // 235 = 0xEB = 0b11101011, lengths 4 2 2 4
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6,   7, 128, 128,   8,   9,  10,  11],
+    // 236 = 0xEC = 0b11101100, lengths 0 4 2 4
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4,   5, 128, 128,   6,   7,   8,   9],
+    // 237 = 0xED = 0b11101101, lengths 1 4 2 4
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5,   6, 128, 128,   7,   8,   9,  10],
+    // 238 = 0xEE = 0b11101110, lengths 2 4 2 4
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6,   7, 128, 128,   8,   9,  10,  11],
+    // 239 = 0xEF = 0b11101111, lengths 4 4 2 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128,  10,  11,  12,  13],
+    // 240 = 0xF0 = 0b11110000, lengths 0 0 4 4
+    [ 128, 128, 128, 128, 128, 128, 128, 128,   0,   1,   2,   3,   4,   5,   6,   7],
+    // 241 = 0xF1 = 0b11110001, lengths 1 0 4 4
+    [   0, 128, 128, 128, 128, 128, 128, 128,   1,   2,   3,   4,   5,   6,   7,   8],
+    // 242 = 0xF2 = 0b11110010, lengths 2 0 4 4
+    [   0,   1, 128, 128, 128, 128, 128, 128,   2,   3,   4,   5,   6,   7,   8,   9],
+    // 243 = 0xF3 = 0b11110011, lengths 4 0 4 4
+    [   0,   1,   2,   3, 128, 128, 128, 128,   4,   5,   6,   7,   8,   9,  10,  11],
+    // 244 = 0xF4 = 0b11110100, lengths 0 1 4 4
+    [ 128, 128, 128, 128,   0, 128, 128, 128,   1,   2,   3,   4,   5,   6,   7,   8],
+    // 245 = 0xF5 = 0b11110101, lengths 1 1 4 4
+    [   0, 128, 128, 128,   1, 128, 128, 128,   2,   3,   4,   5,   6,   7,   8,   9],
+    // 246 = 0xF6 = 0b11110110, lengths 2 1 4 4
+    [   0,   1, 128, 128,   2, 128, 128, 128,   3,   4,   5,   6,   7,   8,   9,  10],
+    // 247 = 0xF7 = 0b11110111, lengths 4 1 4 4
+    [   0,   1,   2,   3,   4, 128, 128, 128,   5,   6,   7,   8,   9,  10,  11,  12],
+    // 248 = 0xF8 = 0b11111000, lengths 0 2 4 4
+    [ 128, 128, 128, 128,   0,   1, 128, 128,   2,   3,   4,   5,   6,   7,   8,   9],
+    // 249 = 0xF9 = 0b11111001, lengths 1 2 4 4
+    [   0, 128, 128, 128,   1,   2, 128, 128,   3,   4,   5,   6,   7,   8,   9,  10],
+    // 250 = 0xFA = 0b11111010, lengths 2 2 4 4
+    [   0,   1, 128, 128,   2,   3, 128, 128,   4,   5,   6,   7,   8,   9,  10,  11],
+    // 251 = 0xFB = 0b11111011, lengths 4 2 4 4
+    [   0,   1,   2,   3,   4,   5, 128, 128,   6,   7,   8,   9,  10,  11,  12,  13],
+    // 252 = 0xFC = 0b11111100, lengths 0 4 4 4
+    [ 128, 128, 128, 128,   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11],
+    // 253 = 0xFD = 0b11111101, lengths 1 4 4 4
+    [   0, 128, 128, 128,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12],
+    // 254 = 0xFE = 0b11111110, lengths 2 4 4 4
+    [   0,   1, 128, 128,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13],
+    // 255 = 0xFF = 0b11111111, lengths 4 4 4 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15],
+];
+
+#[rustfmt::skip]
+#[cfg(any(feature = "x86_ssse3", feature = "x86_sse41", feature = "aarch64_neon"))]
+pub const ENCODE_0124_SHUFFLE_TABLE: &[[u8; 16]; 256] = &[
+    // 0 = 0x0 = 0b00000000, lengths 0 0 0 0
+    [ 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 1 = 0x1 = 0b00000001, lengths 1 0 0 0
+    [   0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 2 = 0x2 = 0b00000010, lengths 2 0 0 0
+    [   0,   1, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 3 = 0x3 = 0b00000011, lengths 4 0 0 0
+    [   0,   1,   2,   3, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 4 = 0x4 = 0b00000100, lengths 0 1 0 0
+    [   4, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 5 = 0x5 = 0b00000101, lengths 1 1 0 0
+    [   0,   4, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 6 = 0x6 = 0b00000110, lengths 2 1 0 0
+    [   0,   1,   4, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 7 = 0x7 = 0b00000111, lengths 4 1 0 0
+    [   0,   1,   2,   3,   4, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 8 = 0x8 = 0b00001000, lengths 0 2 0 0
+    [   4,   5, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 9 = 0x9 = 0b00001001, lengths 1 2 0 0
+    [   0,   4,   5, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 10 = 0xA = 0b00001010, lengths 2 2 0 0
+    [   0,   1,   4,   5, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 11 = 0xB = 0b00001011, lengths 4 2 0 0
+    [   0,   1,   2,   3,   4,   5, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 12 = 0xC = 0b00001100, lengths 0 4 0 0
+    [   4,   5,   6,   7, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 13 = 0xD = 0b00001101, lengths 1 4 0 0
+    [   0,   4,   5,   6,   7, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 14 = 0xE = 0b00001110, lengths 2 4 0 0
+    [   0,   1,   4,   5,   6,   7, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 15 = 0xF = 0b00001111, lengths 4 4 0 0
+    [   0,   1,   2,   3,   4,   5,   6,   7, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 16 = 0x10 = 0b00010000, lengths 0 0 1 0
+    [   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 17 = 0x11 = 0b00010001, lengths 1 0 1 0
+    [   0,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 18 = 0x12 = 0b00010010, lengths 2 0 1 0
+    [   0,   1,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 19 = 0x13 = 0b00010011, lengths 4 0 1 0
+    [   0,   1,   2,   3,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 20 = 0x14 = 0b00010100, lengths 0 1 1 0
+    [   4,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 21 = 0x15 = 0b00010101, lengths 1 1 1 0
+    [   0,   4,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 22 = 0x16 = 0b00010110, lengths 2 1 1 0
+    [   0,   1,   4,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 23 = 0x17 = 0b00010111, lengths 4 1 1 0
+    [   0,   1,   2,   3,   4,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 24 = 0x18 = 0b00011000, lengths 0 2 1 0
+    [   4,   5,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 25 = 0x19 = 0b00011001, lengths 1 2 1 0
+    [   0,   4,   5,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 26 = 0x1A = 0b00011010, lengths 2 2 1 0
+    [   0,   1,   4,   5,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 27 = 0x1B = 0b00011011, lengths 4 2 1 0
+    [   0,   1,   2,   3,   4,   5,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 28 = 0x1C = 0b00011100, lengths 0 4 1 0
+    [   4,   5,   6,   7,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 29 = 0x1D = 0b00011101, lengths 1 4 1 0
+    [   0,   4,   5,   6,   7,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 30 = 0x1E = 0b00011110, lengths 2 4 1 0
+    [   0,   1,   4,   5,   6,   7,   8, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 31 = 0x1F = 0b00011111, lengths 4 4 1 0
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8, 128, 128, 128, 128, 128, 128, 128],
+    // 32 = 0x20 = 0b00100000, lengths 0 0 2 0
+    [   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 33 = 0x21 = 0b00100001, lengths 1 0 2 0
+    [   0,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 34 = 0x22 = 0b00100010, lengths 2 0 2 0
+    [   0,   1,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 35 = 0x23 = 0b00100011, lengths 4 0 2 0
+    [   0,   1,   2,   3,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 36 = 0x24 = 0b00100100, lengths 0 1 2 0
+    [   4,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 37 = 0x25 = 0b00100101, lengths 1 1 2 0
+    [   0,   4,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 38 = 0x26 = 0b00100110, lengths 2 1 2 0
+    [   0,   1,   4,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 39 = 0x27 = 0b00100111, lengths 4 1 2 0
+    [   0,   1,   2,   3,   4,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 40 = 0x28 = 0b00101000, lengths 0 2 2 0
+    [   4,   5,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 41 = 0x29 = 0b00101001, lengths 1 2 2 0
+    [   0,   4,   5,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 42 = 0x2A = 0b00101010, lengths 2 2 2 0
+    [   0,   1,   4,   5,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 43 = 0x2B = 0b00101011, lengths 4 2 2 0
+    [   0,   1,   2,   3,   4,   5,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 44 = 0x2C = 0b00101100, lengths 0 4 2 0
+    [   4,   5,   6,   7,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 45 = 0x2D = 0b00101101, lengths 1 4 2 0
+    [   0,   4,   5,   6,   7,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 46 = 0x2E = 0b00101110, lengths 2 4 2 0
+    [   0,   1,   4,   5,   6,   7,   8,   9, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 47 = 0x2F = 0b00101111, lengths 4 4 2 0
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9, 128, 128, 128, 128, 128, 128],
+    // 48 = 0x30 = 0b00110000, lengths 0 0 4 0
+    [   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 49 = 0x31 = 0b00110001, lengths 1 0 4 0
+    [   0,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 50 = 0x32 = 0b00110010, lengths 2 0 4 0
+    [   0,   1,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 51 = 0x33 = 0b00110011, lengths 4 0 4 0
+    [   0,   1,   2,   3,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 52 = 0x34 = 0b00110100, lengths 0 1 4 0
+    [   4,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 53 = 0x35 = 0b00110101, lengths 1 1 4 0
+    [   0,   4,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 54 = 0x36 = 0b00110110, lengths 2 1 4 0
+    [   0,   1,   4,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 55 = 0x37 = 0b00110111, lengths 4 1 4 0
+    [   0,   1,   2,   3,   4,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128],
+    // 56 = 0x38 = 0b00111000, lengths 0 2 4 0
+    [   4,   5,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 57 = 0x39 = 0b00111001, lengths 1 2 4 0
+    [   0,   4,   5,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 58 = 0x3A = 0b00111010, lengths 2 2 4 0
+    [   0,   1,   4,   5,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 59 = 0x3B = 0b00111011, lengths 4 2 4 0
+    [   0,   1,   2,   3,   4,   5,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128],
+    // 60 = 0x3C = 0b00111100, lengths 0 4 4 0
+    [   4,   5,   6,   7,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 61 = 0x3D = 0b00111101, lengths 1 4 4 0
+    [   0,   4,   5,   6,   7,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128, 128],
+    // 62 = 0x3E = 0b00111110, lengths 2 4 4 0
+    [   0,   1,   4,   5,   6,   7,   8,   9,  10,  11, 128, 128, 128, 128, 128, 128],
+    // 63 = 0x3F = 0b00111111, lengths 4 4 4 0
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11, 128, 128, 128, 128],
+    // 64 = 0x40 = 0b01000000, lengths 0 0 0 1
+    [  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 65 = 0x41 = 0b01000001, lengths 1 0 0 1
+    [   0,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 66 = 0x42 = 0b01000010, lengths 2 0 0 1
+    [   0,   1,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 67 = 0x43 = 0b01000011, lengths 4 0 0 1
+    [   0,   1,   2,   3,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 68 = 0x44 = 0b01000100, lengths 0 1 0 1
+    [   4,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 69 = 0x45 = 0b01000101, lengths 1 1 0 1
+    [   0,   4,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 70 = 0x46 = 0b01000110, lengths 2 1 0 1
+    [   0,   1,   4,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 71 = 0x47 = 0b01000111, lengths 4 1 0 1
+    [   0,   1,   2,   3,   4,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 72 = 0x48 = 0b01001000, lengths 0 2 0 1
+    [   4,   5,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 73 = 0x49 = 0b01001001, lengths 1 2 0 1
+    [   0,   4,   5,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 74 = 0x4A = 0b01001010, lengths 2 2 0 1
+    [   0,   1,   4,   5,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 75 = 0x4B = 0b01001011, lengths 4 2 0 1
+    [   0,   1,   2,   3,   4,   5,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 76 = 0x4C = 0b01001100, lengths 0 4 0 1
+    [   4,   5,   6,   7,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 77 = 0x4D = 0b01001101, lengths 1 4 0 1
+    [   0,   4,   5,   6,   7,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 78 = 0x4E = 0b01001110, lengths 2 4 0 1
+    [   0,   1,   4,   5,   6,   7,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 79 = 0x4F = 0b01001111, lengths 4 4 0 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,  12, 128, 128, 128, 128, 128, 128, 128],
+    // 80 = 0x50 = 0b01010000, lengths 0 0 1 1
+    [   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 81 = 0x51 = 0b01010001, lengths 1 0 1 1
+    [   0,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 82 = 0x52 = 0b01010010, lengths 2 0 1 1
+    [   0,   1,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 83 = 0x53 = 0b01010011, lengths 4 0 1 1
+    [   0,   1,   2,   3,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 84 = 0x54 = 0b01010100, lengths 0 1 1 1
+    [   4,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 85 = 0x55 = 0b01010101, lengths 1 1 1 1
+    [   0,   4,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 86 = 0x56 = 0b01010110, lengths 2 1 1 1
+    [   0,   1,   4,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 87 = 0x57 = 0b01010111, lengths 4 1 1 1
+    [   0,   1,   2,   3,   4,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 88 = 0x58 = 0b01011000, lengths 0 2 1 1
+    [   4,   5,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 89 = 0x59 = 0b01011001, lengths 1 2 1 1
+    [   0,   4,   5,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 90 = 0x5A = 0b01011010, lengths 2 2 1 1
+    [   0,   1,   4,   5,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 91 = 0x5B = 0b01011011, lengths 4 2 1 1
+    [   0,   1,   2,   3,   4,   5,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 92 = 0x5C = 0b01011100, lengths 0 4 1 1
+    [   4,   5,   6,   7,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 93 = 0x5D = 0b01011101, lengths 1 4 1 1
+    [   0,   4,   5,   6,   7,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 94 = 0x5E = 0b01011110, lengths 2 4 1 1
+    [   0,   1,   4,   5,   6,   7,   8,  12, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 95 = 0x5F = 0b01011111, lengths 4 4 1 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  12, 128, 128, 128, 128, 128, 128],
+    // 96 = 0x60 = 0b01100000, lengths 0 0 2 1
+    [   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 97 = 0x61 = 0b01100001, lengths 1 0 2 1
+    [   0,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 98 = 0x62 = 0b01100010, lengths 2 0 2 1
+    [   0,   1,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 99 = 0x63 = 0b01100011, lengths 4 0 2 1
+    [   0,   1,   2,   3,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 100 = 0x64 = 0b01100100, lengths 0 1 2 1
+    [   4,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 101 = 0x65 = 0b01100101, lengths 1 1 2 1
+    [   0,   4,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 102 = 0x66 = 0b01100110, lengths 2 1 2 1
+    [   0,   1,   4,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 103 = 0x67 = 0b01100111, lengths 4 1 2 1
+    [   0,   1,   2,   3,   4,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 104 = 0x68 = 0b01101000, lengths 0 2 2 1
+    [   4,   5,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 105 = 0x69 = 0b01101001, lengths 1 2 2 1
+    [   0,   4,   5,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 106 = 0x6A = 0b01101010, lengths 2 2 2 1
+    [   0,   1,   4,   5,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 107 = 0x6B = 0b01101011, lengths 4 2 2 1
+    [   0,   1,   2,   3,   4,   5,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128],
+    // 108 = 0x6C = 0b01101100, lengths 0 4 2 1
+    [   4,   5,   6,   7,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 109 = 0x6D = 0b01101101, lengths 1 4 2 1
+    [   0,   4,   5,   6,   7,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 110 = 0x6E = 0b01101110, lengths 2 4 2 1
+    [   0,   1,   4,   5,   6,   7,   8,   9,  12, 128, 128, 128, 128, 128, 128, 128],
+    // 111 = 0x6F = 0b01101111, lengths 4 4 2 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  12, 128, 128, 128, 128, 128],
+    // 112 = 0x70 = 0b01110000, lengths 0 0 4 1
+    [   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 113 = 0x71 = 0b01110001, lengths 1 0 4 1
+    [   0,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 114 = 0x72 = 0b01110010, lengths 2 0 4 1
+    [   0,   1,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 115 = 0x73 = 0b01110011, lengths 4 0 4 1
+    [   0,   1,   2,   3,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128],
+    // 116 = 0x74 = 0b01110100, lengths 0 1 4 1
+    [   4,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 117 = 0x75 = 0b01110101, lengths 1 1 4 1
+    [   0,   4,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 118 = 0x76 = 0b01110110, lengths 2 1 4 1
+    [   0,   1,   4,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 119 = 0x77 = 0b01110111, lengths 4 1 4 1
+    [   0,   1,   2,   3,   4,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128],
+    // 120 = 0x78 = 0b01111000, lengths 0 2 4 1
+    [   4,   5,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 121 = 0x79 = 0b01111001, lengths 1 2 4 1
+    [   0,   4,   5,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 122 = 0x7A = 0b01111010, lengths 2 2 4 1
+    [   0,   1,   4,   5,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128],
+    // 123 = 0x7B = 0b01111011, lengths 4 2 4 1
+    [   0,   1,   2,   3,   4,   5,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128],
+    // 124 = 0x7C = 0b01111100, lengths 0 4 4 1
+    [   4,   5,   6,   7,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128, 128],
+    // 125 = 0x7D = 0b01111101, lengths 1 4 4 1
+    [   0,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128, 128],
+    // 126 = 0x7E = 0b01111110, lengths 2 4 4 1
+    [   0,   1,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128, 128, 128, 128, 128],
+    // 127 = 0x7F = 0b01111111, lengths 4 4 4 1
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12, 128, 128, 128],
+    // 128 = 0x80 = 0b10000000, lengths 0 0 0 2
+    [  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 129 = 0x81 = 0b10000001, lengths 1 0 0 2
+    [   0,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 130 = 0x82 = 0b10000010, lengths 2 0 0 2
+    [   0,   1,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 131 = 0x83 = 0b10000011, lengths 4 0 0 2
+    [   0,   1,   2,   3,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 132 = 0x84 = 0b10000100, lengths 0 1 0 2
+    [   4,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 133 = 0x85 = 0b10000101, lengths 1 1 0 2
+    [   0,   4,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 134 = 0x86 = 0b10000110, lengths 2 1 0 2
+    [   0,   1,   4,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 135 = 0x87 = 0b10000111, lengths 4 1 0 2
+    [   0,   1,   2,   3,   4,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 136 = 0x88 = 0b10001000, lengths 0 2 0 2
+    [   4,   5,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 137 = 0x89 = 0b10001001, lengths 1 2 0 2
+    [   0,   4,   5,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 138 = 0x8A = 0b10001010, lengths 2 2 0 2
+    [   0,   1,   4,   5,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 139 = 0x8B = 0b10001011, lengths 4 2 0 2
+    [   0,   1,   2,   3,   4,   5,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 140 = 0x8C = 0b10001100, lengths 0 4 0 2
+    [   4,   5,   6,   7,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 141 = 0x8D = 0b10001101, lengths 1 4 0 2
+    [   0,   4,   5,   6,   7,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 142 = 0x8E = 0b10001110, lengths 2 4 0 2
+    [   0,   1,   4,   5,   6,   7,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 143 = 0x8F = 0b10001111, lengths 4 4 0 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,  12,  13, 128, 128, 128, 128, 128, 128],
+    // 144 = 0x90 = 0b10010000, lengths 0 0 1 2
+    [   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 145 = 0x91 = 0b10010001, lengths 1 0 1 2
+    [   0,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 146 = 0x92 = 0b10010010, lengths 2 0 1 2
+    [   0,   1,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 147 = 0x93 = 0b10010011, lengths 4 0 1 2
+    [   0,   1,   2,   3,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 148 = 0x94 = 0b10010100, lengths 0 1 1 2
+    [   4,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 149 = 0x95 = 0b10010101, lengths 1 1 1 2
+    [   0,   4,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 150 = 0x96 = 0b10010110, lengths 2 1 1 2
+    [   0,   1,   4,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 151 = 0x97 = 0b10010111, lengths 4 1 1 2
+    [   0,   1,   2,   3,   4,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 152 = 0x98 = 0b10011000, lengths 0 2 1 2
+    [   4,   5,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 153 = 0x99 = 0b10011001, lengths 1 2 1 2
+    [   0,   4,   5,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 154 = 0x9A = 0b10011010, lengths 2 2 1 2
+    [   0,   1,   4,   5,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 155 = 0x9B = 0b10011011, lengths 4 2 1 2
+    [   0,   1,   2,   3,   4,   5,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128],
+    // 156 = 0x9C = 0b10011100, lengths 0 4 1 2
+    [   4,   5,   6,   7,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 157 = 0x9D = 0b10011101, lengths 1 4 1 2
+    [   0,   4,   5,   6,   7,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 158 = 0x9E = 0b10011110, lengths 2 4 1 2
+    [   0,   1,   4,   5,   6,   7,   8,  12,  13, 128, 128, 128, 128, 128, 128, 128],
+    // 159 = 0x9F = 0b10011111, lengths 4 4 1 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  12,  13, 128, 128, 128, 128, 128],
+    // 160 = 0xA0 = 0b10100000, lengths 0 0 2 2
+    [   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 161 = 0xA1 = 0b10100001, lengths 1 0 2 2
+    [   0,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 162 = 0xA2 = 0b10100010, lengths 2 0 2 2
+    [   0,   1,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 163 = 0xA3 = 0b10100011, lengths 4 0 2 2
+    [   0,   1,   2,   3,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 164 = 0xA4 = 0b10100100, lengths 0 1 2 2
+    [   4,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 165 = 0xA5 = 0b10100101, lengths 1 1 2 2
+    [   0,   4,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 166 = 0xA6 = 0b10100110, lengths 2 1 2 2
+    [   0,   1,   4,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 167 = 0xA7 = 0b10100111, lengths 4 1 2 2
+    [   0,   1,   2,   3,   4,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128],
+    // 168 = 0xA8 = 0b10101000, lengths 0 2 2 2
+    [   4,   5,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 169 = 0xA9 = 0b10101001, lengths 1 2 2 2
+    [   0,   4,   5,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 170 = 0xAA = 0b10101010, lengths 2 2 2 2
+    [   0,   1,   4,   5,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 171 = 0xAB = 0b10101011, lengths 4 2 2 2
+    [   0,   1,   2,   3,   4,   5,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128],
+    // 172 = 0xAC = 0b10101100, lengths 0 4 2 2
+    [   4,   5,   6,   7,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 173 = 0xAD = 0b10101101, lengths 1 4 2 2
+    [   0,   4,   5,   6,   7,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128, 128],
+    // 174 = 0xAE = 0b10101110, lengths 2 4 2 2
+    [   0,   1,   4,   5,   6,   7,   8,   9,  12,  13, 128, 128, 128, 128, 128, 128],
+    // 175 = 0xAF = 0b10101111, lengths 4 4 2 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  12,  13, 128, 128, 128, 128],
+    // 176 = 0xB0 = 0b10110000, lengths 0 0 4 2
+    [   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 177 = 0xB1 = 0b10110001, lengths 1 0 4 2
+    [   0,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 178 = 0xB2 = 0b10110010, lengths 2 0 4 2
+    [   0,   1,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 179 = 0xB3 = 0b10110011, lengths 4 0 4 2
+    [   0,   1,   2,   3,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128],
+    // 180 = 0xB4 = 0b10110100, lengths 0 1 4 2
+    [   4,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 181 = 0xB5 = 0b10110101, lengths 1 1 4 2
+    [   0,   4,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 182 = 0xB6 = 0b10110110, lengths 2 1 4 2
+    [   0,   1,   4,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128, 128],
+    // 183 = 0xB7 = 0b10110111, lengths 4 1 4 2
+    [   0,   1,   2,   3,   4,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128],
+    // 184 = 0xB8 = 0b10111000, lengths 0 2 4 2
+    [   4,   5,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 185 = 0xB9 = 0b10111001, lengths 1 2 4 2
+    [   0,   4,   5,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128, 128],
+    // 186 = 0xBA = 0b10111010, lengths 2 2 4 2
+    [   0,   1,   4,   5,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128],
+    // 187 = 0xBB = 0b10111011, lengths 4 2 4 2
+    [   0,   1,   2,   3,   4,   5,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128],
+    // 188 = 0xBC = 0b10111100, lengths 0 4 4 2
+    [   4,   5,   6,   7,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128, 128],
+    // 189 = 0xBD = 0b10111101, lengths 1 4 4 2
+    [   0,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128, 128],
+    // 190 = 0xBE = 0b10111110, lengths 2 4 4 2
+    [   0,   1,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13, 128, 128, 128, 128],
+    // 191 = 0xBF = 0b10111111, lengths 4 4 4 2
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13, 128, 128],
+    // 192 = 0xC0 = 0b11000000, lengths 0 0 0 4
+    [  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 193 = 0xC1 = 0b11000001, lengths 1 0 0 4
+    [   0,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 194 = 0xC2 = 0b11000010, lengths 2 0 0 4
+    [   0,   1,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 195 = 0xC3 = 0b11000011, lengths 4 0 0 4
+    [   0,   1,   2,   3,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 196 = 0xC4 = 0b11000100, lengths 0 1 0 4
+    [   4,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 197 = 0xC5 = 0b11000101, lengths 1 1 0 4
+    [   0,   4,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 198 = 0xC6 = 0b11000110, lengths 2 1 0 4
+    [   0,   1,   4,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 199 = 0xC7 = 0b11000111, lengths 4 1 0 4
+    [   0,   1,   2,   3,   4,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 200 = 0xC8 = 0b11001000, lengths 0 2 0 4
+    [   4,   5,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 201 = 0xC9 = 0b11001001, lengths 1 2 0 4
+    [   0,   4,   5,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 202 = 0xCA = 0b11001010, lengths 2 2 0 4
+    [   0,   1,   4,   5,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 203 = 0xCB = 0b11001011, lengths 4 2 0 4
+    [   0,   1,   2,   3,   4,   5,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 204 = 0xCC = 0b11001100, lengths 0 4 0 4
+    [   4,   5,   6,   7,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 205 = 0xCD = 0b11001101, lengths 1 4 0 4
+    [   0,   4,   5,   6,   7,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 206 = 0xCE = 0b11001110, lengths 2 4 0 4
+    [   0,   1,   4,   5,   6,   7,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 207 = 0xCF = 0b11001111, lengths 4 4 0 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 208 = 0xD0 = 0b11010000, lengths 0 0 1 4
+    [   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 209 = 0xD1 = 0b11010001, lengths 1 0 1 4
+    [   0,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 210 = 0xD2 = 0b11010010, lengths 2 0 1 4
+    [   0,   1,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 211 = 0xD3 = 0b11010011, lengths 4 0 1 4
+    [   0,   1,   2,   3,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 212 = 0xD4 = 0b11010100, lengths 0 1 1 4
+    [   4,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 213 = 0xD5 = 0b11010101, lengths 1 1 1 4
+    [   0,   4,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 214 = 0xD6 = 0b11010110, lengths 2 1 1 4
+    [   0,   1,   4,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 215 = 0xD7 = 0b11010111, lengths 4 1 1 4
+    [   0,   1,   2,   3,   4,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 216 = 0xD8 = 0b11011000, lengths 0 2 1 4
+    [   4,   5,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 217 = 0xD9 = 0b11011001, lengths 1 2 1 4
+    [   0,   4,   5,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 218 = 0xDA = 0b11011010, lengths 2 2 1 4
+    [   0,   1,   4,   5,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 219 = 0xDB = 0b11011011, lengths 4 2 1 4
+    [   0,   1,   2,   3,   4,   5,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 220 = 0xDC = 0b11011100, lengths 0 4 1 4
+    [   4,   5,   6,   7,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 221 = 0xDD = 0b11011101, lengths 1 4 1 4
+    [   0,   4,   5,   6,   7,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 222 = 0xDE = 0b11011110, lengths 2 4 1 4
+    [   0,   1,   4,   5,   6,   7,   8,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 223 = 0xDF = 0b11011111, lengths 4 4 1 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,  12,  13,  14,  15, 128, 128, 128],
+    // 224 = 0xE0 = 0b11100000, lengths 0 0 2 4
+    [   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 225 = 0xE1 = 0b11100001, lengths 1 0 2 4
+    [   0,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 226 = 0xE2 = 0b11100010, lengths 2 0 2 4
+    [   0,   1,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 227 = 0xE3 = 0b11100011, lengths 4 0 2 4
+    [   0,   1,   2,   3,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 228 = 0xE4 = 0b11100100, lengths 0 1 2 4
+    [   4,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 229 = 0xE5 = 0b11100101, lengths 1 1 2 4
+    [   0,   4,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 230 = 0xE6 = 0b11100110, lengths 2 1 2 4
+    [   0,   1,   4,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 231 = 0xE7 = 0b11100111, lengths 4 1 2 4
+    [   0,   1,   2,   3,   4,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 232 = 0xE8 = 0b11101000, lengths 0 2 2 4
+    [   4,   5,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 233 = 0xE9 = 0b11101001, lengths 1 2 2 4
+    [   0,   4,   5,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 234 = 0xEA = 0b11101010, lengths 2 2 2 4
+    [   0,   1,   4,   5,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 235 = 0xEB = 0b11101011, lengths 4 2 2 4
+    [   0,   1,   2,   3,   4,   5,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 236 = 0xEC = 0b11101100, lengths 0 4 2 4
+    [   4,   5,   6,   7,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 237 = 0xED = 0b11101101, lengths 1 4 2 4
+    [   0,   4,   5,   6,   7,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 238 = 0xEE = 0b11101110, lengths 2 4 2 4
+    [   0,   1,   4,   5,   6,   7,   8,   9,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 239 = 0xEF = 0b11101111, lengths 4 4 2 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  12,  13,  14,  15, 128, 128],
+    // 240 = 0xF0 = 0b11110000, lengths 0 0 4 4
+    [   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128, 128],
+    // 241 = 0xF1 = 0b11110001, lengths 1 0 4 4
+    [   0,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 242 = 0xF2 = 0b11110010, lengths 2 0 4 4
+    [   0,   1,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 243 = 0xF3 = 0b11110011, lengths 4 0 4 4
+    [   0,   1,   2,   3,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 244 = 0xF4 = 0b11110100, lengths 0 1 4 4
+    [   4,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128, 128],
+    // 245 = 0xF5 = 0b11110101, lengths 1 1 4 4
+    [   0,   4,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 246 = 0xF6 = 0b11110110, lengths 2 1 4 4
+    [   0,   1,   4,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 247 = 0xF7 = 0b11110111, lengths 4 1 4 4
+    [   0,   1,   2,   3,   4,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 248 = 0xF8 = 0b11111000, lengths 0 2 4 4
+    [   4,   5,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128, 128],
+    // 249 = 0xF9 = 0b11111001, lengths 1 2 4 4
+    [   0,   4,   5,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128, 128],
+    // 250 = 0xFA = 0b11111010, lengths 2 2 4 4
+    [   0,   1,   4,   5,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 251 = 0xFB = 0b11111011, lengths 4 2 4 4
+    [   0,   1,   2,   3,   4,   5,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 252 = 0xFC = 0b11111100, lengths 0 4 4 4
+    [   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128, 128],
+    // 253 = 0xFD = 0b11111101, lengths 1 4 4 4
+    [   0,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128, 128],
+    // 254 = 0xFE = 0b11111110, lengths 2 4 4 4
+    [   0,   1,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15, 128, 128],
+    // 255 = 0xFF = 0b11111111, lengths 4 4 4 4
+    [   0,   1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15],
+];
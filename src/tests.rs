@@ -81,6 +81,18 @@ fn encoded_shape_len_5() {
     assert_eq!(expected, shape);
 }
 
+#[test]
+fn encoded_shape_len_max() {
+    let shape = encoded_shape(usize::MAX);
+    let expected = EncodedShape {
+        control_bytes_len: usize::MAX / 4 + 1,
+        complete_control_bytes_len: usize::MAX / 4,
+        leftover_numbers: 3,
+    };
+
+    assert_eq!(expected, shape);
+}
+
 #[test]
 fn cumulative_encoded_len_accurate_complete_quad() {
     let mut nums: Vec<u32> = Vec::new();
@@ -0,0 +1,103 @@
+//! Unsigned LEB128 varints, for the counts and lengths in container headers.
+
+/// The longest a `u64` LEB128 varint can be
+const MAX_VARINT_LEN: usize = 10;
+
+/// Ways a varint can fail to be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VarintError {
+    /// The input ends before the varint does
+    Truncated,
+    /// The varint is too long, or its value doesn't fit in a `usize`
+    Overflow,
+}
+
+/// Append `num` as an unsigned LEB128 varint: 7 bits at a time, low bits
+/// first, with the high bit set on every byte but the last.
+pub(crate) fn write_varint(mut num: u64, output: &mut Vec<u8>) {
+    while num >= 0x80 {
+        output.push((num as u8) | 0x80);
+        num >>= 7;
+    }
+
+    output.push(num as u8);
+}
+
+/// Read an unsigned LEB128 varint from the start of `input`.
+///
+/// Returns the number and the length of its varint.
+pub(crate) fn read_varint(input: &[u8]) -> Result<(usize, usize), VarintError> {
+    let mut num: u64 = 0;
+
+    for (i, &byte) in input.iter().enumerate().take(MAX_VARINT_LEN) {
+        let bits = (byte & 0x7F) as u64;
+        // the 10th byte only has room for the top bit of a u64
+        if i == MAX_VARINT_LEN - 1 && bits > 1 {
+            return Err(VarintError::Overflow);
+        }
+        num |= bits << (i * 7);
+
+        if byte & 0x80 == 0 {
+            if num > usize::MAX as u64 {
+                return Err(VarintError::Overflow);
+            }
+
+            return Ok((num as usize, i + 1));
+        }
+    }
+
+    if input.len() < MAX_VARINT_LEN {
+        Err(VarintError::Truncated)
+    } else {
+        Err(VarintError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varint_roundtrip() {
+        let nums = [
+            0,
+            1,
+            0x7F,
+            0x80,
+            0x3FFF,
+            0x4000,
+            u32::MAX as u64,
+            usize::MAX as u64,
+        ];
+
+        for &num in nums.iter() {
+            let mut encoded = Vec::new();
+            write_varint(num, &mut encoded);
+
+            assert_eq!(Ok((num as usize, encoded.len())), read_varint(&encoded));
+        }
+    }
+
+    #[test]
+    fn varint_known_output() {
+        let mut encoded = Vec::new();
+        write_varint(300, &mut encoded);
+
+        assert_eq!(vec![0xAC, 0x02], encoded);
+    }
+
+    #[test]
+    fn varint_truncated() {
+        assert_eq!(Err(VarintError::Truncated), read_varint(&[]));
+        assert_eq!(Err(VarintError::Truncated), read_varint(&[0x80, 0x80]));
+    }
+
+    #[test]
+    fn varint_too_long() {
+        assert_eq!(Err(VarintError::Overflow), read_varint(&[0x80; 11]));
+        // the 10th byte has more than the top bit of a u64
+        let mut too_big = [0xFF; 10];
+        too_big[9] = 0x02;
+        assert_eq!(Err(VarintError::Overflow), read_varint(&too_big));
+    }
+}
@@ -1,6 +1,160 @@
 //! `x86_64`-specific accelerated code.
 
-#[cfg(all(feature = "x86_ssse3", target_arch = "x86_64"))]
-pub use crate::decode::ssse3::Ssse3;
+use std::arch::x86_64::{
+    __m128i, _mm_cvtsi128_si32, _mm_set1_epi32, _mm_shuffle_epi32, _mm_slli_epi32, _mm_srai_epi32,
+    _mm_xor_si128,
+};
+#[cfg(any(feature = "x86_ssse3", feature = "x86_avx2", feature = "x86_avx512"))]
+use std::arch::x86_64::{_mm_add_epi32, _mm_slli_si128, _mm_srli_epi32};
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3", feature = "x86_avx512"))]
+use std::arch::x86_64::{_mm_alignr_epi8, _mm_sub_epi32};
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3"))]
+use std::{
+    arch::x86_64::{_mm_cmpgt_epi32, _mm_loadu_si128, _mm_setzero_si128},
+    mem,
+};
+
+#[cfg(all(feature = "x86_avx2", target_arch = "x86_64"))]
+pub use crate::decode::avx2::Avx2;
+#[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+pub use crate::decode::avx512::Avx512Decoder;
+#[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+pub use crate::encode::avx512::Avx512Encoder;
 #[cfg(all(feature = "x86_sse41", target_arch = "x86_64"))]
 pub use crate::encode::sse41::Sse41;
+
+/// Decoder and encoder using SSSE3 instructions.
+///
+/// As an encoder it's for x86 CPUs that don't have SSE4.1: `Sse41` is faster
+/// where it's available.
+#[cfg(feature = "x86_ssse3")]
+pub struct Ssse3;
+
+/// True iff the CPU supports everything the AVX-512 encoder and decoder use.
+#[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+pub(crate) fn avx512_vbmi_detected() -> bool {
+    is_x86_feature_detected!("avx512f")
+        && is_x86_feature_detected!("avx512bw")
+        && is_x86_feature_detected!("avx512vbmi")
+}
+
+/// A mask with the low `len` bits set, for `len` up to 64, for the AVX-512
+/// masked loads and stores.
+#[cfg(all(feature = "x86_avx512", target_arch = "x86_64"))]
+#[inline]
+pub(crate) fn low_bits_mask(len: usize) -> u64 {
+    if len >= 64 {
+        u64::MAX
+    } else {
+        (1 << len) - 1
+    }
+}
+
+/// The body of `Ssse3::encoded_len_quads()` and `Sse41::encoded_len_quads()`,
+/// which only needs SSE2.
+///
+/// Callers must ensure that the CPU supports SSE2, which every `x86_64` CPU
+/// does.
+///
+/// Returns the count of numbers measured and their encoded length.
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3"))]
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn encoded_len_quads_sse2(input: &[u32]) -> (usize, usize) {
+    let mut len: usize = 0;
+
+    // There's no unsigned compare, so flip the sign bits of both sides to make a
+    // signed compare give the same answer.
+    let sign_bits = _mm_set1_epi32(i32::MIN);
+    let max_1_byte = _mm_set1_epi32(0xFF ^ i32::MIN);
+    let max_2_bytes = _mm_set1_epi32(0xFFFF ^ i32::MIN);
+    let max_3_bytes = _mm_set1_epi32(0xFF_FFFF ^ i32::MIN);
+
+    // Each lane counts how many byte length thresholds its numbers exceed, which
+    // grows by at most 3 per quad, so add the lanes up every 2^28 quads before
+    // they could overflow.
+    for batch in input.chunks(4 << 28) {
+        let mut extra_bytes = _mm_setzero_si128();
+
+        for quad in batch.chunks_exact(4) {
+            let nums = _mm_xor_si128(
+                unsafe { _mm_loadu_si128(quad.as_ptr() as *const __m128i) },
+                sign_bits,
+            );
+
+            // lanes that compare greater are all 1s, i.e. -1
+            extra_bytes = _mm_sub_epi32(extra_bytes, _mm_cmpgt_epi32(nums, max_1_byte));
+            extra_bytes = _mm_sub_epi32(extra_bytes, _mm_cmpgt_epi32(nums, max_2_bytes));
+            extra_bytes = _mm_sub_epi32(extra_bytes, _mm_cmpgt_epi32(nums, max_3_bytes));
+        }
+
+        let lanes = mem::transmute::<__m128i, [u32; 4]>(extra_bytes);
+        // every number takes at least 1 byte
+        len += batch.len() + lanes.iter().map(|&l| l as usize).sum::<usize>();
+    }
+
+    (input.len() / 4 * 4, len)
+}
+
+/// The body of `DeltaDecodeQuad::delta_decode_quad()` for the decoders that
+/// hand out quads as `__m128i`, which only needs SSE2.
+///
+/// Callers must ensure that the CPU supports SSE2, which every `x86_64` CPU
+/// does.
+#[cfg(any(feature = "x86_ssse3", feature = "x86_avx2", feature = "x86_avx512"))]
+#[inline]
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn delta_decode_quad_sse2(quad: __m128i, prev: u32) -> (__m128i, u32) {
+    // prefix sum in two steps: add each number to its neighbor, then to the
+    // pair 2 lanes over
+    let sums = _mm_add_epi32(quad, _mm_slli_si128(quad, 4));
+    let sums = _mm_add_epi32(sums, _mm_slli_si128(sums, 8));
+    let sums = _mm_add_epi32(sums, _mm_set1_epi32(prev as i32));
+    let last = _mm_cvtsi128_si32(_mm_shuffle_epi32(sums, 0xFF)) as u32;
+
+    (sums, last)
+}
+
+/// The body of `ZigzagDecodeQuad::zigzag_decode_quad()` for the decoders that
+/// hand out quads as `__m128i`, which only needs SSE2.
+///
+/// Callers must ensure that the CPU supports SSE2, which every `x86_64` CPU
+/// does.
+#[cfg(any(feature = "x86_ssse3", feature = "x86_avx2", feature = "x86_avx512"))]
+#[inline]
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn zigzag_decode_quad_sse2(quad: __m128i) -> __m128i {
+    // (num >> 1) ^ -(num & 1): smear the low bit across the lane to negate
+    let sign = _mm_srai_epi32(_mm_slli_epi32(quad, 31), 31);
+
+    _mm_xor_si128(_mm_srli_epi32(quad, 1), sign)
+}
+
+/// The body of `DeltaEncodeQuad::delta_encode_quad()` for the encoders that
+/// take quads as `__m128i`.
+///
+/// Callers must ensure that the CPU supports SSSE3.
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3", feature = "x86_avx512"))]
+#[inline]
+#[target_feature(enable = "ssse3")]
+pub(crate) unsafe fn delta_encode_quad_ssse3(quad: __m128i, prev: u32) -> (__m128i, u32) {
+    // shift in prev from the low end: [prev, num0, num1, num2]
+    let preceding = _mm_alignr_epi8(quad, _mm_set1_epi32(prev as i32), 12);
+
+    (
+        _mm_sub_epi32(quad, preceding),
+        _mm_cvtsi128_si32(_mm_shuffle_epi32(quad, 0xFF)) as u32,
+    )
+}
+
+/// The body of `ZigzagEncodeQuad::zigzag_encode_quad()` for the encoders that
+/// take quads as `__m128i`, which only needs SSE2.
+///
+/// Callers must ensure that the CPU supports SSE2, which every `x86_64` CPU
+/// does.
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3", feature = "x86_avx512"))]
+#[inline]
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn zigzag_encode_quad_sse2(quad: __m128i) -> __m128i {
+    // (num << 1) ^ (num >> 31), with an arithmetic shift to smear the sign bit
+    _mm_xor_si128(_mm_slli_epi32(quad, 1), _mm_srai_epi32(quad, 31))
+}
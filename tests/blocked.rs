@@ -0,0 +1,298 @@
+use rand::Rng;
+#[cfg(feature = "x86_ssse3")]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    blocked::{encode_blocked, BlockedError, BlockedReader, DEFAULT_BLOCK_LEN},
+    decode::{DecodeError, Decoder, WriteQuadToSlice},
+    encode::encode_to_vec,
+    scalar::Scalar,
+};
+
+#[path = "../src/random_varint.rs"]
+mod random_varint;
+
+use crate::random_varint::*;
+
+#[test]
+fn random_blocked_roundtrip_scalar() {
+    do_random_blocked_roundtrip::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_blocked_roundtrip_ssse3() {
+    do_random_blocked_roundtrip::<x86::Ssse3>();
+}
+
+#[test]
+fn blocked_known_output() {
+    let mut encoded = Vec::new();
+    let len = encode_blocked::<Scalar>(&[1, 300, 2, 3, 4], 4, &mut encoded);
+
+    #[rustfmt::skip]
+    let expected = vec![
+        // magic, version
+        b'S', b'V', b'B', b'B', 1,
+        // count, block length
+        5, 4,
+        // end of each block
+        6, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 0, 0, 0, 0, 0,
+        // first block: control byte, then 1 + 2 + 1 + 1 bytes of numbers
+        0b00_00_01_00, 1, 0x2C, 0x01, 2, 3,
+        // second block
+        0b00, 4,
+    ];
+    assert_eq!(expected, encoded);
+    assert_eq!(encoded.len(), len);
+}
+
+#[test]
+fn blocked_empty() {
+    let mut encoded = Vec::new();
+    encode_blocked::<Scalar>(&[], DEFAULT_BLOCK_LEN, &mut encoded);
+
+    let mut reader = BlockedReader::new(&encoded).unwrap();
+    assert!(reader.is_empty());
+    assert!(!reader.has_more());
+    assert_eq!(encoded.len(), reader.container_len());
+    assert_eq!(
+        Err(BlockedError::OutOfBounds { index: 0, len: 0 }),
+        reader.get(0)
+    );
+    assert_eq!(Ok(()), reader.seek(0));
+    assert_eq!(Ok(0), reader.decode_slice::<Scalar>(&mut [0; 4]));
+}
+
+#[test]
+fn blocked_get_and_seek_every_position() {
+    let nums: Vec<u32> = (0..103).map(|i| i * 1_000_003).collect();
+    let mut encoded = Vec::new();
+    encode_blocked::<Scalar>(&nums, 8, &mut encoded);
+
+    let mut reader = BlockedReader::new(&encoded).unwrap();
+    for (i, &num) in nums.iter().enumerate() {
+        assert_eq!(Ok(num), reader.get(i));
+    }
+    assert_eq!(
+        Err(BlockedError::OutOfBounds {
+            index: 103,
+            len: 103
+        }),
+        reader.get(103)
+    );
+
+    let mut decoded = vec![0; nums.len()];
+    for position in 0..(nums.len() + 1) {
+        reader.seek(position).unwrap();
+        assert_eq!(position, reader.position());
+
+        let count = decode_all::<Scalar>(&mut reader, &mut decoded);
+        assert_eq!(&nums[position..], &decoded[0..count]);
+        assert!(!reader.has_more());
+    }
+
+    assert_eq!(
+        Err(BlockedError::OutOfBounds {
+            index: 104,
+            len: 103
+        }),
+        reader.seek(104)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Block length must be a nonzero multiple of 4")]
+fn encode_blocked_panics_on_bad_block_len() {
+    encode_blocked::<Scalar>(&[1, 2, 3], 6, &mut Vec::new());
+}
+
+#[test]
+fn blocked_container_len_ignores_trailing_data() {
+    let nums: Vec<u32> = (0..1000).collect();
+    let mut encoded = Vec::new();
+    let len = encode_blocked::<Scalar>(&nums, 64, &mut encoded);
+    encoded.extend_from_slice(&[0xFF; 10]);
+
+    let reader = BlockedReader::new(&encoded).unwrap();
+    assert_eq!(len, reader.container_len());
+}
+
+#[test]
+fn blocked_bad_header_errors() {
+    let nums: Vec<u32> = (0..100).collect();
+    let mut encoded = Vec::new();
+    encode_blocked::<Scalar>(&nums, 16, &mut encoded);
+
+    let mut bad_magic = encoded.clone();
+    bad_magic[3] = b'F';
+    assert_eq!(
+        BlockedError::BadMagic,
+        BlockedReader::new(&bad_magic).unwrap_err()
+    );
+
+    let mut bad_version = encoded.clone();
+    bad_version[4] = 0;
+    assert_eq!(
+        BlockedError::UnsupportedVersion(0),
+        BlockedReader::new(&bad_version).unwrap_err()
+    );
+
+    let mut bad_block_len = encoded.clone();
+    bad_block_len[6] = 6;
+    assert_eq!(
+        BlockedError::InvalidBlockLen(6),
+        BlockedReader::new(&bad_block_len).unwrap_err()
+    );
+
+    for len in 0..7 {
+        assert_eq!(
+            BlockedError::TruncatedHeader,
+            BlockedReader::new(&encoded[0..len]).unwrap_err()
+        );
+    }
+
+    // 7 blocks of index entries
+    for len in 7..(7 + 7 * 8) {
+        assert_eq!(
+            BlockedError::TruncatedIndex,
+            BlockedReader::new(&encoded[0..len]).unwrap_err()
+        );
+    }
+}
+
+#[test]
+fn blocked_bad_index_errors() {
+    let nums: Vec<u32> = (0..100).collect();
+    let mut encoded = Vec::new();
+    encode_blocked::<Scalar>(&nums, 16, &mut encoded);
+
+    // block 2 ends before block 1
+    let mut decreasing = encoded.clone();
+    decreasing[7 + 2 * 8] = 0;
+    assert_eq!(
+        BlockedError::InvalidIndex { block: 2 },
+        BlockedReader::new(&decreasing).unwrap_err()
+    );
+
+    // last block ends past the end of the input
+    let last_block_len = encoded.len() - 1;
+    assert_eq!(
+        BlockedError::InvalidIndex { block: 6 },
+        BlockedReader::new(&encoded[0..last_block_len]).unwrap_err()
+    );
+}
+
+#[test]
+fn blocked_short_block_errors_when_read() {
+    let nums: Vec<u32> = (0..100).map(|i| i * 1000).collect();
+    let mut encoded = Vec::new();
+    encode_blocked::<Scalar>(&nums, 16, &mut encoded);
+
+    // make block 1 end a byte early, so block 2 starts a byte early
+    encoded[7 + 8] -= 1;
+
+    let mut reader = BlockedReader::new(&encoded).unwrap();
+    assert_eq!(Ok(15_000), reader.get(15));
+    // get() only needs the quad it reads from to be there
+    assert_eq!(Ok(27_000), reader.get(27));
+    assert_eq!(
+        Err(BlockedError::Decode {
+            block: 1,
+            error: DecodeError::TruncatedData {
+                required: 32,
+                actual: 31
+            }
+        }),
+        reader.get(28)
+    );
+
+    assert_eq!(
+        Err(BlockedError::Decode {
+            block: 1,
+            error: DecodeError::TruncatedData {
+                required: 32,
+                actual: 31
+            }
+        }),
+        reader.seek(20)
+    );
+
+    // numbers before the bad block are decoded, then there's an error
+    reader.seek(0).unwrap();
+    let mut decoded = vec![0; 100];
+    assert_eq!(Ok(16), reader.decode_slice::<Scalar>(&mut decoded));
+    assert!(reader.decode_slice::<Scalar>(&mut decoded).is_err());
+}
+
+fn do_random_blocked_roundtrip<D: Decoder + WriteQuadToSlice>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..200 {
+        nums.clear();
+        encoded.clear();
+
+        let count = rng.gen_range(0, 5000);
+        let block_len = 4 * rng.gen_range(1, 300);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+
+        let len = encode_blocked::<Scalar>(&nums, block_len, &mut encoded);
+        assert_eq!(encoded.len(), len);
+
+        let mut reader = BlockedReader::new(&encoded).unwrap();
+        assert_eq!(count, reader.len());
+        assert_eq!(block_len, reader.block_len());
+        assert_eq!(len, reader.container_len());
+
+        // in random size chunks
+        decoded.clear();
+        decoded.resize(count + 4, 0);
+        let mut total = 0;
+        loop {
+            let chunk_len = rng.gen_range(4, 1000);
+            let end = std::cmp::min(decoded.len(), total + chunk_len);
+            let decoded_this_time = reader.decode_slice::<D>(&mut decoded[total..end]).unwrap();
+            if decoded_this_time == 0 {
+                break;
+            }
+            total += decoded_this_time;
+        }
+        assert_eq!(count, total);
+        assert_eq!(&nums[..], &decoded[0..count]);
+
+        // each block is the same as encoding it separately
+        if count > 0 {
+            let last_block_start = (count - 1) / block_len * block_len;
+            assert!(encoded.ends_with(&encode_to_vec::<Scalar>(&nums[last_block_start..])));
+        }
+
+        for _ in 0..10 {
+            if count == 0 {
+                break;
+            }
+
+            let i = rng.gen_range(0, count);
+            assert_eq!(Ok(nums[i]), reader.get(i));
+
+            reader.seek(i).unwrap();
+            let decoded_count = decode_all::<D>(&mut reader, &mut decoded);
+            assert_eq!(&nums[i..], &decoded[0..decoded_count]);
+        }
+    }
+}
+
+fn decode_all<D: Decoder + WriteQuadToSlice>(
+    reader: &mut BlockedReader,
+    output: &mut [u32],
+) -> usize {
+    let mut total = 0;
+    loop {
+        let decoded = reader.decode_slice::<D>(&mut output[total..]).unwrap();
+        if decoded == 0 {
+            return total;
+        }
+        total += decoded;
+    }
+}
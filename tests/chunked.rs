@@ -0,0 +1,281 @@
+use std::io::{self, BufReader, Write};
+
+use rand::Rng;
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3"))]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    chunked::{StreamVbyteReader, StreamVbyteWriter, WriteTotals},
+    decode::{Decoder, WriteQuadToSlice},
+    encode::{encode_to_vec, Encoder},
+    scalar::Scalar,
+};
+
+#[path = "../src/random_varint.rs"]
+mod random_varint;
+
+use crate::random_varint::*;
+
+#[test]
+fn random_chunked_roundtrip_scalar() {
+    do_random_chunked_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn random_chunked_roundtrip_sse41() {
+    do_random_chunked_roundtrip::<x86::Sse41, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_chunked_roundtrip_ssse3() {
+    do_random_chunked_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[test]
+fn chunked_known_output() {
+    let mut writer = StreamVbyteWriter::<_, Scalar>::with_chunk_len(Vec::new(), 4);
+    writer.write_nums(&[1, 300, 2, 3, 4]).unwrap();
+    assert_eq!(5, writer.len());
+    assert_eq!(1, writer.pending_len());
+
+    let (output, totals) = writer.finish().unwrap();
+
+    #[rustfmt::skip]
+    let expected = vec![
+        // count, then control byte and 1 + 2 + 1 + 1 bytes of numbers
+        4, 0b00_00_01_00, 1, 0x2C, 0x01, 2, 3,
+        // last chunk
+        1, 0b00, 4,
+    ];
+    assert_eq!(expected, output);
+    assert_eq!(
+        WriteTotals {
+            nums: 5,
+            chunks: 2,
+            bytes: 10,
+        },
+        totals
+    );
+}
+
+#[test]
+fn chunked_empty() {
+    let writer = StreamVbyteWriter::<_, Scalar>::new(Vec::new());
+    assert!(writer.is_empty());
+
+    let (output, totals) = writer.finish().unwrap();
+    assert!(output.is_empty());
+    assert_eq!(WriteTotals::default(), totals);
+
+    let mut reader = StreamVbyteReader::new(&output[..]);
+    let mut decoded = Vec::new();
+    assert_eq!(0, reader.read_chunk::<Scalar>(&mut decoded).unwrap());
+    assert!(decoded.is_empty());
+}
+
+#[test]
+fn chunked_memory_bounded_by_chunk_len() {
+    let nums: Vec<u32> = (0..10_000).map(|i| i * 1_001).collect();
+    let mut writer = StreamVbyteWriter::<_, Scalar>::with_chunk_len(Vec::new(), 100);
+
+    for (i, &num) in nums.iter().enumerate() {
+        writer.write_num(num).unwrap();
+        assert_eq!(i + 1, writer.len());
+        assert_eq!((i + 1) % 100, writer.pending_len());
+        assert_eq!((i + 1) / 100, writer.totals().chunks);
+        assert_eq!(writer.get_ref().len() as u64, writer.totals().bytes);
+    }
+
+    let (output, totals) = writer.finish().unwrap();
+    assert_eq!(100, totals.chunks);
+    assert_eq!(nums, read_all::<Scalar>(&output));
+}
+
+#[test]
+fn chunked_flush_writes_short_chunk() {
+    let mut writer = StreamVbyteWriter::<_, Scalar>::with_chunk_len(Vec::new(), 100);
+    writer.write_nums(&[1, 2, 3]).unwrap();
+    assert!(writer.get_ref().is_empty());
+
+    writer.flush().unwrap();
+    assert_eq!(&[3, 0b00_00_00, 1, 2, 3], &writer.get_ref()[..]);
+    assert_eq!(0, writer.pending_len());
+
+    // nothing to write, so no empty chunk
+    writer.flush().unwrap();
+    writer.write_nums(&[4]).unwrap();
+    let (output, totals) = writer.finish().unwrap();
+    assert_eq!(2, totals.chunks);
+    assert_eq!(vec![1, 2, 3, 4], read_all::<Scalar>(&output));
+}
+
+#[test]
+#[should_panic(expected = "Chunk length must be nonzero")]
+fn chunked_panics_on_zero_chunk_len() {
+    StreamVbyteWriter::<_, Scalar>::with_chunk_len(Vec::new(), 0);
+}
+
+#[test]
+fn chunked_write_error_is_returned() {
+    let mut writer = StreamVbyteWriter::<_, Scalar>::with_chunk_len(FailingWriter, 4);
+    writer.write_nums(&[1, 2, 3]).unwrap();
+
+    assert_eq!(
+        io::ErrorKind::BrokenPipe,
+        writer.write_num(4).unwrap_err().kind()
+    );
+    assert_eq!(0, writer.totals().chunks);
+}
+
+#[test]
+fn chunked_reader_truncated_errors() {
+    let nums: Vec<u32> = (0..100).map(|i| i * 1000).collect();
+    let mut writer = StreamVbyteWriter::<_, Scalar>::with_chunk_len(Vec::new(), 64);
+    writer.write_nums(&nums).unwrap();
+    let (output, _) = writer.finish().unwrap();
+
+    for len in 1..output.len() {
+        let mut reader = StreamVbyteReader::new(&output[0..len]);
+        let mut decoded = Vec::new();
+
+        let result = reader
+            .read_chunk::<Scalar>(&mut decoded)
+            .and_then(|_| reader.read_chunk::<Scalar>(&mut decoded));
+        // only the end of the first chunk is a valid place to stop
+        match result {
+            Ok(_) => assert_eq!(&nums[0..64], &decoded[..]),
+            Err(error) => assert_eq!(io::ErrorKind::UnexpectedEof, error.kind()),
+        }
+    }
+}
+
+#[test]
+fn chunked_reader_reads_counts_across_buffer_refills() {
+    let nums: Vec<u32> = (0..1000).map(|i| i * 1000).collect();
+    // 2 byte counts
+    let mut writer = StreamVbyteWriter::<_, Scalar>::with_chunk_len(Vec::new(), 300);
+    writer.write_nums(&nums).unwrap();
+    let (output, _) = writer.finish().unwrap();
+
+    // the buffer only ever holds 1 byte of a count
+    let mut reader = StreamVbyteReader::new(BufReader::with_capacity(1, &output[..]));
+    let mut decoded = Vec::new();
+    while reader.read_chunk::<Scalar>(&mut decoded).unwrap() > 0 {}
+    assert_eq!(nums, decoded);
+
+    for len in 1..output.len() {
+        let mut reader = StreamVbyteReader::new(BufReader::with_capacity(1, &output[0..len]));
+        decoded.clear();
+
+        let result = (0..4).try_for_each(|_| reader.read_chunk::<Scalar>(&mut decoded).map(|_| ()));
+        // chunks end after 300, 600 and 900 numbers
+        match result {
+            Ok(_) => assert_eq!(0, decoded.len() % 300),
+            Err(error) => assert_eq!(io::ErrorKind::UnexpectedEof, error.kind()),
+        }
+    }
+}
+
+#[test]
+fn chunked_reader_bad_count_errors() {
+    let mut decoded = Vec::new();
+
+    let zero_count = [0, 0, 1];
+    let error = StreamVbyteReader::new(&zero_count[..])
+        .read_chunk::<Scalar>(&mut decoded)
+        .unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+
+    // 11 byte varint
+    let overlong = [0xFF; 11];
+    let error = StreamVbyteReader::new(&overlong[..])
+        .read_chunk::<Scalar>(&mut decoded)
+        .unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+
+    // 5 numbers is more than the limit
+    let too_long = [5, 0, 0, 1, 2, 3, 4, 5];
+    let error = StreamVbyteReader::with_max_chunk_len(&too_long[..], 4)
+        .read_chunk::<Scalar>(&mut decoded)
+        .unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    let mut reader = StreamVbyteReader::with_max_chunk_len(&too_long[..], 5);
+    assert_eq!(5, reader.read_chunk::<Scalar>(&mut decoded).unwrap());
+    assert_eq!(vec![1, 2, 3, 4, 5], decoded);
+}
+
+fn do_random_chunked_roundtrip<E: Encoder, D: Decoder + WriteQuadToSlice>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..200 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 5000);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+
+        let chunk_len = rng.gen_range(1, 1000);
+        let mut writer = StreamVbyteWriter::<_, E>::with_chunk_len(Vec::new(), chunk_len);
+
+        // a mix of single numbers and slices of random lengths
+        let mut added = 0;
+        while added < count {
+            if rng.gen_weighted_bool(3) {
+                writer.write_num(nums[added]).unwrap();
+                added += 1;
+            } else {
+                let len = rng.gen_range(0, std::cmp::min(count - added, 2500) + 1);
+                writer.write_nums(&nums[added..(added + len)]).unwrap();
+                added += len;
+            }
+
+            assert_eq!(added, writer.len());
+            assert!(writer.pending_len() < chunk_len);
+        }
+
+        let (output, totals) = writer.finish().unwrap();
+        assert_eq!(count, totals.nums);
+        assert_eq!((count + chunk_len - 1) / chunk_len, totals.chunks);
+        assert_eq!(output.len() as u64, totals.bytes);
+
+        // every chunk is a count and then a plain encoding
+        let mut reader = StreamVbyteReader::new(&output[..]);
+        let mut decoded = Vec::new();
+        let mut start = 0;
+        for chunk in nums.chunks(chunk_len) {
+            assert_eq!(chunk.len(), reader.read_chunk::<D>(&mut decoded).unwrap());
+
+            let remaining = reader.get_ref();
+            let encoded = encode_to_vec::<Scalar>(chunk);
+            let chunk_start = output.len() - remaining.len() - encoded.len();
+            assert_eq!(
+                &encoded[..],
+                &output[chunk_start..(output.len() - remaining.len())]
+            );
+            assert!(chunk_start > start);
+            start = output.len() - remaining.len();
+        }
+        assert_eq!(0, reader.read_chunk::<D>(&mut decoded).unwrap());
+        assert_eq!(nums, decoded);
+    }
+}
+
+fn read_all<D: Decoder + WriteQuadToSlice>(input: &[u8]) -> Vec<u32> {
+    let mut reader = StreamVbyteReader::new(input);
+    let mut decoded = Vec::new();
+    while reader.read_chunk::<D>(&mut decoded).unwrap() > 0 {}
+
+    decoded
+}
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "nope"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -1,19 +1,19 @@
-#![cfg_attr(
-    any(feature = "x86_ssse3", feature = "x86_sse41"),
-    feature(portable_simd)
-)]
-
 use std::cmp;
 #[cfg(feature = "x86_ssse3")]
-use std::{arch::x86_64::__m128i, simd};
+use std::{arch::x86_64::__m128i, mem};
 
 use rand::Rng;
+#[cfg(any(feature = "x86_ssse3", feature = "x86_avx2", feature = "x86_avx512"))]
+use stream_vbyte::x86;
 #[cfg(feature = "x86_ssse3")]
-use stream_vbyte::x86::{self, Ssse3};
+use stream_vbyte::x86::Ssse3;
 use stream_vbyte::{
-    decode::{cursor::DecodeCursor, DecodeQuadSink, DecodeSingleSink, Decoder, WriteQuadToSlice},
+    decode::{
+        cursor::{DecodeCursor, DeltaDecodeCursor},
+        DecodeError, DecodeQuadSink, DecodeSingleSink, Decoder, DeltaDecodeQuad, WriteQuadToSlice,
+    },
     decode_quad_scalar,
-    encode::encode,
+    encode::{encode, encode_delta},
     scalar::Scalar,
 };
 
@@ -35,6 +35,22 @@ fn decode_cursor_random_decode_len_ssse3() {
     do_decode_cursor_slice_random_decode_len::<x86::Ssse3>();
 }
 
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn decode_cursor_random_decode_len_avx2() {
+    do_decode_cursor_slice_random_decode_len::<x86::Avx2>();
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn decode_cursor_random_decode_len_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_decode_cursor_slice_random_decode_len::<x86::Avx512Decoder>();
+}
+
 #[test]
 fn decode_cursor_every_decode_len_scalar() {
     do_decode_cursor_slice_every_decode_len::<Scalar>()
@@ -46,15 +62,165 @@ fn decode_cursor_every_decode_len_ssse3() {
     do_decode_cursor_slice_every_decode_len::<x86::Ssse3>()
 }
 
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn decode_cursor_every_decode_len_avx2() {
+    do_decode_cursor_slice_every_decode_len::<x86::Avx2>()
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn decode_cursor_every_decode_len_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_decode_cursor_slice_every_decode_len::<x86::Avx512Decoder>()
+}
+
 #[test]
 fn decode_cursor_skip_from_start_scalar() {
-    do_decode_cursor_skip_every_allowable_len_from_start::<Scalar>();
+    do_decode_cursor_skip_every_len_from_start::<Scalar>();
 }
 
 #[cfg(feature = "x86_ssse3")]
 #[test]
 fn decode_cursor_skip_from_start_ssse3() {
-    do_decode_cursor_skip_every_allowable_len_from_start::<x86::Ssse3>();
+    do_decode_cursor_skip_every_len_from_start::<x86::Ssse3>();
+}
+
+#[test]
+fn decode_cursor_random_skip_and_decode_len_scalar() {
+    do_decode_cursor_random_skip_and_decode_len::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn decode_cursor_random_skip_and_decode_len_ssse3() {
+    do_decode_cursor_random_skip_and_decode_len::<x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn decode_cursor_random_skip_and_decode_len_avx2() {
+    do_decode_cursor_random_skip_and_decode_len::<x86::Avx2>();
+}
+
+#[test]
+fn decode_cursor_iter_scalar() {
+    do_decode_cursor_iter::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn decode_cursor_iter_ssse3() {
+    do_decode_cursor_iter::<x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn decode_cursor_iter_avx2() {
+    do_decode_cursor_iter::<x86::Avx2>();
+}
+
+#[test]
+fn decode_cursor_iter_doesnt_move_cursor() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    prepare_offset_nums(100, 1000, &mut nums, &mut encoded);
+
+    let mut cursor = DecodeCursor::new(&encoded, 100);
+    cursor.skip(10);
+    assert_eq!(
+        &nums[10..],
+        &cursor.iter::<Scalar>().collect::<Vec<u32>>()[..]
+    );
+    assert_eq!(10, cursor.position());
+
+    // but into_cursor() carries on from where the iterator got to
+    let mut iter = cursor.iter::<Scalar>();
+    assert_eq!(Some(nums[10]), iter.next());
+    assert_eq!(Some(nums[20]), iter.nth(9));
+    let mut cursor = iter.into_cursor();
+    assert_eq!(21, cursor.position());
+    let mut decoded = [0; 4];
+    assert_eq!(3, cursor.decode_slice::<Scalar>(&mut decoded));
+    assert_eq!(&nums[21..24], &decoded[0..3]);
+}
+
+#[test]
+fn decode_cursor_seek_to_random_positions() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = [0; 4];
+    let mut rng = rand::weak_rng();
+
+    for _ in 0..100 {
+        nums.clear();
+        encoded.clear();
+
+        let count = rng.gen_range(1, 500);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+        encoded.resize(count * 5, 0);
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+
+        let mut cursor = DecodeCursor::new(&encoded[0..encoded_len], count);
+        for _ in 0..100 {
+            let index = rng.gen_range(0, count);
+            cursor.seek_to(index);
+            assert_eq!(index, cursor.position());
+
+            // there's always room for the rest of the current quad
+            let nums_decoded = cursor.decode_slice::<Scalar>(&mut decoded);
+            assert!(nums_decoded > 0);
+            assert_eq!(
+                &nums[index..(index + nums_decoded)],
+                &decoded[0..nums_decoded]
+            );
+        }
+
+        cursor.seek_to(count);
+        assert!(!cursor.has_more());
+        assert_eq!(encoded_len, cursor.input_consumed());
+    }
+}
+
+#[test]
+#[should_panic(expected = "Can't seek past the end of the input")]
+fn decode_cursor_seek_to_past_end_panics() {
+    DecodeCursor::new(&[0, 1, 2, 3, 4], 4).seek_to(5);
+}
+
+#[test]
+fn decode_cursor_try_decode_slice_partway_through_quad() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = [0; 4];
+
+    prepare_offset_nums(23, 1000, &mut nums, &mut encoded);
+    let mut cursor = DecodeCursor::try_new(&encoded, 23).unwrap();
+    cursor.skip(6);
+
+    // one number at a time until the end of the quad
+    assert_eq!(Ok(1), cursor.try_decode_slice::<Scalar>(&mut decoded[0..1]));
+    assert_eq!(nums[6], decoded[0]);
+    assert_eq!(Ok(1), cursor.try_decode_slice::<Scalar>(&mut decoded[0..1]));
+    assert_eq!(nums[7], decoded[0]);
+
+    // then whole quads again
+    assert_eq!(
+        Err(DecodeError::OutputTooSmall {
+            required: 4,
+            actual: 1
+        }),
+        cursor.try_decode_slice::<Scalar>(&mut decoded[0..1])
+    );
+
+    // and into the partial quad
+    cursor.skip(13);
+    assert_eq!(Ok(2), cursor.try_decode_slice::<Scalar>(&mut decoded));
+    assert_eq!(&nums[21..], &decoded[0..2]);
+    assert!(!cursor.has_more());
 }
 
 #[test]
@@ -68,6 +234,22 @@ fn decode_cursor_skip_every_allowable_len_between_decodes_ssse3() {
     do_decode_cursor_skip_every_allowable_len_between_decodes::<x86::Ssse3>();
 }
 
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn decode_cursor_skip_every_allowable_len_between_decodes_avx2() {
+    do_decode_cursor_skip_every_allowable_len_between_decodes::<x86::Avx2>();
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn decode_cursor_skip_every_allowable_len_between_decodes_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_decode_cursor_skip_every_allowable_len_between_decodes::<x86::Avx512Decoder>();
+}
+
 #[test]
 fn decode_cursor_slice_input_only_partial_quad_decodes_all_scalar() {
     do_decode_cursor_slice_input_only_partial_quad_decodes_all::<Scalar>()
@@ -154,6 +336,44 @@ fn decode_cursor_sink_decode_after_finishing_input_decodes_0_numbers_ssse3() {
     do_decode_cursor_sink_decode_after_finishing_input_decodes_0_numbers::<x86::Ssse3>()
 }
 
+#[test]
+fn decode_cursor_try_decode_slice_output_too_small_scalar() {
+    do_decode_cursor_try_decode_slice_output_too_small::<Scalar>()
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn decode_cursor_try_decode_slice_output_too_small_ssse3() {
+    do_decode_cursor_try_decode_slice_output_too_small::<x86::Ssse3>()
+}
+
+#[test]
+fn delta_decode_cursor_random_skip_and_decode_len_scalar() {
+    do_delta_decode_cursor_random_skip_and_decode_len::<Scalar>()
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn delta_decode_cursor_random_skip_and_decode_len_ssse3() {
+    do_delta_decode_cursor_random_skip_and_decode_len::<x86::Ssse3>()
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn delta_decode_cursor_random_skip_and_decode_len_avx2() {
+    do_delta_decode_cursor_random_skip_and_decode_len::<x86::Avx2>()
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn delta_decode_cursor_random_skip_and_decode_len_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_delta_decode_cursor_random_skip_and_decode_len::<x86::Avx512Decoder>()
+}
+
 fn do_decode_cursor_slice_every_decode_len<D: Decoder + WriteQuadToSlice>() {
     let mut nums: Vec<u32> = Vec::new();
     let mut encoded = Vec::new();
@@ -222,6 +442,15 @@ fn do_decode_cursor_slice_every_decode_len<D: Decoder + WriteQuadToSlice>() {
     }
 }
 
+/// AVX-512 isn't widespread, so tests for it are skipped rather than failing
+/// (or silently testing the `Scalar` fallback) on CPUs without it.
+#[cfg(feature = "x86_avx512")]
+fn avx512_vbmi_supported() -> bool {
+    is_x86_feature_detected!("avx512f")
+        && is_x86_feature_detected!("avx512bw")
+        && is_x86_feature_detected!("avx512vbmi")
+}
+
 fn do_decode_cursor_slice_random_decode_len<D: Decoder + WriteQuadToSlice>() {
     let mut nums: Vec<u32> = Vec::new();
     let mut encoded = Vec::new();
@@ -277,7 +506,7 @@ fn do_decode_cursor_slice_random_decode_len<D: Decoder + WriteQuadToSlice>() {
     }
 }
 
-fn do_decode_cursor_skip_every_allowable_len_from_start<D: Decoder + WriteQuadToSlice>() {
+fn do_decode_cursor_skip_every_len_from_start<D: Decoder + WriteQuadToSlice>() {
     let mut nums: Vec<u32> = Vec::new();
     let mut encoded = Vec::new();
     let mut decoded = Vec::new();
@@ -297,11 +526,12 @@ fn do_decode_cursor_skip_every_allowable_len_from_start<D: Decoder + WriteQuadTo
 
         let extra_slots = 100;
 
-        // skips must be divisible by 4
-        for skip_len in (0..(count / QUAD_LEN + 1)).map(|i| i * QUAD_LEN) {
+        // skips can end anywhere, including partway through the trailing partial quad
+        for skip_len in 0..(count + 1) {
             let mut cursor = DecodeCursor::new(&encoded[0..encoded_len], count);
 
             cursor.skip(skip_len);
+            assert_eq!(skip_len, cursor.position());
 
             let garbage = rng.gen();
             decoded.clear();
@@ -326,6 +556,95 @@ fn do_decode_cursor_skip_every_allowable_len_from_start<D: Decoder + WriteQuadTo
     }
 }
 
+fn do_decode_cursor_random_skip_and_decode_len<D: Decoder + WriteQuadToSlice>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+    let mut rng = rand::weak_rng();
+
+    for _ in 0..5_000 {
+        nums.clear();
+        encoded.clear();
+
+        let count = rng.gen_range(0, 500);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+        encoded.resize(count * 5, 0);
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+
+        let mut cursor = DecodeCursor::new(&encoded[0..encoded_len], count);
+        let mut position = 0;
+        while cursor.has_more() {
+            if rng.gen() {
+                let skip_len = rng.gen_range(0, count - position + 1);
+                cursor.skip(skip_len);
+                position += skip_len;
+                assert_eq!(position, cursor.position());
+            }
+
+            decoded.clear();
+            decoded.resize(count + QUAD_LEN, 0);
+            let decode_len = rng.gen_range(QUAD_LEN, cmp::max(QUAD_LEN + 1, count + 1));
+            let nums_decoded = cursor.decode_slice::<D>(&mut decoded[0..decode_len]);
+
+            assert_eq!(
+                &nums[position..(position + nums_decoded)],
+                &decoded[0..nums_decoded]
+            );
+            position += nums_decoded;
+            assert_eq!(position, cursor.position());
+        }
+
+        assert_eq!(count, position);
+        assert_eq!(encoded_len, cursor.input_consumed());
+    }
+}
+
+fn do_decode_cursor_iter<D: Decoder + WriteQuadToSlice>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut rng = rand::weak_rng();
+
+    for _ in 0..1_000 {
+        nums.clear();
+        encoded.clear();
+
+        let count = rng.gen_range(0, 500);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+        encoded.resize(count * 5, 0);
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+
+        let mut cursor = DecodeCursor::new(&encoded[0..encoded_len], count);
+        let start = rng.gen_range(0, count + 1);
+        cursor.skip(start);
+
+        let decoded: Vec<u32> = cursor.iter::<D>().collect();
+        assert_eq!(&nums[start..], &decoded[..]);
+
+        // jump around with nth(), checking the length as we go
+        let mut iter = cursor.iter::<D>();
+        let mut position = start;
+        loop {
+            assert_eq!(count - position, iter.len());
+            assert_eq!((count - position, Some(count - position)), iter.size_hint());
+
+            let n = if rng.gen() { 0 } else { rng.gen_range(0, 100) };
+            match iter.nth(n) {
+                Some(num) => {
+                    position += n;
+                    assert_eq!(nums[position], num);
+                    position += 1;
+                }
+                None => {
+                    assert!(position + n >= count);
+                    break;
+                }
+            }
+        }
+        assert_eq!(0, iter.len());
+        assert_eq!(None, iter.next());
+    }
+}
+
 fn do_decode_cursor_slice_input_only_partial_quad_decodes_all<D: Decoder + WriteQuadToSlice>() {
     let mut nums: Vec<u32> = Vec::new();
     let mut encoded = Vec::new();
@@ -481,7 +800,7 @@ where
         expected.clear();
 
         for num in 0..len {
-            expected.push((num as usize, num as u32 + 1000));
+            expected.push((num, num as u32 + 1000));
         }
 
         prepare_offset_nums(len, 1000, &mut nums, &mut encoded);
@@ -516,7 +835,7 @@ where
             let complete_quad_len = partial_len - (partial_len % QUAD_LEN);
 
             for num in 0..complete_quad_len {
-                expected.push((num as usize, num as u32 + 1000));
+                expected.push((num, num as u32 + 1000));
             }
 
             let mut cursor = DecodeCursor::new(&encoded, len);
@@ -567,7 +886,7 @@ where
                 };
 
                 for num in 0..expected_decode_len {
-                    expected.push((num as usize, total_nums_decoded as u32 + num as u32 + 1000));
+                    expected.push((num, total_nums_decoded as u32 + num as u32 + 1000));
                 }
 
                 let mut sink = TupleSink::new();
@@ -631,7 +950,7 @@ where
         let nums_decoded = cursor.decode_sink::<D, _>(&mut sink, decode_len);
 
         assert_eq!(40, nums_decoded);
-        let expected_suffix = vec![(35, 1035), (36, 1036), (37, 1037), (38, 1038), (39, 1039)];
+        let expected_suffix = [(35, 1035), (36, 1036), (37, 1037), (38, 1038), (39, 1039)];
         assert_eq!(&expected_suffix[..], &sink.tuples[35..]);
     }
 
@@ -689,6 +1008,97 @@ where
     }
 }
 
+fn do_decode_cursor_try_decode_slice_output_too_small<D: Decoder + WriteQuadToSlice>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = [0; 4];
+
+    // 5 complete quads and 3 leftovers
+    prepare_offset_nums(23, 1000, &mut nums, &mut encoded);
+    let mut cursor = DecodeCursor::try_new(&encoded, 23).unwrap();
+
+    // need room for a complete quad
+    assert_eq!(
+        Err(DecodeError::OutputTooSmall {
+            required: 4,
+            actual: 3
+        }),
+        cursor.try_decode_slice::<D>(&mut decoded[0..3])
+    );
+
+    for quad in 0..5 {
+        assert_eq!(Ok(4), cursor.try_decode_slice::<D>(&mut decoded));
+        assert_eq!(&nums[quad * 4..(quad + 1) * 4], &decoded[..]);
+    }
+
+    // only the partial quad is left
+    assert_eq!(
+        Err(DecodeError::OutputTooSmall {
+            required: 3,
+            actual: 2
+        }),
+        cursor.try_decode_slice::<D>(&mut decoded[0..2])
+    );
+    assert_eq!(Ok(3), cursor.try_decode_slice::<D>(&mut decoded[0..3]));
+    assert_eq!(&nums[20..], &decoded[0..3]);
+    assert!(!cursor.has_more());
+
+    // nothing left to decode, so any output size is fine
+    assert_eq!(Ok(0), cursor.try_decode_slice::<D>(&mut decoded[0..0]));
+}
+
+fn do_delta_decode_cursor_random_skip_and_decode_len<D: DeltaDecodeQuad + WriteQuadToSlice>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+    let mut rng = rand::weak_rng();
+
+    for _ in 0..5_000 {
+        nums.clear();
+        encoded.clear();
+
+        let count = rng.gen_range(0, 500);
+        let prev: u32 = rng.gen();
+        let mut num = prev;
+        for i in RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count) {
+            num = num.wrapping_add(i);
+            nums.push(num);
+        }
+
+        encoded.resize(count * 5, 0);
+        let encoded_len = encode_delta::<Scalar>(&nums, prev, &mut encoded);
+
+        let mut cursor = DeltaDecodeCursor::new(&encoded[0..encoded_len], count, prev);
+        let mut position = 0;
+        while cursor.has_more() {
+            // skip some numbers, not necessarily whole quads
+            if rng.gen() {
+                let skip_len = rng.gen_range(0, count - position + 1);
+                cursor.skip(skip_len);
+                position += skip_len;
+
+                if position > 0 {
+                    assert_eq!(nums[position - 1], cursor.prev());
+                }
+            }
+
+            decoded.clear();
+            decoded.resize(count + QUAD_LEN, 0);
+            let decode_len = rng.gen_range(QUAD_LEN, cmp::max(QUAD_LEN + 1, count + 1));
+            let nums_decoded = cursor.decode_slice::<D>(&mut decoded[0..decode_len]);
+
+            assert_eq!(
+                &nums[position..(position + nums_decoded)],
+                &decoded[0..nums_decoded]
+            );
+            position += nums_decoded;
+        }
+
+        assert_eq!(count, position);
+        assert_eq!(encoded_len, cursor.input_consumed());
+    }
+}
+
 /// Prepare some input
 fn prepare_offset_nums(
     count: usize,
@@ -701,7 +1111,7 @@ fn prepare_offset_nums(
     }
 
     encoded.resize(count * 5, 0);
-    let encoded_len = encode::<Scalar>(&nums, encoded);
+    let encoded_len = encode::<Scalar>(nums, encoded);
     encoded.truncate(encoded_len);
 
     encoded_len
@@ -728,7 +1138,7 @@ decode_quad_scalar!(TupleSink);
 #[cfg(feature = "x86_ssse3")]
 impl DecodeQuadSink<Ssse3> for TupleSink {
     fn on_quad(&mut self, quad: __m128i, nums_decoded: usize) {
-        let u32s = simd::u32x4::from(quad);
+        let u32s = unsafe { mem::transmute::<__m128i, [u32; 4]>(quad) };
         self.tuples.push((nums_decoded, u32s[0]));
         self.tuples.push((nums_decoded + 1, u32s[1]));
         self.tuples.push((nums_decoded + 2, u32s[2]));
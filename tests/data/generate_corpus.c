@@ -0,0 +1,144 @@
+/*
+ * Writes the expected encoding of every case in the interop test corpus with
+ * the reference Stream VByte implementation (https://github.com/lemire/streamvbyte).
+ *
+ * For each `<name>.nums` in the given directory, which holds little-endian
+ * `uint32_t`s, this writes `<name>.bin`. Cases whose name starts with `delta_`
+ * are encoded with `streamvbyte_delta_encode()` with a previous value of 0,
+ * those starting with `0124_` with `streamvbyte_encode_0124()`, and the rest
+ * with `streamvbyte_encode()`.
+ *
+ * Build it against the reference implementation's headers and library, and
+ * run it from the root of this crate:
+ *
+ *   cc -O2 -I<streamvbyte>/include tests/data/generate_corpus.c \
+ *     -L<streamvbyte build dir> -lstreamvbyte -o generate_corpus
+ *   ./generate_corpus tests/data/corpus
+ */
+
+#include <dirent.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "streamvbyte.h"
+// older releases declare the delta functions in their own header
+#if defined(__has_include)
+#if __has_include("streamvbyte_delta.h")
+#include "streamvbyte_delta.h"
+#endif
+#endif
+
+static const char NUMS_SUFFIX[] = ".nums";
+
+// Reads all of `path` into a new buffer, setting `len` to its length.
+static uint8_t *read_file(const char *path, size_t *len) {
+  FILE *f = fopen(path, "rb");
+  if (f == NULL) {
+    perror(path);
+    exit(1);
+  }
+
+  size_t capacity = 4096;
+  uint8_t *bytes = malloc(capacity);
+  *len = 0;
+  size_t read;
+  while ((read = fread(bytes + *len, 1, capacity - *len, f)) > 0) {
+    *len += read;
+    if (*len == capacity) {
+      capacity *= 2;
+      bytes = realloc(bytes, capacity);
+    }
+  }
+  if (ferror(f)) {
+    perror(path);
+    exit(1);
+  }
+  fclose(f);
+
+  return bytes;
+}
+
+static void write_file(const char *path, const uint8_t *bytes, size_t len) {
+  FILE *f = fopen(path, "wb");
+  if (f == NULL || fwrite(bytes, 1, len, f) != len || fclose(f) != 0) {
+    perror(path);
+    exit(1);
+  }
+}
+
+static int starts_with(const char *s, const char *prefix) {
+  return strncmp(s, prefix, strlen(prefix)) == 0;
+}
+
+static void write_case(const char *dir, const char *name) {
+  char path[4096];
+
+  snprintf(path, sizeof(path), "%s/%s.nums", dir, name);
+  size_t nums_len;
+  uint8_t *nums_bytes = read_file(path, &nums_len);
+  if (nums_len % 4 != 0) {
+    fprintf(stderr, "%s is not whole uint32_ts\n", path);
+    exit(1);
+  }
+
+  uint32_t count = (uint32_t)(nums_len / 4);
+  // room for at least 1 number so that empty cases don't malloc(0)
+  uint32_t *datain = malloc((count + 1) * sizeof(uint32_t));
+  for (uint32_t i = 0; i < count; ++i) {
+    const uint8_t *b = nums_bytes + 4 * i;
+    datain[i] = (uint32_t)b[0] | (uint32_t)b[1] << 8 | (uint32_t)b[2] << 16 |
+                (uint32_t)b[3] << 24;
+  }
+
+  uint8_t *compressedbuffer = malloc(streamvbyte_max_compressedbytes(count) + 1);
+  size_t compsize;
+  if (starts_with(name, "delta_")) {
+    compsize = streamvbyte_delta_encode(datain, count, compressedbuffer, 0);
+  } else if (starts_with(name, "0124_")) {
+    compsize = streamvbyte_encode_0124(datain, count, compressedbuffer);
+  } else {
+    compsize = streamvbyte_encode(datain, count, compressedbuffer);
+  }
+
+  snprintf(path, sizeof(path), "%s/%s.bin", dir, name);
+  write_file(path, compressedbuffer, compsize);
+  printf("%s: %u numbers in %zu bytes\n", name, count, compsize);
+
+  free(compressedbuffer);
+  free(datain);
+  free(nums_bytes);
+}
+
+int main(int argc, char **argv) {
+  if (argc != 2) {
+    fprintf(stderr, "usage: %s <corpus dir>\n", argv[0]);
+    return 1;
+  }
+  const char *dir = argv[1];
+
+  DIR *d = opendir(dir);
+  if (d == NULL) {
+    perror(dir);
+    return 1;
+  }
+
+  struct dirent *entry;
+  while ((entry = readdir(d)) != NULL) {
+    size_t len = strlen(entry->d_name);
+    size_t suffix_len = strlen(NUMS_SUFFIX);
+    if (len <= suffix_len ||
+        strcmp(entry->d_name + len - suffix_len, NUMS_SUFFIX) != 0) {
+      continue;
+    }
+
+    char name[1024];
+    snprintf(name, sizeof(name), "%.*s", (int)(len - suffix_len),
+             entry->d_name);
+    write_case(dir, name);
+  }
+  closedir(d);
+
+  return 0;
+}
@@ -0,0 +1,354 @@
+use rand::Rng;
+#[cfg(all(feature = "x86_sse41", feature = "x86_ssse3"))]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    decode::{DecodeError, DeltaDecodeQuad, WriteQuadToSlice, ZigzagDecodeQuad},
+    encode::{encode, DeltaEncodeQuad, ZigzagEncodeQuad},
+    frame::{
+        decode_framed, decode_framed_zigzag, encode_framed, encode_framed_zigzag, verify_framed,
+        FrameError, FrameHeader, FrameOptions, FramedDecodeCursor,
+    },
+    scalar::Scalar,
+};
+
+#[path = "../src/random_varint.rs"]
+mod random_varint;
+
+use crate::random_varint::*;
+
+#[test]
+fn random_framed_roundtrip_scalar_scalar() {
+    do_random_framed_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_ssse3"))]
+#[test]
+fn random_framed_roundtrip_sse41_ssse3() {
+    do_random_framed_roundtrip::<x86::Sse41, x86::Ssse3>();
+}
+
+#[test]
+fn framed_known_output() {
+    let mut framed = Vec::new();
+    let len = encode_framed::<Scalar>(&[1, 300, 2], FrameOptions::default(), &mut framed);
+
+    assert_eq!(
+        vec![
+            // magic, version
+            b'S',
+            b'V',
+            b'B',
+            b'F',
+            1, //
+            // count, flags
+            3,
+            0x00, //
+            // control byte, then 1 + 2 + 1 bytes of numbers
+            0b00_00_01_00,
+            1,
+            0x2C,
+            0x01,
+            2
+        ],
+        framed
+    );
+    assert_eq!(framed.len(), len);
+}
+
+#[test]
+fn framed_header_flags() {
+    let mut framed = Vec::new();
+    let options = FrameOptions {
+        delta: true,
+        ..FrameOptions::default()
+    };
+    encode_framed_zigzag::<Scalar>(&[-1; 200], options, &mut framed);
+
+    let header = FrameHeader::read(&framed).unwrap();
+    assert_eq!(200, header.count());
+    assert!(header.is_delta());
+    assert!(header.is_zigzag());
+    // count takes 2 bytes as a varint
+    assert_eq!(4 + 1 + 2 + 1, header.header_len());
+}
+
+#[test]
+fn framed_wrong_signedness() {
+    let mut unsigned = Vec::new();
+    encode_framed::<Scalar>(&[1, 2, 3], FrameOptions::default(), &mut unsigned);
+    let mut signed = Vec::new();
+    encode_framed_zigzag::<Scalar>(&[1, 2, 3], FrameOptions::default(), &mut signed);
+
+    assert_eq!(
+        Err(FrameError::SignednessMismatch { zigzag: false }),
+        decode_framed_zigzag::<Scalar>(&unsigned)
+    );
+    assert_eq!(
+        Err(FrameError::SignednessMismatch { zigzag: true }),
+        decode_framed::<Scalar>(&signed)
+    );
+}
+
+#[test]
+fn framed_bad_header_errors() {
+    let mut framed = Vec::new();
+    encode_framed::<Scalar>(&[1, 2, 3], FrameOptions::default(), &mut framed);
+
+    let mut bad_magic = framed.clone();
+    bad_magic[0] = b'X';
+    assert_eq!(Err(FrameError::BadMagic), FrameHeader::read(&bad_magic));
+
+    let mut bad_version = framed.clone();
+    bad_version[4] = 2;
+    assert_eq!(
+        Err(FrameError::UnsupportedVersion(2)),
+        FrameHeader::read(&bad_version)
+    );
+
+    let mut bad_flags = framed.clone();
+    bad_flags[6] = 0x80;
+    assert_eq!(
+        Err(FrameError::UnsupportedFlags(0x80)),
+        FrameHeader::read(&bad_flags)
+    );
+
+    for len in 0..7 {
+        assert_eq!(
+            Err(FrameError::TruncatedHeader),
+            FrameHeader::read(&framed[0..len])
+        );
+    }
+}
+
+#[test]
+fn framed_truncated_stream_errors() {
+    let mut framed = Vec::new();
+    encode_framed::<Scalar>(&[1, 300, 2], FrameOptions::default(), &mut framed);
+
+    assert_eq!(
+        Err(FrameError::Decode(DecodeError::TruncatedData {
+            required: 4,
+            actual: 3
+        })),
+        decode_framed::<Scalar>(&framed[0..(framed.len() - 1)])
+    );
+}
+
+#[test]
+fn framed_huge_count_errors() {
+    // a count of u64::MAX with nothing after the header
+    let mut framed = b"SVBF\x01".to_vec();
+    framed.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+    framed.push(0x00);
+
+    assert_eq!(16, framed.len());
+    assert_eq!(Err(FrameError::CountOverflow), verify_framed(&framed));
+    assert_eq!(
+        Err(FrameError::CountOverflow),
+        decode_framed::<Scalar>(&framed)
+    );
+}
+
+#[test]
+fn framed_cursor_walks_concatenated_frames() {
+    let first: Vec<u32> = (0..1000).collect();
+    let second: Vec<u32> = (0..10).map(|i| i * 1_000_000).collect();
+
+    let mut framed = Vec::new();
+    encode_framed::<Scalar>(
+        &first,
+        FrameOptions {
+            delta: true,
+            ..FrameOptions::default()
+        },
+        &mut framed,
+    );
+    encode_framed::<Scalar>(&second, FrameOptions::default(), &mut framed);
+
+    let mut decoded = vec![0; 1000];
+
+    let mut cursor = FramedDecodeCursor::new(&framed).unwrap();
+    assert_eq!(1000, cursor.decode_slice::<Scalar>(&mut decoded));
+    assert!(!cursor.has_more());
+    assert_eq!(&first[..], &decoded[..]);
+    let first_len = cursor.input_consumed();
+
+    let mut cursor = FramedDecodeCursor::new(&framed[first_len..]).unwrap();
+    assert_eq!(10, cursor.decode_slice::<Scalar>(&mut decoded));
+    assert_eq!(&second[..], &decoded[0..10]);
+    assert_eq!(framed.len(), first_len + cursor.input_consumed());
+}
+
+#[test]
+fn framed_cursor_skip_keeps_delta_sum() {
+    let nums: Vec<u32> = (0..1000).map(|i| i * 7).collect();
+    let signed: Vec<i32> = (0..1000).map(|i| 500 - i * 3).collect();
+
+    let mut framed = Vec::new();
+    encode_framed::<Scalar>(
+        &nums,
+        FrameOptions {
+            delta: true,
+            ..FrameOptions::default()
+        },
+        &mut framed,
+    );
+    let mut signed_framed = Vec::new();
+    encode_framed_zigzag::<Scalar>(
+        &signed,
+        FrameOptions {
+            delta: true,
+            ..FrameOptions::default()
+        },
+        &mut signed_framed,
+    );
+
+    let mut decoded = vec![0; 1000];
+
+    let mut cursor = FramedDecodeCursor::new(&framed).unwrap();
+    cursor.skip(401);
+    assert_eq!(599, cursor.decode_slice::<Scalar>(&mut decoded));
+    assert_eq!(&nums[401..], &decoded[0..599]);
+
+    let mut cursor = FramedDecodeCursor::new(&signed_framed).unwrap();
+    cursor.skip(403);
+    assert_eq!(597, cursor.decode_slice::<Scalar>(&mut decoded));
+    let decoded_signed: Vec<i32> = decoded[0..597].iter().map(|&n| n as i32).collect();
+    assert_eq!(&signed[403..], &decoded_signed[..]);
+}
+
+#[test]
+fn framed_checksum_known_output() {
+    let mut framed = Vec::new();
+    let options = FrameOptions {
+        checksum: true,
+        ..FrameOptions::default()
+    };
+    encode_framed::<Scalar>(&[1, 300, 2], options, &mut framed);
+
+    let header = FrameHeader::read(&framed).unwrap();
+    assert!(header.has_checksum());
+    assert_eq!(0x04, framed[6]);
+
+    let stream = [0b00_00_01_00, 1, 0x2C, 0x01, 2];
+    assert_eq!(&stream[..], &framed[7..12]);
+    // CRC32C of the stream, little endian
+    assert_eq!(&[0x76, 0xEB, 0x20, 0xCD][..], &framed[12..]);
+}
+
+#[test]
+fn framed_checksum_detects_every_bit_flip() {
+    let nums: Vec<u32> = (0..100).map(|i| i * 1_001).collect();
+    let options = FrameOptions {
+        checksum: true,
+        ..FrameOptions::default()
+    };
+    let mut framed = Vec::new();
+    encode_framed::<Scalar>(&nums, options, &mut framed);
+    let header_len = FrameHeader::read(&framed).unwrap().header_len();
+
+    for i in header_len..framed.len() {
+        for bit in 0..8 {
+            let mut corrupt = framed.clone();
+            corrupt[i] ^= 1 << bit;
+
+            // a flipped control byte can move where the checksum is read from,
+            // or past the end of the input
+            let err = verify_framed(&corrupt).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    FrameError::ChecksumMismatch { .. }
+                        | FrameError::TruncatedChecksum
+                        | FrameError::Decode(DecodeError::TruncatedData { .. })
+                ),
+                "{:?}",
+                err
+            );
+            assert_eq!(Err(err), decode_framed::<Scalar>(&corrupt));
+        }
+    }
+}
+
+#[test]
+fn framed_checksum_mismatch_error() {
+    let options = FrameOptions {
+        checksum: true,
+        ..FrameOptions::default()
+    };
+    let mut framed = Vec::new();
+    encode_framed::<Scalar>(&[1, 2, 3, 4], options, &mut framed);
+    let checksum_start = framed.len() - 4;
+    framed[checksum_start] ^= 0xFF;
+
+    match FramedDecodeCursor::new(&framed).unwrap_err() {
+        FrameError::ChecksumMismatch { expected, actual } => assert_eq!(expected ^ 0xFF, actual),
+        e => panic!("Unexpected error {:?}", e),
+    }
+
+    for len in (framed.len() - 4)..framed.len() {
+        assert_eq!(
+            Err(FrameError::TruncatedChecksum),
+            verify_framed(&framed[0..len])
+        );
+    }
+}
+
+#[test]
+fn framed_cursor_input_consumed_includes_checksum() {
+    let nums: Vec<u32> = (0..10).collect();
+    let options = FrameOptions {
+        checksum: true,
+        ..FrameOptions::default()
+    };
+    let mut framed = Vec::new();
+    encode_framed::<Scalar>(&nums, options, &mut framed);
+    // another frame after it
+    encode_framed::<Scalar>(&nums, FrameOptions::default(), &mut framed);
+
+    let mut decoded = vec![0; 10];
+    let mut cursor = FramedDecodeCursor::new(&framed).unwrap();
+    assert_eq!(10, cursor.decode_slice::<Scalar>(&mut decoded));
+    assert_eq!(nums, decoded);
+    assert_eq!(verify_framed(&framed).unwrap(), cursor.input_consumed());
+}
+
+fn do_random_framed_roundtrip<E, D>()
+where
+    E: DeltaEncodeQuad + ZigzagEncodeQuad,
+    D: DeltaDecodeQuad + ZigzagDecodeQuad + WriteQuadToSlice,
+{
+    let mut nums: Vec<u32> = Vec::new();
+    let mut framed = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 1000);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+        let signed: Vec<i32> = nums.iter().map(|&n| n as i32).collect();
+        let options = FrameOptions {
+            delta: rng.gen(),
+            checksum: rng.gen(),
+        };
+
+        framed.clear();
+        let framed_len = encode_framed::<E>(&nums, options, &mut framed);
+        assert_eq!(framed.len(), framed_len);
+        assert_eq!(nums, decode_framed::<D>(&framed).unwrap());
+
+        assert_eq!(framed_len, verify_framed(&framed).unwrap());
+
+        if !options.delta && !options.checksum {
+            // the stream is the same as plain encoding
+            let mut encoded = vec![0; count * 5];
+            let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+            let header_len = FrameHeader::read(&framed).unwrap().header_len();
+            assert_eq!(&encoded[0..encoded_len], &framed[header_len..]);
+        }
+
+        framed.clear();
+        encode_framed_zigzag::<E>(&signed, options, &mut framed);
+        assert_eq!(signed, decode_framed_zigzag::<D>(&framed).unwrap());
+    }
+}
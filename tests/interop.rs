@@ -0,0 +1,298 @@
+//! Checks every `Encoder` and `Decoder` against the corpus in
+//! `tests/data/corpus`, where each `<name>.bin` is the encoding of the
+//! little-endian `u32`s in `<name>.nums`. See `tests/data/README.md`.
+
+use std::{fs, path::PathBuf};
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+use stream_vbyte::aarch64;
+#[cfg(any(
+    feature = "x86_sse41",
+    feature = "x86_ssse3",
+    feature = "x86_avx2",
+    feature = "x86_avx512"
+))]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    decode::{decode, decode_delta, DeltaDecodeQuad, WriteQuadToSlice},
+    encode::{encode, encode_delta, max_encoded_len, DeltaEncodeQuad},
+    scalar::Scalar,
+    svb0124::{decode_0124, encode_0124, Decoder0124, Encoder0124},
+};
+
+#[test]
+fn corpus_has_every_case() {
+    let names: Vec<String> = corpus().into_iter().map(|case| case.name).collect();
+
+    for &name in [
+        "empty",
+        "len_1",
+        "len_7",
+        "zeros",
+        "max",
+        "random_lengths",
+        "delta_sorted",
+        "0124_len_1",
+        "0124_len_2",
+        "0124_len_3",
+        "0124_len_4",
+        "0124_len_5",
+        "0124_len_6",
+        "0124_len_7",
+        "0124_zeros",
+        "0124_sparse",
+    ]
+    .iter()
+    {
+        assert!(names.iter().any(|n| n == name), "missing {}", name);
+    }
+
+    // cases without their expected encoding would otherwise be silently skipped
+    for file_name in corpus_file_names() {
+        if let Some(name) = file_name.strip_suffix(".nums") {
+            assert!(
+                names.iter().any(|n| n == name),
+                "{}.nums has no {}.bin, see tests/data/README.md",
+                name,
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn decode_corpus_scalar() {
+    do_decode_corpus::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn decode_corpus_ssse3() {
+    do_decode_corpus::<x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn decode_corpus_avx2() {
+    do_decode_corpus::<x86::Avx2>();
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn decode_corpus_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_decode_corpus::<x86::Avx512Decoder>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn decode_corpus_neon() {
+    do_decode_corpus::<aarch64::NeonDecoder>();
+}
+
+#[test]
+fn encode_corpus_scalar() {
+    do_encode_corpus::<Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn encode_corpus_sse41() {
+    do_encode_corpus::<x86::Sse41>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn encode_corpus_ssse3() {
+    do_encode_corpus::<x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn encode_corpus_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_encode_corpus::<x86::Avx512Encoder>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn encode_corpus_neon() {
+    do_encode_corpus::<aarch64::NeonEncoder>();
+}
+
+#[test]
+fn decode_0124_corpus_scalar() {
+    do_decode_0124_corpus::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn decode_0124_corpus_ssse3() {
+    do_decode_0124_corpus::<x86::Ssse3>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn decode_0124_corpus_neon() {
+    do_decode_0124_corpus::<aarch64::NeonDecoder>();
+}
+
+#[test]
+fn encode_0124_corpus_scalar() {
+    do_encode_0124_corpus::<Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn encode_0124_corpus_sse41() {
+    do_encode_0124_corpus::<x86::Sse41>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn encode_0124_corpus_ssse3() {
+    do_encode_0124_corpus::<x86::Ssse3>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn encode_0124_corpus_neon() {
+    do_encode_0124_corpus::<aarch64::NeonEncoder>();
+}
+
+/// How a case was encoded, from the prefix of its name
+#[derive(Debug, PartialEq)]
+enum Mode {
+    Plain,
+    /// `encode_delta()` with a `prev` of 0
+    Delta,
+    Zero124,
+}
+
+struct Case {
+    name: String,
+    mode: Mode,
+    nums: Vec<u32>,
+    encoded: Vec<u8>,
+}
+
+/// All the cases in the corpus, sorted by name
+fn corpus() -> Vec<Case> {
+    let dir = corpus_dir();
+
+    let mut names: Vec<String> = corpus_file_names()
+        .into_iter()
+        .filter_map(|file_name| file_name.strip_suffix(".bin").map(|s| s.to_owned()))
+        .collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let nums_bytes = fs::read(dir.join(format!("{}.nums", name))).unwrap();
+            assert_eq!(0, nums_bytes.len() % 4, "{}.nums is not whole u32s", name);
+
+            let mode = if name.starts_with("delta_") {
+                Mode::Delta
+            } else if name.starts_with("0124_") {
+                Mode::Zero124
+            } else {
+                Mode::Plain
+            };
+
+            Case {
+                nums: nums_bytes
+                    .chunks_exact(4)
+                    .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                    .collect(),
+                encoded: fs::read(dir.join(format!("{}.bin", name))).unwrap(),
+                name,
+                mode,
+            }
+        })
+        .collect()
+}
+
+fn corpus_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/data/corpus")
+}
+
+fn corpus_file_names() -> Vec<String> {
+    fs::read_dir(corpus_dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect()
+}
+
+fn do_decode_corpus<D: DeltaDecodeQuad + WriteQuadToSlice>() {
+    for case in corpus().iter().filter(|c| c.mode != Mode::Zero124) {
+        let mut decoded = vec![0; case.nums.len()];
+
+        let bytes_read = match case.mode {
+            Mode::Delta => decode_delta::<D>(&case.encoded, case.nums.len(), 0, &mut decoded),
+            _ => decode::<D>(&case.encoded, case.nums.len(), &mut decoded),
+        };
+
+        assert_eq!(case.encoded.len(), bytes_read, "{}", case.name);
+        assert_eq!(case.nums, decoded, "{}", case.name);
+    }
+}
+
+fn do_encode_corpus<E: DeltaEncodeQuad>() {
+    for case in corpus().iter().filter(|c| c.mode != Mode::Zero124) {
+        let mut encoded = vec![0; max_encoded_len(case.nums.len())];
+
+        let bytes_written = match case.mode {
+            Mode::Delta => encode_delta::<E>(&case.nums, 0, &mut encoded),
+            _ => encode::<E>(&case.nums, &mut encoded),
+        };
+
+        assert_eq!(case.encoded, &encoded[0..bytes_written], "{}", case.name);
+    }
+}
+
+fn do_decode_0124_corpus<D: Decoder0124>() {
+    let cases: Vec<Case> = corpus()
+        .into_iter()
+        .filter(|c| c.mode == Mode::Zero124)
+        .collect();
+    assert!(!cases.is_empty(), "no 0124_ cases in the corpus");
+
+    for case in &cases {
+        let mut decoded = vec![0; case.nums.len()];
+
+        let bytes_read = decode_0124::<D>(&case.encoded, case.nums.len(), &mut decoded);
+
+        assert_eq!(case.encoded.len(), bytes_read, "{}", case.name);
+        assert_eq!(case.nums, decoded, "{}", case.name);
+    }
+}
+
+fn do_encode_0124_corpus<E: Encoder0124>() {
+    let cases: Vec<Case> = corpus()
+        .into_iter()
+        .filter(|c| c.mode == Mode::Zero124)
+        .collect();
+    assert!(!cases.is_empty(), "no 0124_ cases in the corpus");
+
+    for case in &cases {
+        let mut encoded = vec![0; stream_vbyte::svb0124::max_encoded_len(case.nums.len())];
+
+        let bytes_written = encode_0124::<E>(&case.nums, &mut encoded);
+
+        assert_eq!(case.encoded, &encoded[0..bytes_written], "{}", case.name);
+    }
+}
+
+#[cfg(feature = "x86_avx512")]
+fn avx512_vbmi_supported() -> bool {
+    is_x86_feature_detected!("avx512f")
+        && is_x86_feature_detected!("avx512bw")
+        && is_x86_feature_detected!("avx512vbmi")
+}
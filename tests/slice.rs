@@ -0,0 +1,181 @@
+use rand::Rng;
+#[cfg(any(feature = "x86_ssse3", feature = "x86_avx2"))]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    decode::{slice::StreamVbyteSlice, DecodeError, Decoder, WriteQuadToSlice},
+    encode::encode_to_vec,
+    scalar::Scalar,
+};
+
+#[path = "../src/random_varint.rs"]
+mod random_varint;
+
+use crate::random_varint::*;
+
+#[test]
+fn slice_get_every_index() {
+    for count in 0..50 {
+        let nums: Vec<u32> = (0..count).map(|i| i * 100_003).collect();
+        let encoded = encode_to_vec::<Scalar>(&nums);
+
+        let plain = StreamVbyteSlice::new(&encoded, nums.len());
+        let indexed = plain.clone().with_sampled_index(8);
+        for slice in [plain, indexed].iter() {
+            assert_eq!(nums.len(), slice.len());
+            assert_eq!(count == 0, slice.is_empty());
+
+            for (i, &num) in nums.iter().enumerate() {
+                assert_eq!(Some(num), slice.get(i));
+            }
+            assert_eq!(None, slice.get(nums.len()));
+            assert_eq!(None, slice.get(usize::MAX));
+        }
+    }
+}
+
+#[test]
+fn slice_get_range_every_range() {
+    let nums: Vec<u32> = (0..30).map(|i| i * 100_003).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+    let slice = StreamVbyteSlice::new(&encoded, nums.len()).with_sampled_index(4);
+
+    let mut output = vec![0; nums.len() + 1];
+    for start in 0..(nums.len() + 1) {
+        for end in start..(nums.len() + 1) {
+            for o in output.iter_mut() {
+                *o = 12345;
+            }
+
+            slice.get_range(start..end, &mut output);
+            assert_eq!(&nums[start..end], &output[0..(end - start)]);
+            // the rest is untouched
+            assert!(output[(end - start)..].iter().all(|&n| n == 12345));
+        }
+    }
+}
+
+#[test]
+fn slice_random_get_and_get_range() {
+    let mut rng = rand::weak_rng();
+    let mut output = Vec::new();
+
+    for _ in 0..200 {
+        let count = rng.gen_range(0, 5000);
+        let nums: Vec<u32> = RandomVarintEncodedLengthIter::new(rand::weak_rng())
+            .take(count)
+            .collect();
+        let encoded = encode_to_vec::<Scalar>(&nums);
+
+        let slice = StreamVbyteSlice::try_new(&encoded, count).unwrap();
+        let interval = 4 * rng.gen_range(1, 100);
+        let indexed = slice.clone().with_sampled_index(interval);
+
+        for _ in 0..20 {
+            if count == 0 {
+                break;
+            }
+
+            let i = rng.gen_range(0, count);
+            assert_eq!(Some(nums[i]), slice.get(i));
+            assert_eq!(Some(nums[i]), indexed.get(i));
+
+            let end = rng.gen_range(i, count + 1);
+            output.clear();
+            output.resize(end - i, 0);
+            indexed.get_range(i..end, &mut output);
+            assert_eq!(&nums[i..end], &output[..]);
+
+            // a cursor from the middle decodes the rest
+            let mut cursor = indexed.cursor_at(i);
+            output.resize(count - i, 0);
+            let mut decoded = 0;
+            while cursor.has_more() {
+                decoded += cursor.decode_slice::<Scalar>(&mut output[decoded..]);
+            }
+            assert_eq!(&nums[i..], &output[..]);
+            assert_eq!(encoded.len(), cursor.input_consumed());
+        }
+    }
+}
+
+#[test]
+fn slice_iter() {
+    let nums: Vec<u32> = (0..1001).map(|i| i * 100_003).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+    let slice = StreamVbyteSlice::new(&encoded, nums.len());
+
+    assert_eq!(nums.len(), slice.iter().len());
+    assert_eq!(nums, slice.iter().collect::<Vec<u32>>());
+    assert_eq!(Some(nums[999]), slice.iter().nth(999));
+    assert_eq!(None, slice.iter().nth(1001));
+}
+
+#[test]
+fn slice_with_decoder_scalar() {
+    do_slice_with_decoder::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn slice_with_decoder_ssse3() {
+    do_slice_with_decoder::<x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn slice_with_decoder_avx2() {
+    do_slice_with_decoder::<x86::Avx2>();
+}
+
+#[test]
+fn slice_try_new_truncated() {
+    let nums: Vec<u32> = (0..10).map(|i| i * 1000).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+
+    assert_eq!(
+        DecodeError::TruncatedData {
+            required: 19,
+            actual: 18
+        },
+        StreamVbyteSlice::try_new(&encoded[0..(encoded.len() - 1)], nums.len()).unwrap_err()
+    );
+    assert_eq!(
+        DecodeError::TruncatedControlBytes {
+            required: 3,
+            actual: 2
+        },
+        StreamVbyteSlice::try_new(&encoded[0..2], nums.len()).unwrap_err()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Range 3..11 is out of bounds for 10 numbers")]
+fn slice_get_range_panics_out_of_bounds() {
+    let nums: Vec<u32> = (0..10).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+
+    StreamVbyteSlice::new(&encoded, nums.len()).get_range(3..11, &mut [0; 8]);
+}
+
+#[test]
+#[should_panic(expected = "Interval must be a nonzero multiple of 4")]
+fn slice_sampled_index_panics_on_bad_interval() {
+    StreamVbyteSlice::new(&[], 0).with_sampled_index(6);
+}
+
+fn do_slice_with_decoder<D: Decoder + WriteQuadToSlice>() {
+    let nums: Vec<u32> = (0..1001).map(|i| i * 100_003).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+    let slice = StreamVbyteSlice::new(&encoded, nums.len()).with_sampled_index(64);
+
+    for (i, &num) in nums.iter().enumerate() {
+        assert_eq!(Some(num), slice.get_with::<D>(i));
+    }
+    assert_eq!(None, slice.get_with::<D>(nums.len()));
+
+    let mut output = vec![0; 500];
+    slice.get_range_with::<D>(333..833, &mut output);
+    assert_eq!(&nums[333..833], &output[..]);
+
+    assert_eq!(nums, slice.iter_with::<D>().collect::<Vec<u32>>());
+}
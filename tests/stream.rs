@@ -0,0 +1,95 @@
+use rand::Rng;
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3"))]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    decode::decode_to_vec,
+    encode::{encode_to_vec, stream::StreamEncoder, Encoder},
+    scalar::Scalar,
+};
+
+#[path = "../src/random_varint.rs"]
+mod random_varint;
+
+use crate::random_varint::*;
+
+#[test]
+fn random_stream_encoder_matches_encode_scalar() {
+    do_random_stream_encoder_matches_encode::<Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn random_stream_encoder_matches_encode_sse41() {
+    do_random_stream_encoder_matches_encode::<x86::Sse41>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_stream_encoder_matches_encode_ssse3() {
+    do_random_stream_encoder_matches_encode::<x86::Ssse3>();
+}
+
+#[test]
+fn stream_encoder_empty() {
+    let encoder = StreamEncoder::<Scalar>::new();
+    assert!(encoder.is_empty());
+    assert_eq!(0, encoder.encoded_len_so_far());
+    assert_eq!(Vec::<u8>::new(), encoder.finish());
+
+    let mut encoder = StreamEncoder::<Scalar>::default();
+    encoder.extend(&[]);
+    assert!(encoder.is_empty());
+    assert_eq!(Vec::<u8>::new(), encoder.finish());
+}
+
+#[test]
+fn stream_encoder_push_one_at_a_time() {
+    let nums: Vec<u32> = (0..10_000).map(|i| i * 1_001).collect();
+
+    let mut encoder = StreamEncoder::<Scalar>::new();
+    for (i, &num) in nums.iter().enumerate() {
+        encoder.push(num);
+        assert_eq!(i + 1, encoder.len());
+    }
+    assert_eq!(
+        encode_to_vec::<Scalar>(&nums).len(),
+        encoder.encoded_len_so_far()
+    );
+
+    let encoded = encoder.finish();
+    assert_eq!(encode_to_vec::<Scalar>(&nums), encoded);
+    assert_eq!(nums, decode_to_vec::<Scalar>(&encoded, nums.len()));
+}
+
+fn do_random_stream_encoder_matches_encode<E: Encoder>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..500 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 3000);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+
+        // a mix of pushes and extends of random lengths
+        let mut encoder = StreamEncoder::<E>::new();
+        let mut added = 0;
+        while added < count {
+            if rng.gen_weighted_bool(3) {
+                encoder.push(nums[added]);
+                added += 1;
+            } else {
+                let len = rng.gen_range(0, std::cmp::min(count - added, 600) + 1);
+                encoder.extend(&nums[added..(added + len)]);
+                added += len;
+            }
+
+            assert_eq!(added, encoder.len());
+            assert_eq!(
+                encode_to_vec::<Scalar>(&nums[0..added]).len(),
+                encoder.encoded_len_so_far()
+            );
+        }
+
+        assert_eq!(encode_to_vec::<Scalar>(&nums), encoder.finish());
+    }
+}
@@ -0,0 +1,360 @@
+use rand::Rng;
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+use stream_vbyte::aarch64;
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3"))]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    decode::{DecodeError, StreamVbyteDecoder},
+    encode::StreamVbyteEncoder,
+    scalar::Scalar,
+    svb0124::{
+        cursor::DecodeCursor0124, decode_0124, decode_0124_to_vec, encode_0124, encode_0124_to_vec,
+        max_encoded_len, Decoder0124, Encoder0124,
+    },
+};
+
+#[test]
+fn random_roundtrip_scalar_scalar() {
+    do_random_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn random_roundtrip_sse41_scalar() {
+    do_random_roundtrip::<x86::Sse41, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_roundtrip_scalar_ssse3() {
+    do_random_roundtrip::<Scalar, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_ssse3"))]
+#[test]
+fn random_roundtrip_sse41_ssse3() {
+    do_random_roundtrip::<x86::Sse41, x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_roundtrip_ssse3_ssse3() {
+    do_random_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn random_roundtrip_neon_neon() {
+    do_random_roundtrip::<aarch64::NeonEncoder, aarch64::NeonDecoder>();
+}
+
+#[test]
+fn random_roundtrip_default_encoder_decoder() {
+    do_random_roundtrip::<StreamVbyteEncoder, StreamVbyteDecoder>();
+}
+
+#[test]
+fn all_zeros_scalar_scalar() {
+    do_all_zeros::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn all_zeros_ssse3_ssse3() {
+    do_all_zeros::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[test]
+fn partial_final_quad_roundtrip_scalar() {
+    do_partial_final_quad_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn partial_final_quad_roundtrip_ssse3() {
+    do_partial_final_quad_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn partial_final_quad_roundtrip_neon() {
+    do_partial_final_quad_roundtrip::<aarch64::NeonEncoder, aarch64::NeonDecoder>();
+}
+
+#[test]
+fn known_output_scalar() {
+    do_known_output::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn known_output_sse41() {
+    do_known_output::<x86::Sse41, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn known_output_ssse3() {
+    do_known_output::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[test]
+fn max_encoded_len_counts_partial_quad() {
+    assert_eq!(0, max_encoded_len(0));
+    assert_eq!(1 + 4, max_encoded_len(1));
+    assert_eq!(1 + 16, max_encoded_len(4));
+    assert_eq!(2 + 20, max_encoded_len(5));
+}
+
+#[test]
+#[should_panic(expected = "output buffer was not large enough")]
+fn decode_panics_on_short_output() {
+    let encoded = encode_0124_to_vec::<Scalar>(&[1, 0, 3]);
+
+    decode_0124::<Scalar>(&encoded, 3, &mut [0; 2]);
+}
+
+#[test]
+fn try_new_truncated_input_errors() {
+    let nums: Vec<u32> = (0..10)
+        .map(|i| if i % 3 == 0 { 0 } else { i << 12 })
+        .collect();
+    let encoded = encode_0124_to_vec::<Scalar>(&nums);
+
+    assert_eq!(
+        DecodeError::TruncatedControlBytes {
+            required: 3,
+            actual: 2
+        },
+        DecodeCursor0124::try_new(&encoded[0..2], nums.len()).unwrap_err()
+    );
+
+    for len in 3..encoded.len() {
+        assert_eq!(
+            DecodeError::TruncatedData {
+                required: encoded.len() - 3,
+                actual: len - 3
+            },
+            DecodeCursor0124::try_new(&encoded[0..len], nums.len()).unwrap_err()
+        );
+    }
+
+    assert!(DecodeCursor0124::try_new(&encoded, nums.len()).is_ok());
+}
+
+#[test]
+fn cursor_skip_every_len_scalar() {
+    do_cursor_skip_every_len::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn cursor_skip_every_len_ssse3() {
+    do_cursor_skip_every_len::<x86::Ssse3>();
+}
+
+#[test]
+fn cursor_random_skip_and_decode_scalar() {
+    do_cursor_random_skip_and_decode::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn cursor_random_skip_and_decode_ssse3() {
+    do_cursor_random_skip_and_decode::<x86::Ssse3>();
+}
+
+fn do_random_roundtrip<E: Encoder0124, D: Decoder0124>() {
+    let mut rng = rand::weak_rng();
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 1000);
+        // anywhere from no zeros to all zeros
+        let zero_odds = rng.gen_range(0, 11);
+        nums.extend((0..count).map(|_| random_u32(&mut rng, zero_odds)));
+
+        encoded.clear();
+        encoded.resize(max_encoded_len(count), 0xFF);
+        let encoded_len = encode_0124::<E>(&nums, &mut encoded);
+        assert_eq!(
+            &encode_0124_to_vec::<Scalar>(&nums)[..],
+            &encoded[0..encoded_len]
+        );
+
+        decoded.clear();
+        decoded.resize(count, 0);
+        assert_eq!(
+            encoded_len,
+            decode_0124::<D>(&encoded[0..encoded_len], count, &mut decoded)
+        );
+        assert_eq!(nums, decoded);
+    }
+}
+
+fn do_all_zeros<E: Encoder0124, D: Decoder0124>() {
+    for count in 0..100 {
+        let nums = vec![0; count];
+        let encoded = encode_0124_to_vec::<E>(&nums);
+
+        // only control bytes, all of them zero
+        assert_eq!((count + 3) / 4, encoded.len());
+        assert!(encoded.iter().all(|&b| b == 0));
+        assert_eq!(nums, decode_0124_to_vec::<D>(&encoded, nums.len()));
+    }
+}
+
+fn do_partial_final_quad_roundtrip<E: Encoder0124, D: Decoder0124>() {
+    let all_nums: Vec<u32> = (0..100_u32)
+        .map(|i| {
+            if i % 3 == 1 {
+                0
+            } else {
+                i.wrapping_mul(0x0123_4567) >> (i % 32)
+            }
+        })
+        .collect();
+
+    for len in 0..all_nums.len() {
+        let nums = &all_nums[0..len];
+        let encoded = encode_0124_to_vec::<E>(nums);
+        assert_eq!(encode_0124_to_vec::<Scalar>(nums), encoded);
+
+        assert_eq!(nums, &decode_0124_to_vec::<D>(&encoded, len)[..]);
+    }
+}
+
+// These were worked out by hand from the format, not written by the reference
+// C implementation's `streamvbyte_encode_0124()`: (count + 3) / 4 control
+// bytes, then the encoded numbers, with 2-bit codes from the low bits of each
+// control byte where 0 is a zero in 0 bytes, 1 is 1 byte, 2 is 2 bytes and 3
+// is 4 bytes. Checking against the reference's own output is left to the
+// `0124_` cases in `tests/data/corpus`, which `tests/interop.rs` runs.
+fn do_known_output<E: Encoder0124, D: Decoder0124>() {
+    let nums = [0, 1, 0xFF, 0x100, 0xFFFF, 0x1_0000, 0, 0, 0x1234_5678];
+
+    #[rustfmt::skip]
+    let expected = vec![
+        // codes 0, 1, 1, 2
+        0b10_01_01_00,
+        // codes 2, 3, 0, 0
+        0b00_00_11_10,
+        // the trailing partial quad of 1, with the unused bits clear
+        0b00_00_00_11,
+        0x01,
+        0xFF,
+        0x00, 0x01,
+        0xFF, 0xFF,
+        0x00, 0x00, 0x01, 0x00,
+        0x78, 0x56, 0x34, 0x12,
+    ];
+    assert_eq!(expected, encode_0124_to_vec::<E>(&nums));
+    assert_eq!(
+        &nums[..],
+        &decode_0124_to_vec::<D>(&expected, nums.len())[..]
+    );
+
+    // 3-byte numbers take 4 bytes, and a quad of zeros takes only its control
+    // byte
+    let nums = [0x12_3456, 0, 0, 0, 0, 0, 0, 0, 0xAB, 0, 0xCDEF, 0];
+
+    #[rustfmt::skip]
+    let expected = vec![
+        0b00_00_00_11,
+        0b00_00_00_00,
+        0b00_10_00_01,
+        0x56, 0x34, 0x12, 0x00,
+        0xAB,
+        0xEF, 0xCD,
+    ];
+    assert_eq!(expected, encode_0124_to_vec::<E>(&nums));
+    assert_eq!(
+        &nums[..],
+        &decode_0124_to_vec::<D>(&expected, nums.len())[..]
+    );
+
+    // the same numbers as tests/data/data.bin, 0, 100, 200, ... 499900, where 0
+    // takes no bytes, 100 and 200 take 1, up to 65500 take 2, and the rest take 4
+    let nums: Vec<u32> = (0..5000).map(|x| x * 100).collect();
+    let encoded = encode_0124_to_vec::<E>(&nums);
+    assert_eq!(1250 + 2 + 2 * 653 + 4 * 4344, encoded.len());
+    assert_eq!(0b10_01_01_00, encoded[0]);
+    assert_eq!(&[100, 200, 0x2C, 0x01][..], &encoded[1250..1254]);
+    assert_eq!(nums, decode_0124_to_vec::<D>(&encoded, nums.len()));
+}
+
+fn do_cursor_skip_every_len<D: Decoder0124>() {
+    let nums: Vec<u32> = (0..100_u32)
+        .map(|i| if i % 4 == 2 { 0 } else { (i * 7) << (i % 24) })
+        .collect();
+    let encoded = encode_0124_to_vec::<Scalar>(&nums);
+    let mut decoded = vec![0; nums.len()];
+
+    for skip in 0..=nums.len() {
+        let mut cursor = DecodeCursor0124::new(&encoded, nums.len());
+        cursor.skip(skip);
+        assert_eq!(skip, cursor.position());
+
+        let count = cursor.decode_slice::<D>(&mut decoded);
+        assert_eq!(nums.len() - skip, count);
+        assert_eq!(&nums[skip..], &decoded[0..count]);
+
+        assert!(!cursor.has_more());
+        assert_eq!(encoded.len(), cursor.input_consumed());
+    }
+}
+
+fn do_cursor_random_skip_and_decode<D: Decoder0124>() {
+    let mut rng = rand::weak_rng();
+    let mut buf = [0; 20];
+
+    for _ in 0..200 {
+        let count = rng.gen_range(0, 500);
+        let nums: Vec<u32> = (0..count).map(|_| random_u32(&mut rng, 7)).collect();
+        let encoded = encode_0124_to_vec::<Scalar>(&nums);
+
+        let mut cursor = DecodeCursor0124::new(&encoded, count);
+        let mut expected_pos = 0;
+
+        while cursor.has_more() {
+            let remaining = count - expected_pos;
+
+            if rng.gen_weighted_bool(3) {
+                let to_skip = rng.gen_range(0, remaining + 1);
+                cursor.skip(to_skip);
+                expected_pos += to_skip;
+            } else {
+                let buf_len = rng.gen_range(0, buf.len() + 1);
+                let decoded = cursor.decode_slice::<D>(&mut buf[0..buf_len]);
+                assert_eq!(std::cmp::min(buf_len, remaining), decoded);
+                assert_eq!(
+                    &nums[expected_pos..(expected_pos + decoded)],
+                    &buf[0..decoded]
+                );
+                expected_pos += decoded;
+            }
+
+            assert_eq!(expected_pos, cursor.position());
+        }
+
+        assert_eq!(encoded.len(), cursor.input_consumed());
+    }
+}
+
+/// A zero `zero_odds` times out of 10, otherwise equally likely to be any of
+/// the other 3 encoded lengths
+fn random_u32<R: Rng>(rng: &mut R, zero_odds: u32) -> u32 {
+    if rng.gen_range(0, 10) < zero_odds {
+        return 0;
+    }
+
+    match rng.gen_range(0, 3) {
+        0 => rng.gen_range(1, 0x100),
+        1 => rng.gen_range(0x100, 0x1_0000),
+        _ => rng.gen_range(0x1_0000, u32::MAX),
+    }
+}
@@ -0,0 +1,139 @@
+use rand::Rng;
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+use stream_vbyte::aarch64;
+#[cfg(feature = "x86_ssse3")]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    decode::StreamVbyteDecoder,
+    encode::StreamVbyteEncoder,
+    scalar::Scalar,
+    svb16::{decode, decode_to_vec, encode, encode_to_vec, max_encoded_len, Decoder16, Encoder16},
+};
+
+#[test]
+fn random_roundtrip_scalar_scalar() {
+    do_random_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_roundtrip_ssse3_ssse3() {
+    do_random_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_ssse3"))]
+#[test]
+fn random_roundtrip_sse41_ssse3() {
+    do_random_roundtrip::<x86::Sse41, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn random_roundtrip_neon_neon() {
+    do_random_roundtrip::<aarch64::NeonEncoder, aarch64::NeonDecoder>();
+}
+
+#[test]
+fn random_roundtrip_default_encoder_decoder() {
+    do_random_roundtrip::<StreamVbyteEncoder, StreamVbyteDecoder>();
+}
+
+#[test]
+fn every_len_roundtrip_scalar() {
+    do_every_len_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn every_len_roundtrip_ssse3() {
+    do_every_len_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[test]
+fn known_output() {
+    let nums = [1, 300, 2, 0xFFFF, 0, 0x100, 0xFF, 3, 4, 0x1234];
+    let encoded = encode_to_vec::<Scalar>(&nums);
+
+    #[rustfmt::skip]
+    let expected = vec![
+        // numbers 1, 3 and 5 take 2 bytes
+        0b0010_1010,
+        // the trailing partial group of 2, where the second takes 2 bytes and the
+        // unused bits are clear
+        0b0000_0010,
+        1, 0x2C, 0x01, 2, 0xFF, 0xFF, 0, 0x00, 0x01, 0xFF, 3,
+        4, 0x34, 0x12,
+    ];
+    assert_eq!(expected, encoded);
+
+    assert_eq!(
+        &nums[..],
+        &decode_to_vec::<Scalar>(&encoded, nums.len())[..]
+    );
+}
+
+#[test]
+fn max_encoded_len_counts_partial_group() {
+    assert_eq!(0, max_encoded_len(0));
+    assert_eq!(1 + 2, max_encoded_len(1));
+    assert_eq!(1 + 16, max_encoded_len(8));
+    assert_eq!(2 + 18, max_encoded_len(9));
+}
+
+#[test]
+#[should_panic(expected = "output buffer was not large enough")]
+fn decode_panics_on_short_output() {
+    let encoded = encode_to_vec::<Scalar>(&[1, 2, 3]);
+
+    decode::<Scalar>(&encoded, 3, &mut [0; 2]);
+}
+
+fn do_random_roundtrip<E: Encoder16, D: Decoder16>() {
+    let mut rng = rand::weak_rng();
+    let mut nums: Vec<u16> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 2000);
+        // a random mix of lengths, with some runs that are mostly one length
+        let two_byte_odds = rng.gen_range(0, 11);
+        nums.extend((0..count).map(|_| {
+            if rng.gen_range(0, 10) < two_byte_odds {
+                rng.gen_range(0x100, 0x1_0000) as u16
+            } else {
+                rng.gen_range(0, 0x100) as u16
+            }
+        }));
+
+        encoded.clear();
+        encoded.resize(max_encoded_len(count), 0xFF);
+        let encoded_len = encode::<E>(&nums, &mut encoded);
+        assert_eq!(
+            &encode_to_vec::<Scalar>(&nums)[..],
+            &encoded[0..encoded_len]
+        );
+
+        decoded.clear();
+        decoded.resize(count, 0);
+        assert_eq!(
+            encoded_len,
+            decode::<D>(&encoded[0..encoded_len], count, &mut decoded)
+        );
+        assert_eq!(nums, decoded);
+    }
+}
+
+fn do_every_len_roundtrip<E: Encoder16, D: Decoder16>() {
+    let all_nums: Vec<u16> = (0..100_u32).map(|i| (i * i * 7) as u16).collect();
+
+    for len in 0..all_nums.len() {
+        let nums = &all_nums[0..len];
+        let encoded = encode_to_vec::<E>(nums);
+        assert_eq!(encode_to_vec::<Scalar>(nums), encoded);
+
+        assert_eq!(nums, &decode_to_vec::<D>(&encoded, len)[..]);
+    }
+}
@@ -0,0 +1,354 @@
+use rand::Rng;
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+use stream_vbyte::aarch64;
+#[cfg(any(feature = "x86_sse41", feature = "x86_ssse3"))]
+use stream_vbyte::x86;
+use stream_vbyte::{
+    decode::{DecodeError, StreamVbyteDecoder},
+    encode::StreamVbyteEncoder,
+    scalar::Scalar,
+    svb64::{
+        cursor::DecodeCursor64, decode, decode_to_vec, encode, encode_to_vec, max_encoded_len,
+        Decoder64, Encoder64,
+    },
+};
+
+#[test]
+fn random_roundtrip_scalar_scalar() {
+    do_random_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn random_roundtrip_sse41_scalar() {
+    do_random_roundtrip::<x86::Sse41, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_roundtrip_scalar_ssse3() {
+    do_random_roundtrip::<Scalar, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_ssse3"))]
+#[test]
+fn random_roundtrip_sse41_ssse3() {
+    do_random_roundtrip::<x86::Sse41, x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_roundtrip_ssse3_ssse3() {
+    do_random_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn random_roundtrip_neon_neon() {
+    do_random_roundtrip::<aarch64::NeonEncoder, aarch64::NeonDecoder>();
+}
+
+#[test]
+fn random_roundtrip_default_encoder_decoder() {
+    do_random_roundtrip::<StreamVbyteEncoder, StreamVbyteDecoder>();
+}
+
+#[test]
+fn all_same_single_byte_scalar_scalar() {
+    do_all_same_single_byte::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn all_same_single_byte_ssse3_ssse3() {
+    do_all_same_single_byte::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[test]
+fn partial_final_quad_roundtrip_scalar() {
+    do_partial_final_quad_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn partial_final_quad_roundtrip_ssse3() {
+    do_partial_final_quad_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn partial_final_quad_roundtrip_neon() {
+    do_partial_final_quad_roundtrip::<aarch64::NeonEncoder, aarch64::NeonDecoder>();
+}
+
+#[test]
+fn known_output() {
+    let nums = [1, 300, 70_000, 0x1_2345_6789, 0xFF, 0x1_0000];
+    let encoded = encode_to_vec::<Scalar>(&nums);
+
+    #[rustfmt::skip]
+    let expected = vec![
+        // lengths 1, 2, 4 and 8
+        0b11_10_01_00,
+        // the trailing partial quad of 2, where the second takes 4 bytes and the
+        // unused bits are clear
+        0b00_00_10_00,
+        1,
+        0x2C, 0x01,
+        0x70, 0x11, 0x01, 0x00,
+        0x89, 0x67, 0x45, 0x23, 0x01, 0x00, 0x00, 0x00,
+        0xFF,
+        0x00, 0x00, 0x01, 0x00,
+    ];
+    assert_eq!(expected, encoded);
+
+    assert_eq!(
+        &nums[..],
+        &decode_to_vec::<Scalar>(&encoded, nums.len())[..]
+    );
+}
+
+#[test]
+fn max_encoded_len_counts_partial_quad() {
+    assert_eq!(0, max_encoded_len(0));
+    assert_eq!(1 + 8, max_encoded_len(1));
+    assert_eq!(1 + 32, max_encoded_len(4));
+    assert_eq!(2 + 40, max_encoded_len(5));
+}
+
+#[test]
+#[should_panic(expected = "output buffer was not large enough")]
+fn decode_panics_on_short_output() {
+    let encoded = encode_to_vec::<Scalar>(&[1, 2, 3]);
+
+    decode::<Scalar>(&encoded, 3, &mut [0; 2]);
+}
+
+#[test]
+fn try_new_truncated_input_errors() {
+    let nums: Vec<u64> = (0..10).map(|i| i << 20).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+
+    assert_eq!(
+        DecodeError::TruncatedControlBytes {
+            required: 3,
+            actual: 2
+        },
+        DecodeCursor64::try_new(&encoded[0..2], nums.len()).unwrap_err()
+    );
+
+    for len in 3..encoded.len() {
+        assert_eq!(
+            DecodeError::TruncatedData {
+                required: encoded.len() - 3,
+                actual: len - 3
+            },
+            DecodeCursor64::try_new(&encoded[0..len], nums.len()).unwrap_err()
+        );
+    }
+
+    assert!(DecodeCursor64::try_new(&encoded, nums.len()).is_ok());
+}
+
+#[test]
+fn try_new_random_garbage_doesnt_panic_scalar() {
+    do_try_new_random_garbage_doesnt_panic::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn try_new_random_garbage_doesnt_panic_ssse3() {
+    do_try_new_random_garbage_doesnt_panic::<x86::Ssse3>();
+}
+
+#[test]
+fn cursor_skip_every_len_scalar() {
+    do_cursor_skip_every_len::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn cursor_skip_every_len_ssse3() {
+    do_cursor_skip_every_len::<x86::Ssse3>();
+}
+
+#[test]
+fn cursor_random_skip_and_decode_scalar() {
+    do_cursor_random_skip_and_decode::<Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn cursor_random_skip_and_decode_ssse3() {
+    do_cursor_random_skip_and_decode::<x86::Ssse3>();
+}
+
+#[test]
+fn cursor_seek_to_back_and_forth() {
+    let nums: Vec<u64> = (0..50).map(|i| i * 0x1234_5678).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+    let mut cursor = DecodeCursor64::new(&encoded, nums.len());
+    let mut decoded = [0; 3];
+
+    for &index in [30, 7, 7, 48, 0, 21].iter() {
+        cursor.seek_to(index);
+        assert_eq!(index, cursor.position());
+
+        let count = cursor.decode_slice::<Scalar>(&mut decoded);
+        assert_eq!(&nums[index..(index + count)], &decoded[0..count]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Can't skip past the end of the input")]
+fn cursor_skip_past_end_panics() {
+    let encoded = encode_to_vec::<Scalar>(&[1, 2, 3]);
+    let mut cursor = DecodeCursor64::new(&encoded, 3);
+
+    cursor.skip(4);
+}
+
+fn do_random_roundtrip<E: Encoder64, D: Decoder64>() {
+    let mut rng = rand::weak_rng();
+    let mut nums: Vec<u64> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 1000);
+        nums.extend((0..count).map(|_| random_u64(&mut rng)));
+
+        encoded.clear();
+        encoded.resize(max_encoded_len(count), 0xFF);
+        let encoded_len = encode::<E>(&nums, &mut encoded);
+        assert_eq!(
+            &encode_to_vec::<Scalar>(&nums)[..],
+            &encoded[0..encoded_len]
+        );
+
+        decoded.clear();
+        decoded.resize(count, 0);
+        assert_eq!(
+            encoded_len,
+            decode::<D>(&encoded[0..encoded_len], count, &mut decoded)
+        );
+        assert_eq!(nums, decoded);
+    }
+}
+
+fn do_all_same_single_byte<E: Encoder64, D: Decoder64>() {
+    // every byte value, so some of them look like 8-byte numbers' bytes
+    for num in 0..256_u64 {
+        let nums = vec![num; 100];
+        let encoded = encode_to_vec::<E>(&nums);
+
+        // 1 byte per number
+        assert_eq!(25 + 100, encoded.len());
+        assert!(encoded[0..25].iter().all(|&b| b == 0));
+        assert_eq!(nums, decode_to_vec::<D>(&encoded, nums.len()));
+    }
+}
+
+fn do_partial_final_quad_roundtrip<E: Encoder64, D: Decoder64>() {
+    let all_nums: Vec<u64> = (0..100_u64)
+        .map(|i| i.wrapping_mul(0x0123_4567_89AB_CDEF) >> (i % 64))
+        .collect();
+
+    for len in 0..all_nums.len() {
+        let nums = &all_nums[0..len];
+        let encoded = encode_to_vec::<E>(nums);
+        assert_eq!(encode_to_vec::<Scalar>(nums), encoded);
+
+        assert_eq!(nums, &decode_to_vec::<D>(&encoded, len)[..]);
+    }
+}
+
+fn do_try_new_random_garbage_doesnt_panic<D: Decoder64>() {
+    let mut rng = rand::weak_rng();
+    let mut garbage = Vec::new();
+    let mut decoded = Vec::new();
+
+    for _ in 0..1_000 {
+        garbage.clear();
+        let len = rng.gen_range(0, 200);
+        garbage.extend((0..len).map(|_| rng.gen::<u8>()));
+
+        let count = rng.gen_range(0, 100);
+
+        if let Ok(mut cursor) = DecodeCursor64::try_new(&garbage, count) {
+            decoded.clear();
+            decoded.resize(count, 0);
+
+            assert_eq!(count, cursor.decode_slice::<D>(&mut decoded));
+            assert!(cursor.input_consumed() <= garbage.len());
+        }
+    }
+}
+
+fn do_cursor_skip_every_len<D: Decoder64>() {
+    let nums: Vec<u64> = (0..100_u64).map(|i| (i * 7) << (i % 60)).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+    let mut decoded = vec![0; nums.len()];
+
+    for skip in 0..=nums.len() {
+        let mut cursor = DecodeCursor64::new(&encoded, nums.len());
+        cursor.skip(skip);
+        assert_eq!(skip, cursor.position());
+
+        let count = cursor.decode_slice::<D>(&mut decoded);
+        assert_eq!(nums.len() - skip, count);
+        assert_eq!(&nums[skip..], &decoded[0..count]);
+
+        assert!(!cursor.has_more());
+        assert_eq!(encoded.len(), cursor.input_consumed());
+    }
+}
+
+fn do_cursor_random_skip_and_decode<D: Decoder64>() {
+    let mut rng = rand::weak_rng();
+    let mut buf = [0; 20];
+
+    for _ in 0..200 {
+        let count = rng.gen_range(0, 500);
+        let nums: Vec<u64> = (0..count).map(|_| random_u64(&mut rng)).collect();
+        let encoded = encode_to_vec::<Scalar>(&nums);
+
+        let mut cursor = DecodeCursor64::new(&encoded, count);
+        let mut expected_pos = 0;
+
+        while cursor.has_more() {
+            let remaining = count - expected_pos;
+
+            if rng.gen_weighted_bool(3) {
+                let to_skip = rng.gen_range(0, remaining + 1);
+                cursor.skip(to_skip);
+                expected_pos += to_skip;
+            } else {
+                let buf_len = rng.gen_range(0, buf.len() + 1);
+                let decoded = cursor.decode_slice::<D>(&mut buf[0..buf_len]);
+                assert_eq!(std::cmp::min(buf_len, remaining), decoded);
+                assert_eq!(
+                    &nums[expected_pos..(expected_pos + decoded)],
+                    &buf[0..decoded]
+                );
+                expected_pos += decoded;
+            }
+
+            assert_eq!(expected_pos, cursor.position());
+        }
+
+        assert_eq!(encoded.len(), cursor.input_consumed());
+    }
+}
+
+/// A number that is equally likely to be any of the 4 encoded lengths
+fn random_u64<R: Rng>(rng: &mut R) -> u64 {
+    match rng.gen_range(0, 4) {
+        0 => rng.gen_range(0, 0x100),
+        1 => rng.gen_range(0x100, 0x1_0000),
+        2 => rng.gen_range(0x1_0000, 0x1_0000_0000),
+        _ => rng.gen_range(0x1_0000_0000, u64::MAX),
+    }
+}
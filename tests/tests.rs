@@ -1,11 +1,26 @@
 use std::{cmp, fs::File, io::Read};
 
 use rand::Rng;
-#[cfg(feature = "x86_sse41")]
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+use stream_vbyte::aarch64;
+#[cfg(any(
+    feature = "x86_sse41",
+    feature = "x86_ssse3",
+    feature = "x86_avx2",
+    feature = "x86_avx512"
+))]
 use stream_vbyte::x86;
 use stream_vbyte::{
-    decode::{decode, Decoder, WriteQuadToSlice},
-    encode::{encode, Encoder},
+    decode::{
+        decode, decode_auto, decode_delta, decode_to_vec, decode_zigzag, decode_zigzag_delta,
+        encoded_len_from_control_bytes, try_decode, validate, DecodeError, Decoder,
+        DeltaDecodeQuad, WriteQuadToSlice, ZigzagDecodeQuad,
+    },
+    encode::{
+        encode, encode_append, encode_auto, encode_delta, encode_iter, encode_to_vec,
+        encode_zigzag, encode_zigzag_delta, encoded_len, max_encoded_len, zigzag_encode_num,
+        DeltaEncodeQuad, EncodedLenQuads, Encoder, ZigzagEncodeQuad,
+    },
     scalar::Scalar,
 };
 
@@ -37,6 +52,24 @@ fn random_roundtrip_sse41_ssse3() {
     do_random_roundtrip::<x86::Sse41, x86::Ssse3>();
 }
 
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_roundtrip_ssse3_ssse3() {
+    do_random_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn random_roundtrip_scalar_avx2() {
+    do_random_roundtrip::<Scalar, x86::Avx2>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_avx2"))]
+#[test]
+fn random_roundtrip_sse41_avx2() {
+    do_random_roundtrip::<x86::Sse41, x86::Avx2>();
+}
+
 #[test]
 fn all_same_single_byte_scalar_scalar() {
     do_all_same_single_byte::<Scalar, Scalar>();
@@ -60,6 +93,24 @@ fn all_same_single_byte_sse41_ssse3() {
     do_all_same_single_byte::<x86::Sse41, x86::Ssse3>();
 }
 
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn all_same_single_byte_ssse3_ssse3() {
+    do_all_same_single_byte::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn all_same_single_byte_scalar_avx2() {
+    do_all_same_single_byte::<Scalar, x86::Avx2>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_avx2"))]
+#[test]
+fn all_same_single_byte_sse41_avx2() {
+    do_all_same_single_byte::<x86::Sse41, x86::Avx2>();
+}
+
 #[test]
 fn partial_final_quad_roundtrip_scalar() {
     do_partial_final_quad_roundtrip_scalar::<Scalar>()
@@ -71,6 +122,12 @@ fn partial_final_quad_roundtrip_sse41() {
     do_partial_final_quad_roundtrip_scalar::<x86::Sse41>()
 }
 
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn partial_final_quad_roundtrip_ssse3() {
+    do_partial_final_quad_roundtrip_scalar::<x86::Ssse3>()
+}
+
 #[test]
 fn encode_scalar_compare_reference_impl() {
     do_compare_reference_data::<Scalar>()
@@ -82,6 +139,585 @@ fn encode_sse41_compare_reference_impl() {
     do_compare_reference_data::<x86::Sse41>()
 }
 
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn encode_ssse3_compare_reference_impl() {
+    do_compare_reference_data::<x86::Ssse3>()
+}
+
+#[test]
+fn random_roundtrip_auto_matches_scalar() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut auto_encoded = Vec::new();
+    let mut decoded = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 1000);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+
+        let garbage: u8 = rng.gen();
+        encoded.clear();
+        encoded.resize(count * 5, garbage);
+        auto_encoded.clear();
+        auto_encoded.resize(count * 5, garbage);
+        decoded.clear();
+        decoded.resize(cmp::max(4, count), garbage as u32);
+
+        // whichever encoder is picked, the output is the same
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+        assert_eq!(encoded_len, encode_auto(&nums, &mut auto_encoded));
+        assert_eq!(encoded, auto_encoded);
+
+        assert_eq!(
+            encoded_len,
+            decode_auto(&encoded[0..encoded_len], count, &mut decoded)
+        );
+        assert_eq!(&nums[..], &decoded[0..count]);
+    }
+}
+
+#[test]
+fn random_roundtrip_to_vec_matches_scalar() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 1000);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+
+        encoded.clear();
+        encoded.resize(max_encoded_len(count), 0);
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+
+        let vec_encoded = encode_to_vec::<Scalar>(&nums);
+        assert_eq!(&encoded[0..encoded_len], &vec_encoded[..]);
+
+        assert_eq!(nums, decode_to_vec::<Scalar>(&vec_encoded, count));
+    }
+}
+
+#[test]
+fn encode_append_keeps_existing_bytes() {
+    let nums: Vec<u32> = (0..1_000).map(|i| i * 1_000).collect();
+    let mut appended = vec![1, 2, 3];
+
+    let first_len = encode_append::<Scalar>(&nums[0..10], &mut appended);
+    let second_len = encode_append::<Scalar>(&nums[10..], &mut appended);
+    assert_eq!(3 + first_len + second_len, appended.len());
+
+    assert_eq!(&[1, 2, 3], &appended[0..3]);
+    assert_eq!(
+        &nums[0..10],
+        &decode_to_vec::<Scalar>(&appended[3..(3 + first_len)], 10)[..]
+    );
+    assert_eq!(
+        &nums[10..],
+        &decode_to_vec::<Scalar>(&appended[(3 + first_len)..], nums.len() - 10)[..]
+    );
+}
+
+#[test]
+fn random_encode_iter_matches_encode_scalar() {
+    do_random_encode_iter_matches_encode::<Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn random_encode_iter_matches_encode_sse41() {
+    do_random_encode_iter_matches_encode::<x86::Sse41>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_encode_iter_matches_encode_ssse3() {
+    do_random_encode_iter_matches_encode::<x86::Ssse3>();
+}
+
+#[test]
+fn encode_iter_wrong_size_hint() {
+    let nums: Vec<u32> = (0..1_000).map(|i| i * 1_000).collect();
+    let expected = encode_to_vec::<Scalar>(&nums);
+
+    for &hint in [0, 1, 4, 5, 999, 1_000, 1_001, 5_000].iter() {
+        let mut encoded = vec![1, 2, 3];
+        let iter = SizeHintIter {
+            inner: nums.iter().cloned(),
+            hint,
+        };
+        assert_eq!(
+            (nums.len(), expected.len()),
+            encode_iter::<Scalar, _>(iter, &mut encoded)
+        );
+        assert_eq!(&[1, 2, 3], &encoded[0..3]);
+        assert_eq!(&expected[..], &encoded[3..]);
+    }
+}
+
+#[test]
+fn random_encoded_len_matches_encode() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 1000);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+
+        encoded.clear();
+        encoded.resize(max_encoded_len(count), 0);
+        let expected_len = encode::<Scalar>(&nums, &mut encoded);
+
+        assert_eq!(expected_len, encoded_len(&nums));
+
+        // only the control bytes are needed
+        let control_bytes_len = (count + 3) / 4;
+        assert_eq!(
+            Ok(expected_len),
+            encoded_len_from_control_bytes(&encoded[0..control_bytes_len], count)
+        );
+    }
+}
+
+#[test]
+fn encoded_len_from_control_bytes_truncated_control_bytes() {
+    let nums: Vec<u32> = (0..9).collect();
+    let encoded = encode_to_vec::<Scalar>(&nums);
+
+    assert_eq!(
+        Err(DecodeError::TruncatedControlBytes {
+            required: 3,
+            actual: 2
+        }),
+        encoded_len_from_control_bytes(&encoded[0..2], nums.len())
+    );
+}
+
+#[test]
+fn encoded_len_quads_scalar() {
+    do_encoded_len_quads::<Scalar>()
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn encoded_len_quads_sse41() {
+    do_encoded_len_quads::<x86::Sse41>()
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn encoded_len_quads_ssse3() {
+    do_encoded_len_quads::<x86::Ssse3>()
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn encoded_len_quads_neon() {
+    do_encoded_len_quads::<aarch64::NeonEncoder>()
+}
+
+#[test]
+fn random_delta_roundtrip_scalar_scalar() {
+    do_random_delta_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn random_delta_roundtrip_sse41_scalar() {
+    do_random_delta_roundtrip::<x86::Sse41, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_delta_roundtrip_scalar_ssse3() {
+    do_random_delta_roundtrip::<Scalar, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_ssse3"))]
+#[test]
+fn random_delta_roundtrip_sse41_ssse3() {
+    do_random_delta_roundtrip::<x86::Sse41, x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_delta_roundtrip_ssse3_ssse3() {
+    do_random_delta_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn random_delta_roundtrip_scalar_avx2() {
+    do_random_delta_roundtrip::<Scalar, x86::Avx2>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_avx2"))]
+#[test]
+fn random_delta_roundtrip_sse41_avx2() {
+    do_random_delta_roundtrip::<x86::Sse41, x86::Avx2>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn random_delta_roundtrip_neon_neon() {
+    do_random_delta_roundtrip::<aarch64::NeonEncoder, aarch64::NeonDecoder>();
+}
+
+#[test]
+fn encode_delta_known_output_scalar() {
+    do_delta_known_output::<Scalar>()
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn encode_delta_known_output_sse41() {
+    do_delta_known_output::<x86::Sse41>()
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn encode_delta_known_output_ssse3() {
+    do_delta_known_output::<x86::Ssse3>()
+}
+
+#[test]
+fn random_zigzag_roundtrip_scalar_scalar() {
+    do_random_zigzag_roundtrip::<Scalar, Scalar>();
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn random_zigzag_roundtrip_sse41_scalar() {
+    do_random_zigzag_roundtrip::<x86::Sse41, Scalar>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_zigzag_roundtrip_scalar_ssse3() {
+    do_random_zigzag_roundtrip::<Scalar, x86::Ssse3>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_ssse3"))]
+#[test]
+fn random_zigzag_roundtrip_sse41_ssse3() {
+    do_random_zigzag_roundtrip::<x86::Sse41, x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn random_zigzag_roundtrip_ssse3_ssse3() {
+    do_random_zigzag_roundtrip::<x86::Ssse3, x86::Ssse3>();
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn random_zigzag_roundtrip_scalar_avx2() {
+    do_random_zigzag_roundtrip::<Scalar, x86::Avx2>();
+}
+
+#[cfg(all(feature = "x86_sse41", feature = "x86_avx2"))]
+#[test]
+fn random_zigzag_roundtrip_sse41_avx2() {
+    do_random_zigzag_roundtrip::<x86::Sse41, x86::Avx2>();
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn random_zigzag_roundtrip_neon_neon() {
+    do_random_zigzag_roundtrip::<aarch64::NeonEncoder, aarch64::NeonDecoder>();
+}
+
+#[test]
+fn encode_zigzag_known_output_scalar() {
+    do_zigzag_known_output::<Scalar>()
+}
+
+#[cfg(feature = "x86_sse41")]
+#[test]
+fn encode_zigzag_known_output_sse41() {
+    do_zigzag_known_output::<x86::Sse41>()
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn encode_zigzag_known_output_ssse3() {
+    do_zigzag_known_output::<x86::Ssse3>()
+}
+
+#[test]
+fn try_decode_truncated_input_errors_scalar() {
+    do_try_decode_truncated_input_errors::<Scalar>()
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn try_decode_truncated_input_errors_ssse3() {
+    do_try_decode_truncated_input_errors::<x86::Ssse3>()
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn try_decode_truncated_input_errors_neon() {
+    do_try_decode_truncated_input_errors::<aarch64::NeonDecoder>()
+}
+
+#[test]
+fn try_decode_random_garbage_doesnt_panic_scalar() {
+    do_try_decode_random_garbage_doesnt_panic::<Scalar>()
+}
+
+#[cfg(feature = "x86_avx2")]
+#[test]
+fn try_decode_random_garbage_doesnt_panic_avx2() {
+    do_try_decode_random_garbage_doesnt_panic::<x86::Avx2>()
+}
+
+#[cfg(feature = "x86_ssse3")]
+#[test]
+fn try_decode_random_garbage_doesnt_panic_ssse3() {
+    do_try_decode_random_garbage_doesnt_panic::<x86::Ssse3>()
+}
+
+#[cfg(all(feature = "aarch64_neon", target_arch = "aarch64"))]
+#[test]
+fn try_decode_random_garbage_doesnt_panic_neon() {
+    do_try_decode_random_garbage_doesnt_panic::<aarch64::NeonDecoder>()
+}
+
+#[test]
+fn try_decode_output_too_small_errors() {
+    let nums: Vec<u32> = (0..10).collect();
+    let mut encoded = vec![0; nums.len() * 5];
+    let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+
+    let mut decoded = vec![0; 9];
+    assert_eq!(
+        Err(DecodeError::OutputTooSmall {
+            required: 10,
+            actual: 9
+        }),
+        try_decode::<Scalar>(&encoded[0..encoded_len], nums.len(), &mut decoded)
+    );
+}
+
+#[test]
+fn validate_accepts_exactly_encoded_input() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+
+    for count in 0..100 {
+        nums.clear();
+        encoded.clear();
+
+        for i in RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count) {
+            nums.push(i);
+        }
+
+        encoded.resize(count * 5 + 1, 0);
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+        let control_bytes_len = (count + 3) / 4;
+
+        assert_eq!(Ok(encoded_len), validate(&encoded[0..encoded_len], count));
+
+        if count > 0 {
+            assert_eq!(
+                Err(DecodeError::TruncatedData {
+                    required: encoded_len - control_bytes_len,
+                    actual: encoded_len - control_bytes_len - 1
+                }),
+                validate(&encoded[0..encoded_len - 1], count)
+            );
+        }
+
+        assert_eq!(
+            Err(DecodeError::TrailingData {
+                required: encoded_len - control_bytes_len,
+                actual: encoded_len - control_bytes_len + 1
+            }),
+            validate(&encoded[0..encoded_len + 1], count)
+        );
+    }
+}
+
+#[test]
+fn validate_rejects_unused_bits_in_partial_control_byte() {
+    let nums: Vec<u32> = (0..7).collect();
+    let mut encoded = vec![0; nums.len() * 5];
+    let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+    encoded.truncate(encoded_len);
+
+    // 3 leftover numbers use the low 6 bits of the second control byte
+    assert_eq!(0, encoded[1]);
+    encoded[1] = 0x40;
+
+    assert_eq!(
+        Err(DecodeError::UnusedControlBitsSet { control_byte: 0x40 }),
+        validate(&encoded, nums.len())
+    );
+}
+
+#[test]
+fn validate_truncated_control_bytes() {
+    assert_eq!(
+        Err(DecodeError::TruncatedControlBytes {
+            required: 2,
+            actual: 1
+        }),
+        validate(&[0], 5)
+    );
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn random_roundtrip_avx512_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_random_roundtrip::<x86::Avx512Encoder, x86::Avx512Decoder>();
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn all_same_single_byte_avx512_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_all_same_single_byte::<x86::Avx512Encoder, x86::Avx512Decoder>();
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn encode_avx512_compare_reference_impl() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_compare_reference_data::<x86::Avx512Encoder>()
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn random_delta_roundtrip_avx512_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_random_delta_roundtrip::<x86::Avx512Encoder, x86::Avx512Decoder>();
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn encode_delta_known_output_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_delta_known_output::<x86::Avx512Encoder>()
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn random_zigzag_roundtrip_avx512_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_random_zigzag_roundtrip::<x86::Avx512Encoder, x86::Avx512Decoder>();
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn encode_zigzag_known_output_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_zigzag_known_output::<x86::Avx512Encoder>()
+}
+
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn try_decode_random_garbage_doesnt_panic_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_try_decode_random_garbage_doesnt_panic::<x86::Avx512Decoder>()
+}
+
+/// AVX-512 isn't widespread, so tests for it are skipped rather than failing
+/// (or silently testing the `Scalar` fallback) on CPUs without it.
+#[cfg(feature = "x86_avx512")]
+#[test]
+fn encoded_len_quads_avx512() {
+    if !avx512_vbmi_supported() {
+        return;
+    }
+
+    do_encoded_len_quads::<x86::Avx512Encoder>()
+}
+
+#[cfg(feature = "x86_avx512")]
+fn avx512_vbmi_supported() -> bool {
+    is_x86_feature_detected!("avx512f")
+        && is_x86_feature_detected!("avx512bw")
+        && is_x86_feature_detected!("avx512vbmi")
+}
+
+fn do_random_encode_iter_matches_encode<E: Encoder>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 2000);
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+        let expected = encode_to_vec::<Scalar>(&nums);
+
+        // with an exact size hint
+        let mut encoded = vec![0xFF; 7];
+        assert_eq!(
+            (count, expected.len()),
+            encode_iter::<E, _>(nums.iter().cloned(), &mut encoded)
+        );
+        assert_eq!(&[0xFF; 7], &encoded[0..7]);
+        assert_eq!(&expected[..], &encoded[7..]);
+
+        // and without one
+        encoded.clear();
+        assert_eq!(
+            (count, expected.len()),
+            encode_iter::<E, _>(nums.iter().cloned().filter(|_| true), &mut encoded)
+        );
+        assert_eq!(expected, encoded);
+    }
+}
+
+/// Reports whatever size hint it's told to, right or wrong
+struct SizeHintIter<I> {
+    inner: I,
+    hint: usize,
+}
+
+impl<I: Iterator<Item = u32>> Iterator for SizeHintIter<I> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.hint, None)
+    }
+}
+
 fn do_random_roundtrip<E: Encoder, D: Decoder + WriteQuadToSlice>() {
     let mut nums: Vec<u32> = Vec::new();
     let mut encoded = Vec::new();
@@ -206,8 +842,7 @@ fn do_compare_reference_data<E: Encoder>() {
         .unwrap();
     let ref_data = ref_data;
 
-    let mut rust_encoded_data = Vec::new();
-    rust_encoded_data.resize(ref_nums.len() * 5, 0);
+    let mut rust_encoded_data = vec![0; ref_nums.len() * 5];
     let bytes_written = encode::<E>(&ref_nums, &mut rust_encoded_data);
     rust_encoded_data.truncate(bytes_written);
 
@@ -215,6 +850,300 @@ fn do_compare_reference_data<E: Encoder>() {
     assert_eq!(ref_data, rust_encoded_data);
 }
 
+fn do_encoded_len_quads<E: EncodedLenQuads>() {
+    // either side of each length boundary, and of the sign bit
+    let boundaries: [u32; 12] = [
+        0,
+        0xFF,
+        0x100,
+        0xFFFF,
+        0x1_0000,
+        0xFF_FFFF,
+        0x100_0000,
+        0x7FFF_FFFF,
+        0x8000_0000,
+        0x80FF_FFFF,
+        0xFFFF_FF00,
+        u32::MAX,
+    ];
+    let (nums_measured, len) = E::encoded_len_quads(&boundaries);
+    let expected_lens = [1, 1, 2, 2, 3, 3, 4, 4, 4, 4, 4, 4];
+    assert_eq!(expected_lens[0..nums_measured].iter().sum::<usize>(), len);
+
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..1_000 {
+        nums.clear();
+
+        let count = rng.gen_range(0, 250) * 4;
+        nums.extend(RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count));
+
+        let (nums_measured, len) = E::encoded_len_quads(&nums);
+        assert_eq!(0, nums_measured % 4);
+        assert!(nums_measured <= count);
+
+        encoded.clear();
+        encoded.resize(max_encoded_len(nums_measured), 0);
+        let encoded_len = encode::<Scalar>(&nums[0..nums_measured], &mut encoded);
+
+        assert_eq!(encoded_len - nums_measured / 4, len);
+    }
+}
+
+fn do_random_delta_roundtrip<E: DeltaEncodeQuad, D: DeltaDecodeQuad + WriteQuadToSlice>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut deltas: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut delta_encoded = Vec::new();
+    let mut decoded = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..10_000 {
+        nums.clear();
+        deltas.clear();
+
+        let count = rng.gen_range(0, 1000);
+        let prev: u32 = rng.gen();
+
+        // sorted increasing like a posting list, wrapping around now and then to
+        // exercise wrapping arithmetic
+        let mut num = prev;
+        for i in RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count) {
+            let next = num.wrapping_add(i);
+            nums.push(next);
+            deltas.push(i);
+            num = next;
+        }
+
+        let extra_slots = 1000;
+        let garbage: u8 = rng.gen();
+        encoded.clear();
+        encoded.resize(count * 5 + extra_slots, garbage);
+        delta_encoded.clear();
+        delta_encoded.resize(count * 5 + extra_slots, garbage);
+        decoded.clear();
+        decoded.resize(count + extra_slots, garbage as u32);
+
+        // delta encoding is just encoding the deltas
+        let encoded_len = encode::<Scalar>(&deltas, &mut encoded);
+        assert_eq!(
+            encoded_len,
+            encode_delta::<E>(&nums, prev, &mut delta_encoded)
+        );
+        assert_eq!(&encoded[..], &delta_encoded[..]);
+
+        assert_eq!(
+            encoded_len,
+            decode_delta::<D>(&encoded[0..encoded_len], count, prev, &mut decoded)
+        );
+        assert_eq!(&nums[..], &decoded[0..count]);
+        // extra u32s in decoded were not touched
+        for (i, &n) in decoded[count..].iter().enumerate() {
+            assert_eq!(garbage as u32, n, "index {}", i);
+        }
+    }
+}
+
+fn do_delta_known_output<E: DeltaEncodeQuad>() {
+    let nums = vec![10, 20, 300, 301, 70_000];
+    let mut encoded = vec![0xFF; nums.len() * 5];
+
+    // deltas from 5 are 5, 10, 280, 1, 69_699
+    #[rustfmt::skip]
+    let expected = vec![0x10, 0x02,
+                        0x05,
+                        0x0A,
+                        0x18, 0x01,
+                        0x01,
+                        0x43, 0x10, 0x01];
+    assert_eq!(expected.len(), encode_delta::<E>(&nums, 5, &mut encoded));
+    assert_eq!(&expected[..], &encoded[0..expected.len()]);
+
+    let mut decoded = vec![0; nums.len()];
+    decode_delta::<Scalar>(&encoded, nums.len(), 5, &mut decoded);
+    assert_eq!(nums, decoded);
+}
+
+fn do_random_zigzag_roundtrip<E, D>()
+where
+    E: ZigzagEncodeQuad + DeltaEncodeQuad,
+    D: ZigzagDecodeQuad + DeltaDecodeQuad + WriteQuadToSlice,
+{
+    let mut nums: Vec<i32> = Vec::new();
+    let mut mapped: Vec<u32> = Vec::new();
+    let mut mapped_deltas: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut zigzag_encoded = Vec::new();
+    let mut decoded = Vec::new();
+    let mut rng = rand::weak_rng();
+    for _ in 0..10_000 {
+        nums.clear();
+        mapped.clear();
+        mapped_deltas.clear();
+
+        let count = rng.gen_range(0, 1000);
+        let prev: i32 = rng.gen();
+
+        // small magnitudes of either sign, like a noisy sensor reading
+        let mut last = prev;
+        for i in RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count) {
+            let num = if rng.gen() {
+                i as i32
+            } else {
+                (i as i32).wrapping_neg()
+            };
+            nums.push(num);
+            mapped.push(zigzag_encode_num(num));
+            mapped_deltas.push(zigzag_encode_num(num.wrapping_sub(last)));
+            last = num;
+        }
+
+        let extra_slots = 1000;
+        let garbage: u8 = rng.gen();
+        encoded.clear();
+        encoded.resize(count * 5 + extra_slots, garbage);
+        zigzag_encoded.clear();
+        zigzag_encoded.resize(count * 5 + extra_slots, garbage);
+        decoded.clear();
+        decoded.resize(count + extra_slots, garbage as i32);
+
+        // zigzag encoding is just encoding the mapped numbers
+        let encoded_len = encode::<Scalar>(&mapped, &mut encoded);
+        assert_eq!(encoded_len, encode_zigzag::<E>(&nums, &mut zigzag_encoded));
+        assert_eq!(&encoded[..], &zigzag_encoded[..]);
+
+        assert_eq!(
+            encoded_len,
+            decode_zigzag::<D>(&encoded[0..encoded_len], count, &mut decoded)
+        );
+        assert_eq!(&nums[..], &decoded[0..count]);
+
+        // likewise for the mapped deltas
+        let encoded_len = encode::<Scalar>(&mapped_deltas, &mut encoded);
+        assert_eq!(
+            encoded_len,
+            encode_zigzag_delta::<E>(&nums, prev, &mut zigzag_encoded)
+        );
+        assert_eq!(&encoded[..], &zigzag_encoded[..]);
+
+        assert_eq!(
+            encoded_len,
+            decode_zigzag_delta::<D>(&encoded[0..encoded_len], count, prev, &mut decoded)
+        );
+        assert_eq!(&nums[..], &decoded[0..count]);
+        // extra i32s in decoded were not touched
+        for (i, &n) in decoded[count..].iter().enumerate() {
+            assert_eq!(garbage as i32, n, "index {}", i);
+        }
+    }
+}
+
+fn do_zigzag_known_output<E: ZigzagEncodeQuad>() {
+    let nums = vec![0, -1, 1, -2, -200];
+    let mut encoded = vec![0xFF; nums.len() * 5];
+
+    // mapped to 0, 1, 2, 3, 399
+    #[rustfmt::skip]
+    let expected = [0x00, 0x01,
+                        0x00,
+                        0x01,
+                        0x02,
+                        0x03,
+                        0x8F, 0x01];
+    assert_eq!(expected.len(), encode_zigzag::<E>(&nums, &mut encoded));
+    assert_eq!(&expected[..], &encoded[0..expected.len()]);
+
+    let mut decoded = vec![0; nums.len()];
+    decode_zigzag::<Scalar>(&encoded, nums.len(), &mut decoded);
+    assert_eq!(nums, decoded);
+}
+
+fn do_try_decode_truncated_input_errors<D: Decoder + WriteQuadToSlice>() {
+    let mut nums: Vec<u32> = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+
+    for count in 0..100 {
+        nums.clear();
+        encoded.clear();
+        decoded.clear();
+
+        for i in RandomVarintEncodedLengthIter::new(rand::weak_rng()).take(count) {
+            nums.push(i);
+        }
+
+        encoded.resize(count * 5, 0);
+        let encoded_len = encode::<Scalar>(&nums, &mut encoded);
+        let control_bytes_len = (count + 3) / 4;
+        decoded.resize(count, 0);
+
+        for truncated_len in 0..encoded_len {
+            let err = try_decode::<D>(&encoded[0..truncated_len], count, &mut decoded).unwrap_err();
+
+            if truncated_len < control_bytes_len {
+                assert_eq!(
+                    DecodeError::TruncatedControlBytes {
+                        required: control_bytes_len,
+                        actual: truncated_len
+                    },
+                    err
+                );
+            } else {
+                assert_eq!(
+                    DecodeError::TruncatedData {
+                        required: encoded_len - control_bytes_len,
+                        actual: truncated_len - control_bytes_len
+                    },
+                    err
+                );
+            }
+        }
+
+        assert_eq!(
+            Ok(encoded_len),
+            try_decode::<D>(&encoded[0..encoded_len], count, &mut decoded)
+        );
+        assert_eq!(&nums[..], &decoded[..]);
+    }
+}
+
+fn do_try_decode_random_garbage_doesnt_panic<D: Decoder + WriteQuadToSlice>() {
+    let mut garbage: Vec<u8> = Vec::new();
+    let mut decoded = Vec::new();
+    let mut scalar_decoded = Vec::new();
+    let mut rng = rand::weak_rng();
+
+    for _ in 0..10_000 {
+        garbage.clear();
+        let garbage_len = rng.gen_range(0, 200);
+        for _ in 0..garbage_len {
+            garbage.push(rng.gen());
+        }
+
+        let count = rng.gen_range(0, 200);
+        decoded.clear();
+        decoded.resize(count, 0);
+        scalar_decoded.clear();
+        scalar_decoded.resize(count, 0);
+
+        match try_decode::<D>(&garbage, count, &mut decoded) {
+            Ok(bytes_read) => {
+                assert!(bytes_read <= garbage.len());
+                // whatever the garbage decodes to, all decoders should agree
+                assert_eq!(
+                    Ok(bytes_read),
+                    try_decode::<Scalar>(&garbage, count, &mut scalar_decoded)
+                );
+                assert_eq!(scalar_decoded, decoded);
+            }
+            Err(e) => assert_eq!(
+                Err(e),
+                try_decode::<Scalar>(&garbage, count, &mut scalar_decoded)
+            ),
+        }
+    }
+}
+
 fn do_partial_final_quad_roundtrip_scalar<E: Encoder>() {
     // easily recognizable bit patterns
     let nums = vec![0, 1 << 8, 3 << 16, 7 << 24, 2 << 8, 4 << 16];
@@ -230,7 +1159,7 @@ fn do_partial_final_quad_roundtrip_scalar<E: Encoder>() {
     }
 
     // output, broken down by number
-    #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[rustfmt::skip]
         let expected = vec![0xE4, 0x09,
                             0x00,
                             0x00, 0x01,
@@ -240,8 +1169,7 @@ fn do_partial_final_quad_roundtrip_scalar<E: Encoder>() {
                             0x00, 0x00, 0x04];
     assert_eq!(&expected[..], &encoded[0..encoded_len]);
 
-    let mut decoded = Vec::new();
-    decoded.resize(nums.len(), 0);
+    let mut decoded = vec![0; nums.len()];
     decode::<Scalar>(&encoded[..], nums.len(), &mut decoded);
     assert_eq!(nums, decoded);
 }